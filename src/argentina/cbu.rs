@@ -0,0 +1,153 @@
+/// Argentina CBU Validator
+///
+/// This module provides functionality to validate and parse the CBU (Clave Bancaria
+/// Uniforme), the 22-digit identifier of bank accounts used for transfers. It is made of
+/// two blocks, each ending with its own check digit:
+///
+/// - first block, 8 digits: bank code (3), branch code (4) and check digit, with weights
+///   `7139713`;
+/// - second block, 14 digits: account number (13) and check digit, with weights
+///   `3971397139713`.
+///
+/// In both blocks, the check digit is the complement to 10 of the last digit of the
+/// weighted sum.
+///
+/// # Usage
+///
+/// ```
+/// use docval::argentina::{ArgentinaCbuValidator, Cbu};
+///
+/// assert!(ArgentinaCbuValidator::is_valid("2850590940090418135201").is_ok());
+///
+/// let cbu = Cbu::parse("28505909 40090418135201").unwrap();
+/// assert_eq!(cbu.bank_code(), "285");
+/// assert_eq!(cbu.branch_code(), "0590");
+/// assert_eq!(cbu.account_number(), "4009041813520");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CBU. Removes non-digit characters, checks the length,
+///   and validates the check digits of both blocks.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct ArgentinaCbuValidator;
+
+const CBU_LENGTH: usize = 22;
+const FIRST_BLOCK_LENGTH: usize = 8;
+const FIRST_BLOCK_WEIGHTS: &[u32] = &[7, 1, 3, 9, 7, 1, 3];
+const SECOND_BLOCK_WEIGHTS: &[u32] = &[3, 9, 7, 1, 3, 9, 7, 1, 3, 9, 7, 1, 3];
+
+impl ArgentinaCbuValidator {
+    /// Validates if the given CBU is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CBU_LENGTH {
+            return Err("Invalid length");
+        }
+        let (first_block, second_block) = sanitized_value.split_at(FIRST_BLOCK_LENGTH);
+        if !Self::is_valid_block(first_block, FIRST_BLOCK_WEIGHTS) {
+            return Err("Invalid bank and branch checksum");
+        }
+        if !Self::is_valid_block(second_block, SECOND_BLOCK_WEIGHTS) {
+            return Err("Invalid account checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CBUs using the ArgentinaCbuValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Checks if the last digit of the block is the check digit of the others.
+    fn is_valid_block(block: &str, weights: &[u32]) -> bool {
+        let (digits, check_digit) = block.split_at(block.len() - 1);
+        let sum: u32 = digits
+            .chars()
+            .zip(weights)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        check_digit == ((10 - sum % 10) % 10).to_string()
+    }
+}
+
+/// A validated CBU, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cbu(String);
+
+impl Cbu {
+    /// Parses and validates the given CBU, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ArgentinaCbuValidator::is_valid(value)?;
+        Ok(Cbu(ArgentinaCbuValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 22 digits of the CBU.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the code of the bank that holds the account.
+    pub fn bank_code(&self) -> &str {
+        &self.0[0..3]
+    }
+
+    /// Returns the code of the branch that holds the account.
+    pub fn branch_code(&self) -> &str {
+        &self.0[3..7]
+    }
+
+    /// Returns the account number.
+    pub fn account_number(&self) -> &str {
+        &self.0[8..21]
+    }
+}
+
+impl FromStr for Cbu {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cbu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_block() {
+        assert!(ArgentinaCbuValidator::is_valid_block(
+            "28505909",
+            FIRST_BLOCK_WEIGHTS
+        ));
+        assert!(!ArgentinaCbuValidator::is_valid_block(
+            "28505908",
+            FIRST_BLOCK_WEIGHTS
+        ));
+    }
+}
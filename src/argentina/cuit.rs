@@ -0,0 +1,195 @@
+/// Argentina CUIT/CUIL Validator
+///
+/// This module provides functionality to validate and parse the CUIT (Clave Única de
+/// Identificación Tributaria) and the CUIL (Código Único de Identificación Laboral), which
+/// share the same 11-digit structure, formatted as `XX-XXXXXXXX-X`:
+///
+/// - 2 digits: type prefix. `20` and `24` identify men, `27` women, and `23` people of
+///   either sex whose number would otherwise have collided; `30`, `33` and `34` identify
+///   companies;
+/// - 8 digits: the DNI of the person, or a sequential number for companies;
+/// - 1 digit: check digit.
+///
+/// The first 10 digits are weighted with `5432765432`, and the check digit is the
+/// complement of the sum modulo 11, where 11 yields 0. A complement of 10 is never
+/// assigned: the AFIP changes the prefix to `23` or `33` instead.
+///
+/// # Usage
+///
+/// ```
+/// use docval::argentina::{ArgentinaCuitValidator, Cuit, CuitKind};
+///
+/// assert!(ArgentinaCuitValidator::is_valid("20-12345678-6").is_ok());
+///
+/// let cuit = Cuit::parse("27172543591").unwrap();
+/// assert_eq!(cuit.kind(), CuitKind::Person);
+/// assert_eq!(cuit.dni(), Some("17254359"));
+/// assert_eq!(cuit.to_string(), "27-17254359-1");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CUIT or CUIL. Removes non-digit characters, checks the
+///   length and the type prefix, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::Sex;
+
+pub struct ArgentinaCuitValidator;
+
+/// The kinds of taxpayers identified by the type prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CuitKind {
+    /// Natural person, whose DNI is embedded in the number (prefixes 20, 23, 24 and 27).
+    Person,
+    /// Legal entity (prefixes 30, 33 and 34).
+    Company,
+}
+
+impl CuitKind {
+    /// Returns the kind identified by the given type prefix, if any.
+    pub fn from_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            20 | 23 | 24 | 27 => Some(CuitKind::Person),
+            30 | 33 | 34 => Some(CuitKind::Company),
+            _ => None,
+        }
+    }
+}
+
+const CUIT_LENGTH: usize = 11;
+const MULTIPLIER_WEIGHTS: &[u32] = &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl ArgentinaCuitValidator {
+    /// Validates if the given CUIT or CUIL is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CUIT_LENGTH {
+            return Err("Invalid length");
+        }
+        let prefix = sanitized_value[..2].parse().expect("Validated digits");
+        if CuitKind::from_prefix(prefix).is_none() {
+            return Err("Invalid prefix");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..10]) {
+            Some(check_digit) if sanitized_value[10..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CUITs and CUILs using the ArgentinaCuitValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the first 10 digits, or `None` if no digit can be
+    /// assigned to them.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => Some(0),
+            10 => None,
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated CUIT or CUIL, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cuit(String);
+
+impl Cuit {
+    /// Parses and validates the given CUIT or CUIL, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ArgentinaCuitValidator::is_valid(value)?;
+        Ok(Cuit(ArgentinaCuitValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the CUIT.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the type prefix.
+    pub fn prefix(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+
+    /// Returns whether the CUIT belongs to a person or a company.
+    pub fn kind(&self) -> CuitKind {
+        CuitKind::from_prefix(self.prefix()).expect("Validated prefix")
+    }
+
+    /// Returns the DNI embedded in the CUIL or CUIT of a person, or `None` for companies.
+    pub fn dni(&self) -> Option<&str> {
+        match self.kind() {
+            CuitKind::Person => Some(&self.0[2..10]),
+            CuitKind::Company => None,
+        }
+    }
+
+    /// Returns the sex given by the type prefix, or `None` for companies and for the
+    /// prefix `23`, which is used for both.
+    pub fn sex(&self) -> Option<Sex> {
+        match self.prefix() {
+            20 | 24 => Some(Sex::Male),
+            27 => Some(Sex::Female),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Cuit {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cuit {
+    /// Formats the CUIT as `XX-XXXXXXXX-X`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", &self.0[..2], &self.0[2..10], &self.0[10..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            ArgentinaCuitValidator::calculate_check_digit("2012345678"),
+            Some(6)
+        );
+        assert_eq!(
+            ArgentinaCuitValidator::calculate_check_digit("2000000001"),
+            None
+        );
+    }
+}
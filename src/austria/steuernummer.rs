@@ -0,0 +1,158 @@
+/// Austria Steuernummer Validator
+///
+/// This module provides functionality to validate and parse the Steuernummer, the 9-digit
+/// tax number assigned by the tax administration to individuals and businesses. It is
+/// written as `FA-NNN/NNNC`, as in `59-119/9013`:
+///
+/// | Positions | Field                                         |
+/// |-----------|-----------------------------------------------|
+/// | 1-2       | Code of the tax office (Finanzamt)            |
+/// | 3-8       | Serial number within the tax office           |
+/// | 9         | Luhn check digit of the first 8 digits        |
+///
+/// The tax offices were merged into the Finanzamt Österreich in 2021, but numbers keep the
+/// codes of the offices that issued them.
+///
+/// # Usage
+///
+/// ```
+/// use docval::austria::{AustriaSteuernummerValidator, Steuernummer};
+///
+/// assert!(AustriaSteuernummerValidator::is_valid("59-119/9013").is_ok());
+/// assert!(AustriaSteuernummerValidator::is_valid("591199014").is_err());
+///
+/// let steuernummer = Steuernummer::parse("591199013").unwrap();
+/// assert_eq!(steuernummer.tax_office(), "59");
+/// assert_eq!(steuernummer.to_string(), "59-119/9013");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a Steuernummer. Removes spaces, hyphens and slashes, checks
+///   the length, format and tax office, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct AustriaSteuernummerValidator;
+
+const STEUERNUMMER_LENGTH: usize = 9;
+const TAX_OFFICES: &[&str] = &[
+    "03", "04", "06", "07", "08", "09", "10", "12", "15", "16", "18", "22", "23", "29", "33", "38",
+    "41", "46", "51", "52", "53", "54", "57", "59", "61", "65", "67", "68", "69", "71", "72", "81",
+    "82", "83", "84", "90", "91", "93", "97", "98",
+];
+
+impl AustriaSteuernummerValidator {
+    /// Validates if the given Steuernummer is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != STEUERNUMMER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if !TAX_OFFICES.contains(&&sanitized_value[..2]) {
+            return Err("Invalid tax office");
+        }
+        let check_digit = luhn_check_digit(&sanitized_value[..8]);
+        if sanitized_value[8..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate Steuernummern using the AustriaSteuernummerValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace, hyphens and slashes from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '/'))
+            .collect()
+    }
+}
+
+/// A validated Steuernummer, stored as its 9 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Steuernummer(String);
+
+impl Steuernummer {
+    /// Parses and validates the given Steuernummer, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        AustriaSteuernummerValidator::is_valid(value)?;
+        Ok(Steuernummer(AustriaSteuernummerValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 9 digits of the Steuernummer.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 2-digit code of the tax office.
+    pub fn tax_office(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Returns the 6-digit serial number.
+    pub fn serial(&self) -> &str {
+        &self.0[2..8]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+}
+
+impl FromStr for Steuernummer {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Steuernummer {
+    /// Formats the Steuernummer as in `59-119/9013`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}/{}", &self.0[..2], &self.0[2..5], &self.0[5..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            AustriaSteuernummerValidator::sanitize_input("59-119/9013"),
+            "591199013"
+        );
+        assert_eq!(
+            AustriaSteuernummerValidator::sanitize_input("59 119 9013"),
+            "591199013"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+/// Austria UID Validator
+///
+/// This module provides offline validation of the Umsatzsteuer-Identifikationsnummer (UID),
+/// the VAT identification number assigned by the tax administration to businesses trading
+/// within the EU. It is written as `ATU` followed by 8 digits, as in `ATU13585627`.
+///
+/// The check digit is a variant of Luhn: among the first 7 digits, those in even positions
+/// are doubled and reduced to a single digit, 4 is added to the sum, and the check digit is
+/// the complement of the result modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::austria::{AustriaUidValidator, Uid};
+///
+/// assert!(AustriaUidValidator::is_valid("ATU 1358 5627").is_ok());
+/// assert!(AustriaUidValidator::is_valid("U13585627").is_ok());
+///
+/// let uid = Uid::parse("atu13585627").unwrap();
+/// assert_eq!(uid.as_str(), "13585627");
+/// assert_eq!(uid.to_string(), "ATU13585627");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a UID. Removes spaces, dots, hyphens and the `ATU` prefix,
+///   checks the length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct AustriaUidValidator;
+
+const COUNTRY_PREFIX: &str = "AT";
+const UID_PREFIX: &str = "U";
+const UID_LENGTH: usize = 8;
+const CHECKSUM_OFFSET: u32 = 4;
+
+impl AustriaUidValidator {
+    /// Validates if the given UID is correct. The input can be a plain or formatted string,
+    /// with or without the `AT` prefix, but always with the `U`.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let digits = sanitized_value
+            .strip_prefix(UID_PREFIX)
+            .ok_or("Invalid format")?;
+        if digits.len() != UID_LENGTH {
+            return Err("Invalid length");
+        }
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let check_digit = Self::calculate_check_digit(&digits[..7]);
+        if digits[7..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate UIDs using the AustriaUidValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and the `AT` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Calculates the check digit of the first 7 digits of a UID.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = c.to_digit(10).expect("Invalid digit in input");
+                if i % 2 == 1 {
+                    digit * 2 / 10 + digit * 2 % 10
+                } else {
+                    digit
+                }
+            })
+            .sum();
+        (10 - (sum + CHECKSUM_OFFSET) % 10) % 10
+    }
+}
+
+/// A validated UID, stored as its 8 digits, without the `ATU` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Uid(String);
+
+impl Uid {
+    /// Parses and validates the given UID, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        AustriaUidValidator::is_valid(value)?;
+        let sanitized_value = AustriaUidValidator::sanitize_input(value);
+        Ok(Uid(sanitized_value[UID_PREFIX.len()..].to_string()))
+    }
+
+    /// Returns the 8 digits of the UID, without the `ATU` prefix.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[7] - b'0'
+    }
+}
+
+impl FromStr for Uid {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Uid {
+    /// Formats the UID with its `ATU` prefix, as in `ATU13585627`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", COUNTRY_PREFIX, UID_PREFIX, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(AustriaUidValidator::calculate_check_digit("1358562"), 7);
+        assert_eq!(AustriaUidValidator::calculate_check_digit("1234567"), 5);
+    }
+}
@@ -0,0 +1,180 @@
+/// Belgium Enterprise Number Validator
+///
+/// This module provides functionality to validate and parse the numbers of the Crossroads
+/// Bank for Enterprises (KBO/BCE). Enterprise numbers start with `0` or `1`, and also serve
+/// as VAT numbers with the `BE` prefix; establishment unit numbers, identifying the
+/// locations where an enterprise operates, start with `2` to `8`. Both have 10 digits,
+/// written as `0403.062.714`.
+///
+/// The last 2 digits are the complement to 97 of the first 8 digits modulo 97.
+///
+/// # Usage
+///
+/// ```
+/// use docval::belgium::{BelgiumEnterpriseNumberValidator, EnterpriseNumber, EnterpriseNumberKind};
+///
+/// assert!(BelgiumEnterpriseNumberValidator::is_valid("0403.062.714").is_ok());
+/// assert!(BelgiumEnterpriseNumberValidator::is_valid("BE0403062714").is_ok());
+///
+/// let enterprise_number = EnterpriseNumber::parse("2123456791").unwrap();
+/// assert_eq!(enterprise_number.kind(), EnterpriseNumberKind::Establishment);
+/// assert_eq!(enterprise_number.to_string(), "2123.456.791");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an enterprise or establishment unit number. Removes
+///   spaces, dots, hyphens and the `BE` prefix, checks the length and the leading digit,
+///   and validates the check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct BelgiumEnterpriseNumberValidator;
+
+/// The kinds of units registered in the Crossroads Bank for Enterprises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnterpriseNumberKind {
+    /// Enterprise, or legal entity (0 or 1).
+    Enterprise,
+    /// Establishment unit of an enterprise (2 to 8).
+    Establishment,
+}
+
+impl EnterpriseNumberKind {
+    /// Returns the kind of unit identified by the given leading digit, if any.
+    pub fn from_digit(digit: char) -> Option<Self> {
+        match digit {
+            '0' | '1' => Some(EnterpriseNumberKind::Enterprise),
+            '2'..='8' => Some(EnterpriseNumberKind::Establishment),
+            _ => None,
+        }
+    }
+}
+
+const COUNTRY_PREFIX: &str = "BE";
+const ENTERPRISE_NUMBER_LENGTH: usize = 10;
+const VALIDATION_MODULUS: u32 = 97;
+
+impl BelgiumEnterpriseNumberValidator {
+    /// Validates if the given enterprise or establishment unit number is correct. The input
+    /// can be a plain or formatted string, with or without the `BE` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ENTERPRISE_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if EnterpriseNumberKind::from_digit(
+            sanitized_value.chars().next().expect("Validated length"),
+        )
+        .is_none()
+        {
+            return Err("Invalid format");
+        }
+        let check_digits = Self::calculate_check_digits(&sanitized_value[..8]);
+        if sanitized_value[8..] == format!("{:02}", check_digits) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate enterprise numbers using the
+    /// BelgiumEnterpriseNumberValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and the `BE` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Calculates the check digits of the first 8 digits.
+    fn calculate_check_digits(value: &str) -> u32 {
+        let number: u32 = value.parse().expect("Validated digits");
+        VALIDATION_MODULUS - number % VALIDATION_MODULUS
+    }
+}
+
+/// A validated enterprise or establishment unit number, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnterpriseNumber(String);
+
+impl EnterpriseNumber {
+    /// Parses and validates the given number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BelgiumEnterpriseNumberValidator::is_valid(value)?;
+        Ok(EnterpriseNumber(
+            BelgiumEnterpriseNumberValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 10 digits of the number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the number identifies an enterprise or an establishment unit.
+    pub fn kind(&self) -> EnterpriseNumberKind {
+        EnterpriseNumberKind::from_digit(char::from(self.0.as_bytes()[0])).expect("Validated kind")
+    }
+
+    /// Returns the 2 check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.0[8..].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for EnterpriseNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for EnterpriseNumber {
+    /// Formats the number as in `0403.062.714`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", &self.0[..4], &self.0[4..7], &self.0[7..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digits() {
+        assert_eq!(
+            BelgiumEnterpriseNumberValidator::calculate_check_digits("04030627"),
+            14
+        );
+        assert_eq!(
+            BelgiumEnterpriseNumberValidator::calculate_check_digits("10000000"),
+            21
+        );
+    }
+}
@@ -0,0 +1,225 @@
+/// Belgium National Register Number Validator
+///
+/// This module provides functionality to validate and parse the national register number
+/// (Rijksregisternummer / numéro de registre national), assigned to residents of Belgium and
+/// printed on the eID card as `85.07.30-033.28`:
+///
+/// | Positions | Field                                                           |
+/// |-----------|-----------------------------------------------------------------|
+/// | 1-6       | Date of birth as `YYMMDD`, with `00` for an unknown month or day |
+/// | 7-9       | Serial number: odd for men, even for women                      |
+/// | 10-11     | Check digits                                                    |
+///
+/// The check digits are the complement to 97 of the first 9 digits modulo 97. For people
+/// born from 2000 onwards, a `2` is prepended to the 9 digits before the calculation, which
+/// is how the century of birth is told apart.
+///
+/// # Usage
+///
+/// ```
+/// use docval::belgium::{BelgiumNationalNumberValidator, NationalNumber};
+/// use docval::Sex;
+///
+/// assert!(BelgiumNationalNumberValidator::is_valid("85.07.30-033.28").is_ok());
+///
+/// let national_number = NationalNumber::parse("00010100204").unwrap();
+/// assert_eq!(national_number.birth_year(), 2000);
+/// assert_eq!(national_number.birth_month(), Some(1));
+/// assert_eq!(national_number.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a national register number. Removes spaces, dots and
+///   hyphens, checks the length, the date of birth and the serial number, and validates
+///   the check digits for both centuries.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct BelgiumNationalNumberValidator;
+
+const NATIONAL_NUMBER_LENGTH: usize = 11;
+const MAX_SERIAL: u32 = 997;
+const VALIDATION_MODULUS: u64 = 97;
+const MILLENNIUM_PREFIX: u64 = 2_000_000_000;
+
+impl BelgiumNationalNumberValidator {
+    /// Validates if the given national register number is correct. The input can be a plain
+    /// or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NATIONAL_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let serial: u32 = sanitized_value[6..9].parse().expect("Validated digits");
+        if !(1..=MAX_SERIAL).contains(&serial) {
+            return Err("Invalid format");
+        }
+        let century = Self::century(&sanitized_value).ok_or("Invalid checksum")?;
+        if !Self::is_valid_birth_date(&sanitized_value, century) {
+            return Err("Invalid date");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate national register numbers using the
+    /// BelgiumNationalNumberValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .collect()
+    }
+
+    /// Returns the century of birth for which the check digits are correct, if any.
+    fn century(value: &str) -> Option<u32> {
+        let number: u64 = value[..9].parse().expect("Validated digits");
+        let check_digits: u64 = value[9..].parse().expect("Validated digits");
+        if VALIDATION_MODULUS - number % VALIDATION_MODULUS == check_digits {
+            Some(1900)
+        } else if VALIDATION_MODULUS - (MILLENNIUM_PREFIX + number) % VALIDATION_MODULUS
+            == check_digits
+        {
+            Some(2000)
+        } else {
+            None
+        }
+    }
+
+    /// Checks the date of birth, where an unknown month or day is written as `00`.
+    fn is_valid_birth_date(value: &str, century: u32) -> bool {
+        let (year, month, day) = Self::birth_date(value, century);
+        if month == 0 || day == 0 {
+            month <= 12 && day <= 31
+        } else {
+            is_valid_date(year, month, day)
+        }
+    }
+
+    fn birth_date(value: &str, century: u32) -> (u32, u32, u32) {
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        (century + field(0), field(2), field(4))
+    }
+}
+
+/// A validated national register number, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NationalNumber(String);
+
+impl NationalNumber {
+    /// Parses and validates the given national register number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BelgiumNationalNumberValidator::is_valid(value)?;
+        Ok(NationalNumber(
+            BelgiumNationalNumberValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 11 digits of the national register number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, whose century is given by the check digits.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth, or `None` when it is unknown.
+    pub fn birth_month(&self) -> Option<u8> {
+        Some(self.birth_date().1 as u8).filter(|&month| month != 0)
+    }
+
+    /// Returns the day of birth, or `None` when it is unknown.
+    pub fn birth_day(&self) -> Option<u8> {
+        Some(self.birth_date().2 as u8).filter(|&day| day != 0)
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if self.serial() % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[6..9].parse().expect("Validated digits")
+    }
+
+    /// Returns the 2 check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.0[9..].parse().expect("Validated digits")
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        let century = BelgiumNationalNumberValidator::century(&self.0).expect("Validated checksum");
+        BelgiumNationalNumberValidator::birth_date(&self.0, century)
+    }
+}
+
+impl FromStr for NationalNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for NationalNumber {
+    /// Formats the national register number as in `85.07.30-033.28`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}-{}.{}",
+            &self.0[..2],
+            &self.0[2..4],
+            &self.0[4..6],
+            &self.0[6..9],
+            &self.0[9..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_century() {
+        assert_eq!(
+            BelgiumNationalNumberValidator::century("85073003328"),
+            Some(1900)
+        );
+        assert_eq!(
+            BelgiumNationalNumberValidator::century("17010100171"),
+            Some(2000)
+        );
+        assert_eq!(BelgiumNationalNumberValidator::century("85073003329"), None);
+    }
+}
@@ -0,0 +1,143 @@
+/// Bolivia NIT Validator
+///
+/// This module provides functionality to validate and parse the NIT (Número de
+/// Identificación Tributaria), the tax identifier assigned by the SIN (Servicio de
+/// Impuestos Nacionales). It is made of a base of 6 to 11 digits followed by a check digit.
+///
+/// The base is weighted from right to left with the repeating sequence 2 to 9, and the
+/// check digit is the complement of the sum modulo 11, where 11 yields 0 and, unlike most
+/// modulus 11 schemes, 10 yields 1.
+///
+/// # Usage
+///
+/// ```
+/// use docval::bolivia::{BoliviaNitValidator, Nit};
+///
+/// assert!(BoliviaNitValidator::is_valid("1020703021").is_ok());
+///
+/// let nit = Nit::parse("1020703031").unwrap();
+/// assert_eq!(nit.number(), "102070303");
+/// assert_eq!(nit.check_digit(), 1);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIT. Removes non-digit characters, checks the length,
+///   and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct BoliviaNitValidator;
+
+const NIT_MIN_LENGTH: usize = 7;
+const NIT_MAX_LENGTH: usize = 12;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl BoliviaNitValidator {
+    /// Validates if the given NIT, including its check digit, is correct. The input can be a
+    /// plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < NIT_MIN_LENGTH || sanitized_value.len() > NIT_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        let (base, check_digit) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if check_digit == Self::calculate_check_digit(base).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NITs using the BoliviaNitValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the given base.
+    fn calculate_check_digit(base: &str) -> u32 {
+        let sum: u32 = base
+            .chars()
+            .rev()
+            .zip((2..=9).cycle())
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => 0,
+            10 => 1,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated NIT, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nit(String);
+
+impl Nit {
+    /// Parses and validates the given NIT, including its check digit, discarding any
+    /// formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BoliviaNitValidator::is_valid(value)?;
+        Ok(Nit(BoliviaNitValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the NIT, including the check digit.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the base of the NIT, without the check digit.
+    pub fn number(&self) -> &str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0[self.0.len() - 1..]
+            .parse()
+            .expect("Validated digits")
+    }
+}
+
+impl FromStr for Nit {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(BoliviaNitValidator::calculate_check_digit("123456789"), 7);
+        assert_eq!(BoliviaNitValidator::calculate_check_digit("154321"), 0);
+        assert_eq!(BoliviaNitValidator::calculate_check_digit("1000002"), 1);
+    }
+}
@@ -0,0 +1,243 @@
+/// Brazil Fiscal Document Access Key Validator
+///
+/// This module provides functionality to validate and parse the 44-digit access key
+/// (chave de acesso) that identifies electronic fiscal documents such as the NF-e, NFC-e,
+/// CT-e and MDF-e, and which is printed on their auxiliary documents (DANFE, DACTE).
+///
+/// The key is composed of the following fields:
+///
+/// | Positions | Field                                   |
+/// |-----------|-----------------------------------------|
+/// | 1-2       | IBGE code of the issuer's state         |
+/// | 3-6       | Year and month of emission (`AAMM`)     |
+/// | 7-20      | Issuer's CNPJ (or zero-padded CPF)      |
+/// | 21-22     | Document model                          |
+/// | 23-25     | Series                                  |
+/// | 26-34     | Document number                         |
+/// | 35        | Emission type                           |
+/// | 36-43     | Numeric code                            |
+/// | 44        | Check digit (modulus 11, weights 2-9)   |
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{AccessKey, BrazilAccessKeyValidator, FiscalDocumentModel, Uf};
+///
+/// let key = "3517 0612 3456 7800 0195 5500 1000 0000 0110 0000 0014";
+/// assert!(BrazilAccessKeyValidator::is_valid(key).is_ok());
+///
+/// let key = AccessKey::parse(key).unwrap();
+/// assert_eq!(key.uf(), Uf::SP);
+/// assert_eq!(key.year(), 2017);
+/// assert_eq!(key.month(), 6);
+/// assert_eq!(key.issuer_id(), "12345678000195");
+/// assert_eq!(key.model(), FiscalDocumentModel::NFe);
+/// assert_eq!(key.number(), 1);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an access key. Removes non-digit characters, checks the
+///   length, the state code, the emission month and the document model, and validates
+///   the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::Uf;
+use crate::checksum::mod11_check_digit;
+
+pub struct BrazilAccessKeyValidator;
+
+/// The electronic fiscal document models that are identified by an access key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiscalDocumentModel {
+    /// Nota Fiscal Eletrônica (model 55).
+    NFe,
+    /// Conhecimento de Transporte Eletrônico (model 57).
+    CTe,
+    /// Manifesto Eletrônico de Documentos Fiscais (model 58).
+    MDFe,
+    /// Nota Fiscal de Consumidor Eletrônica (model 65).
+    NFCe,
+    /// Conhecimento de Transporte Eletrônico para Outros Serviços (model 67).
+    CTeOS,
+}
+
+impl FiscalDocumentModel {
+    /// Returns the model with the given code, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            55 => Some(FiscalDocumentModel::NFe),
+            57 => Some(FiscalDocumentModel::CTe),
+            58 => Some(FiscalDocumentModel::MDFe),
+            65 => Some(FiscalDocumentModel::NFCe),
+            67 => Some(FiscalDocumentModel::CTeOS),
+            _ => None,
+        }
+    }
+
+    /// Returns the code of the model as it appears in the access key.
+    pub fn code(&self) -> u8 {
+        match self {
+            FiscalDocumentModel::NFe => 55,
+            FiscalDocumentModel::CTe => 57,
+            FiscalDocumentModel::MDFe => 58,
+            FiscalDocumentModel::NFCe => 65,
+            FiscalDocumentModel::CTeOS => 67,
+        }
+    }
+}
+
+const ACCESS_KEY_LENGTH: usize = 44;
+
+impl BrazilAccessKeyValidator {
+    /// Validates if the given access key is correct. The input can be a plain or
+    /// formatted string (with spaces, dots or hyphens).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ACCESS_KEY_LENGTH {
+            return Err("Invalid length");
+        }
+        if Uf::from_ibge_code(Self::field(&sanitized_value, 0, 2) as u8).is_none() {
+            return Err("Invalid state code");
+        }
+        if !(1..=12).contains(&Self::field(&sanitized_value, 4, 6)) {
+            return Err("Invalid emission date");
+        }
+        if FiscalDocumentModel::from_code(Self::field(&sanitized_value, 20, 22) as u8).is_none() {
+            return Err("Invalid model");
+        }
+        let check_digit = Self::field(&sanitized_value, 43, 44);
+        if mod11_check_digit(&sanitized_value[..ACCESS_KEY_LENGTH - 1]) == check_digit {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate access keys using the BrazilAccessKeyValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Reads the numeric value of the digits between the given positions.
+    fn field(value: &str, start: usize, end: usize) -> u32 {
+        value[start..end].parse().expect("Invalid digit in input")
+    }
+}
+
+/// A validated access key, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccessKey(String);
+
+impl AccessKey {
+    /// Parses and validates the given access key, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BrazilAccessKeyValidator::is_valid(value)?;
+        Ok(AccessKey(BrazilAccessKeyValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 44 digits of the access key.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the state of the issuer.
+    pub fn uf(&self) -> Uf {
+        Uf::from_ibge_code(self.field(0, 2) as u8).expect("Validated state code")
+    }
+
+    /// Returns the four-digit year of emission.
+    pub fn year(&self) -> u16 {
+        2000 + self.field(2, 4) as u16
+    }
+
+    /// Returns the month of emission, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.field(4, 6) as u8
+    }
+
+    /// Returns the CNPJ of the issuer. Issuers identified by CPF have it left-padded with zeros.
+    pub fn issuer_id(&self) -> &str {
+        &self.0[6..20]
+    }
+
+    /// Returns the model of the fiscal document.
+    pub fn model(&self) -> FiscalDocumentModel {
+        FiscalDocumentModel::from_code(self.field(20, 22) as u8).expect("Validated model")
+    }
+
+    /// Returns the series of the fiscal document.
+    pub fn series(&self) -> u16 {
+        self.field(22, 25) as u16
+    }
+
+    /// Returns the number of the fiscal document.
+    pub fn number(&self) -> u32 {
+        self.field(25, 34)
+    }
+
+    /// Returns the emission type (1 for normal emission, other values for contingency modes).
+    pub fn emission_type(&self) -> u8 {
+        self.field(34, 35) as u8
+    }
+
+    /// Returns the random numeric code chosen by the issuer.
+    pub fn numeric_code(&self) -> &str {
+        &self.0[35..43]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.field(43, 44) as u8
+    }
+
+    fn field(&self, start: usize, end: usize) -> u32 {
+        BrazilAccessKeyValidator::field(&self.0, start, end)
+    }
+}
+
+impl FromStr for AccessKey {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for AccessKey {
+    /// Formats the access key in groups of four digits, as printed on the DANFE.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups: Vec<&str> = (0..ACCESS_KEY_LENGTH)
+            .step_by(4)
+            .map(|i| &self.0[i..i + 4])
+            .collect();
+        f.write_str(&groups.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field() {
+        assert_eq!(BrazilAccessKeyValidator::field("35170612", 2, 6), 1706);
+    }
+}
@@ -0,0 +1,258 @@
+/// Brazil Bank Branch and Account Validator
+///
+/// This module provides functionality to validate the check digits of bank branches
+/// (agências) and accounts (contas) of the major Brazilian banks. There is no common
+/// standard: each institution, identified by its COMPE code, uses its own weights and rules.
+///
+/// | COMPE | Bank            | Branch               | Account                                      |
+/// |-------|-----------------|----------------------|----------------------------------------------|
+/// | 001   | Banco do Brasil | 4 digits + modulo 11 | 8 digits + modulo 11, 10 written as `X`      |
+/// | 033   | Santander       | 4 digits             | 8 digits + modulo 10 over branch and account |
+/// | 104   | Caixa           | 4 digits             | 3-digit operation + 8 digits + modulo 11     |
+/// | 237   | Bradesco        | 4 digits + modulo 11 | 7 digits + modulo 11, 10 written as `P`      |
+/// | 341   | Itaú            | 4 digits             | 5 digits + modulo 10 over branch and account |
+///
+/// Branch check digits are optional, since they are often stored apart; when present, they
+/// are verified. Account numbers shorter than the bank's length are padded with zeros on
+/// the left, and must always end with their check digit.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{Bank, BrazilBankAccountValidator};
+///
+/// assert!(BrazilBankAccountValidator::is_valid_branch("001", "1584-9").is_ok());
+/// assert!(BrazilBankAccountValidator::is_valid_account("001", "1584-9", "210169-6").is_ok());
+/// assert!(BrazilBankAccountValidator::is_valid_account("341", "2545", "02366-1").is_ok());
+/// assert_eq!(Bank::from_code(237), Some(Bank::Bradesco));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid_branch(bank_code: &str, branch: &str) -> Result<(), &'static str>`:
+///   Validates a branch number, and its check digit if present, for the given bank.
+///
+/// - `is_valid_account(bank_code: &str, branch: &str, account: &str) -> Result<(), &'static str>`:
+///   Validates an account number and its check digit for the given bank and branch.
+use crate::checksum::luhn_check_digit;
+
+pub struct BrazilBankAccountValidator;
+
+/// The banks whose branch and account check digits are supported, with their COMPE codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bank {
+    /// Banco do Brasil (001).
+    BancoDoBrasil,
+    /// Banco Santander (033).
+    Santander,
+    /// Caixa Econômica Federal (104).
+    Caixa,
+    /// Banco Bradesco (237).
+    Bradesco,
+    /// Itaú Unibanco (341).
+    Itau,
+}
+
+impl Bank {
+    /// Returns the bank with the given COMPE code, if supported.
+    pub fn from_code(code: u16) -> Option<Self> {
+        match code {
+            1 => Some(Bank::BancoDoBrasil),
+            33 => Some(Bank::Santander),
+            104 => Some(Bank::Caixa),
+            237 => Some(Bank::Bradesco),
+            341 => Some(Bank::Itau),
+            _ => None,
+        }
+    }
+
+    /// Returns the COMPE code of the bank.
+    pub fn code(&self) -> u16 {
+        match self {
+            Bank::BancoDoBrasil => 1,
+            Bank::Santander => 33,
+            Bank::Caixa => 104,
+            Bank::Bradesco => 237,
+            Bank::Itau => 341,
+        }
+    }
+
+    /// Returns the length of the account number, without its check digit.
+    fn account_length(&self) -> usize {
+        match self {
+            Bank::BancoDoBrasil | Bank::Santander => 8,
+            Bank::Caixa => 11,
+            Bank::Bradesco => 7,
+            Bank::Itau => 5,
+        }
+    }
+
+    /// Returns whether the bank's branches have a check digit.
+    fn has_branch_check_digit(&self) -> bool {
+        matches!(self, Bank::BancoDoBrasil | Bank::Bradesco)
+    }
+}
+
+const BANK_CODE_MAX_LENGTH: usize = 3;
+const BRANCH_LENGTH: usize = 4;
+const BRANCH_MULTIPLIER_WEIGHTS: &[u32] = &[5, 4, 3, 2];
+const BB_ACCOUNT_MULTIPLIER_WEIGHTS: &[u32] = &[9, 8, 7, 6, 5, 4, 3, 2];
+const BRADESCO_ACCOUNT_MULTIPLIER_WEIGHTS: &[u32] = &[2, 7, 6, 5, 4, 3, 2];
+const CAIXA_MULTIPLIER_WEIGHTS: &[u32] = &[8, 7, 6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const SANTANDER_MULTIPLIER_WEIGHTS: &[u32] = &[9, 7, 3, 1, 9, 7, 1, 3, 1, 9, 7, 3];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl BrazilBankAccountValidator {
+    /// Validates if the given branch is correct for the bank with the given COMPE code. The
+    /// branch can be formatted, and its check digit can be omitted.
+    pub fn is_valid_branch(bank_code: &str, branch: &str) -> Result<(), &'static str> {
+        let bank = Self::parse_bank(bank_code)?;
+        Self::validate_branch(bank, branch).map(|_| ())
+    }
+
+    /// Validates if the given account is correct for the bank with the given COMPE code and
+    /// the given branch. Both can be formatted; the account must include its check digit, and
+    /// for Caixa, its 3-digit operation code.
+    pub fn is_valid_account(
+        bank_code: &str,
+        branch: &str,
+        account: &str,
+    ) -> Result<(), &'static str> {
+        let bank = Self::parse_bank(bank_code)?;
+        let branch = Self::validate_branch(bank, branch)?;
+        let sanitized_value = Self::sanitize_input(account);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let (number, check_digit) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if number.is_empty() || number.len() > bank.account_length() {
+            return Err("Invalid length");
+        }
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let number = format!("{:0>width$}", number, width = bank.account_length());
+        let expected = match bank {
+            Bank::BancoDoBrasil => {
+                Self::mod11_check_digit(&number, BB_ACCOUNT_MULTIPLIER_WEIGHTS, 'X')
+            }
+            Bank::Bradesco => {
+                Self::mod11_check_digit(&number, BRADESCO_ACCOUNT_MULTIPLIER_WEIGHTS, 'P')
+            }
+            Bank::Caixa => Self::caixa_check_digit(&format!("{}{}", branch, number)),
+            Bank::Itau => Self::itau_check_digit(&format!("{}{}", branch, number)),
+            Bank::Santander => Self::santander_check_digit(&format!("{}{}", branch, number)),
+        };
+        if check_digit.starts_with(expected) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    /// Looks up the bank with the given COMPE code, which may omit its leading zeros.
+    fn parse_bank(bank_code: &str) -> Result<Bank, &'static str> {
+        let bank_code = bank_code.trim();
+        if bank_code.is_empty() {
+            return Err("Invalid input");
+        }
+        if bank_code.len() > BANK_CODE_MAX_LENGTH || !bank_code.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err("Invalid bank code");
+        }
+        let code = bank_code.parse().expect("Validated digits");
+        Bank::from_code(code).ok_or("Unsupported bank")
+    }
+
+    /// Validates a branch, returning its 4 digits without the check digit.
+    fn validate_branch(bank: Bank, branch: &str) -> Result<String, &'static str> {
+        let sanitized_value = Self::sanitize_input(branch);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let has_check_digit =
+            bank.has_branch_check_digit() && sanitized_value.len() == BRANCH_LENGTH + 1;
+        if sanitized_value.len() != BRANCH_LENGTH && !has_check_digit {
+            return Err("Invalid length");
+        }
+        let number = &sanitized_value[..BRANCH_LENGTH];
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if has_check_digit {
+            let ten = if bank == Bank::Bradesco { 'P' } else { 'X' };
+            let expected = Self::mod11_check_digit(number, BRANCH_MULTIPLIER_WEIGHTS, ten);
+            if !sanitized_value.ends_with(expected) {
+                return Err("Invalid checksum");
+            }
+        }
+        Ok(number.to_string())
+    }
+
+    /// Removes formatting characters from the input, keeping digits and letters.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Calculates the complement of the weighted sum modulo 11, used by Banco do Brasil and
+    /// Bradesco, where 10 is written as the given letter and 11 as `0`.
+    fn mod11_check_digit(value: &str, weights: &[u32], ten: char) -> char {
+        match VALIDATION_MODULUS - weighted_sum(value, weights) % VALIDATION_MODULUS {
+            10 => ten,
+            11 => '0',
+            digit => char::from_digit(digit, 10).expect("Single digit"),
+        }
+    }
+
+    /// Calculates the Caixa check digit over the branch, operation and account: the weighted
+    /// sum times 10, modulo 11, where 10 yields 0.
+    fn caixa_check_digit(value: &str) -> char {
+        let digit = weighted_sum(value, CAIXA_MULTIPLIER_WEIGHTS) * 10 % VALIDATION_MODULUS;
+        char::from_digit(digit % 10, 10).expect("Single digit")
+    }
+
+    /// Calculates the Itaú check digit over the branch and account, which is the Luhn check
+    /// digit of their concatenation.
+    fn itau_check_digit(value: &str) -> char {
+        char::from_digit(luhn_check_digit(value), 10).expect("Single digit")
+    }
+
+    /// Calculates the Santander check digit over the branch and account: only the units of
+    /// each product are added, and the digit is the complement of the sum to 10.
+    fn santander_check_digit(value: &str) -> char {
+        let sum: u32 = value
+            .chars()
+            .zip(SANTANDER_MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w % 10)
+            .sum();
+        char::from_digit((10 - sum % 10) % 10, 10).expect("Single digit")
+    }
+}
+
+/// Sums the digits of the input multiplied by the corresponding weights.
+fn weighted_sum(value: &str, weights: &[u32]) -> u32 {
+    assert_eq!(value.chars().count(), weights.len());
+
+    value
+        .chars()
+        .zip(weights.iter())
+        .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_santander_check_digit() {
+        assert_eq!(
+            BrazilBankAccountValidator::santander_check_digit("200601008407"),
+            '4'
+        );
+    }
+}
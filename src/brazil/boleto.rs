@@ -0,0 +1,273 @@
+/// Brazil Boleto Validator
+///
+/// This module provides functionality to validate Brazilian bank payment slips (boletos
+/// bancários), both in their 47-digit typeable line (linha digitável) and in their
+/// 44-digit barcode representation, following the FEBRABAN specification.
+///
+/// The barcode is composed of the bank code (3 digits), the currency code (1), the general
+/// check digit (1), the due date factor (4), the amount in cents (10) and a free field
+/// defined by each bank (25). The typeable line rearranges those fields into five groups,
+/// the first three of which carry their own modulus 10 check digit.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{Boleto, BrazilBoletoValidator};
+///
+/// let line = "00190.00009 01234.567806 00000.017186 8 10000000010000";
+/// assert!(BrazilBoletoValidator::is_valid(line).is_ok());
+///
+/// let boleto = Boleto::parse(line).unwrap();
+/// assert_eq!(boleto.bank_code(), "001");
+/// assert_eq!(boleto.due_date_factor(), 1000);
+/// assert_eq!(boleto.amount(), 10000);
+/// assert_eq!(boleto.barcode(), "00198100000000100000000001234567800000001718");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a typeable line. Removes non-digit characters, checks
+///   the length, and validates the check digit of each field and the general check digit.
+///
+/// - `is_valid_barcode(value: &str) -> Result<(), &'static str>`:
+///   Validates a 44-digit barcode and its general check digit.
+///
+/// - `to_barcode(value: &str) -> Result<String, &'static str>`:
+///   Converts a typeable line to the barcode representation.
+///
+/// - `to_typeable_line(value: &str) -> Result<String, &'static str>`:
+///   Converts a barcode to the typeable line representation.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct BrazilBoletoValidator;
+
+const TYPEABLE_LINE_LENGTH: usize = 47;
+const BARCODE_LENGTH: usize = 44;
+const GENERAL_CHECK_DIGIT_POSITION: usize = 4;
+const MULTIPLIER_WEIGHTS: &[u32] = &[2, 3, 4, 5, 6, 7, 8, 9];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl BrazilBoletoValidator {
+    /// Validates if the given typeable line is correct. The input can be a plain or
+    /// formatted string (with dots and spaces).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != TYPEABLE_LINE_LENGTH {
+            return Err("Invalid length");
+        }
+        let fields = [
+            &sanitized_value[0..10],
+            &sanitized_value[10..21],
+            &sanitized_value[21..32],
+        ];
+        for field in fields {
+            let (payload, check_digit) = field.split_at(field.len() - 1);
+            if luhn_check_digit(payload).to_string() != check_digit {
+                return Err("Invalid field checksum");
+            }
+        }
+        Self::validate_general_check_digit(&Self::line_to_barcode(&sanitized_value))
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate typeable lines using the BrazilBoletoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given 44-digit barcode is correct.
+    pub fn is_valid_barcode(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != BARCODE_LENGTH {
+            return Err("Invalid length");
+        }
+        Self::validate_general_check_digit(&sanitized_value)
+    }
+
+    /// Converts a valid typeable line to its barcode.
+    pub fn to_barcode(value: &str) -> Result<String, &'static str> {
+        Self::is_valid(value)?;
+        Ok(Self::line_to_barcode(&Self::sanitize_input(value)))
+    }
+
+    /// Converts a valid barcode to its typeable line, without formatting.
+    pub fn to_typeable_line(value: &str) -> Result<String, &'static str> {
+        Self::is_valid_barcode(value)?;
+        let barcode = Self::sanitize_input(value);
+        let fields = [
+            format!("{}{}", &barcode[0..4], &barcode[19..24]),
+            barcode[24..34].to_string(),
+            barcode[34..44].to_string(),
+        ];
+        let mut line: String = fields
+            .iter()
+            .map(|field| format!("{}{}", field, luhn_check_digit(field)))
+            .collect();
+        line.push_str(&barcode[4..19]);
+        Ok(line)
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Rearranges the fields of a typeable line into a barcode, dropping the field check digits.
+    fn line_to_barcode(line: &str) -> String {
+        format!(
+            "{}{}{}{}{}{}",
+            &line[0..4],
+            &line[32..33],
+            &line[33..47],
+            &line[4..9],
+            &line[10..20],
+            &line[21..31]
+        )
+    }
+
+    /// Validates the general check digit, in the 5th position of the barcode.
+    fn validate_general_check_digit(barcode: &str) -> Result<(), &'static str> {
+        let payload = format!(
+            "{}{}",
+            &barcode[..GENERAL_CHECK_DIGIT_POSITION],
+            &barcode[GENERAL_CHECK_DIGIT_POSITION + 1..]
+        );
+        let check_digit = &barcode[GENERAL_CHECK_DIGIT_POSITION..GENERAL_CHECK_DIGIT_POSITION + 1];
+        if Self::calculate_general_check_digit(&payload).to_string() == check_digit {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    /// Calculates the modulus 11 general check digit, weighting the digits from right to
+    /// left with the sequence 2 to 9. Results of 0, 10 and 11 are replaced by 1.
+    fn calculate_general_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .rev()
+            .zip(MULTIPLIER_WEIGHTS.iter().cycle())
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 | 11 => 1,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated boleto, stored as its 44-digit barcode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Boleto(String);
+
+impl Boleto {
+    /// Parses and validates either a typeable line or a barcode, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        let sanitized_value = BrazilBoletoValidator::sanitize_input(value);
+        let barcode = match sanitized_value.len() {
+            TYPEABLE_LINE_LENGTH => BrazilBoletoValidator::to_barcode(&sanitized_value)?,
+            BARCODE_LENGTH => {
+                BrazilBoletoValidator::is_valid_barcode(&sanitized_value)?;
+                sanitized_value
+            }
+            0 => return Err("Invalid input"),
+            _ => return Err("Invalid length"),
+        };
+        Ok(Boleto(barcode))
+    }
+
+    /// Returns the 44 digits of the barcode.
+    pub fn barcode(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 47 digits of the typeable line.
+    pub fn typeable_line(&self) -> String {
+        BrazilBoletoValidator::to_typeable_line(&self.0).expect("Validated barcode")
+    }
+
+    /// Returns the COMPE code of the issuing bank.
+    pub fn bank_code(&self) -> &str {
+        &self.0[0..3]
+    }
+
+    /// Returns the currency code (`9` for the Brazilian real).
+    pub fn currency_code(&self) -> u8 {
+        self.0[3..4].parse().expect("Validated barcode")
+    }
+
+    /// Returns the due date factor, the number of days since the FEBRABAN base date.
+    /// A factor of zero means the boleto has no due date.
+    pub fn due_date_factor(&self) -> u16 {
+        self.0[5..9].parse().expect("Validated barcode")
+    }
+
+    /// Returns the amount in cents. An amount of zero means it is filled in at payment time.
+    pub fn amount(&self) -> u64 {
+        self.0[9..19].parse().expect("Validated barcode")
+    }
+
+    /// Returns the 25-digit free field, whose layout is defined by each bank.
+    pub fn free_field(&self) -> &str {
+        &self.0[19..44]
+    }
+}
+
+impl FromStr for Boleto {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Boleto {
+    /// Formats the boleto as its typeable line (`AAAAA.AAAAA BBBBB.BBBBBB CCCCC.CCCCCC D EEEEEEEEEEEEEE`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = self.typeable_line();
+        write!(
+            f,
+            "{}.{} {}.{} {}.{} {} {}",
+            &line[0..5],
+            &line[5..10],
+            &line[10..15],
+            &line[15..21],
+            &line[21..26],
+            &line[26..32],
+            &line[32..33],
+            &line[33..47]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_general_check_digit() {
+        assert_eq!(
+            BrazilBoletoValidator::calculate_general_check_digit(
+                "0019100000000100000000001234567800000001718"
+            ),
+            8
+        );
+    }
+}
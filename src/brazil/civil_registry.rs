@@ -0,0 +1,264 @@
+/// Brazil Civil Registry Certificate Validator
+///
+/// This module provides functionality to validate and parse the 32-digit registration
+/// number (matrícula) printed on birth, marriage and death certificates since the CNJ
+/// standardized it in 2009. The number is composed of the following fields:
+///
+/// | Positions | Field                                                 |
+/// |-----------|-------------------------------------------------------|
+/// | 1-6       | National code (CNS) of the registry office (cartório) |
+/// | 7-8       | Collection: `01` own, `02` incorporated               |
+/// | 9-10      | Service type: `55` for the civil registry             |
+/// | 11-14     | Year of registration                                  |
+/// | 15        | Book type                                             |
+/// | 16-20     | Book number                                           |
+/// | 21-23     | Page number                                           |
+/// | 24-30     | Term number                                           |
+/// | 31-32     | Check digits                                          |
+///
+/// Both check digits use modulus 11, with weights that increase by one for each digit and
+/// wrap from 10 back to 0: starting at 2 over the first 30 digits for the first check digit,
+/// and starting at 1 over the first 31 digits for the second. A remainder of 10 yields 1.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilCivilRegistryValidator, CertificateBookType, CivilRegistryNumber};
+///
+/// let matricula = "104539 01 55 2013 1 00012 021 0000123 21";
+/// assert!(BrazilCivilRegistryValidator::is_valid(matricula).is_ok());
+///
+/// let matricula = CivilRegistryNumber::parse(matricula).unwrap();
+/// assert_eq!(matricula.registry_office_code(), "104539");
+/// assert_eq!(matricula.year(), 2013);
+/// assert_eq!(matricula.book_type(), CertificateBookType::Birth);
+/// assert_eq!(matricula.term(), 123);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a registration number. Removes non-digit characters,
+///   checks the length and the book type, and validates the check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct BrazilCivilRegistryValidator;
+
+/// The books a civil registry act can be recorded in, identified by the 15th digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertificateBookType {
+    /// Book A: births (1).
+    Birth,
+    /// Book B: marriages (2).
+    Marriage,
+    /// Book B Auxiliary: religious marriages with civil effects (3).
+    ReligiousMarriage,
+    /// Book C: deaths (4).
+    Death,
+    /// Book C Auxiliary: stillbirths (5).
+    Stillbirth,
+    /// Book D: marriage banns (6).
+    MarriageBanns,
+    /// Book E: other acts of the civil registry (7).
+    OtherActs,
+    /// Book E, split for emancipations (8).
+    Emancipation,
+    /// Book E, split for interdictions (9).
+    Interdiction,
+}
+
+impl CertificateBookType {
+    /// Returns the book type with the given code, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(CertificateBookType::Birth),
+            2 => Some(CertificateBookType::Marriage),
+            3 => Some(CertificateBookType::ReligiousMarriage),
+            4 => Some(CertificateBookType::Death),
+            5 => Some(CertificateBookType::Stillbirth),
+            6 => Some(CertificateBookType::MarriageBanns),
+            7 => Some(CertificateBookType::OtherActs),
+            8 => Some(CertificateBookType::Emancipation),
+            9 => Some(CertificateBookType::Interdiction),
+            _ => None,
+        }
+    }
+}
+
+const MATRICULA_LENGTH: usize = 32;
+const BOOK_TYPE_POSITION: usize = 14;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl BrazilCivilRegistryValidator {
+    /// Validates if the given registration number is correct. The input can be a plain or
+    /// formatted string (with spaces, dots or hyphens).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != MATRICULA_LENGTH {
+            return Err("Invalid length");
+        }
+        let book_type = sanitized_value.as_bytes()[BOOK_TYPE_POSITION] - b'0';
+        if CertificateBookType::from_code(book_type).is_none() {
+            return Err("Invalid book type");
+        }
+        let first_digit = Self::calculate_check_digit(&sanitized_value[..30], 2);
+        let second_digit = Self::calculate_check_digit(&sanitized_value[..31], 1);
+        if sanitized_value[30..] == format!("{}{}", first_digit, second_digit) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate registration numbers using the BrazilCivilRegistryValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates a check digit with weights increasing from `first_weight` and wrapping
+    /// around modulo 11.
+    fn calculate_check_digit(value: &str, first_weight: u32) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(first_weight..)
+            .map(|(c, w)| {
+                c.to_digit(10).expect("Invalid digit in input") * (w % VALIDATION_MODULUS)
+            })
+            .sum();
+
+        match sum % VALIDATION_MODULUS {
+            10 => 1,
+            remainder => remainder,
+        }
+    }
+}
+
+/// A validated civil registry number, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CivilRegistryNumber(String);
+
+impl CivilRegistryNumber {
+    /// Parses and validates the given registration number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BrazilCivilRegistryValidator::is_valid(value)?;
+        Ok(CivilRegistryNumber(
+            BrazilCivilRegistryValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 32 digits of the registration number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the national code (CNS) of the registry office.
+    pub fn registry_office_code(&self) -> &str {
+        &self.0[0..6]
+    }
+
+    /// Returns the collection code: 1 for the office's own, 2 for an incorporated one.
+    pub fn collection(&self) -> u8 {
+        self.field(6, 8) as u8
+    }
+
+    /// Returns the service type code (55 for the civil registry of natural persons).
+    pub fn service_type(&self) -> u8 {
+        self.field(8, 10) as u8
+    }
+
+    /// Returns the year of registration.
+    pub fn year(&self) -> u16 {
+        self.field(10, 14) as u16
+    }
+
+    /// Returns the type of book the act is recorded in.
+    pub fn book_type(&self) -> CertificateBookType {
+        CertificateBookType::from_code(self.field(14, 15) as u8).expect("Validated book type")
+    }
+
+    /// Returns the book number.
+    pub fn book(&self) -> u32 {
+        self.field(15, 20)
+    }
+
+    /// Returns the page number.
+    pub fn page(&self) -> u16 {
+        self.field(20, 23) as u16
+    }
+
+    /// Returns the term number.
+    pub fn term(&self) -> u32 {
+        self.field(23, 30)
+    }
+
+    /// Returns the check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.field(30, 32) as u8
+    }
+
+    fn field(&self, start: usize, end: usize) -> u32 {
+        self.0[start..end].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for CivilRegistryNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for CivilRegistryNumber {
+    /// Formats the registration number with its fields separated by spaces, as printed on
+    /// certificates.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {} {} {}",
+            &self.0[0..6],
+            &self.0[6..8],
+            &self.0[8..10],
+            &self.0[10..14],
+            &self.0[14..15],
+            &self.0[15..20],
+            &self.0[20..23],
+            &self.0[23..30],
+            &self.0[30..32]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        let value = "659447025590151996794682559590";
+        assert_eq!(
+            BrazilCivilRegistryValidator::calculate_check_digit(value, 2),
+            1
+        );
+        assert_eq!(
+            BrazilCivilRegistryValidator::calculate_check_digit(&format!("{}1", value), 1),
+            6
+        );
+    }
+}
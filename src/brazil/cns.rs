@@ -0,0 +1,182 @@
+/// Brazil CNS Validator
+///
+/// This module provides functionality to validate the Brazilian CNS (Cartão Nacional de Saúde),
+/// the 15-digit number that identifies users of the public health system (SUS).
+///
+/// Two kinds of cards are issued. Definitive cards start with `1` or `2` and are derived from
+/// the holder's PIS/PASEP number, while provisional cards start with `7`, `8` or `9`. Both are
+/// verified through a weighted sum with weights 15 down to 1, which must be divisible by 11.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilCnsValidator, Cns, CnsKind};
+///
+/// assert!(BrazilCnsValidator::is_valid("170 1234 5678 0008").is_ok());
+/// assert!(BrazilCnsValidator::is_valid("898 0010 2800 0016").is_ok());
+///
+/// let cns: Cns = "898001028000016".parse().unwrap();
+/// assert_eq!(cns.kind(), CnsKind::Provisional);
+/// assert_eq!(cns.to_string(), "898 0010 2800 0016");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a given CNS. Removes non-digit characters,
+///   checks the length and the leading digit, and validates the check digits.
+///
+/// - `kind(value: &str) -> Result<CnsKind, &'static str>`:
+///   Tells whether a valid CNS belongs to a definitive or a provisional card.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct BrazilCnsValidator;
+
+/// The kinds of CNS cards, told apart by the leading digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CnsKind {
+    /// Cards derived from the holder's PIS/PASEP number, starting with `1` or `2`.
+    Definitive,
+    /// Cards issued while the definitive number is unavailable, starting with `7`, `8` or `9`.
+    Provisional,
+}
+
+const CNS_STANDARD_LENGTH: usize = 15;
+const PIS_LENGTH: usize = 11;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl BrazilCnsValidator {
+    /// Validates if the given CNS is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::kind(value).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CNS numbers using the BrazilCnsValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates the given CNS and tells which kind of card it belongs to.
+    pub fn kind(value: &str) -> Result<CnsKind, &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CNS_STANDARD_LENGTH {
+            return Err("Invalid length");
+        }
+        let kind = match sanitized_value.as_bytes()[0] {
+            b'1' | b'2' => CnsKind::Definitive,
+            b'7'..=b'9' => CnsKind::Provisional,
+            _ => return Err("Invalid prefix"),
+        };
+        let is_valid = match kind {
+            CnsKind::Definitive => Self::is_valid_definitive(&sanitized_value),
+            CnsKind::Provisional => {
+                Self::weighted_sum(&sanitized_value).is_multiple_of(VALIDATION_MODULUS)
+            }
+        };
+        if is_valid {
+            Ok(kind)
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Rebuilds a definitive CNS from its PIS/PASEP prefix and compares it to the input.
+    fn is_valid_definitive(value: &str) -> bool {
+        let pis = &value[..PIS_LENGTH];
+        let mut sum = Self::weighted_sum(pis);
+        let mut check_digit = VALIDATION_MODULUS - sum % VALIDATION_MODULUS;
+        if check_digit == VALIDATION_MODULUS {
+            check_digit = 0;
+        }
+        let expected = if check_digit == 10 {
+            sum += 2;
+            check_digit = VALIDATION_MODULUS - sum % VALIDATION_MODULUS;
+            format!("{}001{}", pis, check_digit)
+        } else {
+            format!("{}000{}", pis, check_digit)
+        };
+        value == expected
+    }
+
+    /// Sums the digits weighted from 15 downwards.
+    fn weighted_sum(value: &str) -> u32 {
+        value
+            .chars()
+            .zip((1..=CNS_STANDARD_LENGTH as u32).rev())
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum()
+    }
+}
+
+/// A validated CNS, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cns(String);
+
+impl Cns {
+    /// Parses and validates the given CNS, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BrazilCnsValidator::is_valid(value)?;
+        Ok(Cns(BrazilCnsValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 15 digits of the CNS.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the kind of card this CNS belongs to.
+    pub fn kind(&self) -> CnsKind {
+        match self.0.as_bytes()[0] {
+            b'1' | b'2' => CnsKind::Definitive,
+            _ => CnsKind::Provisional,
+        }
+    }
+}
+
+impl FromStr for Cns {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cns {
+    /// Formats the CNS as printed on the card (`999 9999 9999 9999`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            &self.0[..3],
+            &self.0[3..7],
+            &self.0[7..11],
+            &self.0[11..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_sum() {
+        assert_eq!(BrazilCnsValidator::weighted_sum("700000000000005"), 110);
+    }
+}
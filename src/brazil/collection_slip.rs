@@ -0,0 +1,307 @@
+/// Brazil Collection Slip Validator
+///
+/// This module provides functionality to validate Brazilian collection slips (boletos de
+/// arrecadação), issued by utility companies and government bodies for bills, taxes and
+/// fines. Unlike bank slips, their barcode always starts with `8`.
+///
+/// The 44-digit barcode is composed of the product identifier (`8`), the segment (1), the
+/// value indicator (1), the general check digit (1), the amount (11) and the identification
+/// of the company or agency followed by a free field. The 48-digit typeable line splits the
+/// barcode in four blocks of 11 digits, each followed by its own check digit.
+///
+/// The value indicator selects the check-digit algorithm: `6` and `7` use modulus 10,
+/// `8` and `9` use modulus 11. Indicators `6` and `8` mean the amount is in cents of real,
+/// while `7` and `9` mean it is a reference quantity (such as an index-linked value).
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilCollectionSlipValidator, CollectionSegment, CollectionSlip};
+///
+/// let line = "82650000001-1 23450001202-1 01231000000-0 00000000001-8";
+/// assert!(BrazilCollectionSlipValidator::is_valid(line).is_ok());
+///
+/// let slip = CollectionSlip::parse(line).unwrap();
+/// assert_eq!(slip.segment(), CollectionSegment::Sanitation);
+/// assert_eq!(slip.company_code(), "0001");
+/// assert_eq!(slip.amount(), 12345);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a typeable line. Removes non-digit characters, checks
+///   the length, and validates the check digit of each block and the general check digit.
+///
+/// - `is_valid_barcode(value: &str) -> Result<(), &'static str>`:
+///   Validates a 44-digit barcode and its general check digit.
+///
+/// - `to_barcode(value: &str) -> Result<String, &'static str>`:
+///   Converts a typeable line to the barcode representation.
+///
+/// - `to_typeable_line(value: &str) -> Result<String, &'static str>`:
+///   Converts a barcode to the typeable line representation.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::{luhn_check_digit, mod11_check_digit};
+
+pub struct BrazilCollectionSlipValidator;
+
+/// The segments a collection slip can belong to, identified by the second barcode digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionSegment {
+    /// City halls (1).
+    CityHalls,
+    /// Water and sanitation (2).
+    Sanitation,
+    /// Electricity and gas (3).
+    EnergyAndGas,
+    /// Telecommunications (4).
+    Telecommunications,
+    /// Government bodies (5).
+    GovernmentBodies,
+    /// Booklets and other issuers identified by CNPJ (6).
+    IdentifiedByCnpj,
+    /// Traffic fines (7).
+    TrafficFines,
+    /// Reserved for the exclusive use of the collecting bank (9).
+    BankExclusive,
+}
+
+const TYPEABLE_LINE_LENGTH: usize = 48;
+const BARCODE_LENGTH: usize = 44;
+const BLOCK_LENGTH: usize = 11;
+const PRODUCT_IDENTIFIER: u8 = b'8';
+const GENERAL_CHECK_DIGIT_POSITION: usize = 3;
+
+impl BrazilCollectionSlipValidator {
+    /// Validates if the given typeable line is correct. The input can be a plain or
+    /// formatted string (with spaces and hyphens).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != TYPEABLE_LINE_LENGTH {
+            return Err("Invalid length");
+        }
+        let calculate = Self::check_digit_algorithm(&sanitized_value)?;
+        for block in sanitized_value.as_bytes().chunks(BLOCK_LENGTH + 1) {
+            let block = std::str::from_utf8(block).expect("Sanitized input");
+            let (payload, check_digit) = block.split_at(BLOCK_LENGTH);
+            if calculate(payload).to_string() != check_digit {
+                return Err("Invalid block checksum");
+            }
+        }
+        Self::validate_general_check_digit(&Self::line_to_barcode(&sanitized_value))
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate typeable lines using the BrazilCollectionSlipValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given 44-digit barcode is correct.
+    pub fn is_valid_barcode(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != BARCODE_LENGTH {
+            return Err("Invalid length");
+        }
+        Self::validate_general_check_digit(&sanitized_value)
+    }
+
+    /// Converts a valid typeable line to its barcode.
+    pub fn to_barcode(value: &str) -> Result<String, &'static str> {
+        Self::is_valid(value)?;
+        Ok(Self::line_to_barcode(&Self::sanitize_input(value)))
+    }
+
+    /// Converts a valid barcode to its typeable line, without formatting.
+    pub fn to_typeable_line(value: &str) -> Result<String, &'static str> {
+        Self::is_valid_barcode(value)?;
+        let barcode = Self::sanitize_input(value);
+        let calculate = Self::check_digit_algorithm(&barcode)?;
+        Ok(barcode
+            .as_bytes()
+            .chunks(BLOCK_LENGTH)
+            .map(|block| {
+                let block = std::str::from_utf8(block).expect("Sanitized input");
+                format!("{}{}", block, calculate(block))
+            })
+            .collect())
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Checks the product identifier and segment, and selects the check-digit algorithm
+    /// from the value indicator. Works on both the barcode and the typeable line, as they
+    /// share the first three digits.
+    fn check_digit_algorithm(value: &str) -> Result<fn(&str) -> u32, &'static str> {
+        let bytes = value.as_bytes();
+        if bytes[0] != PRODUCT_IDENTIFIER {
+            return Err("Invalid product identifier");
+        }
+        if CollectionSegment::from_code(bytes[1] - b'0').is_none() {
+            return Err("Invalid segment");
+        }
+        match bytes[2] {
+            b'6' | b'7' => Ok(luhn_check_digit),
+            b'8' | b'9' => Ok(mod11_check_digit),
+            _ => Err("Invalid value indicator"),
+        }
+    }
+
+    /// Joins the blocks of a typeable line into a barcode, dropping the block check digits.
+    fn line_to_barcode(line: &str) -> String {
+        line.as_bytes()
+            .chunks(BLOCK_LENGTH + 1)
+            .map(|block| std::str::from_utf8(&block[..BLOCK_LENGTH]).expect("Sanitized input"))
+            .collect()
+    }
+
+    /// Validates the general check digit, in the 4th position of the barcode.
+    fn validate_general_check_digit(barcode: &str) -> Result<(), &'static str> {
+        let calculate = Self::check_digit_algorithm(barcode)?;
+        let payload = format!(
+            "{}{}",
+            &barcode[..GENERAL_CHECK_DIGIT_POSITION],
+            &barcode[GENERAL_CHECK_DIGIT_POSITION + 1..]
+        );
+        let check_digit = &barcode[GENERAL_CHECK_DIGIT_POSITION..GENERAL_CHECK_DIGIT_POSITION + 1];
+        if calculate(&payload).to_string() == check_digit {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+}
+
+impl CollectionSegment {
+    /// Returns the segment with the given code, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(CollectionSegment::CityHalls),
+            2 => Some(CollectionSegment::Sanitation),
+            3 => Some(CollectionSegment::EnergyAndGas),
+            4 => Some(CollectionSegment::Telecommunications),
+            5 => Some(CollectionSegment::GovernmentBodies),
+            6 => Some(CollectionSegment::IdentifiedByCnpj),
+            7 => Some(CollectionSegment::TrafficFines),
+            9 => Some(CollectionSegment::BankExclusive),
+            _ => None,
+        }
+    }
+}
+
+/// A validated collection slip, stored as its 44-digit barcode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollectionSlip(String);
+
+impl CollectionSlip {
+    /// Parses and validates either a typeable line or a barcode, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        let sanitized_value = BrazilCollectionSlipValidator::sanitize_input(value);
+        let barcode = match sanitized_value.len() {
+            TYPEABLE_LINE_LENGTH => BrazilCollectionSlipValidator::to_barcode(&sanitized_value)?,
+            BARCODE_LENGTH => {
+                BrazilCollectionSlipValidator::is_valid_barcode(&sanitized_value)?;
+                sanitized_value
+            }
+            0 => return Err("Invalid input"),
+            _ => return Err("Invalid length"),
+        };
+        Ok(CollectionSlip(barcode))
+    }
+
+    /// Returns the 44 digits of the barcode.
+    pub fn barcode(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 48 digits of the typeable line.
+    pub fn typeable_line(&self) -> String {
+        BrazilCollectionSlipValidator::to_typeable_line(&self.0).expect("Validated barcode")
+    }
+
+    /// Returns the segment the issuer belongs to.
+    pub fn segment(&self) -> CollectionSegment {
+        CollectionSegment::from_code(self.0.as_bytes()[1] - b'0').expect("Validated segment")
+    }
+
+    /// Returns whether the amount is in cents of real (`true`) or a reference quantity.
+    pub fn is_effective_amount(&self) -> bool {
+        matches!(self.0.as_bytes()[2], b'6' | b'8')
+    }
+
+    /// Returns the amount, in cents when `is_effective_amount` is true.
+    pub fn amount(&self) -> u64 {
+        self.0[4..15].parse().expect("Validated barcode")
+    }
+
+    /// Returns the identification of the issuer: the 4-digit company or agency code, or the
+    /// first 8 digits of its CNPJ for the segment identified by CNPJ.
+    pub fn company_code(&self) -> &str {
+        match self.segment() {
+            CollectionSegment::IdentifiedByCnpj => &self.0[15..23],
+            _ => &self.0[15..19],
+        }
+    }
+
+    /// Returns the free field that follows the company code, whose layout is defined by the issuer.
+    pub fn free_field(&self) -> &str {
+        &self.0[15 + self.company_code().len()..]
+    }
+}
+
+impl FromStr for CollectionSlip {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for CollectionSlip {
+    /// Formats the collection slip as its typeable line, in four blocks with their check digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = self.typeable_line();
+        let blocks: Vec<String> = line
+            .as_bytes()
+            .chunks(BLOCK_LENGTH + 1)
+            .map(|block| {
+                let block = std::str::from_utf8(block).expect("Sanitized input");
+                format!("{}-{}", &block[..BLOCK_LENGTH], &block[BLOCK_LENGTH..])
+            })
+            .collect();
+        f.write_str(&blocks.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_to_barcode() {
+        assert_eq!(
+            BrazilCollectionSlipValidator::line_to_barcode(
+                "826500000011234500012021012310000000000000000018"
+            ),
+            "82650000001234500012020123100000000000000001"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+/// Brazil Employer Registry Validators
+///
+/// This module provides functionality to validate the registrations used to identify
+/// employers that have no CNPJ, as required by payroll and social security filings:
+///
+/// - CEI (Cadastro Específico do INSS), 12 digits, the legacy registration of individual
+///   employers and construction works;
+/// - CAEPF (Cadastro de Atividade Econômica da Pessoa Física), 14 digits, which replaced
+///   the CEI for individual employers;
+/// - CNO (Cadastro Nacional de Obras), 12 digits, which replaced the CEI for construction
+///   works and kept its numbering and check digit.
+///
+/// The CEI and CNO check digit weights the first 11 digits with `74185216374`, adds the
+/// units and tens digits of the sum, and takes the complement to 10 of the units digit of
+/// the result. The CAEPF check digits are calculated as the CNPJ ones over the first 12
+/// digits, and the resulting two-digit number is then increased by 12, modulo 100.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilCaepfValidator, BrazilCeiValidator, BrazilCnoValidator};
+///
+/// assert!(BrazilCeiValidator::is_valid("10.041.23456/74").is_ok());
+/// assert!(BrazilCnoValidator::is_valid("51.201.05137/04").is_ok());
+/// assert!(BrazilCaepfValidator::is_valid("293.001.100/001-62").is_ok());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point of each validator. Removes non-digit characters, checks the length,
+///   and validates the check digits.
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::mod11_check_digit;
+
+pub struct BrazilCeiValidator;
+pub struct BrazilCaepfValidator;
+pub struct BrazilCnoValidator;
+
+const CEI_STANDARD_LENGTH: usize = 12;
+const CAEPF_STANDARD_LENGTH: usize = 14;
+const CEI_MULTIPLIER_WEIGHTS: &[u32] = &[7, 4, 1, 8, 5, 2, 1, 6, 3, 7, 4];
+const CAEPF_CHECK_DIGITS_OFFSET: u32 = 12;
+
+impl BrazilCeiValidator {
+    /// Validates if the given CEI is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        validate_cei(value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CEI numbers using the BrazilCeiValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+impl BrazilCnoValidator {
+    /// Validates if the given CNO is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        validate_cei(value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CNO numbers using the BrazilCnoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+impl BrazilCaepfValidator {
+    /// Validates if the given CAEPF is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CAEPF_STANDARD_LENGTH {
+            return Err("Invalid length");
+        }
+        let first_digit = mod11_check_digit(&sanitized_value[..12]);
+        let second_digit = mod11_check_digit(&format!("{}{}", &sanitized_value[..12], first_digit));
+        let check_digits = (first_digit * 10 + second_digit + CAEPF_CHECK_DIGITS_OFFSET) % 100;
+        if sanitized_value[12..] == format!("{:02}", check_digits) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CAEPF numbers using the BrazilCaepfValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+/// Validates the CEI check digit, shared by the CNO.
+fn validate_cei(value: &str) -> Result<(), &'static str> {
+    let sanitized_value = sanitize_input(value);
+    if sanitized_value.is_empty() {
+        return Err("Invalid input");
+    }
+    if sanitized_value.len() != CEI_STANDARD_LENGTH {
+        return Err("Invalid length");
+    }
+    let sum = weighted_sum(&sanitized_value[..11], CEI_MULTIPLIER_WEIGHTS);
+    let units_and_tens = sum % 10 + (sum / 10) % 10;
+    let check_digit = (10 - units_and_tens % 10) % 10;
+    if sanitized_value[11..] == check_digit.to_string() {
+        Ok(())
+    } else {
+        Err("Invalid checksum")
+    }
+}
+
+/// Removes non-digit characters from the input.
+fn sanitize_input(value: &str) -> String {
+    value.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// Sums the digits of the input multiplied by the corresponding weights.
+fn weighted_sum(value: &str, weights: &[u32]) -> u32 {
+    assert_eq!(value.chars().count(), weights.len());
+
+    value
+        .chars()
+        .zip(weights.iter())
+        .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_sum() {
+        assert_eq!(weighted_sum("11111111111", CEI_MULTIPLIER_WEIGHTS), 48);
+    }
+}
@@ -0,0 +1,222 @@
+/// Brazil Judicial Process Number Validator
+///
+/// This module provides functionality to validate and parse the unified judicial process
+/// number established by the CNJ (Conselho Nacional de Justiça) in Resolution 65/2008,
+/// formatted as `NNNNNNN-DD.AAAA.J.TR.OOOO`:
+///
+/// - `NNNNNNN`: sequential number of the process in the origin unit and year;
+/// - `DD`: check digits, calculated with ISO 7064 MOD 97-10;
+/// - `AAAA`: year the process was filed;
+/// - `J`: segment of the judiciary;
+/// - `TR`: tribunal within the segment;
+/// - `OOOO`: origin unit within the tribunal.
+///
+/// The check digits are verified by moving them to the end of the number, which must then
+/// leave a remainder of 1 when divided by 97.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilJudicialProcessValidator, JudicialProcessNumber, JudiciarySegment};
+///
+/// assert!(BrazilJudicialProcessValidator::is_valid("0000001-78.2020.8.26.0100").is_ok());
+///
+/// let process = JudicialProcessNumber::parse("00000017820208260100").unwrap();
+/// assert_eq!(process.year(), 2020);
+/// assert_eq!(process.segment(), JudiciarySegment::State);
+/// assert_eq!(process.tribunal(), 26);
+/// assert_eq!(process.origin(), 100);
+/// assert_eq!(process.to_string(), "0000001-78.2020.8.26.0100");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a process number. Removes non-digit characters, checks
+///   the length and the judiciary segment, and validates the check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::mod97;
+
+pub struct BrazilJudicialProcessValidator;
+
+/// The segments of the Brazilian judiciary, identified by the `J` digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JudiciarySegment {
+    /// Supremo Tribunal Federal (1).
+    SupremeFederalCourt,
+    /// Conselho Nacional de Justiça (2).
+    NationalCouncilOfJustice,
+    /// Superior Tribunal de Justiça (3).
+    SuperiorCourtOfJustice,
+    /// Justiça Federal (4).
+    Federal,
+    /// Justiça do Trabalho (5).
+    Labor,
+    /// Justiça Eleitoral (6).
+    Electoral,
+    /// Justiça Militar da União (7).
+    FederalMilitary,
+    /// Justiça dos Estados e do Distrito Federal e Territórios (8).
+    State,
+    /// Justiça Militar Estadual (9).
+    StateMilitary,
+}
+
+impl JudiciarySegment {
+    /// Returns the segment with the given code, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(JudiciarySegment::SupremeFederalCourt),
+            2 => Some(JudiciarySegment::NationalCouncilOfJustice),
+            3 => Some(JudiciarySegment::SuperiorCourtOfJustice),
+            4 => Some(JudiciarySegment::Federal),
+            5 => Some(JudiciarySegment::Labor),
+            6 => Some(JudiciarySegment::Electoral),
+            7 => Some(JudiciarySegment::FederalMilitary),
+            8 => Some(JudiciarySegment::State),
+            9 => Some(JudiciarySegment::StateMilitary),
+            _ => None,
+        }
+    }
+}
+
+const PROCESS_NUMBER_LENGTH: usize = 20;
+const SEGMENT_POSITION: usize = 13;
+
+impl BrazilJudicialProcessValidator {
+    /// Validates if the given process number is correct. The input can be a plain or
+    /// formatted string (with dots and hyphens).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != PROCESS_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        let segment = sanitized_value.as_bytes()[SEGMENT_POSITION] - b'0';
+        if JudiciarySegment::from_code(segment).is_none() {
+            return Err("Invalid segment");
+        }
+        let rearranged = format!(
+            "{}{}{}",
+            &sanitized_value[..7],
+            &sanitized_value[9..],
+            &sanitized_value[7..9]
+        );
+        if mod97(&rearranged) == 1 {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate process numbers using the BrazilJudicialProcessValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+/// A validated judicial process number, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JudicialProcessNumber(String);
+
+impl JudicialProcessNumber {
+    /// Parses and validates the given process number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BrazilJudicialProcessValidator::is_valid(value)?;
+        Ok(JudicialProcessNumber(
+            BrazilJudicialProcessValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 20 digits of the process number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the sequential number of the process.
+    pub fn sequence(&self) -> u32 {
+        self.field(0, 7)
+    }
+
+    /// Returns the check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.field(7, 9) as u8
+    }
+
+    /// Returns the year the process was filed.
+    pub fn year(&self) -> u16 {
+        self.field(9, 13) as u16
+    }
+
+    /// Returns the segment of the judiciary.
+    pub fn segment(&self) -> JudiciarySegment {
+        JudiciarySegment::from_code(self.field(13, 14) as u8).expect("Validated segment")
+    }
+
+    /// Returns the code of the tribunal within the segment (0 for the superior courts).
+    pub fn tribunal(&self) -> u8 {
+        self.field(14, 16) as u8
+    }
+
+    /// Returns the code of the origin unit within the tribunal.
+    pub fn origin(&self) -> u16 {
+        self.field(16, 20) as u16
+    }
+
+    fn field(&self, start: usize, end: usize) -> u32 {
+        self.0[start..end].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for JudicialProcessNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for JudicialProcessNumber {
+    /// Formats the process number as `NNNNNNN-DD.AAAA.J.TR.OOOO`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}.{}.{}.{}.{}",
+            &self.0[0..7],
+            &self.0[7..9],
+            &self.0[9..13],
+            &self.0[13..14],
+            &self.0[14..16],
+            &self.0[16..20]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            BrazilJudicialProcessValidator::sanitize_input("0000001-78.2020.8.26.0100"),
+            "00000017820208260100"
+        );
+    }
+}
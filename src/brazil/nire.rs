@@ -0,0 +1,143 @@
+/// Brazil NIRE Validator
+///
+/// This module provides functionality to validate and parse the NIRE (Número de
+/// Identificação do Registro de Empresas), the 11-digit number under which companies are
+/// registered at the commercial board (Junta Comercial) of their state:
+///
+/// - 2 digits: IBGE code of the state of the Junta Comercial;
+/// - 1 digit: legal nature of the company;
+/// - 7 digits: sequential number;
+/// - 1 digit: check digit.
+///
+/// Each Junta Comercial computes the check digit with its own algorithm, and none of them
+/// is published, so only the length and the state code are verified.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilNireValidator, Nire, Uf};
+///
+/// assert!(BrazilNireValidator::is_valid("33300032061").is_ok());
+///
+/// let nire = Nire::parse("35.3.0001023-0").unwrap();
+/// assert_eq!(nire.uf(), Uf::SP);
+/// assert_eq!(nire.legal_nature(), 3);
+/// assert_eq!(nire.to_string(), "35300010230");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIRE. Removes non-digit characters, and checks the
+///   length and the state code.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::Uf;
+
+pub struct BrazilNireValidator;
+
+const NIRE_LENGTH: usize = 11;
+
+impl BrazilNireValidator {
+    /// Validates if the given NIRE is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NIRE_LENGTH {
+            return Err("Invalid length");
+        }
+        let state_code = sanitized_value[..2].parse().expect("Validated digits");
+        if Uf::from_ibge_code(state_code).is_none() {
+            return Err("Invalid state code");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NIREs using the BrazilNireValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+/// A validated NIRE, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nire(String);
+
+impl Nire {
+    /// Parses and validates the given NIRE, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BrazilNireValidator::is_valid(value)?;
+        Ok(Nire(BrazilNireValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the NIRE.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the state of the Junta Comercial the company is registered at.
+    pub fn uf(&self) -> Uf {
+        Uf::from_ibge_code(self.field(0, 2) as u8).expect("Validated state code")
+    }
+
+    /// Returns the code of the legal nature of the company.
+    pub fn legal_nature(&self) -> u8 {
+        self.field(2, 3) as u8
+    }
+
+    /// Returns the sequential number.
+    pub fn sequence(&self) -> u32 {
+        self.field(3, 10)
+    }
+
+    /// Returns the check digit, which is not verified.
+    pub fn check_digit(&self) -> u8 {
+        self.field(10, 11) as u8
+    }
+
+    fn field(&self, start: usize, end: usize) -> u32 {
+        self.0[start..end].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for Nire {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nire {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            BrazilNireValidator::sanitize_input("35.2.0012345-1"),
+            "35200123451"
+        );
+    }
+}
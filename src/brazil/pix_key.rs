@@ -0,0 +1,241 @@
+/// Brazil PIX Key Validator
+///
+/// This module provides functionality to classify and validate the keys used to address
+/// accounts in PIX, the Brazilian instant payment system. Five kinds of keys exist:
+///
+/// - CPF, for individuals, and CNPJ, for companies, validated with the `BrazilTaxIdValidator`;
+/// - phone numbers in E.164 format with the Brazilian country code (`+5561912345678`);
+/// - email addresses, up to 77 characters;
+/// - random keys (EVP), which are UUID version 4 strings.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilPixKeyValidator, PixKey, PixKeyKind};
+///
+/// assert!(BrazilPixKeyValidator::is_valid("123.456.789-09").is_ok());
+/// assert!(BrazilPixKeyValidator::is_valid("+5561912345678").is_ok());
+/// assert!(BrazilPixKeyValidator::is_valid("pix@example.com").is_ok());
+///
+/// let key = PixKey::parse("123e4567-e89b-42d3-a456-426614174000").unwrap();
+/// assert_eq!(key.kind(), PixKeyKind::Evp);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a PIX key of any kind.
+///
+/// - `kind(value: &str) -> Result<PixKeyKind, &'static str>`:
+///   Classifies and validates a PIX key.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::BrazilTaxIdValidator;
+
+pub struct BrazilPixKeyValidator;
+
+/// The kinds of PIX keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixKeyKind {
+    Cpf,
+    Cnpj,
+    Phone,
+    Email,
+    /// Random key (Endereço Virtual de Pagamento).
+    Evp,
+}
+
+const BRAZIL_PHONE_PREFIX: &str = "+55";
+const EMAIL_MAX_LENGTH: usize = 77;
+const EMAIL_LABEL_MAX_LENGTH: usize = 63;
+const EMAIL_LOCAL_SPECIAL_CHARACTERS: &str = ".!#$&'*+/=?^_`{|}~-";
+const CPF_LENGTH: usize = 11;
+const CNPJ_LENGTH: usize = 14;
+const UUID_GROUP_LENGTHS: &[usize] = &[8, 4, 4, 4, 12];
+
+impl BrazilPixKeyValidator {
+    /// Validates if the given value is a PIX key of any kind.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::kind(value).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate PIX keys using the BrazilPixKeyValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Classifies the given PIX key and validates it according to the rules of its kind.
+    /// CPF and CNPJ keys may be formatted; other kinds must be given as they are registered,
+    /// except that letters are compared case-insensitively.
+    pub fn kind(value: &str) -> Result<PixKeyKind, &'static str> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("Invalid input");
+        }
+        if value.contains('@') {
+            Self::validate_email(value).map(|_| PixKeyKind::Email)
+        } else if value.starts_with('+') {
+            Self::validate_phone(value).map(|_| PixKeyKind::Phone)
+        } else if value.len() == 36 && value.contains('-') && !value.contains('.') {
+            Self::validate_evp(value).map(|_| PixKeyKind::Evp)
+        } else {
+            Self::validate_tax_id(value)
+        }
+    }
+
+    /// Normalizes a valid key to the form it is registered in the PIX directory.
+    fn normalize(value: &str, kind: PixKeyKind) -> String {
+        let value = value.trim();
+        match kind {
+            PixKeyKind::Cpf | PixKeyKind::Cnpj => {
+                value.chars().filter(char::is_ascii_digit).collect()
+            }
+            PixKeyKind::Phone => value.to_string(),
+            PixKeyKind::Email | PixKeyKind::Evp => value.to_ascii_lowercase(),
+        }
+    }
+
+    /// Validates CPF and CNPJ keys, which may contain the usual formatting characters.
+    fn validate_tax_id(value: &str) -> Result<PixKeyKind, &'static str> {
+        if !value
+            .chars()
+            .all(|c| c.is_ascii_digit() || ".-/".contains(c))
+        {
+            return Err("Invalid format");
+        }
+        let kind = match value.chars().filter(char::is_ascii_digit).count() {
+            CPF_LENGTH => PixKeyKind::Cpf,
+            CNPJ_LENGTH => PixKeyKind::Cnpj,
+            _ => return Err("Invalid length"),
+        };
+        BrazilTaxIdValidator::is_valid(value)?;
+        Ok(kind)
+    }
+
+    /// Validates phone keys: `+55`, a two-digit area code and an 8 or 9-digit number.
+    fn validate_phone(value: &str) -> Result<(), &'static str> {
+        let number = value
+            .strip_prefix(BRAZIL_PHONE_PREFIX)
+            .ok_or("Invalid country code")?;
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if number.len() != 10 && number.len() != 11 {
+            return Err("Invalid length");
+        }
+        if number[..2].contains('0') {
+            return Err("Invalid area code");
+        }
+        Ok(())
+    }
+
+    /// Validates email keys against the pattern accepted by the PIX directory.
+    fn validate_email(value: &str) -> Result<(), &'static str> {
+        if value.len() > EMAIL_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        let (local, domain) = value.split_once('@').ok_or("Invalid format")?;
+        let local_valid = !local.is_empty()
+            && local
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || EMAIL_LOCAL_SPECIAL_CHARACTERS.contains(c));
+        let domain_valid = domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= EMAIL_LABEL_MAX_LENGTH
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+        if local_valid && domain_valid {
+            Ok(())
+        } else {
+            Err("Invalid format")
+        }
+    }
+
+    /// Validates random keys: a hyphenated UUID with version 4 and the RFC 4122 variant.
+    fn validate_evp(value: &str) -> Result<(), &'static str> {
+        let groups: Vec<&str> = value.split('-').collect();
+        let layout_valid = groups.len() == UUID_GROUP_LENGTHS.len()
+            && groups
+                .iter()
+                .zip(UUID_GROUP_LENGTHS)
+                .all(|(group, &length)| {
+                    group.len() == length && group.chars().all(|c| c.is_ascii_hexdigit())
+                });
+        if !layout_valid {
+            return Err("Invalid format");
+        }
+        if !groups[2].starts_with('4') {
+            return Err("Invalid UUID version");
+        }
+        if !groups[3].starts_with(['8', '9', 'a', 'b', 'A', 'B']) {
+            return Err("Invalid UUID variant");
+        }
+        Ok(())
+    }
+}
+
+/// A validated PIX key, stored in the form it is registered in the PIX directory: tax IDs
+/// without formatting, and emails and random keys in lowercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PixKey {
+    kind: PixKeyKind,
+    value: String,
+}
+
+impl PixKey {
+    /// Parses, classifies and validates the given PIX key.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        let kind = BrazilPixKeyValidator::kind(value)?;
+        Ok(PixKey {
+            kind,
+            value: BrazilPixKeyValidator::normalize(value, kind),
+        })
+    }
+
+    /// Returns the kind of the key.
+    pub fn kind(&self) -> PixKeyKind {
+        self.kind
+    }
+
+    /// Returns the normalized key.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+}
+
+impl FromStr for PixKey {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for PixKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            BrazilPixKeyValidator::normalize("12.345.678/0001-95", PixKeyKind::Cnpj),
+            "12345678000195"
+        );
+    }
+}
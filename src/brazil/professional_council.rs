@@ -0,0 +1,300 @@
+/// Brazil Professional Council Registration Validator
+///
+/// This module provides format validation of the registration numbers issued by Brazilian
+/// professional councils, such as the CRM for physicians or the OAB for lawyers. Each
+/// council keeps a registry per state, so a registration is identified by the council, the
+/// state (UF) and the number, optionally followed by a one-letter category suffix:
+///
+/// | Council | Profession  | Digits | Suffixes                                                      |
+/// |---------|-------------|--------|---------------------------------------------------------------|
+/// | CRM     | Physicians  | 1-6    | none                                                          |
+/// | OAB     | Lawyers     | 1-6    | `A` supplementary, `B` transferred, `E` trainee               |
+/// | CREA    | Engineers   | 1-10   | `D` definitive                                                |
+/// | CRC     | Accountants | 1-6    | `O` original, `P` provisional, `S` secondary, `T` transferred |
+/// | CRO     | Dentists    | 1-6    | none                                                          |
+/// | CRF     | Pharmacists | 1-6    | none                                                          |
+///
+/// No council publishes a check digit, so only the format is validated; whether the
+/// registration exists must be verified with the council itself.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilProfessionalCouncilValidator, Council, ProfessionalRegistration, Uf};
+///
+/// assert!(BrazilProfessionalCouncilValidator::is_valid("CRM/SP 123456").is_ok());
+/// assert!(BrazilProfessionalCouncilValidator::is_valid_for_council("123.456/RJ", Council::Oab).is_ok());
+///
+/// let registration = ProfessionalRegistration::parse("oab-rj 123.456-e").unwrap();
+/// assert_eq!(registration.council(), Council::Oab);
+/// assert_eq!(registration.uf(), Uf::RJ);
+/// assert_eq!(registration.number(), "123456");
+/// assert_eq!(registration.to_string(), "OAB/RJ 123456-E");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Validates a registration that starts with the council acronym, followed by the state
+///   and the number in either order.
+///
+/// - `is_valid_for_council(value: &str, council: Council) -> Result<(), &'static str>`:
+///   Validates a registration for the given council, for which the acronym can be omitted.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::Uf;
+
+pub struct BrazilProfessionalCouncilValidator;
+
+/// The professional councils whose registration formats are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Council {
+    /// Conselho Regional de Medicina.
+    Crm,
+    /// Ordem dos Advogados do Brasil.
+    Oab,
+    /// Conselho Regional de Engenharia e Agronomia.
+    Crea,
+    /// Conselho Regional de Contabilidade.
+    Crc,
+    /// Conselho Regional de Odontologia.
+    Cro,
+    /// Conselho Regional de Farmácia.
+    Crf,
+}
+
+impl Council {
+    /// Returns the council with the given acronym, compared case-insensitively, if any.
+    pub fn from_acronym(acronym: &str) -> Option<Self> {
+        match acronym.to_ascii_uppercase().as_str() {
+            "CRM" => Some(Council::Crm),
+            "OAB" => Some(Council::Oab),
+            "CREA" => Some(Council::Crea),
+            "CRC" => Some(Council::Crc),
+            "CRO" => Some(Council::Cro),
+            "CRF" => Some(Council::Crf),
+            _ => None,
+        }
+    }
+
+    /// Returns the acronym of the council.
+    pub fn acronym(&self) -> &'static str {
+        match self {
+            Council::Crm => "CRM",
+            Council::Oab => "OAB",
+            Council::Crea => "CREA",
+            Council::Crc => "CRC",
+            Council::Cro => "CRO",
+            Council::Crf => "CRF",
+        }
+    }
+
+    /// Returns the maximum number of digits of a registration number.
+    fn max_length(&self) -> usize {
+        match self {
+            Council::Crea => 10,
+            _ => 6,
+        }
+    }
+
+    /// Returns the category suffixes the council appends to registration numbers.
+    fn suffixes(&self) -> &'static str {
+        match self {
+            Council::Oab => "ABE",
+            Council::Crea => "D",
+            Council::Crc => "OPST",
+            Council::Crm | Council::Cro | Council::Crf => "",
+        }
+    }
+}
+
+const UF_LENGTH: usize = 2;
+const SEPARATORS: &str = " /-";
+
+impl BrazilProfessionalCouncilValidator {
+    /// Validates if the given registration is correctly formatted. The input must start with
+    /// the council acronym, as in `CRM/SP 123456` or `OAB 123.456-E/RJ`.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::parse(value, None).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate registrations using the BrazilProfessionalCouncilValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given registration is correctly formatted for the given council. The
+    /// acronym can be omitted, as in `SP 123456`; if present, it must match the council.
+    pub fn is_valid_for_council(value: &str, council: Council) -> Result<(), &'static str> {
+        Self::parse(value, Some(council)).map(|_| ())
+    }
+
+    /// Parses a registration, requiring the acronym unless the council is given.
+    fn parse(
+        value: &str,
+        council: Option<Council>,
+    ) -> Result<ProfessionalRegistration, &'static str> {
+        let mut tokens = Self::tokenize(value)?;
+        if tokens.is_empty() {
+            return Err("Invalid input");
+        }
+        let council = match Council::from_acronym(&tokens[0]) {
+            Some(found) if council.is_some_and(|expected| expected != found) => {
+                return Err("Invalid council")
+            }
+            Some(found) => {
+                tokens.remove(0);
+                found
+            }
+            None => council.ok_or("Invalid council")?,
+        };
+
+        let uf_position = tokens
+            .iter()
+            .position(|token| token.len() == UF_LENGTH && !Self::is_number(token))
+            .ok_or("Invalid state")?;
+        let uf: Uf = tokens.remove(uf_position).parse()?;
+
+        let (number, suffix) = match tokens.as_slice() {
+            [number] => (number, None),
+            [number, suffix] if suffix.len() == 1 => (number, suffix.chars().next()),
+            _ => return Err("Invalid format"),
+        };
+        if !Self::is_number(number) {
+            return Err("Invalid format");
+        }
+        if number.len() > council.max_length() {
+            return Err("Invalid length");
+        }
+        if suffix.is_some_and(|suffix| !council.suffixes().contains(suffix)) {
+            return Err("Invalid suffix");
+        }
+
+        Ok(ProfessionalRegistration {
+            council,
+            uf,
+            number: number.to_string(),
+            suffix,
+        })
+    }
+
+    /// Splits the input into uppercase runs of letters and of digits. Dots are ignored, as
+    /// they only group the digits of the number; spaces, slashes and hyphens separate runs.
+    fn tokenize(value: &str) -> Result<Vec<String>, &'static str> {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut current = String::new();
+        for c in value.chars().filter(|&c| c != '.') {
+            if SEPARATORS.contains(c) {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            if !c.is_ascii_alphanumeric() {
+                return Err("Invalid format");
+            }
+            let continues_run = current
+                .chars()
+                .last()
+                .is_none_or(|last| last.is_ascii_digit() == c.is_ascii_digit());
+            if !continues_run {
+                tokens.push(std::mem::take(&mut current));
+            }
+            current.push(c.to_ascii_uppercase());
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+        Ok(tokens)
+    }
+
+    fn is_number(token: &str) -> bool {
+        token.chars().all(|c| c.is_ascii_digit())
+    }
+}
+
+/// A registration with a professional council, whose format has been validated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProfessionalRegistration {
+    council: Council,
+    uf: Uf,
+    number: String,
+    suffix: Option<char>,
+}
+
+impl ProfessionalRegistration {
+    /// Parses and validates the given registration, which must start with the council acronym.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BrazilProfessionalCouncilValidator::parse(value, None)
+    }
+
+    /// Parses and validates the given registration for the given council, for which the
+    /// acronym can be omitted.
+    pub fn parse_for_council(value: &str, council: Council) -> Result<Self, &'static str> {
+        BrazilProfessionalCouncilValidator::parse(value, Some(council))
+    }
+
+    /// Returns the council the professional is registered with.
+    pub fn council(&self) -> Council {
+        self.council
+    }
+
+    /// Returns the state of the regional council.
+    pub fn uf(&self) -> Uf {
+        self.uf
+    }
+
+    /// Returns the digits of the registration number.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// Returns the category suffix, if any.
+    pub fn suffix(&self) -> Option<char> {
+        self.suffix
+    }
+}
+
+impl FromStr for ProfessionalRegistration {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for ProfessionalRegistration {
+    /// Formats the registration as `CRM/SP 123456`, followed by `-` and the suffix, if any.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} {}", self.council.acronym(), self.uf, self.number)?;
+        if let Some(suffix) = self.suffix {
+            write!(f, "-{}", suffix)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            BrazilProfessionalCouncilValidator::tokenize("OAB/sp 123.456-E").unwrap(),
+            vec!["OAB", "SP", "123456", "E"]
+        );
+        assert_eq!(
+            BrazilProfessionalCouncilValidator::tokenize("CRMSP123456").unwrap(),
+            vec!["CRMSP", "123456"]
+        );
+    }
+}
@@ -0,0 +1,149 @@
+/// Brazil RG Validator
+///
+/// This module provides best-effort validation of the Brazilian RG (Registro Geral), the
+/// identity card number issued by each state's public security department. There is no
+/// national standard: lengths differ between states, and only some of them publish the
+/// algorithm behind the check digit.
+///
+/// For São Paulo, whose SSP-SP algorithm is public, the check digit is verified: the first
+/// eight digits are weighted from 2 to 9, and the check digit is the complement of the sum
+/// modulo 11, where 10 is written as `X` and 11 as `0`. For the other states, only the
+/// length and character set are checked, and the result says so.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilRgValidator, RgValidation, Uf};
+///
+/// assert_eq!(
+///     BrazilRgValidator::is_valid_for_state("24.678.131-2", Uf::SP),
+///     Ok(RgValidation::ChecksumVerified)
+/// );
+/// assert_eq!(
+///     BrazilRgValidator::is_valid_for_state("12.345.678-9", Uf::RJ),
+///     Ok(RgValidation::FormatOnly)
+/// );
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Checks if the given RG is plausible for at least one state.
+///
+/// - `is_valid_for_state(value: &str, uf: Uf) -> Result<RgValidation, &'static str>`:
+///   Validates an RG against the rules known for the given state, telling how thoroughly
+///   it could be checked.
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::Uf;
+
+pub struct BrazilRgValidator;
+
+/// How thoroughly an RG could be validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RgValidation {
+    /// The check digit was verified with the issuing state's published algorithm.
+    ChecksumVerified,
+    /// The issuing state has no published algorithm: only the length and characters were checked.
+    FormatOnly,
+}
+
+const RG_MIN_LENGTH: usize = 5;
+const RG_MAX_LENGTH: usize = 14;
+const SP_RG_LENGTH: usize = 9;
+const SP_MULTIPLIER_WEIGHTS: &[u32] = &[2, 3, 4, 5, 6, 7, 8, 9];
+const VALIDATION_MODULUS: u32 = 11;
+const CHECK_DIGIT_TEN: char = 'X';
+
+impl BrazilRgValidator {
+    /// Checks if the given RG is plausible, that is, if it has an acceptable length and
+    /// only digits, except for an `X` check digit. The input can be formatted.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        Self::validate_format(&sanitized_value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RGs using the BrazilRgValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates the given RG against the rules known for the issuing state.
+    pub fn is_valid_for_state(value: &str, uf: Uf) -> Result<RgValidation, &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        Self::validate_format(&sanitized_value)?;
+        match uf {
+            Uf::SP => Self::validate_sp(&sanitized_value).map(|_| RgValidation::ChecksumVerified),
+            _ => Ok(RgValidation::FormatOnly),
+        }
+    }
+
+    /// Removes formatting characters from the input, keeping digits and letters.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Checks the length and the character set.
+    fn validate_format(value: &str) -> Result<(), &'static str> {
+        if value.is_empty() {
+            return Err("Invalid input");
+        }
+        if !(RG_MIN_LENGTH..=RG_MAX_LENGTH).contains(&value.len()) {
+            return Err("Invalid length");
+        }
+        let (body, check_digit) = value.split_at(value.len() - 1);
+        let is_valid_format = body.chars().all(|c| c.is_ascii_digit())
+            && check_digit
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == CHECK_DIGIT_TEN);
+        if is_valid_format {
+            Ok(())
+        } else {
+            Err("Invalid format")
+        }
+    }
+
+    /// Validates the SSP-SP check digit.
+    fn validate_sp(value: &str) -> Result<(), &'static str> {
+        if value.len() != SP_RG_LENGTH {
+            return Err("Invalid length");
+        }
+        let sum: u32 = value
+            .chars()
+            .zip(SP_MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        let expected = match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => CHECK_DIGIT_TEN,
+            11 => '0',
+            digit => char::from_digit(digit, 10).expect("Single digit"),
+        };
+        if value.ends_with(expected) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            BrazilRgValidator::sanitize_input("12.345.678-x"),
+            "12345678X"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+/// Brazil SUFRAMA Registration Validator
+///
+/// This module provides functionality to validate the registration of companies with the
+/// SUFRAMA (Superintendência da Zona Franca de Manaus), which NF-e issuers must inform to
+/// benefit from the tax incentives of the Manaus Free Trade Zone. The registration has 9
+/// digits, the last of which is a modulus 11 check digit over the first 8, with weights
+/// from 9 down to 2.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::BrazilSuframaValidator;
+///
+/// assert!(BrazilSuframaValidator::is_valid("20.0106.02-3").is_ok());
+/// assert!(BrazilSuframaValidator::is_valid("200106024").is_err());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a registration. Removes non-digit characters, checks the
+///   length, and validates the check digit.
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::mod11_check_digit;
+
+pub struct BrazilSuframaValidator;
+
+const SUFRAMA_LENGTH: usize = 9;
+
+impl BrazilSuframaValidator {
+    /// Validates if the given registration is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != SUFRAMA_LENGTH {
+            return Err("Invalid length");
+        }
+        let check_digit = mod11_check_digit(&sanitized_value[..8]);
+        if sanitized_value[8..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SUFRAMA registrations using the BrazilSuframaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            BrazilSuframaValidator::sanitize_input("20.0106.02-3"),
+            "200106023"
+        );
+    }
+}
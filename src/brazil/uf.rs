@@ -0,0 +1,175 @@
+/// Brazilian Federative Units
+///
+/// This module provides the `Uf` enum, listing the 26 states and the Federal District.
+/// It is shared by validators whose rules depend on the state a document was issued in.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::Uf;
+///
+/// let uf: Uf = "sp".parse().unwrap();
+/// assert_eq!(uf, Uf::SP);
+/// assert_eq!(uf.abbreviation(), "SP");
+/// assert_eq!(uf.ibge_code(), 35);
+/// assert_eq!(Uf::from_ibge_code(53), Some(Uf::DF));
+/// ```
+use std::fmt;
+use std::str::FromStr;
+
+/// A Brazilian federative unit (Unidade Federativa).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Uf {
+    AC,
+    AL,
+    AP,
+    AM,
+    BA,
+    CE,
+    DF,
+    ES,
+    GO,
+    MA,
+    MT,
+    MS,
+    MG,
+    PA,
+    PB,
+    PR,
+    PE,
+    PI,
+    RJ,
+    RN,
+    RS,
+    RO,
+    RR,
+    SC,
+    SP,
+    SE,
+    TO,
+}
+
+impl Uf {
+    /// All federative units, in alphabetical order of their full names.
+    pub const ALL: [Uf; 27] = [
+        Uf::AC,
+        Uf::AL,
+        Uf::AP,
+        Uf::AM,
+        Uf::BA,
+        Uf::CE,
+        Uf::DF,
+        Uf::ES,
+        Uf::GO,
+        Uf::MA,
+        Uf::MT,
+        Uf::MS,
+        Uf::MG,
+        Uf::PA,
+        Uf::PB,
+        Uf::PR,
+        Uf::PE,
+        Uf::PI,
+        Uf::RJ,
+        Uf::RN,
+        Uf::RS,
+        Uf::RO,
+        Uf::RR,
+        Uf::SC,
+        Uf::SP,
+        Uf::SE,
+        Uf::TO,
+    ];
+
+    /// Returns the two-letter abbreviation of the federative unit.
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Uf::AC => "AC",
+            Uf::AL => "AL",
+            Uf::AP => "AP",
+            Uf::AM => "AM",
+            Uf::BA => "BA",
+            Uf::CE => "CE",
+            Uf::DF => "DF",
+            Uf::ES => "ES",
+            Uf::GO => "GO",
+            Uf::MA => "MA",
+            Uf::MT => "MT",
+            Uf::MS => "MS",
+            Uf::MG => "MG",
+            Uf::PA => "PA",
+            Uf::PB => "PB",
+            Uf::PR => "PR",
+            Uf::PE => "PE",
+            Uf::PI => "PI",
+            Uf::RJ => "RJ",
+            Uf::RN => "RN",
+            Uf::RS => "RS",
+            Uf::RO => "RO",
+            Uf::RR => "RR",
+            Uf::SC => "SC",
+            Uf::SP => "SP",
+            Uf::SE => "SE",
+            Uf::TO => "TO",
+        }
+    }
+
+    /// Returns the numeric code assigned to the federative unit by IBGE, as used in
+    /// fiscal documents and other registries.
+    pub fn ibge_code(&self) -> u8 {
+        match self {
+            Uf::AC => 12,
+            Uf::AL => 27,
+            Uf::AP => 16,
+            Uf::AM => 13,
+            Uf::BA => 29,
+            Uf::CE => 23,
+            Uf::DF => 53,
+            Uf::ES => 32,
+            Uf::GO => 52,
+            Uf::MA => 21,
+            Uf::MT => 51,
+            Uf::MS => 50,
+            Uf::MG => 31,
+            Uf::PA => 15,
+            Uf::PB => 25,
+            Uf::PR => 41,
+            Uf::PE => 26,
+            Uf::PI => 22,
+            Uf::RJ => 33,
+            Uf::RN => 24,
+            Uf::RS => 43,
+            Uf::RO => 11,
+            Uf::RR => 14,
+            Uf::SC => 42,
+            Uf::SP => 35,
+            Uf::SE => 28,
+            Uf::TO => 17,
+        }
+    }
+
+    /// Returns the federative unit with the given IBGE code, if any.
+    pub fn from_ibge_code(code: u8) -> Option<Uf> {
+        Uf::ALL.iter().copied().find(|uf| uf.ibge_code() == code)
+    }
+}
+
+impl FromStr for Uf {
+    type Err = &'static str;
+
+    /// Parses a two-letter abbreviation, ignoring case and surrounding whitespace.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        Uf::ALL
+            .iter()
+            .copied()
+            .find(|uf| uf.abbreviation().eq_ignore_ascii_case(value))
+            .ok_or("Invalid state")
+    }
+}
+
+impl fmt::Display for Uf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
@@ -0,0 +1,155 @@
+/// Brazil Vehicle Plate Validator
+///
+/// This module provides functionality to validate Brazilian vehicle license plates.
+/// Two standards are in circulation: the legacy format (`AAA-9999`), issued until 2018,
+/// and the Mercosul format (`AAA9A99`), which replaces the second digit with a letter.
+///
+/// The `BrazilVehiclePlateValidator` struct contains methods to validate a plate, detect
+/// which standard it follows, and convert between the two standards.
+///
+/// # Usage
+///
+/// ```
+/// use docval::brazil::{BrazilVehiclePlateValidator, VehiclePlateFormat};
+///
+/// assert!(BrazilVehiclePlateValidator::is_valid("ABC-1234").is_ok());
+/// assert!(BrazilVehiclePlateValidator::is_valid("ABC1C34").is_ok());
+///
+/// assert_eq!(
+///     BrazilVehiclePlateValidator::detect_format("ABC1C34"),
+///     Ok(VehiclePlateFormat::Mercosul)
+/// );
+/// assert_eq!(
+///     BrazilVehiclePlateValidator::to_mercosul("ABC-1234"),
+///     Ok("ABC1C34".to_string())
+/// );
+/// assert_eq!(
+///     BrazilVehiclePlateValidator::to_legacy("ABC1C34"),
+///     Ok("ABC-1234".to_string())
+/// );
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a plate in either standard.
+///
+/// - `detect_format(value: &str) -> Result<VehiclePlateFormat, &'static str>`:
+///   Tells which standard a valid plate follows.
+///
+/// - `to_mercosul(value: &str) -> Result<String, &'static str>`:
+///   Converts a plate to the Mercosul standard.
+///
+/// - `to_legacy(value: &str) -> Result<String, &'static str>`:
+///   Converts a plate to the legacy standard. Only Mercosul plates whose fifth
+///   character is a letter between `A` and `J` have a legacy counterpart.
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct BrazilVehiclePlateValidator;
+
+/// The plate standards issued in Brazil.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VehiclePlateFormat {
+    /// The pre-2018 format: three letters followed by four digits (`AAA-9999`).
+    Legacy,
+    /// The Mercosul format: three letters, a digit, a letter and two digits (`AAA9A99`).
+    Mercosul,
+}
+
+const PLATE_LENGTH: usize = 7;
+const LETTERS_LENGTH: usize = 3;
+const CONVERTIBLE_POSITION: usize = 4;
+
+impl BrazilVehiclePlateValidator {
+    /// Validates if the given plate follows either the legacy or the Mercosul standard.
+    /// The input is case-insensitive and may contain a hyphen or spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::detect_format(value).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate vehicle plates using the BrazilVehiclePlateValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Detects which standard the given plate follows.
+    pub fn detect_format(value: &str) -> Result<VehiclePlateFormat, &'static str> {
+        let plate = Self::sanitize_input(value);
+        if plate.is_empty() {
+            return Err("Invalid input");
+        }
+        let chars: Vec<char> = plate.chars().collect();
+        if chars.len() != PLATE_LENGTH {
+            return Err("Invalid length");
+        }
+        if !chars[..LETTERS_LENGTH].iter().all(char::is_ascii_uppercase) {
+            return Err("Invalid format");
+        }
+        let digits_valid = [3, 5, 6].iter().all(|&i| chars[i].is_ascii_digit());
+        if !digits_valid {
+            return Err("Invalid format");
+        }
+        match chars[CONVERTIBLE_POSITION] {
+            c if c.is_ascii_digit() => Ok(VehiclePlateFormat::Legacy),
+            c if c.is_ascii_uppercase() => Ok(VehiclePlateFormat::Mercosul),
+            _ => Err("Invalid format"),
+        }
+    }
+
+    /// Converts the given plate to the Mercosul standard (`AAA9A99`).
+    pub fn to_mercosul(value: &str) -> Result<String, &'static str> {
+        let format = Self::detect_format(value)?;
+        let mut chars: Vec<char> = Self::sanitize_input(value).chars().collect();
+        if format == VehiclePlateFormat::Legacy {
+            let digit = chars[CONVERTIBLE_POSITION] as u8 - b'0';
+            chars[CONVERTIBLE_POSITION] = (b'A' + digit) as char;
+        }
+        Ok(chars.into_iter().collect())
+    }
+
+    /// Converts the given plate to the legacy standard (`AAA-9999`).
+    pub fn to_legacy(value: &str) -> Result<String, &'static str> {
+        let format = Self::detect_format(value)?;
+        let mut chars: Vec<char> = Self::sanitize_input(value).chars().collect();
+        if format == VehiclePlateFormat::Mercosul {
+            let letter = chars[CONVERTIBLE_POSITION];
+            if !('A'..='J').contains(&letter) {
+                return Err("No legacy equivalent");
+            }
+            chars[CONVERTIBLE_POSITION] = (b'0' + (letter as u8 - b'A')) as char;
+        }
+        let (letters, digits) = chars.split_at(LETTERS_LENGTH);
+        Ok(format!(
+            "{}-{}",
+            letters.iter().collect::<String>(),
+            digits.iter().collect::<String>()
+        ))
+    }
+
+    /// Removes hyphens and whitespace and converts the input to uppercase.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| *c != '-' && !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            BrazilVehiclePlateValidator::sanitize_input("abc-1c34"),
+            "ABC1C34"
+        );
+    }
+}
@@ -0,0 +1,202 @@
+/// Bulgaria EGN Validator
+///
+/// This module provides functionality to validate and parse the EGN (Единен граждански
+/// номер), the 10-digit unified civil number assigned to Bulgarian citizens:
+///
+/// | Positions | Field                                                       |
+/// |-----------|-------------------------------------------------------------|
+/// | 1-6       | Date of birth as `YYMMDD`, with the century in the month    |
+/// | 7-9       | Serial number, whose last digit is even for men             |
+/// | 10        | Check digit                                                 |
+///
+/// People born in the 1900s have their month as is, while 20 is added to it for the 1800s
+/// and 40 for the 2000s.
+///
+/// The first 9 digits are weighted by 2, 4, 8, 5, 10, 9, 7, 3 and 6, and the check digit
+/// is the sum modulo 11, where 10 yields 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::bulgaria::{BulgariaEgnValidator, Egn};
+/// use docval::Sex;
+///
+/// assert!(BulgariaEgnValidator::is_valid("7523169263").is_ok());
+/// assert!(BulgariaEgnValidator::is_valid("7523169264").is_err());
+///
+/// let egn = Egn::parse("7542011030").unwrap();
+/// assert_eq!(egn.birth_year(), 2075);
+/// assert_eq!(egn.birth_month(), 2);
+/// assert_eq!(egn.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an EGN. Removes spaces, checks the length, format and
+///   date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct BulgariaEgnValidator;
+
+const EGN_LENGTH: usize = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[2, 4, 8, 5, 10, 9, 7, 3, 6];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl BulgariaEgnValidator {
+    /// Validates if the given EGN is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != EGN_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::birth_date(&sanitized_value) {
+            Some((year, month, day)) if is_valid_date(year, month, day) => {}
+            _ => return Err("Invalid date"),
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..9]);
+        if sanitized_value[9..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate EGNs using the BulgariaEgnValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Decodes the date of birth, taking the century from the month offset. Returns `None`
+    /// if the month is outside every century range.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        let (year, month, day) = match field(2) {
+            month @ 1..=12 => (1900 + field(0), month, field(4)),
+            month @ 21..=32 => (1800 + field(0), month - 20, field(4)),
+            month @ 41..=52 => (2000 + field(0), month - 40, field(4)),
+            _ => return None,
+        };
+        Some((year, month, day))
+    }
+
+    /// Calculates the check digit of the first 9 digits of an EGN.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum % VALIDATION_MODULUS % 10
+    }
+}
+
+/// A validated EGN, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Egn(String);
+
+impl Egn {
+    /// Parses and validates the given EGN, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        BulgariaEgnValidator::is_valid(value)?;
+        Ok(Egn(BulgariaEgnValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the EGN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, whose century is given by the month offset.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth, without the century offset.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if (self.0.as_bytes()[8] - b'0').is_multiple_of(2) {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        BulgariaEgnValidator::birth_date(&self.0).expect("Validated date")
+    }
+}
+
+impl FromStr for Egn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Egn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            BulgariaEgnValidator::birth_date("8001010008"),
+            Some((1980, 1, 1))
+        );
+        assert_eq!(
+            BulgariaEgnValidator::birth_date("7523169263"),
+            Some((1875, 3, 16))
+        );
+        assert_eq!(
+            BulgariaEgnValidator::birth_date("7542011030"),
+            Some((2075, 2, 1))
+        );
+        assert_eq!(BulgariaEgnValidator::birth_date("8013010008"), None);
+    }
+}
@@ -0,0 +1,215 @@
+/// Canada Business Number Validator
+///
+/// This module provides functionality to validate and parse the Business Number assigned by
+/// the Canada Revenue Agency (CRA). It is used in two forms:
+///
+/// - BN9: the 9-digit root that identifies the business, whose last digit is a Luhn check
+///   digit;
+/// - BN15: a program account, made of the root, a two-letter program identifier (such as
+///   `RT` for GST/HST or `RP` for payroll deductions) and a 4-digit reference number that
+///   tells apart accounts of the same program, as in `123456782RT0001`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::canada::{BusinessNumber, CanadaBusinessNumberValidator, ProgramAccount};
+///
+/// assert!(CanadaBusinessNumberValidator::is_valid("123456782").is_ok());
+/// assert!(CanadaBusinessNumberValidator::is_valid("123456782 RT 0001").is_ok());
+///
+/// let bn = BusinessNumber::parse("123456782RP0002").unwrap();
+/// assert_eq!(bn.root(), "123456782");
+/// assert_eq!(bn.program_account(), Some(ProgramAccount::Payroll));
+/// assert_eq!(bn.reference_number(), Some("0002"));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a business number, in the BN9 or BN15 form.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct CanadaBusinessNumberValidator;
+
+/// The CRA programs a business can hold an account in, identified by two letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgramAccount {
+    /// Corporation income tax (RC).
+    CorporateIncomeTax,
+    /// Excise duty (RD).
+    ExciseDuty,
+    /// Excise tax (RE).
+    ExciseTax,
+    /// Air travellers security charge (RG).
+    AirTravellersSecurityCharge,
+    /// Import-export (RM).
+    ImportExport,
+    /// Insurance premium tax (RN).
+    InsurancePremiumTax,
+    /// Payroll deductions (RP).
+    Payroll,
+    /// Registered charity (RR).
+    Charity,
+    /// GST/HST (RT).
+    GstHst,
+    /// Information returns (RZ).
+    InformationReturns,
+    /// Softwood lumber products export charge (SL).
+    SoftwoodLumber,
+}
+
+impl ProgramAccount {
+    /// Returns the program with the given identifier, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "RC" => Some(ProgramAccount::CorporateIncomeTax),
+            "RD" => Some(ProgramAccount::ExciseDuty),
+            "RE" => Some(ProgramAccount::ExciseTax),
+            "RG" => Some(ProgramAccount::AirTravellersSecurityCharge),
+            "RM" => Some(ProgramAccount::ImportExport),
+            "RN" => Some(ProgramAccount::InsurancePremiumTax),
+            "RP" => Some(ProgramAccount::Payroll),
+            "RR" => Some(ProgramAccount::Charity),
+            "RT" => Some(ProgramAccount::GstHst),
+            "RZ" => Some(ProgramAccount::InformationReturns),
+            "SL" => Some(ProgramAccount::SoftwoodLumber),
+            _ => None,
+        }
+    }
+
+    /// Returns the two-letter identifier of the program.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ProgramAccount::CorporateIncomeTax => "RC",
+            ProgramAccount::ExciseDuty => "RD",
+            ProgramAccount::ExciseTax => "RE",
+            ProgramAccount::AirTravellersSecurityCharge => "RG",
+            ProgramAccount::ImportExport => "RM",
+            ProgramAccount::InsurancePremiumTax => "RN",
+            ProgramAccount::Payroll => "RP",
+            ProgramAccount::Charity => "RR",
+            ProgramAccount::GstHst => "RT",
+            ProgramAccount::InformationReturns => "RZ",
+            ProgramAccount::SoftwoodLumber => "SL",
+        }
+    }
+}
+
+const ROOT_LENGTH: usize = 9;
+const PROGRAM_ACCOUNT_LENGTH: usize = 15;
+
+impl CanadaBusinessNumberValidator {
+    /// Validates if the given business number is correct. The input can be a 9-digit root
+    /// or a 15-character program account, plain or formatted, in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ROOT_LENGTH && sanitized_value.len() != PROGRAM_ACCOUNT_LENGTH {
+            return Err("Invalid length");
+        }
+        let root = &sanitized_value[..ROOT_LENGTH];
+        if !root.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.len() == PROGRAM_ACCOUNT_LENGTH {
+            if !sanitized_value[11..].chars().all(|c| c.is_ascii_digit()) {
+                return Err("Invalid format");
+            }
+            if ProgramAccount::from_code(&sanitized_value[9..11]).is_none() {
+                return Err("Invalid program identifier");
+            }
+        }
+        if root[8..] == luhn_check_digit(&root[..8]).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate business numbers using the CanadaBusinessNumberValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes formatting characters from the input, keeping digits and letters.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+}
+
+/// A validated business number, in the BN9 or BN15 form, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BusinessNumber(String);
+
+impl BusinessNumber {
+    /// Parses and validates the given business number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CanadaBusinessNumberValidator::is_valid(value)?;
+        Ok(BusinessNumber(
+            CanadaBusinessNumberValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 9 or 15 characters of the business number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 9-digit root that identifies the business.
+    pub fn root(&self) -> &str {
+        &self.0[..ROOT_LENGTH]
+    }
+
+    /// Returns the program of the account, or `None` for a BN9.
+    pub fn program_account(&self) -> Option<ProgramAccount> {
+        self.0.get(9..11).and_then(ProgramAccount::from_code)
+    }
+
+    /// Returns the reference number of the account, or `None` for a BN9.
+    pub fn reference_number(&self) -> Option<&str> {
+        self.0.get(11..)
+    }
+}
+
+impl FromStr for BusinessNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for BusinessNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            CanadaBusinessNumberValidator::sanitize_input("123456782 rt 0001"),
+            "123456782RT0001"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+/// Canada SIN Validator
+///
+/// This module provides functionality to validate and parse the Social Insurance Number,
+/// the 9-digit number used by Canadians to work and access government programs. Its last
+/// digit is a Luhn check digit, and its first digit identifies where it was registered:
+///
+/// - `1`: Atlantic provinces;
+/// - `2` and `3`: Quebec;
+/// - `4` and `5`: Ontario;
+/// - `6`: Prairie provinces, Northwest Territories and Nunavut;
+/// - `7`: British Columbia and Yukon;
+/// - `9`: temporary residents, such as foreign workers and students.
+///
+/// Numbers starting with `0` are only used for fictitious examples, and `8` is not assigned,
+/// so both are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::canada::{CanadaSinValidator, Sin, SinRegion};
+///
+/// assert!(CanadaSinValidator::is_valid("123 456 782").is_ok());
+///
+/// let sin = Sin::parse("123-456-782").unwrap();
+/// assert_eq!(sin.region(), Some(SinRegion::Atlantic));
+/// assert!(!sin.is_temporary());
+/// assert!(Sin::parse("900000001").unwrap().is_temporary());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a SIN. Removes non-digit characters, checks the length
+///   and the first digit, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct CanadaSinValidator;
+
+/// The regions identified by the first digit of a permanent SIN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SinRegion {
+    /// New Brunswick, Newfoundland and Labrador, Nova Scotia and Prince Edward Island (1).
+    Atlantic,
+    /// Quebec (2, 3).
+    Quebec,
+    /// Ontario (4, 5).
+    Ontario,
+    /// Manitoba, Saskatchewan, Alberta, Northwest Territories and Nunavut (6).
+    Prairies,
+    /// British Columbia and Yukon (7).
+    Pacific,
+}
+
+impl SinRegion {
+    /// Returns the region identified by the given first digit, if any.
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(SinRegion::Atlantic),
+            2 | 3 => Some(SinRegion::Quebec),
+            4 | 5 => Some(SinRegion::Ontario),
+            6 => Some(SinRegion::Prairies),
+            7 => Some(SinRegion::Pacific),
+            _ => None,
+        }
+    }
+}
+
+const SIN_LENGTH: usize = 9;
+const TEMPORARY_PREFIX: char = '9';
+
+impl CanadaSinValidator {
+    /// Validates if the given SIN is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != SIN_LENGTH {
+            return Err("Invalid length");
+        }
+        if sanitized_value.starts_with(['0', '8']) {
+            return Err("Invalid prefix");
+        }
+        let check_digit = luhn_check_digit(&sanitized_value[..8]);
+        if sanitized_value[8..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SINs using the CanadaSinValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+/// A validated SIN, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sin(String);
+
+impl Sin {
+    /// Parses and validates the given SIN, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CanadaSinValidator::is_valid(value)?;
+        Ok(Sin(CanadaSinValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the SIN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the SIN was issued to a temporary resident.
+    pub fn is_temporary(&self) -> bool {
+        self.0.starts_with(TEMPORARY_PREFIX)
+    }
+
+    /// Returns the region where the SIN was registered, or `None` for temporary SINs.
+    pub fn region(&self) -> Option<SinRegion> {
+        SinRegion::from_code(self.0.as_bytes()[0] - b'0')
+    }
+
+    /// Returns the SIN with all but the last three digits masked, as `*** *** 782`.
+    pub fn masked(&self) -> String {
+        format!("*** *** {}", &self.0[6..9])
+    }
+}
+
+impl FromStr for Sin {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Sin {
+    /// Formats the SIN as `XXX XXX XXX`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", &self.0[0..3], &self.0[3..6], &self.0[6..9])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            CanadaSinValidator::sanitize_input("123-456 782"),
+            "123456782"
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Checksum Algorithms
+//!
+//! This module gathers the check-digit algorithms shared by validators of different
+//! documents and countries. Country-specific variations (such as how a remainder of 10
+//! is mapped) stay in the validators themselves.
+//!
+//! All functions expect a string of ASCII digits, and panic otherwise; validators are
+//! expected to sanitize and check their input before calling them.
+
+/// Calculates the Luhn (modulus 10) check digit of the given digits. Starting from the
+/// rightmost digit, every other digit is doubled and the digits of the products are added.
+/// This is the same algorithm as the "módulo 10" used by Brazilian bank slips.
+pub(crate) fn luhn_check_digit(value: &str) -> u32 {
+    let sum: u32 = value
+        .chars()
+        .rev()
+        .zip([2, 1].iter().cycle())
+        .map(|(c, &w)| {
+            let product = c.to_digit(10).expect("Invalid digit in input") * w;
+            product / 10 + product % 10
+        })
+        .sum();
+
+    (10 - sum % 10) % 10
+}
+
+/// Calculates the modulus 11 check digit of the given digits, weighting them from right to
+/// left with the repeating sequence 2 to 9. Remainders of 0 and 1 yield 0.
+pub(crate) fn mod11_check_digit(value: &str) -> u32 {
+    let sum: u32 = value
+        .chars()
+        .rev()
+        .zip((2..=9).cycle())
+        .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+        .sum();
+
+    let remainder = sum % 11;
+    if remainder < 2 {
+        0
+    } else {
+        11 - remainder
+    }
+}
+
+/// Calculates the remainder of the number formed by the given digits divided by 97, as used
+/// by ISO 7064 MOD 97-10. Works on numbers of any length.
+pub(crate) fn mod97(value: &str) -> u32 {
+    value.chars().fold(0, |remainder, c| {
+        (remainder * 10 + c.to_digit(10).expect("Invalid digit in input")) % 97
+    })
+}
+
+/// Calculates the ISO 7064 MOD 11,10 check digit of the given digits. A running product
+/// starts at 10 and, for each digit, is added to it modulo 10 (with 0 taken as 10), doubled,
+/// and reduced modulo 11.
+pub(crate) fn mod11_10_check_digit(value: &str) -> u32 {
+    let product = value.chars().fold(10, |product, c| {
+        let sum = match (c.to_digit(10).expect("Invalid digit in input") + product) % 10 {
+            0 => 10,
+            sum => sum,
+        };
+        sum * 2 % 11
+    });
+
+    (11 - product) % 10
+}
+
+const TWO_STAGE_FIRST_WEIGHTS: &[u32] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 1];
+const TWO_STAGE_SECOND_WEIGHTS: &[u32] = &[3, 4, 5, 6, 7, 8, 9, 1, 2, 3];
+
+/// Calculates the two-stage modulus 11 check digit of the given digits, used by the
+/// Estonian and Lithuanian personal codes. The check digit is the remainder modulo 11 of
+/// the digits weighted by 1 to 9 and then 1 again; if it is 10, the weights start at 3
+/// instead, and a remainder of 10 again yields 0.
+pub(crate) fn two_stage_mod11_check_digit(value: &str) -> u32 {
+    let remainder = |weights: &[u32]| -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(weights)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum % 11
+    };
+    match remainder(TWO_STAGE_FIRST_WEIGHTS) {
+        10 => match remainder(TWO_STAGE_SECOND_WEIGHTS) {
+            10 => 0,
+            digit => digit,
+        },
+        digit => digit,
+    }
+}
+
+const VERHOEFF_MULTIPLICATION: [[u32; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+const VERHOEFF_PERMUTATION: [[u32; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 7, 8, 6, 0],
+    [4, 2, 8, 9, 5, 7, 1, 6, 3, 0],
+    [2, 7, 9, 3, 6, 5, 4, 1, 8, 0],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+const VERHOEFF_INVERSE: [u32; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+/// Calculates the Verhoeff check digit of the given digits, based on the dihedral group
+/// D5. Starting from the rightmost digit, each digit is permuted according to its position
+/// and combined with the running check, which catches all single-digit errors and adjacent
+/// transpositions.
+pub(crate) fn verhoeff_check_digit(value: &str) -> u32 {
+    let check = value.chars().rev().enumerate().fold(0, |check, (i, c)| {
+        let digit = c.to_digit(10).expect("Invalid digit in input") as usize;
+        VERHOEFF_MULTIPLICATION[check as usize][VERHOEFF_PERMUTATION[(i + 1) % 8][digit] as usize]
+    });
+
+    VERHOEFF_INVERSE[check as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_luhn_check_digit() {
+        assert_eq!(luhn_check_digit("7992739871"), 3);
+    }
+
+    #[test]
+    fn test_mod97() {
+        assert_eq!(mod97("79444"), 1);
+    }
+
+    #[test]
+    fn test_mod11_check_digit() {
+        assert_eq!(
+            mod11_check_digit("3517061234567800019555001000000001100000001"),
+            4
+        );
+    }
+
+    #[test]
+    fn test_mod11_10_check_digit() {
+        assert_eq!(mod11_10_check_digit("13669597"), 6);
+        assert_eq!(mod11_10_check_digit("3339200596"), 1);
+    }
+
+    #[test]
+    fn test_two_stage_mod11_check_digit() {
+        assert_eq!(two_stage_mod11_check_digit("3760503029"), 9);
+        assert_eq!(two_stage_mod11_check_digit("3800101104"), 4);
+        assert_eq!(two_stage_mod11_check_digit("3800101025"), 0);
+    }
+
+    #[test]
+    fn test_verhoeff_check_digit() {
+        assert_eq!(verhoeff_check_digit("236"), 3);
+        assert_eq!(verhoeff_check_digit("12345"), 1);
+    }
+}
@@ -0,0 +1,174 @@
+/// Chile RUT/RUN Validator
+///
+/// This module provides functionality to validate and parse the RUT (Rol Único Tributario),
+/// the tax identifier of people and companies in Chile. For people it is the same number as
+/// the RUN (Rol Único Nacional) printed on their identity card. It is made of a body of 7 or
+/// 8 digits followed by a check character, usually formatted as `12.345.678-5`.
+///
+/// The body is weighted from right to left with the repeating sequence 2 to 7, and the
+/// check character is the complement of the sum modulo 11, where 11 yields `0` and 10
+/// yields `K`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::chile::{ChileRutValidator, Rut};
+///
+/// assert!(ChileRutValidator::is_valid("12.345.678-5").is_ok());
+/// assert!(ChileRutValidator::is_valid("10000013-k").is_ok());
+///
+/// let rut = Rut::parse("123456785").unwrap();
+/// assert_eq!(rut.number(), 12345678);
+/// assert_eq!(rut.check_character(), '5');
+/// assert_eq!(rut.to_string(), "12.345.678-5");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a RUT. Removes dots, hyphens and spaces, checks the
+///   length and the format, and validates the check character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct ChileRutValidator;
+
+const RUT_MIN_LENGTH: usize = 8;
+const RUT_MAX_LENGTH: usize = 9;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl ChileRutValidator {
+    /// Validates if the given RUT is correct. The input can be a plain or formatted string,
+    /// and the `K` check character can be lowercase.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < RUT_MIN_LENGTH || sanitized_value.len() > RUT_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        let (body, check_character) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if !body.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if check_character.starts_with(Self::calculate_check_character(body)) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RUTs using the ChileRutValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes dots, hyphens and spaces from the input, and uppercases the `K` check
+    /// character.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, '.' | '-' | ' '))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Calculates the check character of the given body.
+    fn calculate_check_character(body: &str) -> char {
+        let sum: u32 = body
+            .chars()
+            .rev()
+            .zip((2..=7).cycle())
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => '0',
+            10 => 'K',
+            digit => char::from_digit(digit, 10).expect("Single digit"),
+        }
+    }
+}
+
+/// A validated RUT, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rut(String);
+
+impl Rut {
+    /// Parses and validates the given RUT, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ChileRutValidator::is_valid(value)?;
+        Ok(Rut(ChileRutValidator::sanitize_input(value)))
+    }
+
+    /// Returns the body and check character of the RUT, without formatting.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the body of the RUT, without the check character.
+    pub fn number(&self) -> u32 {
+        self.0[..self.0.len() - 1]
+            .parse()
+            .expect("Validated digits")
+    }
+
+    /// Returns the check character, a digit or `K`.
+    pub fn check_character(&self) -> char {
+        self.0.chars().last().expect("Validated length")
+    }
+}
+
+impl FromStr for Rut {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Rut {
+    /// Formats the RUT as `12.345.678-5`, with the body grouped in thousands.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.number();
+        write!(
+            f,
+            "{}.{:03}.{:03}-{}",
+            number / 1_000_000,
+            number / 1_000 % 1_000,
+            number % 1_000,
+            self.check_character()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_character() {
+        assert_eq!(
+            ChileRutValidator::calculate_check_character("12345678"),
+            '5'
+        );
+        assert_eq!(
+            ChileRutValidator::calculate_check_character("10000013"),
+            'K'
+        );
+        assert_eq!(
+            ChileRutValidator::calculate_check_character("30686957"),
+            '4'
+        );
+    }
+}
@@ -0,0 +1,154 @@
+/// Colombia NIT Validator
+///
+/// This module provides functionality to validate and parse the NIT (Número de
+/// Identificación Tributaria), the tax identifier assigned by the DIAN to companies and
+/// people. It is made of a body of 8 to 15 digits, usually 9 for companies, followed by a
+/// verification digit (DV), and is formatted as `900.123.456-8`.
+///
+/// The body is weighted from right to left with the DIAN table of prime numbers
+/// `3, 7, 13, 17, 19, 23, 29, 37, 41, 43, 47, 53, 59, 67, 71`. The verification digit is the
+/// remainder of the sum modulo 11 when it is 0 or 1, and its complement to 11 otherwise.
+///
+/// # Usage
+///
+/// ```
+/// use docval::colombia::{ColombiaNitValidator, Nit};
+///
+/// assert!(ColombiaNitValidator::is_valid("800.197.268-4").is_ok());
+///
+/// let nit = Nit::parse("9001234568").unwrap();
+/// assert_eq!(nit.number(), "900123456");
+/// assert_eq!(nit.check_digit(), 8);
+/// assert_eq!(nit.to_string(), "900.123.456-8");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIT. Removes non-digit characters, checks the length,
+///   and validates the verification digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct ColombiaNitValidator;
+
+const NIT_MIN_LENGTH: usize = 9;
+const NIT_MAX_LENGTH: usize = 16;
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 7, 13, 17, 19, 23, 29, 37, 41, 43, 47, 53, 59, 67, 71];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl ColombiaNitValidator {
+    /// Validates if the given NIT, including its verification digit, is correct. The input
+    /// can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < NIT_MIN_LENGTH || sanitized_value.len() > NIT_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        let (body, check_digit) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if check_digit == Self::calculate_check_digit(body).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NITs using the ColombiaNitValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the verification digit of the given body.
+    fn calculate_check_digit(body: &str) -> u32 {
+        let sum: u32 = body
+            .chars()
+            .rev()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match sum % VALIDATION_MODULUS {
+            remainder @ (0 | 1) => remainder,
+            remainder => VALIDATION_MODULUS - remainder,
+        }
+    }
+}
+
+/// A validated NIT, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nit(String);
+
+impl Nit {
+    /// Parses and validates the given NIT, including its verification digit, discarding
+    /// any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ColombiaNitValidator::is_valid(value)?;
+        Ok(Nit(ColombiaNitValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the NIT, including the verification digit.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the body of the NIT, without the verification digit.
+    pub fn number(&self) -> &str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Returns the verification digit (DV).
+    pub fn check_digit(&self) -> u8 {
+        self.0[self.0.len() - 1..]
+            .parse()
+            .expect("Validated digits")
+    }
+}
+
+impl FromStr for Nit {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nit {
+    /// Formats the NIT as `900.123.456-8`, with the body grouped in thousands.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.number();
+        let first_group = match number.len() % 3 {
+            0 => 3,
+            length => length,
+        };
+        f.write_str(&number[..first_group])?;
+        for start in (first_group..number.len()).step_by(3) {
+            write!(f, ".{}", &number[start..start + 3])?;
+        }
+        write!(f, "-{}", self.check_digit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(ColombiaNitValidator::calculate_check_digit("800197268"), 4);
+        assert_eq!(ColombiaNitValidator::calculate_check_digit("899999034"), 1);
+    }
+}
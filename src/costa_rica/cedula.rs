@@ -0,0 +1,156 @@
+/// Costa Rica Cédula de Identidad Validator
+///
+/// This module provides format validation of the cédula de identidad (cédula física), the
+/// 9-digit identity number assigned to people by the TSE (Tribunal Supremo de Elecciones),
+/// formatted as `P-TTTT-AAAA`:
+///
+/// - 1 digit: province of birth, from `1` to `7`, or `8` for naturalized citizens and `9`
+///   for special cases;
+/// - 4 digits: volume (tomo) of the civil registry;
+/// - 4 digits: entry (asiento) within the volume.
+///
+/// The number has no check digit. It is often written with a leading zero, as
+/// `01-0234-0567`, or with its groups unpadded, as `1-234-567`; both are accepted.
+///
+/// # Usage
+///
+/// ```
+/// use docval::costa_rica::{Cedula, CostaRicaCedulaValidator};
+///
+/// assert!(CostaRicaCedulaValidator::is_valid("1-0234-0567").is_ok());
+///
+/// let cedula = Cedula::parse("1-234-567").unwrap();
+/// assert_eq!(cedula.province(), 1);
+/// assert_eq!(cedula.to_string(), "1-0234-0567");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a cédula. Pads hyphenated groups, removes the leading
+///   zero, and checks the length, the format and the province.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct CostaRicaCedulaValidator;
+
+const CEDULA_LENGTH: usize = 9;
+
+impl CostaRicaCedulaValidator {
+    /// Validates if the given cédula is correctly formatted. The input can be a plain or
+    /// hyphenated string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CEDULA_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.starts_with('0') {
+            return Err("Invalid province");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate cédulas using the CostaRicaCedulaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Pads each group of a number written as `P-TTTT-AAAA` with zeros, joins the groups,
+    /// and removes the leading zero of 10-digit numbers.
+    fn sanitize_input(value: &str) -> String {
+        let groups: Vec<&str> = value
+            .split(['-', ' '])
+            .filter(|group| !group.is_empty())
+            .collect();
+        let joined = match groups.as_slice() {
+            [province, volume, entry] => format!("{:0>2}{:0>4}{:0>4}", province, volume, entry),
+            _ => groups.concat(),
+        };
+        match joined.strip_prefix('0') {
+            Some(stripped) if joined.len() == CEDULA_LENGTH + 1 => stripped.to_string(),
+            _ => joined,
+        }
+    }
+}
+
+/// A validated cédula, stored as its 9 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cedula(String);
+
+impl Cedula {
+    /// Parses and validates the given cédula, padding its groups and discarding any
+    /// formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CostaRicaCedulaValidator::is_valid(value)?;
+        Ok(Cedula(CostaRicaCedulaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the cédula.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the province code.
+    pub fn province(&self) -> u8 {
+        self.0[..1].parse().expect("Validated digits")
+    }
+
+    /// Returns the volume (tomo) of the civil registry.
+    pub fn volume(&self) -> &str {
+        &self.0[1..5]
+    }
+
+    /// Returns the entry (asiento) within the volume.
+    pub fn entry(&self) -> &str {
+        &self.0[5..9]
+    }
+}
+
+impl FromStr for Cedula {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cedula {
+    /// Formats the cédula as `P-TTTT-AAAA`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", &self.0[..1], &self.0[1..5], &self.0[5..9])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            CostaRicaCedulaValidator::sanitize_input("1-234-567"),
+            "102340567"
+        );
+        assert_eq!(
+            CostaRicaCedulaValidator::sanitize_input("0102340567"),
+            "102340567"
+        );
+        assert_eq!(
+            CostaRicaCedulaValidator::sanitize_input("01 0234 0567"),
+            "102340567"
+        );
+    }
+}
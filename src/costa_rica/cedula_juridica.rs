@@ -0,0 +1,157 @@
+/// Costa Rica Cédula Jurídica Validator
+///
+/// This module provides format validation of the cédula jurídica, the 10-digit identifier
+/// assigned to legal entities by the Registro Nacional, formatted as `C-TTT-NNNNNN`:
+///
+/// - 1 digit: class of the entity, from `2` to `5`;
+/// - 3 digits: type of the entity within its class;
+/// - 6 digits: sequential number.
+///
+/// The number has no check digit, but each class only admits certain types:
+///
+/// | Class | Entities                                   | Types                      |
+/// |-------|--------------------------------------------|----------------------------|
+/// | 2     | Government and public institutions         | `100`, `200`, `300`, `400` |
+/// | 3     | Companies, associations and other entities | `002`-`014`, `101`-`110`   |
+/// | 4     | Political parties                          | `000`                      |
+/// | 5     | Other entities                             | `001`                      |
+///
+/// # Usage
+///
+/// ```
+/// use docval::costa_rica::{CedulaJuridica, CostaRicaCedulaJuridicaValidator};
+///
+/// assert!(CostaRicaCedulaJuridicaValidator::is_valid("3-101-123456").is_ok());
+///
+/// let cedula = CedulaJuridica::parse("3101123456").unwrap();
+/// assert_eq!(cedula.class(), 3);
+/// assert_eq!(cedula.entity_type(), "101");
+/// assert_eq!(cedula.to_string(), "3-101-123456");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a cédula jurídica. Removes non-digit characters, and
+///   checks the length, the class and the type.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct CostaRicaCedulaJuridicaValidator;
+
+const CEDULA_JURIDICA_LENGTH: usize = 10;
+
+impl CostaRicaCedulaJuridicaValidator {
+    /// Validates if the given cédula jurídica is correctly formatted. The input can be a
+    /// plain or hyphenated string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CEDULA_JURIDICA_LENGTH {
+            return Err("Invalid length");
+        }
+        let class = sanitized_value.as_bytes()[0] - b'0';
+        let entity_type = sanitized_value[1..4].parse().expect("Validated digits");
+        if !(2..=5).contains(&class) {
+            return Err("Invalid class");
+        }
+        if !Self::is_valid_type(class, entity_type) {
+            return Err("Invalid type");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate cédulas jurídicas using the
+    /// CostaRicaCedulaJuridicaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Checks if the given type is admitted in the given class.
+    fn is_valid_type(class: u8, entity_type: u16) -> bool {
+        match class {
+            2 => matches!(entity_type, 100 | 200 | 300 | 400),
+            3 => matches!(entity_type, 2..=14 | 101..=110),
+            4 => entity_type == 0,
+            5 => entity_type == 1,
+            _ => false,
+        }
+    }
+}
+
+/// A validated cédula jurídica, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CedulaJuridica(String);
+
+impl CedulaJuridica {
+    /// Parses and validates the given cédula jurídica, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CostaRicaCedulaJuridicaValidator::is_valid(value)?;
+        Ok(CedulaJuridica(
+            CostaRicaCedulaJuridicaValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 10 digits of the cédula jurídica.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the class of the entity.
+    pub fn class(&self) -> u8 {
+        self.0[..1].parse().expect("Validated digits")
+    }
+
+    /// Returns the 3-digit type of the entity within its class.
+    pub fn entity_type(&self) -> &str {
+        &self.0[1..4]
+    }
+
+    /// Returns the sequential number.
+    pub fn number(&self) -> &str {
+        &self.0[4..10]
+    }
+}
+
+impl FromStr for CedulaJuridica {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for CedulaJuridica {
+    /// Formats the cédula jurídica as `C-TTT-NNNNNN`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", &self.0[..1], &self.0[1..4], &self.0[4..10])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_type() {
+        assert!(CostaRicaCedulaJuridicaValidator::is_valid_type(2, 300));
+        assert!(CostaRicaCedulaJuridicaValidator::is_valid_type(3, 14));
+        assert!(CostaRicaCedulaJuridicaValidator::is_valid_type(3, 101));
+        assert!(!CostaRicaCedulaJuridicaValidator::is_valid_type(3, 15));
+        assert!(!CostaRicaCedulaJuridicaValidator::is_valid_type(4, 1));
+    }
+}
@@ -0,0 +1,138 @@
+/// Croatia OIB Validator
+///
+/// This module provides functionality to validate and parse the OIB (Osobni identifikacijski
+/// broj), the 11-digit personal identification number assigned by the Tax Administration
+/// to every citizen, resident and business in Croatia. It carries no meaning beyond its
+/// check digit, and is also the VAT number, with the `HR` prefix.
+///
+/// The last digit is an ISO 7064 MOD 11,10 check digit over the first 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::croatia::{CroatiaOibValidator, Oib};
+///
+/// assert!(CroatiaOibValidator::is_valid("94577403194").is_ok());
+/// assert!(CroatiaOibValidator::is_valid("HR 33392005961").is_ok());
+/// assert!(CroatiaOibValidator::is_valid("94577403195").is_err());
+///
+/// let oib = Oib::parse("hr94577403194").unwrap();
+/// assert_eq!(oib.as_str(), "94577403194");
+/// assert_eq!(oib.check_digit(), 4);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an OIB. Removes spaces and the `HR` prefix, checks the
+///   length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::mod11_10_check_digit;
+
+pub struct CroatiaOibValidator;
+
+const COUNTRY_PREFIX: &str = "HR";
+const OIB_LENGTH: usize = 11;
+
+impl CroatiaOibValidator {
+    /// Validates if the given OIB is correct. The input can be a plain string or contain
+    /// spaces, with or without the `HR` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != OIB_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let check_digit = mod11_10_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate OIBs using the CroatiaOibValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and the `HR` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+}
+
+/// A validated OIB, stored as its 11 digits, without the `HR` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Oib(String);
+
+impl Oib {
+    /// Parses and validates the given OIB, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CroatiaOibValidator::is_valid(value)?;
+        Ok(Oib(CroatiaOibValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the OIB.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+}
+
+impl FromStr for Oib {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Oib {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            CroatiaOibValidator::sanitize_input("hr 945 774 031 94"),
+            "94577403194"
+        );
+        assert_eq!(
+            CroatiaOibValidator::sanitize_input("94577403194"),
+            "94577403194"
+        );
+    }
+}
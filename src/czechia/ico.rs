@@ -0,0 +1,133 @@
+/// Czechia IČO Validator
+///
+/// This module provides functionality to validate and parse the IČO (identifikační číslo
+/// osoby), the 8-digit identification number assigned to businesses, public bodies and
+/// self-employed people registered in Czechia. Numbers from older registers are written
+/// with leading zeros, as in `00006947`.
+///
+/// The first 7 digits are weighted from 8 down to 2, and the check digit is the complement
+/// of the sum modulo 11, taken modulo 10: remainders of 0 and 1 yield 1 and 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::czechia::{CzechiaIcoValidator, Ico};
+///
+/// assert!(CzechiaIcoValidator::is_valid("25596641").is_ok());
+/// assert!(CzechiaIcoValidator::is_valid("25596642").is_err());
+///
+/// let ico = Ico::parse("00006947").unwrap();
+/// assert_eq!(ico.check_digit(), 7);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an IČO. Removes spaces, checks the length and format, and
+///   validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct CzechiaIcoValidator;
+
+const ICO_LENGTH: usize = 8;
+const MULTIPLIER_WEIGHTS: &[u32] = &[8, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl CzechiaIcoValidator {
+    /// Validates if the given IČO is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ICO_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..7]);
+        if sanitized_value[7..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate IČOs using the CzechiaIcoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates the check digit of the first 7 digits of an IČO.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (VALIDATION_MODULUS - sum % VALIDATION_MODULUS) % 10
+    }
+}
+
+/// A validated IČO, stored as its 8 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ico(String);
+
+impl Ico {
+    /// Parses and validates the given IČO, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CzechiaIcoValidator::is_valid(value)?;
+        Ok(Ico(CzechiaIcoValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 8 digits of the IČO.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[7] - b'0'
+    }
+}
+
+impl FromStr for Ico {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ico {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(CzechiaIcoValidator::calculate_check_digit("4527464"), 9);
+        assert_eq!(CzechiaIcoValidator::calculate_check_digit("2559664"), 1);
+        assert_eq!(CzechiaIcoValidator::calculate_check_digit("6994783"), 0);
+    }
+}
@@ -0,0 +1,221 @@
+/// Czechia Rodné Číslo Validator
+///
+/// This module provides functionality to validate and parse the rodné číslo, the birth
+/// number assigned to residents of Czechia. It is written as `YYMMDD/SSSC`, as in
+/// `710319/2460`:
+///
+/// | Positions | Field                                                            |
+/// |-----------|------------------------------------------------------------------|
+/// | 1-6       | Date of birth as `YYMMDD`, with offsets added to the month       |
+/// | 7-9       | Serial number among people born on the same day                  |
+/// | 10        | Check digit, absent from numbers issued before 1954              |
+///
+/// Women have 50 added to the month. Since 2004, 20 can also be added when the serial
+/// numbers of a day run out, so months 21-32 and 71-82 are valid too.
+///
+/// Numbers issued before 1954 have 9 digits and no check digit, and their year is in the
+/// 1900s. In 10-digit numbers, years 54-99 are in the 1900s and 00-53 in the 2000s, and the
+/// whole number must be divisible by 11. Until 1985, numbers whose first 9 digits left a
+/// remainder of 10 modulo 11 were issued with a check digit of 0, and are still accepted.
+///
+/// # Usage
+///
+/// ```
+/// use docval::czechia::{CzechiaRodneCisloValidator, RodneCislo};
+/// use docval::Sex;
+///
+/// assert!(CzechiaRodneCisloValidator::is_valid("785101/1234").is_ok());
+/// assert!(CzechiaRodneCisloValidator::is_valid("7851011235").is_err());
+///
+/// let rodne_cislo = RodneCislo::parse("7851011234").unwrap();
+/// assert_eq!(rodne_cislo.birth_year(), 1978);
+/// assert_eq!(rodne_cislo.birth_month(), 1);
+/// assert_eq!(rodne_cislo.sex(), Sex::Female);
+/// assert_eq!(rodne_cislo.to_string(), "785101/1234");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a rodné číslo. Removes spaces and the slash, checks the
+///   length, format and date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct CzechiaRodneCisloValidator;
+
+const SHORT_LENGTH: usize = 9;
+const LONG_LENGTH: usize = 10;
+const FIRST_LONG_YEAR: u32 = 1954;
+const FIRST_EXTENDED_YEAR: u32 = 2004;
+const FEMALE_MONTH_OFFSET: u32 = 50;
+const EXTENDED_MONTH_OFFSET: u32 = 20;
+const VALIDATION_MODULUS: u64 = 11;
+
+impl CzechiaRodneCisloValidator {
+    /// Validates if the given rodné číslo is correct. The input can be a plain string or
+    /// contain the slash.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != SHORT_LENGTH && sanitized_value.len() != LONG_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value);
+        if sanitized_value.len() == SHORT_LENGTH && year >= FIRST_LONG_YEAR {
+            return Err("Invalid length");
+        }
+        let encoded_month = Self::field(&sanitized_value, 2) % FEMALE_MONTH_OFFSET;
+        if encoded_month > EXTENDED_MONTH_OFFSET && year < FIRST_EXTENDED_YEAR {
+            return Err("Invalid date");
+        }
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        if sanitized_value.len() == LONG_LENGTH && !Self::is_valid_checksum(&sanitized_value) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate rodná čísla using the CzechiaRodneCisloValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace and slashes from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '/')
+            .collect()
+    }
+
+    fn field(value: &str, start: usize) -> u32 {
+        value[start..start + 2].parse().expect("Validated digits")
+    }
+
+    /// Decodes the date of birth, removing the offsets from the month. The century is
+    /// given by the length of the number.
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let year = Self::field(value, 0);
+        let century = if value.len() == LONG_LENGTH && year + 1900 < FIRST_LONG_YEAR {
+            2000
+        } else {
+            1900
+        };
+        let month = Self::field(value, 2) % FEMALE_MONTH_OFFSET % EXTENDED_MONTH_OFFSET;
+        (century + year, month, Self::field(value, 4))
+    }
+
+    /// Checks that the 10-digit number is divisible by 11, or that its first 9 digits leave
+    /// a remainder of 10 and the check digit is 0.
+    fn is_valid_checksum(value: &str) -> bool {
+        let number: u64 = value[..9].parse().expect("Validated digits");
+        let check_digit = u64::from(value.as_bytes()[9] - b'0');
+        number % VALIDATION_MODULUS % 10 == check_digit
+    }
+}
+
+/// A validated rodné číslo, stored as its 9 or 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RodneCislo(String);
+
+impl RodneCislo {
+    /// Parses and validates the given rodné číslo, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        CzechiaRodneCisloValidator::is_valid(value)?;
+        Ok(RodneCislo(CzechiaRodneCisloValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 9 or 10 digits of the rodné číslo.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        CzechiaRodneCisloValidator::birth_date(&self.0).0 as u16
+    }
+
+    /// Returns the month of birth, without the offsets.
+    pub fn birth_month(&self) -> u8 {
+        CzechiaRodneCisloValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        CzechiaRodneCisloValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if CzechiaRodneCisloValidator::field(&self.0, 2) > FEMALE_MONTH_OFFSET {
+            Sex::Female
+        } else {
+            Sex::Male
+        }
+    }
+
+    /// Returns the check digit, absent from numbers issued before 1954.
+    pub fn check_digit(&self) -> Option<u8> {
+        self.0.as_bytes().get(9).map(|b| b - b'0')
+    }
+}
+
+impl FromStr for RodneCislo {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for RodneCislo {
+    /// Formats the rodné číslo with a slash after the date of birth, as in `710319/2460`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", &self.0[..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            CzechiaRodneCisloValidator::birth_date("7103192460"),
+            (1971, 3, 19)
+        );
+        assert_eq!(
+            CzechiaRodneCisloValidator::birth_date("0456010016"),
+            (2004, 6, 1)
+        );
+        assert_eq!(
+            CzechiaRodneCisloValidator::birth_date("0421010018"),
+            (2004, 1, 1)
+        );
+        assert_eq!(
+            CzechiaRodneCisloValidator::birth_date("530101123"),
+            (1953, 1, 1)
+        );
+    }
+}
@@ -0,0 +1,77 @@
+//! Date Validation
+//!
+//! This module gathers the calendar checks shared by validators of documents that embed a
+//! date, such as a birthdate or a registration date.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+const EPOCH_YEAR: u32 = 1970;
+
+/// Returns the number of days in the given month of the given year, or 0 for an invalid
+/// month. Years follow the proleptic Gregorian calendar.
+pub(crate) fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Checks if the given year, month and day form a valid calendar date.
+pub(crate) fn is_valid_date(year: u32, month: u32, day: u32) -> bool {
+    day >= 1 && day <= days_in_month(year, month)
+}
+
+/// Returns the current year in UTC, according to the system clock.
+pub(crate) fn current_year() -> u32 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    year_from_days(seconds / SECONDS_PER_DAY)
+}
+
+/// Returns the year of the day that is the given number of days after 1 January 1970.
+fn year_from_days(mut days: u64) -> u32 {
+    let mut year = EPOCH_YEAR;
+    loop {
+        let days_in_year = if days_in_month(year, 2) == 29 {
+            366
+        } else {
+            365
+        };
+        if days < days_in_year {
+            return year;
+        }
+        days -= days_in_year;
+        year += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_date() {
+        assert!(is_valid_date(2000, 2, 29));
+        assert!(!is_valid_date(1900, 2, 29));
+        assert!(!is_valid_date(2023, 4, 31));
+        assert!(!is_valid_date(2023, 13, 1));
+        assert!(!is_valid_date(2023, 1, 0));
+    }
+
+    #[test]
+    fn test_year_from_days() {
+        assert_eq!(year_from_days(0), 1970);
+        assert_eq!(year_from_days(364), 1970);
+        assert_eq!(year_from_days(365), 1971);
+        assert_eq!(year_from_days(10_957), 2000);
+        assert_eq!(year_from_days(11_322), 2000);
+        assert_eq!(year_from_days(11_323), 2001);
+    }
+}
@@ -0,0 +1,227 @@
+/// Denmark CPR Number Validator
+///
+/// This module provides functionality to validate and parse the CPR number (CPR-nummer),
+/// the personal identification number assigned by the Danish Civil Registration System,
+/// written as `DDMMYY-SSSS`. The first 6 digits are the date of birth, and the last 4 a
+/// serial number, whose last digit is odd for men and even for women.
+///
+/// The century of birth is given by the 7th digit:
+///
+/// | 7th digit | `YY` 00-36 | `YY` 37-57 | `YY` 58-99 |
+/// |-----------|------------|------------|------------|
+/// | 0-3       | 1900s      | 1900s      | 1900s      |
+/// | 4, 9      | 2000s      | 1900s      | 1900s      |
+/// | 5-8       | 2000s      | 2000s      | 1800s      |
+///
+/// Numbers were issued so that the sum of their digits weighted by 4, 3, 2, 7, 6, 5, 4, 3,
+/// 2 and 1 was a multiple of 11, until the numbers satisfying it ran out for some dates in
+/// 2007. Validation is therefore lenient by default, and the modulus 11 check is only
+/// applied in strict mode, which suits numbers issued before 2007.
+///
+/// # Usage
+///
+/// ```
+/// use docval::denmark::{Cpr, CprMode, DenmarkCprValidator};
+/// use docval::Sex;
+///
+/// assert!(DenmarkCprValidator::is_valid("070761-4285").is_ok());
+/// assert!(DenmarkCprValidator::is_valid("070761-4286").is_ok());
+/// assert!(DenmarkCprValidator::is_valid_with("070761-4286", CprMode::Strict).is_err());
+///
+/// let cpr = Cpr::parse("0707614285").unwrap();
+/// assert_eq!(cpr.birth_year(), 1961);
+/// assert_eq!(cpr.sex(), Sex::Male);
+/// assert!(cpr.has_valid_checksum());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CPR number. Removes spaces and hyphens, and checks the
+///   length, format and date of birth, without the modulus 11 check.
+///
+/// - `is_valid_with(value: &str, mode: CprMode) -> Result<(), &'static str>`:
+///   Validates a CPR number, applying the modulus 11 check in strict mode.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct DenmarkCprValidator;
+
+/// Whether the modulus 11 check is applied when validating a CPR number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CprMode {
+    /// Requires the modulus 11 check, as for numbers issued before 2007.
+    Strict,
+    /// Checks the date of birth only, as for all numbers issued today.
+    Lenient,
+}
+
+const CPR_LENGTH: usize = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[4, 3, 2, 7, 6, 5, 4, 3, 2, 1];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl DenmarkCprValidator {
+    /// Validates if the given CPR number is correct, without the modulus 11 check. The input
+    /// can be a plain or hyphenated string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::is_valid_with(value, CprMode::Lenient)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CPR numbers using the DenmarkCprValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given CPR number is correct, applying the modulus 11 check in strict
+    /// mode.
+    pub fn is_valid_with(value: &str, mode: CprMode) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CPR_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value);
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        if mode == CprMode::Strict && !Self::has_valid_checksum(&sanitized_value) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    /// Removes spaces and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    /// Decodes the date of birth, taking the century from the 7th digit.
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let day = value[..2].parse().expect("Validated digits");
+        let month = value[2..4].parse().expect("Validated digits");
+        let year: u32 = value[4..6].parse().expect("Validated digits");
+        let century = match (value.as_bytes()[6] - b'0', year) {
+            (0..=3, _) => 1900,
+            (4 | 9, 0..=36) => 2000,
+            (4 | 9, _) => 1900,
+            (_, 0..=57) => 2000,
+            _ => 1800,
+        };
+        (century + year, month, day)
+    }
+
+    /// Checks if the weighted sum of the digits is a multiple of 11.
+    fn has_valid_checksum(value: &str) -> bool {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum.is_multiple_of(VALIDATION_MODULUS)
+    }
+}
+
+/// A validated CPR number, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cpr(String);
+
+impl Cpr {
+    /// Parses and validates the given CPR number, without the modulus 11 check, discarding
+    /// any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        Self::parse_with(value, CprMode::Lenient)
+    }
+
+    /// Parses and validates the given CPR number, applying the modulus 11 check in strict
+    /// mode.
+    pub fn parse_with(value: &str, mode: CprMode) -> Result<Self, &'static str> {
+        DenmarkCprValidator::is_valid_with(value, mode)?;
+        Ok(Cpr(DenmarkCprValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the CPR number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, whose century is given by the 7th digit.
+    pub fn birth_year(&self) -> u16 {
+        DenmarkCprValidator::birth_date(&self.0).0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        DenmarkCprValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        DenmarkCprValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the 4-digit serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[6..].parse().expect("Validated digits")
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if self.serial() % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns whether the CPR number passes the modulus 11 check.
+    pub fn has_valid_checksum(&self) -> bool {
+        DenmarkCprValidator::has_valid_checksum(&self.0)
+    }
+}
+
+impl FromStr for Cpr {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cpr {
+    /// Formats the CPR number as in `070761-4285`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(DenmarkCprValidator::birth_date("0707614285"), (1961, 7, 7));
+        assert_eq!(DenmarkCprValidator::birth_date("2902004001"), (2000, 2, 29));
+        assert_eq!(DenmarkCprValidator::birth_date("1505855120"), (1885, 5, 15));
+        assert_eq!(DenmarkCprValidator::birth_date("0101409000"), (1940, 1, 1));
+        assert_eq!(DenmarkCprValidator::birth_date("0101405000"), (2040, 1, 1));
+    }
+}
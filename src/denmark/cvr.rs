@@ -0,0 +1,143 @@
+/// Denmark CVR Number Validator
+///
+/// This module provides functionality to validate and parse the CVR number (CVR-nummer),
+/// the 8-digit business registration number assigned by the Danish Central Business
+/// Register. It does not start with 0, and doubles as the VAT number with the `DK` prefix,
+/// as in `DK13585628`.
+///
+/// The digits are weighted by 2, 7, 6, 5, 4, 3, 2 and 1, and the weighted sum must be a
+/// multiple of 11.
+///
+/// # Usage
+///
+/// ```
+/// use docval::denmark::{Cvr, DenmarkCvrValidator};
+///
+/// assert!(DenmarkCvrValidator::is_valid("13585628").is_ok());
+/// assert!(DenmarkCvrValidator::is_valid("DK 13 58 56 28").is_ok());
+/// assert!(DenmarkCvrValidator::is_valid("13585629").is_err());
+///
+/// let cvr = Cvr::parse("DK13585628").unwrap();
+/// assert_eq!(cvr.as_str(), "13585628");
+/// assert_eq!(cvr.vat_number(), "DK13585628");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CVR number. Removes spaces and the `DK` prefix, checks
+///   the length and format, and validates the weighted sum.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct DenmarkCvrValidator;
+
+const CVR_LENGTH: usize = 8;
+const COUNTRY_PREFIX: &str = "DK";
+const MULTIPLIER_WEIGHTS: &[u32] = &[2, 7, 6, 5, 4, 3, 2, 1];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl DenmarkCvrValidator {
+    /// Validates if the given CVR number is correct. The input can be a plain or spaced
+    /// string, with or without the `DK` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CVR_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) || sanitized_value.starts_with('0')
+        {
+            return Err("Invalid format");
+        }
+        if !Self::has_valid_checksum(&sanitized_value) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CVR numbers using the DenmarkCvrValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and the `DK` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Checks if the weighted sum of the digits is a multiple of 11.
+    fn has_valid_checksum(value: &str) -> bool {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum.is_multiple_of(VALIDATION_MODULUS)
+    }
+}
+
+/// A validated CVR number, stored as its 8 digits, without the `DK` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cvr(String);
+
+impl Cvr {
+    /// Parses and validates the given CVR number, discarding any spaces and the `DK` prefix.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        DenmarkCvrValidator::is_valid(value)?;
+        Ok(Cvr(DenmarkCvrValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 8 digits of the CVR number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the CVR number in its VAT form, as in `DK13585628`.
+    pub fn vat_number(&self) -> String {
+        format!("{COUNTRY_PREFIX}{}", self.0)
+    }
+}
+
+impl FromStr for Cvr {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cvr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_valid_checksum() {
+        assert!(DenmarkCvrValidator::has_valid_checksum("13585628"));
+        assert!(DenmarkCvrValidator::has_valid_checksum("24256790"));
+        assert!(!DenmarkCvrValidator::has_valid_checksum("13585629"));
+    }
+}
@@ -0,0 +1,124 @@
+/// Dominican Republic Cédula Validator
+///
+/// This module provides functionality to validate and parse the cédula de identidad y
+/// electoral, the 11-digit identity number assigned by the JCE (Junta Central Electoral),
+/// formatted as `XXX-XXXXXXX-X`:
+///
+/// - 3 digits: municipality where the cédula was issued;
+/// - 7 digits: sequential number;
+/// - 1 digit: check digit, calculated over the first 10 digits with the modulus 10 (Luhn)
+///   algorithm.
+///
+/// # Usage
+///
+/// ```
+/// use docval::dominican_republic::{Cedula, DominicanRepublicCedulaValidator};
+///
+/// assert!(DominicanRepublicCedulaValidator::is_valid("001-0001072-7").is_ok());
+///
+/// let cedula = Cedula::parse("22412345674").unwrap();
+/// assert_eq!(cedula.municipality(), "224");
+/// assert_eq!(cedula.to_string(), "224-1234567-4");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a cédula. Removes non-digit characters, checks the
+///   length, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct DominicanRepublicCedulaValidator;
+
+pub(super) const CEDULA_LENGTH: usize = 11;
+
+impl DominicanRepublicCedulaValidator {
+    /// Validates if the given cédula is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CEDULA_LENGTH {
+            return Err("Invalid length");
+        }
+        if sanitized_value[10..] == luhn_check_digit(&sanitized_value[..10]).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate cédulas using the DominicanRepublicCedulaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    pub(super) fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+/// A validated cédula, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cedula(String);
+
+impl Cedula {
+    /// Parses and validates the given cédula, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        DominicanRepublicCedulaValidator::is_valid(value)?;
+        Ok(Cedula(DominicanRepublicCedulaValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 11 digits of the cédula.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the code of the municipality where the cédula was issued.
+    pub fn municipality(&self) -> &str {
+        &self.0[..3]
+    }
+}
+
+impl FromStr for Cedula {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cedula {
+    /// Formats the cédula as `XXX-XXXXXXX-X`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", &self.0[..3], &self.0[3..10], &self.0[10..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            DominicanRepublicCedulaValidator::sanitize_input("001-0001072-7"),
+            "00100010727"
+        );
+    }
+}
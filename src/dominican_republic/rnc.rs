@@ -0,0 +1,162 @@
+/// Dominican Republic RNC Validator
+///
+/// This module provides functionality to validate and parse the RNC (Registro Nacional del
+/// Contribuyente), the tax identifier assigned by the DGII. Companies are given a 9-digit
+/// RNC, formatted as `X-XX-XXXXX-X`, while people use their 11-digit cédula as their RNC.
+///
+/// For 9-digit RNCs, the first 8 digits are weighted with `79865432`, and the check digit
+/// is calculated from the remainder of the sum modulo 11 as `(10 - remainder) % 9 + 1`.
+/// 11-digit RNCs are validated as cédulas.
+///
+/// # Usage
+///
+/// ```
+/// use docval::dominican_republic::{DominicanRepublicRncValidator, Rnc, RncKind};
+///
+/// assert!(DominicanRepublicRncValidator::is_valid("1-01-85004-3").is_ok());
+/// assert!(DominicanRepublicRncValidator::is_valid("001-0001072-7").is_ok());
+///
+/// let rnc = Rnc::parse("131098193").unwrap();
+/// assert_eq!(rnc.kind(), RncKind::Company);
+/// assert_eq!(rnc.to_string(), "1-31-09819-3");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an RNC. Removes non-digit characters, checks the length,
+///   and validates the check digit of either form.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::cedula::CEDULA_LENGTH;
+use super::DominicanRepublicCedulaValidator;
+
+pub struct DominicanRepublicRncValidator;
+
+/// The kinds of taxpayers, told apart by the length of the RNC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RncKind {
+    /// Company or other legal entity, with a 9-digit RNC.
+    Company,
+    /// Natural person, whose RNC is the 11-digit cédula.
+    Person,
+}
+
+const RNC_LENGTH: usize = 9;
+const MULTIPLIER_WEIGHTS: &[u32] = &[7, 9, 8, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl DominicanRepublicRncValidator {
+    /// Validates if the given RNC is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = DominicanRepublicCedulaValidator::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        match sanitized_value.len() {
+            RNC_LENGTH => {
+                let check_digit = Self::calculate_check_digit(&sanitized_value[..8]);
+                if sanitized_value[8..] == check_digit.to_string() {
+                    Ok(())
+                } else {
+                    Err("Invalid checksum")
+                }
+            }
+            CEDULA_LENGTH => DominicanRepublicCedulaValidator::is_valid(&sanitized_value),
+            _ => Err("Invalid length"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RNCs using the DominicanRepublicRncValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Calculates the check digit of the first 8 digits of a 9-digit RNC.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (10 - sum % VALIDATION_MODULUS) % 9 + 1
+    }
+}
+
+/// A validated RNC, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rnc(String);
+
+impl Rnc {
+    /// Parses and validates the given RNC, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        DominicanRepublicRncValidator::is_valid(value)?;
+        Ok(Rnc(DominicanRepublicCedulaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 or 11 digits of the RNC.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the RNC belongs to a company or to a person.
+    pub fn kind(&self) -> RncKind {
+        if self.0.len() == RNC_LENGTH {
+            RncKind::Company
+        } else {
+            RncKind::Person
+        }
+    }
+}
+
+impl FromStr for Rnc {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Rnc {
+    /// Formats the RNC as `X-XX-XXXXX-X` for companies, and as a cédula for people.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            RncKind::Company => write!(
+                f,
+                "{}-{}-{}-{}",
+                &self.0[..1],
+                &self.0[1..3],
+                &self.0[3..8],
+                &self.0[8..]
+            ),
+            RncKind::Person => {
+                write!(f, "{}-{}-{}", &self.0[..3], &self.0[3..10], &self.0[10..])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            DominicanRepublicRncValidator::calculate_check_digit("10185004"),
+            3
+        );
+        assert_eq!(
+            DominicanRepublicRncValidator::calculate_check_digit("40100000"),
+            8
+        );
+    }
+}
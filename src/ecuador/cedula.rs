@@ -0,0 +1,139 @@
+/// Ecuador Cédula Validator
+///
+/// This module provides functionality to validate and parse the cédula de identidad, the
+/// 10-digit identity number assigned by the Registro Civil:
+///
+/// - 2 digits: province of registration, from `01` to `24`, or `30` for Ecuadorians
+///   registered abroad;
+/// - 1 digit: lower than 6, which distinguishes cédulas from the RUCs of companies;
+/// - 6 digits: sequential number;
+/// - 1 digit: check digit.
+///
+/// The check digit is calculated over the first 9 digits with the modulus 10 (Luhn)
+/// algorithm.
+///
+/// # Usage
+///
+/// ```
+/// use docval::ecuador::{Cedula, EcuadorCedulaValidator};
+///
+/// assert!(EcuadorCedulaValidator::is_valid("1710034065").is_ok());
+///
+/// let cedula = Cedula::parse("171003406-5").unwrap();
+/// assert_eq!(cedula.province(), 17);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a cédula. Removes non-digit characters, checks the
+///   length, the province and the third digit, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct EcuadorCedulaValidator;
+
+const CEDULA_LENGTH: usize = 10;
+const MAX_PROVINCE: u8 = 24;
+const ABROAD_PROVINCE: u8 = 30;
+const MAX_THIRD_DIGIT: u8 = 5;
+
+impl EcuadorCedulaValidator {
+    /// Validates if the given cédula is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CEDULA_LENGTH {
+            return Err("Invalid length");
+        }
+        if !Self::is_valid_province(&sanitized_value[..2]) {
+            return Err("Invalid province");
+        }
+        if sanitized_value.as_bytes()[2] - b'0' > MAX_THIRD_DIGIT {
+            return Err("Invalid third digit");
+        }
+        if sanitized_value[9..] == luhn_check_digit(&sanitized_value[..9]).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate cédulas using the EcuadorCedulaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    pub(super) fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Checks if the given two digits are the code of a province, shared with the RUC.
+    pub(super) fn is_valid_province(code: &str) -> bool {
+        let code: u8 = code.parse().expect("Validated digits");
+        (1..=MAX_PROVINCE).contains(&code) || code == ABROAD_PROVINCE
+    }
+}
+
+/// A validated cédula, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cedula(String);
+
+impl Cedula {
+    /// Parses and validates the given cédula, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        EcuadorCedulaValidator::is_valid(value)?;
+        Ok(Cedula(EcuadorCedulaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the cédula.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the code of the province of registration (30 for registrations abroad).
+    pub fn province(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for Cedula {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cedula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_province() {
+        assert!(EcuadorCedulaValidator::is_valid_province("01"));
+        assert!(EcuadorCedulaValidator::is_valid_province("24"));
+        assert!(EcuadorCedulaValidator::is_valid_province("30"));
+        assert!(!EcuadorCedulaValidator::is_valid_province("00"));
+        assert!(!EcuadorCedulaValidator::is_valid_province("25"));
+    }
+}
@@ -0,0 +1,215 @@
+/// Ecuador RUC Validator
+///
+/// This module provides functionality to validate and parse the RUC (Registro Único de
+/// Contribuyentes), the 13-digit tax identifier assigned by the SRI. It starts with the
+/// province code, and its third digit identifies the kind of taxpayer, which determines
+/// how the rest of the number is checked:
+///
+/// | Third digit | Taxpayer         | Structure                                            |
+/// |-------------|------------------|------------------------------------------------------|
+/// | 0-5         | Natural person   | cédula (10 digits) + establishment (3)               |
+/// | 6           | Public entity    | 8 digits + check digit + establishment (4)           |
+/// | 9           | Private company  | 9 digits + check digit + establishment (3)           |
+///
+/// Public entities weight their first 8 digits with `32765432`, and private companies their
+/// first 9 digits with `432765432`; in both cases the check digit is the complement of the
+/// sum modulo 11, where 11 yields 0 and 10 is never assigned. The establishment number
+/// cannot be all zeros.
+///
+/// # Usage
+///
+/// ```
+/// use docval::ecuador::{EcuadorRucValidator, Ruc, RucKind};
+///
+/// assert!(EcuadorRucValidator::is_valid("1792060346001").is_ok());
+///
+/// let ruc = Ruc::parse("1710034065001").unwrap();
+/// assert_eq!(ruc.kind(), RucKind::NaturalPerson);
+/// assert_eq!(ruc.cedula(), Some("1710034065"));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a RUC. Removes non-digit characters, checks the length,
+///   the province and the kind of taxpayer, and validates the number according to it.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::EcuadorCedulaValidator;
+
+pub struct EcuadorRucValidator;
+
+/// The kinds of taxpayers identified by the third digit of the RUC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RucKind {
+    /// Natural person, whose cédula makes up the first 10 digits (third digit 0 to 5).
+    NaturalPerson,
+    /// Public sector entity (third digit 6).
+    PublicEntity,
+    /// Private company or foreign entity (third digit 9).
+    PrivateCompany,
+}
+
+impl RucKind {
+    /// Returns the kind identified by the given third digit, if any.
+    pub fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            0..=5 => Some(RucKind::NaturalPerson),
+            6 => Some(RucKind::PublicEntity),
+            9 => Some(RucKind::PrivateCompany),
+            _ => None,
+        }
+    }
+
+    /// Returns the position of the first digit of the establishment number.
+    fn establishment_start(&self) -> usize {
+        match self {
+            RucKind::PublicEntity => 9,
+            RucKind::NaturalPerson | RucKind::PrivateCompany => 10,
+        }
+    }
+}
+
+const RUC_LENGTH: usize = 13;
+const PUBLIC_ENTITY_MULTIPLIER_WEIGHTS: &[u32] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const PRIVATE_COMPANY_MULTIPLIER_WEIGHTS: &[u32] = &[4, 3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl EcuadorRucValidator {
+    /// Validates if the given RUC is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = EcuadorCedulaValidator::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != RUC_LENGTH {
+            return Err("Invalid length");
+        }
+        if !EcuadorCedulaValidator::is_valid_province(&sanitized_value[..2]) {
+            return Err("Invalid province");
+        }
+        let kind = RucKind::from_digit(sanitized_value.as_bytes()[2] - b'0')
+            .ok_or("Invalid third digit")?;
+        if sanitized_value[kind.establishment_start()..]
+            .chars()
+            .all(|c| c == '0')
+        {
+            return Err("Invalid establishment");
+        }
+        let (number, weights) = match kind {
+            RucKind::NaturalPerson => {
+                return EcuadorCedulaValidator::is_valid(&sanitized_value[..10]);
+            }
+            RucKind::PublicEntity => (&sanitized_value[..9], PUBLIC_ENTITY_MULTIPLIER_WEIGHTS),
+            RucKind::PrivateCompany => (&sanitized_value[..10], PRIVATE_COMPANY_MULTIPLIER_WEIGHTS),
+        };
+        if Self::is_valid_check_digit(number, weights) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RUCs using the EcuadorRucValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Checks if the last digit of the value is the modulus 11 check digit of the others.
+    fn is_valid_check_digit(value: &str, weights: &[u32]) -> bool {
+        let (digits, check_digit) = value.split_at(value.len() - 1);
+        let sum: u32 = digits
+            .chars()
+            .zip(weights)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => check_digit == "0",
+            10 => false,
+            digit => check_digit == digit.to_string(),
+        }
+    }
+}
+
+/// A validated RUC, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ruc(String);
+
+impl Ruc {
+    /// Parses and validates the given RUC, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        EcuadorRucValidator::is_valid(value)?;
+        Ok(Ruc(EcuadorCedulaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 13 digits of the RUC.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the code of the province of registration.
+    pub fn province(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+
+    /// Returns the kind of taxpayer.
+    pub fn kind(&self) -> RucKind {
+        RucKind::from_digit(self.0.as_bytes()[2] - b'0').expect("Validated third digit")
+    }
+
+    /// Returns the cédula embedded in the RUC of a natural person, or `None` otherwise.
+    pub fn cedula(&self) -> Option<&str> {
+        match self.kind() {
+            RucKind::NaturalPerson => Some(&self.0[..10]),
+            RucKind::PublicEntity | RucKind::PrivateCompany => None,
+        }
+    }
+
+    /// Returns the establishment number: 4 digits for public entities, 3 otherwise.
+    pub fn establishment(&self) -> &str {
+        &self.0[self.kind().establishment_start()..]
+    }
+}
+
+impl FromStr for Ruc {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ruc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_check_digit() {
+        assert!(EcuadorRucValidator::is_valid_check_digit(
+            "1792060346",
+            PRIVATE_COMPANY_MULTIPLIER_WEIGHTS
+        ));
+        assert!(EcuadorRucValidator::is_valid_check_digit(
+            "176000139",
+            PUBLIC_ENTITY_MULTIPLIER_WEIGHTS
+        ));
+        assert!(!EcuadorRucValidator::is_valid_check_digit(
+            "1790016170",
+            PRIVATE_COMPANY_MULTIPLIER_WEIGHTS
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+/// Estonia Isikukood Validator
+///
+/// This module provides functionality to validate and parse the personal identification
+/// code (isikukood) assigned to residents of Estonia, written as `37605030299`:
+///
+/// | Positions | Field                                                   |
+/// |-----------|---------------------------------------------------------|
+/// | 1         | Century of birth and sex                                |
+/// | 2-7       | Date of birth as `YYMMDD`                               |
+/// | 8-10      | Serial number                                           |
+/// | 11        | Check digit                                             |
+///
+/// The first digit is odd for men and even for women: `1` and `2` stand for the 1800s, `3`
+/// and `4` for the 1900s, `5` and `6` for the 2000s, and `7` and `8` for the 2100s.
+///
+/// The check digit is the remainder modulo 11 of the first 10 digits weighted by 1 to 9
+/// and 1. If it is 10, the sum is recalculated with the weights 3 to 9 and 1 to 3, and a
+/// remainder of 10 again yields 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::estonia::{EstoniaIsikukoodValidator, Isikukood};
+/// use docval::Sex;
+///
+/// assert!(EstoniaIsikukoodValidator::is_valid("37605030299").is_ok());
+/// assert!(EstoniaIsikukoodValidator::is_valid("37605030298").is_err());
+///
+/// let isikukood = Isikukood::parse("60002291230").unwrap();
+/// assert_eq!(isikukood.birth_year(), 2000);
+/// assert_eq!(isikukood.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an isikukood. Removes spaces, checks the length, format,
+///   first digit and date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::two_stage_mod11_check_digit;
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct EstoniaIsikukoodValidator;
+
+const ISIKUKOOD_LENGTH: usize = 11;
+
+impl EstoniaIsikukoodValidator {
+    /// Validates if the given isikukood is correct. The input can be a plain string or
+    /// contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ISIKUKOOD_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value).ok_or("Invalid format")?;
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        if sanitized_value[10..] != two_stage_mod11_check_digit(&sanitized_value[..10]).to_string()
+        {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate isikukoods using the EstoniaIsikukoodValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Decodes the date of birth, taking the century from the first digit, or returns
+    /// `None` if the first digit is out of range.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let century = match value.as_bytes()[0] - b'0' {
+            digit @ 1..=8 => 1800 + (u32::from(digit) - 1) / 2 * 100,
+            _ => return None,
+        };
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        Some((century + field(1), field(3), field(5)))
+    }
+}
+
+/// A validated isikukood, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Isikukood(String);
+
+impl Isikukood {
+    /// Parses and validates the given isikukood, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        EstoniaIsikukoodValidator::is_valid(value)?;
+        Ok(Isikukood(EstoniaIsikukoodValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the isikukood.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, whose century is given by the first digit.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if (self.0.as_bytes()[0] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[7..10].parse().expect("Validated digits")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        EstoniaIsikukoodValidator::birth_date(&self.0).expect("Validated first digit")
+    }
+}
+
+impl FromStr for Isikukood {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Isikukood {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            EstoniaIsikukoodValidator::birth_date("37605030299"),
+            Some((1976, 5, 3))
+        );
+        assert_eq!(
+            EstoniaIsikukoodValidator::birth_date("29912319996"),
+            Some((1899, 12, 31))
+        );
+        assert_eq!(EstoniaIsikukoodValidator::birth_date("97605030299"), None);
+    }
+}
@@ -0,0 +1,163 @@
+//! EU Member States
+//!
+//! This module provides the `MemberState` enum, listing the 27 member states of the
+//! European Union and Northern Ireland, which keeps issuing EU VAT numbers under the `XI`
+//! prefix, with the prefixes used in VAT numbers. These are ISO 3166-1 alpha-2 codes,
+//! except for Greece, which uses `EL`.
+//!
+//! # Usage
+//!
+//! ```
+//! use docval::eu::MemberState;
+//!
+//! assert_eq!(MemberState::from_prefix("DE"), Some(MemberState::Germany));
+//! assert_eq!(MemberState::Greece.prefix(), "EL");
+//! assert_eq!(MemberState::NorthernIreland.name(), "Northern Ireland");
+//! ```
+
+/// A member state of the European Union, or Northern Ireland.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemberState {
+    Austria,
+    Belgium,
+    Bulgaria,
+    Croatia,
+    Cyprus,
+    Czechia,
+    Denmark,
+    Estonia,
+    Finland,
+    France,
+    Germany,
+    Greece,
+    Hungary,
+    Ireland,
+    Italy,
+    Latvia,
+    Lithuania,
+    Luxembourg,
+    Malta,
+    Netherlands,
+    NorthernIreland,
+    Poland,
+    Portugal,
+    Romania,
+    Slovakia,
+    Slovenia,
+    Spain,
+    Sweden,
+}
+
+impl MemberState {
+    /// All member states and Northern Ireland, in alphabetical order of their names.
+    pub const ALL: [MemberState; 28] = [
+        MemberState::Austria,
+        MemberState::Belgium,
+        MemberState::Bulgaria,
+        MemberState::Croatia,
+        MemberState::Cyprus,
+        MemberState::Czechia,
+        MemberState::Denmark,
+        MemberState::Estonia,
+        MemberState::Finland,
+        MemberState::France,
+        MemberState::Germany,
+        MemberState::Greece,
+        MemberState::Hungary,
+        MemberState::Ireland,
+        MemberState::Italy,
+        MemberState::Latvia,
+        MemberState::Lithuania,
+        MemberState::Luxembourg,
+        MemberState::Malta,
+        MemberState::Netherlands,
+        MemberState::NorthernIreland,
+        MemberState::Poland,
+        MemberState::Portugal,
+        MemberState::Romania,
+        MemberState::Slovakia,
+        MemberState::Slovenia,
+        MemberState::Spain,
+        MemberState::Sweden,
+    ];
+
+    /// Returns the prefix of the VAT numbers issued by the member state.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            MemberState::Austria => "AT",
+            MemberState::Belgium => "BE",
+            MemberState::Bulgaria => "BG",
+            MemberState::Croatia => "HR",
+            MemberState::Cyprus => "CY",
+            MemberState::Czechia => "CZ",
+            MemberState::Denmark => "DK",
+            MemberState::Estonia => "EE",
+            MemberState::Finland => "FI",
+            MemberState::France => "FR",
+            MemberState::Germany => "DE",
+            MemberState::Greece => "EL",
+            MemberState::Hungary => "HU",
+            MemberState::Ireland => "IE",
+            MemberState::Italy => "IT",
+            MemberState::Latvia => "LV",
+            MemberState::Lithuania => "LT",
+            MemberState::Luxembourg => "LU",
+            MemberState::Malta => "MT",
+            MemberState::Netherlands => "NL",
+            MemberState::NorthernIreland => "XI",
+            MemberState::Poland => "PL",
+            MemberState::Portugal => "PT",
+            MemberState::Romania => "RO",
+            MemberState::Slovakia => "SK",
+            MemberState::Slovenia => "SI",
+            MemberState::Spain => "ES",
+            MemberState::Sweden => "SE",
+        }
+    }
+
+    /// Returns the member state with the given VAT prefix, if any. The ISO code `GR` is
+    /// accepted for Greece as well.
+    pub fn from_prefix(prefix: &str) -> Option<MemberState> {
+        if prefix == "GR" {
+            return Some(MemberState::Greece);
+        }
+        MemberState::ALL
+            .iter()
+            .copied()
+            .find(|state| state.prefix() == prefix)
+    }
+
+    /// Returns the English name of the member state.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MemberState::Austria => "Austria",
+            MemberState::Belgium => "Belgium",
+            MemberState::Bulgaria => "Bulgaria",
+            MemberState::Croatia => "Croatia",
+            MemberState::Cyprus => "Cyprus",
+            MemberState::Czechia => "Czechia",
+            MemberState::Denmark => "Denmark",
+            MemberState::Estonia => "Estonia",
+            MemberState::Finland => "Finland",
+            MemberState::France => "France",
+            MemberState::Germany => "Germany",
+            MemberState::Greece => "Greece",
+            MemberState::Hungary => "Hungary",
+            MemberState::Ireland => "Ireland",
+            MemberState::Italy => "Italy",
+            MemberState::Latvia => "Latvia",
+            MemberState::Lithuania => "Lithuania",
+            MemberState::Luxembourg => "Luxembourg",
+            MemberState::Malta => "Malta",
+            MemberState::Netherlands => "Netherlands",
+            MemberState::NorthernIreland => "Northern Ireland",
+            MemberState::Poland => "Poland",
+            MemberState::Portugal => "Portugal",
+            MemberState::Romania => "Romania",
+            MemberState::Slovakia => "Slovakia",
+            MemberState::Slovenia => "Slovenia",
+            MemberState::Spain => "Spain",
+            MemberState::Sweden => "Sweden",
+        }
+    }
+}
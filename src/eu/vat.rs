@@ -0,0 +1,717 @@
+/// EU VAT Number Validator
+///
+/// This module provides offline validation of the VAT identification numbers issued by the
+/// member states of the European Union, and by the United Kingdom for Northern Ireland. A
+/// VAT number starts with the two-letter prefix of the member state, followed by a national
+/// number whose structure and check digits are defined by each state:
+///
+/// | Prefix | Structure                                  | Check                               |
+/// |--------|--------------------------------------------|-------------------------------------|
+/// | AT     | `U` + 8 digits                             | Luhn variant                        |
+/// | BE     | 10 digits, starting with 0 or 1            | 97 complement                       |
+/// | BG     | 9 digits (companies), 10 digits (people)   | modulus 11 / personal number        |
+/// | CY     | 8 digits + letter                          | letter from a weighted sum          |
+/// | CZ     | 8 digits (companies), 9-10 digits (people) | modulus 11 / birth number           |
+/// | DE     | 9 digits                                   | ISO 7064 MOD 11,10                  |
+/// | DK     | 8 digits                                   | modulus 11                          |
+/// | EE     | 9 digits, starting with `10`               | modulus 10                          |
+/// | EL     | 9 digits                                   | powers of 2, modulus 11             |
+/// | ES     | 9 characters (DNI, NIE or CIF)             | letter table or Luhn                |
+/// | FI     | 8 digits                                   | modulus 11                          |
+/// | FR     | 2-character key + SIREN                    | modulus 97 or alphanumeric key      |
+/// | HR     | 11 digits                                  | ISO 7064 MOD 11,10                  |
+/// | HU     | 8 digits                                   | modulus 10                          |
+/// | IE     | 7 digits + 1-2 letters, or the old format  | modulus 23 letter                   |
+/// | IT     | 11 digits                                  | Luhn and office code                |
+/// | LT     | 9 or 12 digits                             | modulus 11                          |
+/// | LU     | 8 digits                                   | modulus 89                          |
+/// | LV     | 11 digits                                  | modulus 11                          |
+/// | MT     | 8 digits                                   | modulus 37                          |
+/// | NL     | 9 digits + `B` + 2 digits                  | modulus 11 or ISO 7064 MOD 97-10    |
+/// | PL     | 10 digits                                  | modulus 11                          |
+/// | PT     | 9 digits                                   | modulus 11                          |
+/// | RO     | 2 to 10 digits                             | modulus 11                          |
+/// | SE     | 10 digits + `01`                           | Luhn                                |
+/// | SI     | 8 digits                                   | modulus 11                          |
+/// | SK     | 10 digits                                  | modulus 11                          |
+/// | XI     | 9 or 12 digits, or `GD`/`HA` + 3 digits    | modulus 97                          |
+///
+/// Only the structure and check digits are verified; whether the number is currently
+/// registered must be checked with VIES.
+///
+/// # Usage
+///
+/// ```
+/// use docval::eu::{EuVatValidator, MemberState, VatNumber};
+///
+/// assert!(EuVatValidator::is_valid("DE 136 695 976").is_ok());
+/// assert!(EuVatValidator::is_valid("FR40303265045").is_ok());
+///
+/// let vat = VatNumber::parse("atu13585627").unwrap();
+/// assert_eq!(vat.member_state(), MemberState::Austria);
+/// assert_eq!(vat.number(), "U13585627");
+/// assert_eq!(vat.to_string(), "ATU13585627");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a VAT number. Removes spaces, dots and hyphens, looks up
+///   the member state from the prefix, and validates the national number.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::MemberState;
+use crate::austria::AustriaUidValidator;
+use crate::belgium::{BelgiumEnterpriseNumberValidator, EnterpriseNumberKind};
+use crate::checksum::{luhn_check_digit, mod11_10_check_digit, mod97};
+use crate::date::is_valid_date;
+use crate::denmark::DenmarkCvrValidator;
+use crate::finland::FinlandYTunnusValidator;
+use crate::greece::GreeceAfmValidator;
+use crate::hungary::HungaryAdoszamValidator;
+use crate::poland::PolandNipValidator;
+use crate::portugal::PortugalNifValidator;
+use crate::romania::RomaniaCuiValidator;
+use crate::uk::UkVatValidator;
+
+pub struct EuVatValidator;
+
+const PREFIX_LENGTH: usize = 2;
+const SPANISH_CONTROL_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+const SPANISH_CIF_CONTROL_LETTERS: &[u8] = b"JABCDEFGHI";
+const FRENCH_KEY_ALPHABET: &str = "0123456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+const IRISH_CHECK_ALPHABET: &str = "WABCDEFGHIJKLMNOPQRSTUV";
+
+impl EuVatValidator {
+    /// Validates if the given VAT number, including its country prefix, is correct. The
+    /// input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::split(value).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate VAT numbers using the EuVatValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given national number, without the country prefix, is a correct VAT
+    /// number of the given member state.
+    pub fn is_valid_for(value: &str, state: MemberState) -> Result<(), &'static str> {
+        let number = Self::sanitize_input(value);
+        if number.is_empty() {
+            return Err("Invalid input");
+        }
+        Self::validate_number(state, &number)
+    }
+
+    /// Splits a VAT number into its member state and validated national number.
+    fn split(value: &str) -> Result<(MemberState, String), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let prefix = sanitized_value
+            .get(..PREFIX_LENGTH)
+            .ok_or("Invalid length")?;
+        let state = MemberState::from_prefix(prefix).ok_or("Invalid country code")?;
+        let number = &sanitized_value[PREFIX_LENGTH..];
+        Self::validate_number(state, number)?;
+        Ok((state, number.to_string()))
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Validates the national number of the given member state.
+    fn validate_number(state: MemberState, number: &str) -> Result<(), &'static str> {
+        if !number.is_ascii() {
+            return Err("Invalid format");
+        }
+        match state {
+            MemberState::Austria => validate_at(number),
+            MemberState::Belgium => validate_be(number),
+            MemberState::Bulgaria => validate_bg(number),
+            MemberState::Croatia => validate_hr(number),
+            MemberState::Cyprus => validate_cy(number),
+            MemberState::Czechia => validate_cz(number),
+            MemberState::Denmark => validate_dk(number),
+            MemberState::Estonia => validate_ee(number),
+            MemberState::Finland => validate_fi(number),
+            MemberState::France => validate_fr(number),
+            MemberState::Germany => validate_de(number),
+            MemberState::Greece => validate_el(number),
+            MemberState::Hungary => validate_hu(number),
+            MemberState::Ireland => validate_ie(number),
+            MemberState::Italy => validate_it(number),
+            MemberState::Latvia => validate_lv(number),
+            MemberState::Lithuania => validate_lt(number),
+            MemberState::Luxembourg => validate_lu(number),
+            MemberState::Malta => validate_mt(number),
+            MemberState::Netherlands => validate_nl(number),
+            MemberState::NorthernIreland => validate_xi(number),
+            MemberState::Poland => validate_pl(number),
+            MemberState::Portugal => validate_pt(number),
+            MemberState::Romania => validate_ro(number),
+            MemberState::Slovakia => validate_sk(number),
+            MemberState::Slovenia => validate_si(number),
+            MemberState::Spain => validate_es(number),
+            MemberState::Sweden => validate_se(number),
+        }
+    }
+}
+
+/// Checks that the number has the given length and only contains digits.
+fn check_digits(number: &str, length: usize) -> Result<(), &'static str> {
+    if number.len() != length {
+        return Err("Invalid length");
+    }
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    Ok(())
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Returns the numeric value of the digit at the given position.
+fn digit(number: &str, position: usize) -> u32 {
+    u32::from(number.as_bytes()[position] - b'0')
+}
+
+/// Sums the digits of the input multiplied by the corresponding weights. Extra digits or
+/// weights are ignored.
+fn weighted_sum(value: &str, weights: &[u32]) -> u32 {
+    value
+        .chars()
+        .zip(weights.iter())
+        .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+        .sum()
+}
+
+fn checksum_result(is_valid: bool) -> Result<(), &'static str> {
+    if is_valid {
+        Ok(())
+    } else {
+        Err("Invalid checksum")
+    }
+}
+
+/// Austria: `U` followed by 7 digits and a check digit, validated with the
+/// `AustriaUidValidator`.
+fn validate_at(number: &str) -> Result<(), &'static str> {
+    if !number.starts_with('U') {
+        return Err("Invalid format");
+    }
+    AustriaUidValidator::is_valid(number)
+}
+
+/// Belgium: the 10-digit enterprise number, validated with the
+/// `BelgiumEnterpriseNumberValidator`, where old 9-digit numbers are padded with a zero.
+/// Establishment unit numbers are not VAT numbers, and are rejected.
+fn validate_be(number: &str) -> Result<(), &'static str> {
+    let number = if number.len() == 9 {
+        format!("0{}", number)
+    } else {
+        number.to_string()
+    };
+    check_digits(&number, 10)?;
+    let kind = EnterpriseNumberKind::from_digit(number.chars().next().expect("Validated length"));
+    if kind != Some(EnterpriseNumberKind::Enterprise) {
+        return Err("Invalid format");
+    }
+    BelgiumEnterpriseNumberValidator::is_valid(&number)
+}
+
+/// Bulgaria: 9 digits for legal entities, or 10 digits for people, which can be the
+/// personal number (EGN) of a citizen, the personal number of a foreigner (PNF), or
+/// another identifier, each with its own weights.
+fn validate_bg(number: &str) -> Result<(), &'static str> {
+    match number.len() {
+        9 => {
+            check_digits(number, 9)?;
+            let mut check = weighted_sum(&number[..8], &[1, 2, 3, 4, 5, 6, 7, 8]) % 11;
+            if check == 10 {
+                check = weighted_sum(&number[..8], &[3, 4, 5, 6, 7, 8, 9, 10]) % 11;
+            }
+            checksum_result(check % 10 == digit(number, 8))
+        }
+        10 => {
+            check_digits(number, 10)?;
+            let check = digit(number, 9);
+            let egn = weighted_sum(number, &[2, 4, 8, 5, 10, 9, 7, 3, 6]) % 11 % 10;
+            let pnf = weighted_sum(number, &[21, 19, 17, 13, 11, 9, 7, 3, 1]) % 10;
+            let other = (11 - weighted_sum(number, &[4, 3, 2, 7, 6, 5, 4, 3, 2]) % 11) % 11;
+            checksum_result(egn == check || pnf == check || other == check)
+        }
+        _ => Err("Invalid length"),
+    }
+}
+
+/// Croatia: the 11-digit OIB, with an ISO 7064 MOD 11,10 check digit.
+fn validate_hr(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 11)?;
+    checksum_result(mod11_10_check_digit(&number[..10]) == digit(number, 10))
+}
+
+/// Cyprus: 8 digits, not starting with `12`, and a check letter. The digits in odd
+/// positions are translated through a fixed table before being added to the others.
+fn validate_cy(number: &str) -> Result<(), &'static str> {
+    if number.len() != 9 {
+        return Err("Invalid length");
+    }
+    let (digits, letter) = number.split_at(8);
+    if !is_digits(digits) || number.starts_with("12") {
+        return Err("Invalid format");
+    }
+    const TRANSLATION: [u32; 10] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21];
+    let sum: u32 = digits
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let value = c.to_digit(10).expect("Invalid digit in input");
+            if i % 2 == 0 {
+                TRANSLATION[value as usize]
+            } else {
+                value
+            }
+        })
+        .sum();
+    checksum_result(letter.as_bytes()[0] == b'A' + (sum % 26) as u8)
+}
+
+/// Czechia: 8 digits for legal entities, 9 digits starting with 6 for individuals without
+/// a birth number, or the 9 or 10-digit birth number (rodné číslo) of individuals. Only
+/// the check digit of birth numbers is verified, which 9-digit numbers issued before 1954
+/// do not have.
+fn validate_cz(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    match number.len() {
+        8 => {
+            if number.starts_with('9') {
+                return Err("Invalid format");
+            }
+            let check = (11 - weighted_sum(&number[..7], &[8, 7, 6, 5, 4, 3, 2]) % 11) % 11;
+            checksum_result(check.max(1) % 10 == digit(number, 7))
+        }
+        9 if number.starts_with('6') => {
+            let sum = weighted_sum(&number[1..8], &[8, 7, 6, 5, 4, 3, 2]) % 11;
+            checksum_result(9 - (11 - sum) % 11 % 10 == digit(number, 8))
+        }
+        9 => {
+            let year: u32 = number[..2].parse().expect("Validated digits");
+            checksum_result(year < 54)
+        }
+        10 => {
+            let value: u64 = number.parse().expect("Validated digits");
+            checksum_result(
+                value.is_multiple_of(11) || (value / 10 % 11 == 10 && value.is_multiple_of(10)),
+            )
+        }
+        _ => Err("Invalid length"),
+    }
+}
+
+/// Denmark: the 8-digit CVR number, validated with the `DenmarkCvrValidator`.
+fn validate_dk(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    DenmarkCvrValidator::is_valid(number)
+}
+
+/// Estonia: 9 digits starting with `10`, whose weighted sum is divisible by 10.
+fn validate_ee(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 9)?;
+    if !number.starts_with("10") {
+        return Err("Invalid format");
+    }
+    checksum_result(weighted_sum(number, &[3, 7, 1, 3, 7, 1, 3, 7, 1]).is_multiple_of(10))
+}
+
+/// Finland: the 8-digit business ID (Y-tunnus), validated with the
+/// `FinlandYTunnusValidator`. Unlike the business ID, the VAT number is never written
+/// without its leading zero.
+fn validate_fi(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 8)?;
+    FinlandYTunnusValidator::is_valid(number)
+}
+
+/// France: a 2-character key followed by the 9-digit SIREN, which must pass the Luhn check
+/// unless it starts with `000` (Monaco). A numeric key is the remainder of the SIREN
+/// followed by `12` modulo 97; alphanumeric keys, which cannot contain `I` or `O`, are
+/// checked against the SIREN modulo 11.
+fn validate_fr(number: &str) -> Result<(), &'static str> {
+    if number.len() != 11 {
+        return Err("Invalid length");
+    }
+    let (key, siren) = number.split_at(2);
+    let key_values: Vec<usize> = key
+        .chars()
+        .map(|c| FRENCH_KEY_ALPHABET.find(c))
+        .collect::<Option<_>>()
+        .ok_or("Invalid format")?;
+    if !is_digits(siren) {
+        return Err("Invalid format");
+    }
+    if !siren.starts_with("000") && luhn_check_digit(&siren[..8]) != digit(siren, 8) {
+        return Err("Invalid checksum");
+    }
+    let siren_value: u64 = siren.parse().expect("Validated digits");
+    if is_digits(key) {
+        let expected = (siren_value * 100 + 12) % 97;
+        return checksum_result(key.parse::<u64>().expect("Validated digits") == expected);
+    }
+    let check = if key.as_bytes()[0].is_ascii_digit() {
+        key_values[0] * 24 + key_values[1] - 10
+    } else {
+        key_values[0] * 34 + key_values[1] - 100
+    } as u64;
+    checksum_result((siren_value + 1 + check / 11) % 11 == check % 11)
+}
+
+/// Germany: 9 digits, not starting with 0, with an ISO 7064 MOD 11,10 check digit.
+fn validate_de(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 9)?;
+    if number.starts_with('0') {
+        return Err("Invalid format");
+    }
+    checksum_result(mod11_10_check_digit(&number[..8]) == digit(number, 8))
+}
+
+/// Greece: the 9-digit AFM, validated with the `GreeceAfmValidator`, where old 8-digit
+/// numbers are padded with a zero.
+fn validate_el(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    if number.len() == 8 {
+        GreeceAfmValidator::is_valid(&format!("0{}", number))
+    } else {
+        GreeceAfmValidator::is_valid(number)
+    }
+}
+
+/// Hungary: the first 8 digits of the adószám, validated with the
+/// `HungaryAdoszamValidator`.
+fn validate_hu(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 8)?;
+    HungaryAdoszamValidator::is_valid(number)
+}
+
+/// Ireland: 7 digits followed by a check letter and, for numbers issued since 2013, a
+/// second letter; or, in the old format, a digit, a letter or symbol, 5 digits and the
+/// check letter.
+fn validate_ie(number: &str) -> Result<(), &'static str> {
+    if number.len() != 8 && number.len() != 9 {
+        return Err("Invalid length");
+    }
+    let letters = &number[7..];
+    if !letters.chars().all(|c| IRISH_CHECK_ALPHABET.contains(c)) {
+        return Err("Invalid format");
+    }
+    let (digits, second_letter) = if is_digits(&number[..7]) {
+        (number[..7].to_string(), &number[8..])
+    } else if number.len() == 8
+        && is_digits(&number[..1])
+        && is_digits(&number[2..7])
+        && (number.as_bytes()[1].is_ascii_uppercase() || matches!(&number[1..2], "+" | "*"))
+    {
+        (format!("0{}{}", &number[2..7], &number[..1]), "")
+    } else {
+        return Err("Invalid format");
+    };
+    let extra = IRISH_CHECK_ALPHABET.find(second_letter).unwrap_or(0) as u32;
+    let sum = weighted_sum(&digits, &[8, 7, 6, 5, 4, 3, 2]) + 9 * extra;
+    let expected = IRISH_CHECK_ALPHABET.as_bytes()[(sum % 23) as usize];
+    checksum_result(number.as_bytes()[7] == expected)
+}
+
+/// Italy: the 11-digit partita IVA, with a Luhn check digit. Digits 8 to 10 are the code of
+/// the issuing office: 001 to 100 for the provinces, or 120, 121, 888 or 999.
+fn validate_it(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 11)?;
+    let office: u32 = number[7..10].parse().expect("Validated digits");
+    if number[..7] == *"0000000" || !matches!(office, 1..=100 | 120 | 121 | 888 | 999) {
+        return Err("Invalid format");
+    }
+    checksum_result(luhn_check_digit(&number[..10]) == digit(number, 10))
+}
+
+/// Latvia: 11 digits. Legal entities start with a digit above 3, and their weighted sum
+/// leaves a remainder of 3 modulo 11. Other numbers are personal codes, which embed a
+/// birthdate unless they start with `32`.
+fn validate_lv(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 11)?;
+    if digit(number, 0) > 3 {
+        return checksum_result(
+            weighted_sum(number, &[9, 1, 4, 8, 3, 10, 2, 5, 7, 6, 1]) % 11 == 3,
+        );
+    }
+    if !number.starts_with("32") {
+        let day: u32 = number[..2].parse().expect("Validated digits");
+        let month: u32 = number[2..4].parse().expect("Validated digits");
+        let year: u32 = number[4..6].parse().expect("Validated digits");
+        let century = match digit(number, 6) {
+            0 => 1800,
+            1 => 1900,
+            2 => 2000,
+            _ => return Err("Invalid date"),
+        };
+        if !is_valid_date(century + year, month, day) {
+            return Err("Invalid date");
+        }
+    }
+    let sum = 1 + weighted_sum(&number[..10], &[10, 5, 8, 4, 2, 1, 6, 3, 7, 9]);
+    checksum_result(sum % 11 % 10 == digit(number, 10))
+}
+
+/// Lithuania: 9 digits for legal entities, whose 8th digit is 1, or 12 digits for
+/// temporary taxpayers, whose 11th digit is 1. Weights from 1 to 9 are repeated over the
+/// number, and shifted by two when the first sum leaves a remainder of 10.
+fn validate_lt(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    let marker = match number.len() {
+        9 => 7,
+        12 => 10,
+        _ => return Err("Invalid length"),
+    };
+    if digit(number, marker) != 1 {
+        return Err("Invalid format");
+    }
+    let base = &number[..number.len() - 1];
+    let sum_with_offset = |offset: u32| -> u32 {
+        base.chars()
+            .zip(0..)
+            .map(|(c, i)| c.to_digit(10).expect("Invalid digit in input") * (1 + (i + offset) % 9))
+            .sum()
+    };
+    let mut check = sum_with_offset(0) % 11;
+    if check == 10 {
+        check = sum_with_offset(2) % 11;
+    }
+    checksum_result(check % 10 == digit(number, number.len() - 1))
+}
+
+/// Luxembourg: 8 digits, where the last two are the first six modulo 89.
+fn validate_lu(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 8)?;
+    let base: u32 = number[..6].parse().expect("Validated digits");
+    let check: u32 = number[6..].parse().expect("Validated digits");
+    checksum_result(base % 89 == check)
+}
+
+/// Malta: 8 digits, not starting with 0, whose weighted sum is divisible by 37.
+fn validate_mt(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 8)?;
+    if number.starts_with('0') {
+        return Err("Invalid format");
+    }
+    checksum_result(weighted_sum(number, &[3, 4, 6, 7, 8, 9, 10, 1]).is_multiple_of(37))
+}
+
+/// Netherlands: 9 digits, `B` and a 2-digit suffix other than `00`. The digits pass the
+/// 11-proof of the BSN or, for sole proprietors since 2020, the whole number prefixed with
+/// `NL` passes ISO 7064 MOD 97-10, with letters converted to numbers from 10 to 35.
+fn validate_nl(number: &str) -> Result<(), &'static str> {
+    if number.len() != 12 {
+        return Err("Invalid length");
+    }
+    if !is_digits(&number[..9]) || &number[9..10] != "B" || !is_digits(&number[10..]) {
+        return Err("Invalid format");
+    }
+    if &number[10..] == "00" {
+        return Err("Invalid format");
+    }
+    let eleven_proof = weighted_sum(&number[..8], &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+    if eleven_proof == digit(number, 8) {
+        return Ok(());
+    }
+    let converted: String = format!("NL{}", number)
+        .chars()
+        .map(|c| c.to_digit(36).expect("Validated characters").to_string())
+        .collect();
+    checksum_result(mod97(&converted) == 1)
+}
+
+/// Northern Ireland: the United Kingdom format, validated with the `UkVatValidator`. A
+/// `GB` prefix is not accepted after the `XI` one.
+fn validate_xi(number: &str) -> Result<(), &'static str> {
+    if number.starts_with("GB") {
+        return Err("Invalid format");
+    }
+    UkVatValidator::is_valid(number)
+}
+
+/// Poland: the 10-digit NIP, validated with the `PolandNipValidator`.
+fn validate_pl(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    PolandNipValidator::is_valid(number)
+}
+
+/// Portugal: the 9-digit NIF, validated with the `PortugalNifValidator`, including its
+/// taxpayer category.
+fn validate_pt(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    PortugalNifValidator::is_valid(number)
+}
+
+/// Romania: the CUI, of 2 to 10 digits, validated with the `RomaniaCuiValidator`.
+fn validate_ro(number: &str) -> Result<(), &'static str> {
+    if !is_digits(number) {
+        return Err("Invalid format");
+    }
+    RomaniaCuiValidator::is_valid(number)
+}
+
+/// Slovakia: 10 digits, not starting with 0 and whose third digit is 2, 3, 4, 7, 8 or 9,
+/// forming a number divisible by 11.
+fn validate_sk(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 10)?;
+    if number.starts_with('0') || !matches!(digit(number, 2), 2 | 3 | 4 | 7 | 8 | 9) {
+        return Err("Invalid format");
+    }
+    checksum_result(number.parse::<u64>().expect("Validated digits") % 11 == 0)
+}
+
+/// Slovenia: 8 digits, not starting with 0, whose check digit is the complement of the
+/// weighted sum modulo 11, where 10 yields 0 and 11 is never assigned.
+fn validate_si(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 8)?;
+    if number.starts_with('0') {
+        return Err("Invalid format");
+    }
+    let check = match 11 - weighted_sum(&number[..7], &[8, 7, 6, 5, 4, 3, 2]) % 11 {
+        11 => return Err("Invalid checksum"),
+        10 => 0,
+        check => check,
+    };
+    checksum_result(check == digit(number, 7))
+}
+
+/// Spain: 9 characters, which can be the DNI of a citizen (8 digits and a control letter),
+/// the NIE of a foreigner (`X`, `Y` or `Z` in place of a leading 0, 1 or 2), the NIF of
+/// other people (`K`, `L` or `M`, 7 digits and a control letter), or the CIF of a legal
+/// entity (a letter, 7 digits, and a Luhn check digit or its letter form).
+fn validate_es(number: &str) -> Result<(), &'static str> {
+    if number.len() != 9 {
+        return Err("Invalid length");
+    }
+    let (first, rest) = number.split_at(1);
+    let (digits, control) = rest.split_at(7);
+    if !is_digits(digits) {
+        return Err("Invalid format");
+    }
+    let first = first.as_bytes()[0];
+    let control = control.as_bytes()[0];
+    let dni_letter = |value: u32| SPANISH_CONTROL_LETTERS[(value % 23) as usize];
+    let digits_value: u32 = digits.parse().expect("Validated digits");
+    match first {
+        b'0'..=b'9' => {
+            let value = u32::from(first - b'0') * 10_000_000 + digits_value;
+            checksum_result(control == dni_letter(value))
+        }
+        b'X' | b'Y' | b'Z' => {
+            let value = u32::from(first - b'X') * 10_000_000 + digits_value;
+            checksum_result(control == dni_letter(value))
+        }
+        b'K' | b'L' | b'M' => checksum_result(control == dni_letter(digits_value)),
+        b'A'..=b'H' | b'J' | b'N' | b'P' | b'Q' | b'R' | b'S' | b'U' | b'V' | b'W' => {
+            let check = luhn_check_digit(digits);
+            checksum_result(
+                control == b'0' + check as u8
+                    || control == SPANISH_CIF_CONTROL_LETTERS[check as usize],
+            )
+        }
+        _ => Err("Invalid format"),
+    }
+}
+
+/// Sweden: the 10-digit organisation or personal number, with a Luhn check digit,
+/// followed by `01`.
+fn validate_se(number: &str) -> Result<(), &'static str> {
+    check_digits(number, 12)?;
+    if !number.ends_with("01") {
+        return Err("Invalid format");
+    }
+    checksum_result(luhn_check_digit(&number[..9]) == digit(number, 9))
+}
+
+/// A validated VAT number, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VatNumber {
+    member_state: MemberState,
+    number: String,
+}
+
+impl VatNumber {
+    /// Parses and validates the given VAT number, including its country prefix, discarding
+    /// any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        let (member_state, number) = EuVatValidator::split(value)?;
+        Ok(VatNumber {
+            member_state,
+            number,
+        })
+    }
+
+    /// Returns the member state that issued the VAT number.
+    pub fn member_state(&self) -> MemberState {
+        self.member_state
+    }
+
+    /// Returns the national number, without the country prefix.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+impl FromStr for VatNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for VatNumber {
+    /// Formats the VAT number as its prefix followed by the national number, as in
+    /// `DE136695976`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.member_state.prefix(), self.number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_sum() {
+        assert_eq!(weighted_sum("13585628", &[2, 7, 6, 5, 4, 3, 2, 1]), 143);
+        assert_eq!(weighted_sum("123", &[1, 1]), 3);
+    }
+}
@@ -0,0 +1,237 @@
+/// Finland HETU Validator
+///
+/// This module provides functionality to validate and parse the personal identity code
+/// (henkilötunnus, HETU) assigned to residents of Finland, written as `131052-308T`:
+///
+/// | Positions | Field                                                           |
+/// |-----------|-----------------------------------------------------------------|
+/// | 1-6       | Date of birth as `DDMMYY`                                       |
+/// | 7         | Century sign                                                    |
+/// | 8-10      | Individual number: odd for men, even for women                  |
+/// | 11        | Control character                                               |
+///
+/// The century sign is `+` for the 1800s, `-`, `Y`, `X`, `W`, `V` or `U` for the 1900s, and
+/// `A`, `B`, `C`, `D`, `E` or `F` for the 2000s. The letters were added in 2023 so that more
+/// codes can be issued for the same date. Individual numbers run from 002 to 899, and 900
+/// to 999 are used for temporary codes.
+///
+/// The control character is taken from `0123456789ABCDEFHJKLMNPRSTUVWXY` at the position
+/// given by the 9-digit number formed by the date of birth and the individual number,
+/// modulo 31.
+///
+/// # Usage
+///
+/// ```
+/// use docval::finland::{FinlandHetuValidator, Hetu};
+/// use docval::Sex;
+///
+/// assert!(FinlandHetuValidator::is_valid("131052-308T").is_ok());
+/// assert!(FinlandHetuValidator::is_valid("131052-308U").is_err());
+///
+/// let hetu = Hetu::parse("290200A1239").unwrap();
+/// assert_eq!(hetu.birth_year(), 2000);
+/// assert_eq!(hetu.sex(), Sex::Male);
+/// assert!(!hetu.is_temporary());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a personal identity code. Removes spaces, checks the
+///   length, format, century sign and date of birth, and validates the control character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct FinlandHetuValidator;
+
+const HETU_LENGTH: usize = 11;
+const MIN_INDIVIDUAL_NUMBER: u16 = 2;
+const FIRST_TEMPORARY_NUMBER: u16 = 900;
+const CONTROL_CHARACTERS: &[u8] = b"0123456789ABCDEFHJKLMNPRSTUVWXY";
+
+impl FinlandHetuValidator {
+    /// Validates if the given personal identity code is correct. The input can be a plain
+    /// string in upper or lower case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != HETU_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        let number = format!("{}{}", &sanitized_value[..6], &sanitized_value[7..10]);
+        if !number.chars().all(|c| c.is_ascii_digit())
+            || !sanitized_value[10..]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err("Invalid format");
+        }
+        let individual_number: u16 = sanitized_value[7..10].parse().expect("Validated digits");
+        if individual_number < MIN_INDIVIDUAL_NUMBER {
+            return Err("Invalid format");
+        }
+        let century = Self::century(sanitized_value.as_bytes()[6]).ok_or("Invalid format")?;
+        let (year, month, day) = Self::birth_date(&sanitized_value, century);
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        if sanitized_value.as_bytes()[10] != Self::calculate_control_character(&number) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate personal identity codes using the FinlandHetuValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input and converts it to uppercase.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Returns the century of birth given by the century sign, if any.
+    fn century(sign: u8) -> Option<u32> {
+        match sign {
+            b'+' => Some(1800),
+            b'-' | b'Y' | b'X' | b'W' | b'V' | b'U' => Some(1900),
+            b'A'..=b'F' => Some(2000),
+            _ => None,
+        }
+    }
+
+    fn birth_date(value: &str, century: u32) -> (u32, u32, u32) {
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        (century + field(4), field(2), field(0))
+    }
+
+    /// Calculates the control character of the 9 digits of the date of birth and the
+    /// individual number.
+    fn calculate_control_character(value: &str) -> u8 {
+        let number: usize = value.parse().expect("Validated digits");
+        CONTROL_CHARACTERS[number % CONTROL_CHARACTERS.len()]
+    }
+}
+
+/// A validated personal identity code, stored as its 11 characters in uppercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hetu(String);
+
+impl Hetu {
+    /// Parses and validates the given personal identity code, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        FinlandHetuValidator::is_valid(value)?;
+        Ok(Hetu(FinlandHetuValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 characters of the personal identity code.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, whose century is given by the century sign.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the century sign.
+    pub fn century_sign(&self) -> char {
+        self.0.as_bytes()[6] as char
+    }
+
+    /// Returns the individual number.
+    pub fn individual_number(&self) -> u16 {
+        self.0[7..10].parse().expect("Validated digits")
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if self.individual_number() % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns whether this is a temporary code, with an individual number from 900 to 999.
+    pub fn is_temporary(&self) -> bool {
+        self.individual_number() >= FIRST_TEMPORARY_NUMBER
+    }
+
+    /// Returns the control character.
+    pub fn control_character(&self) -> char {
+        self.0.as_bytes()[10] as char
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        let century =
+            FinlandHetuValidator::century(self.0.as_bytes()[6]).expect("Validated century sign");
+        FinlandHetuValidator::birth_date(&self.0, century)
+    }
+}
+
+impl FromStr for Hetu {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Hetu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_control_character() {
+        assert_eq!(
+            FinlandHetuValidator::calculate_control_character("131052308"),
+            b'T'
+        );
+        assert_eq!(
+            FinlandHetuValidator::calculate_control_character("010100002"),
+            b'H'
+        );
+        assert_eq!(
+            FinlandHetuValidator::calculate_control_character("290200123"),
+            b'9'
+        );
+    }
+}
@@ -0,0 +1,163 @@
+/// Finland Y-tunnus Validator
+///
+/// This module provides functionality to validate and parse the business ID (Y-tunnus)
+/// assigned by the Finnish Patent and Registration Office and the Tax Administration to
+/// companies and other organisations, written as `0112038-9`. Older IDs with 6 digits
+/// before the hyphen are read with a leading zero.
+///
+/// The first 7 digits are weighted by 7, 9, 10, 5, 8, 4 and 2. A remainder of the sum
+/// modulo 11 of 0 gives a check digit of 0, and any other remainder is subtracted from 11.
+/// IDs with a remainder of 1 are never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::finland::{FinlandYTunnusValidator, YTunnus};
+///
+/// assert!(FinlandYTunnusValidator::is_valid("0112038-9").is_ok());
+/// assert!(FinlandYTunnusValidator::is_valid("0112038-8").is_err());
+///
+/// let y_tunnus = YTunnus::parse("112038-9").unwrap();
+/// assert_eq!(y_tunnus.as_str(), "01120389");
+/// assert_eq!(y_tunnus.to_string(), "0112038-9");
+/// assert_eq!(y_tunnus.vat_number(), "FI01120389");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a business ID. Removes spaces and hyphens, pads older IDs
+///   with a leading zero, checks the length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct FinlandYTunnusValidator;
+
+const Y_TUNNUS_LENGTH: usize = 8;
+const COUNTRY_PREFIX: &str = "FI";
+const MULTIPLIER_WEIGHTS: &[u32] = &[7, 9, 10, 5, 8, 4, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl FinlandYTunnusValidator {
+    /// Validates if the given business ID is correct. The input can be a plain or
+    /// hyphenated string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != Y_TUNNUS_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..7]) {
+            Some(check_digit) if sanitized_value[7..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate business IDs using the FinlandYTunnusValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input, and pads older IDs with a leading zero.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect();
+        if value.len() == Y_TUNNUS_LENGTH - 1 {
+            format!("0{value}")
+        } else {
+            value
+        }
+    }
+
+    /// Calculates the check digit of the first 7 digits of a business ID, or `None` if the
+    /// remainder is 1.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match sum % VALIDATION_MODULUS {
+            0 => Some(0),
+            1 => None,
+            remainder => Some(VALIDATION_MODULUS - remainder),
+        }
+    }
+}
+
+/// A validated business ID, stored as its 8 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct YTunnus(String);
+
+impl YTunnus {
+    /// Parses and validates the given business ID, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        FinlandYTunnusValidator::is_valid(value)?;
+        Ok(YTunnus(FinlandYTunnusValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 8 digits of the business ID.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the business ID in its VAT form, as in `FI01120389`.
+    pub fn vat_number(&self) -> String {
+        format!("{COUNTRY_PREFIX}{}", self.0)
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[7] - b'0'
+    }
+}
+
+impl FromStr for YTunnus {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for YTunnus {
+    /// Formats the business ID as in `0112038-9`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..7], &self.0[7..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            FinlandYTunnusValidator::calculate_check_digit("0112038"),
+            Some(9)
+        );
+        assert_eq!(
+            FinlandYTunnusValidator::calculate_check_digit("1572860"),
+            Some(0)
+        );
+        assert_eq!(
+            FinlandYTunnusValidator::calculate_check_digit("1234568"),
+            None
+        );
+    }
+}
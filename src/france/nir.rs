@@ -0,0 +1,241 @@
+/// France NIR Validator
+///
+/// This module provides functionality to validate and parse the NIR (Numéro d'Inscription
+/// au Répertoire), the 15-digit social security number assigned by the INSEE:
+///
+/// | Positions | Field                                                                 |
+/// |-----------|-----------------------------------------------------------------------|
+/// | 1         | Sex: `1` or `7` for men, `2` or `8` for women (7 and 8 are temporary) |
+/// | 2-3       | Last two digits of the year of birth                                  |
+/// | 4-5       | Month of birth, or 20 and above when unknown                          |
+/// | 6-7       | Department of birth, `2A` or `2B` for Corsica, `99` for abroad        |
+/// | 8-10      | Commune of birth, or country for people born abroad                   |
+/// | 11-13     | Order number of the birth in the commune and month                    |
+/// | 14-15     | Key                                                                   |
+///
+/// For people born in an overseas department, the department takes 3 digits (`971` to
+/// `989`), and the commune the remaining 2.
+///
+/// The key is the complement to 97 of the first 13 digits modulo 97. For Corsica, `2A` is
+/// replaced with `19` and `2B` with `18` before the calculation.
+///
+/// # Usage
+///
+/// ```
+/// use docval::france::{FranceNirValidator, Nir};
+/// use docval::Sex;
+///
+/// assert!(FranceNirValidator::is_valid("2 95 10 99 126 111 93").is_ok());
+///
+/// let nir = Nir::parse("1 85 07 2A 004 123 88").unwrap();
+/// assert_eq!(nir.sex(), Sex::Male);
+/// assert_eq!(nir.birth_year(), 85);
+/// assert_eq!(nir.birth_month(), Some(7));
+/// assert_eq!(nir.department(), "2A");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIR. Removes spaces, dots and hyphens, checks the
+///   length and the fields, and validates the key.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::Sex;
+
+pub struct FranceNirValidator;
+
+const NIR_LENGTH: usize = 15;
+const CORSICA_DEPARTMENTS: &[(&str, &str)] = &[("2A", "19"), ("2B", "18")];
+const OVERSEAS_DEPARTMENT_PREFIXES: &[&str] = &["97", "98"];
+const VALIDATION_MODULUS: u64 = 97;
+
+impl FranceNirValidator {
+    /// Validates if the given NIR is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NIR_LENGTH {
+            return Err("Invalid length");
+        }
+        let numeric_value = Self::numeric_value(&sanitized_value);
+        if !numeric_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if !matches!(&sanitized_value[..1], "1" | "2" | "7" | "8") {
+            return Err("Invalid sex");
+        }
+        let month: u32 = sanitized_value[3..5].parse().expect("Validated digits");
+        if !matches!(month, 1..=12 | 20..=42 | 50..=99) {
+            return Err("Invalid date");
+        }
+        if &sanitized_value[5..7] == "00" {
+            return Err("Invalid department");
+        }
+        let number: u64 = numeric_value[..13].parse().expect("Validated digits");
+        let key = VALIDATION_MODULUS - number % VALIDATION_MODULUS;
+        if sanitized_value[13..] == format!("{:02}", key) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NIRs using the FranceNirValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Replaces the Corsican department codes with the digits used to calculate the key.
+    fn numeric_value(value: &str) -> String {
+        match CORSICA_DEPARTMENTS
+            .iter()
+            .find(|(code, _)| value.get(5..7) == Some(*code))
+        {
+            Some((_, digits)) => format!("{}{}{}", &value[..5], digits, &value[7..]),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// A validated NIR, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nir(String);
+
+impl Nir {
+    /// Parses and validates the given NIR, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        FranceNirValidator::is_valid(value)?;
+        Ok(Nir(FranceNirValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 15 characters of the NIR.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if matches!(&self.0[..1], "1" | "7") {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns whether the NIR is a temporary number, assigned while the holder's birth is
+    /// being registered.
+    pub fn is_temporary(&self) -> bool {
+        matches!(&self.0[..1], "7" | "8")
+    }
+
+    /// Returns the last two digits of the year of birth. The century is not encoded.
+    pub fn birth_year(&self) -> u8 {
+        self.0[1..3].parse().expect("Validated digits")
+    }
+
+    /// Returns the month of birth, or `None` when it is unknown.
+    pub fn birth_month(&self) -> Option<u8> {
+        let month = self.0[3..5].parse().expect("Validated digits");
+        (1..=12).contains(&month).then_some(month)
+    }
+
+    /// Returns the department of birth: 2 characters, or 3 digits for an overseas
+    /// department.
+    pub fn department(&self) -> &str {
+        &self.0[5..self.commune_start()]
+    }
+
+    /// Returns the code of the commune of birth, or of the country for people born abroad.
+    pub fn commune(&self) -> &str {
+        &self.0[self.commune_start()..10]
+    }
+
+    /// Returns whether the holder was born abroad.
+    pub fn is_born_abroad(&self) -> bool {
+        &self.0[5..7] == "99"
+    }
+
+    /// Returns the order number of the birth.
+    pub fn order(&self) -> u16 {
+        self.0[10..13].parse().expect("Validated digits")
+    }
+
+    /// Returns the 2-digit key.
+    pub fn key(&self) -> u8 {
+        self.0[13..].parse().expect("Validated digits")
+    }
+
+    fn commune_start(&self) -> usize {
+        if OVERSEAS_DEPARTMENT_PREFIXES.contains(&&self.0[5..7]) {
+            8
+        } else {
+            7
+        }
+    }
+}
+
+impl FromStr for Nir {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nir {
+    /// Formats the NIR as printed on the carte Vitale, as in `2 95 10 99 126 111 93`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {}",
+            &self.0[..1],
+            &self.0[1..3],
+            &self.0[3..5],
+            &self.0[5..7],
+            &self.0[7..10],
+            &self.0[10..13],
+            &self.0[13..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_value() {
+        assert_eq!(
+            FranceNirValidator::numeric_value("185072A00412388"),
+            "185071900412388"
+        );
+        assert_eq!(
+            FranceNirValidator::numeric_value("282022B12345612"),
+            "282021812345612"
+        );
+        assert_eq!(
+            FranceNirValidator::numeric_value("295109912611193"),
+            "295109912611193"
+        );
+    }
+}
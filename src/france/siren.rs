@@ -0,0 +1,209 @@
+/// France SIREN and SIRET Validators
+///
+/// This module provides functionality to validate and parse the identifiers of businesses
+/// registered in the SIRENE directory of the INSEE:
+///
+/// - SIREN (Système d'Identification du Répertoire des Entreprises), 9 digits, which
+///   identifies a company;
+/// - SIRET (Système d'Identification du Répertoire des Établissements), 14 digits, which
+///   identifies an establishment: the SIREN of the company followed by a 5-digit
+///   establishment number (NIC).
+///
+/// Both end with a Luhn check digit. The establishments of La Poste (SIREN `356000000`)
+/// are too many for the NIC to carry a check digit, so their SIRETs are instead valid when
+/// the sum of their digits is a multiple of 5.
+///
+/// # Usage
+///
+/// ```
+/// use docval::france::{FranceSirenValidator, FranceSiretValidator, Siret};
+///
+/// assert!(FranceSirenValidator::is_valid("732 829 320").is_ok());
+/// assert!(FranceSiretValidator::is_valid("732 829 320 00074").is_ok());
+/// assert!(FranceSiretValidator::is_valid("356 000 000 49837").is_ok());
+///
+/// let siret = Siret::parse("73282932000074").unwrap();
+/// assert_eq!(siret.siren().as_str(), "732829320");
+/// assert_eq!(siret.nic(), "00074");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point of each validator. Removes non-digit characters, checks the length,
+///   and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct FranceSirenValidator;
+pub struct FranceSiretValidator;
+
+const SIREN_LENGTH: usize = 9;
+const SIRET_LENGTH: usize = 14;
+const LA_POSTE_SIREN: &str = "356000000";
+const LA_POSTE_MODULUS: u32 = 5;
+
+impl FranceSirenValidator {
+    /// Validates if the given SIREN is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != SIREN_LENGTH {
+            return Err("Invalid length");
+        }
+        validate_luhn(&sanitized_value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SIRENs using the FranceSirenValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+impl FranceSiretValidator {
+    /// Validates if the given SIRET is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != SIRET_LENGTH {
+            return Err("Invalid length");
+        }
+        if sanitized_value.starts_with(LA_POSTE_SIREN) {
+            let sum: u32 = sanitized_value
+                .chars()
+                .map(|c| c.to_digit(10).expect("Invalid digit in input"))
+                .sum();
+            return if sum.is_multiple_of(LA_POSTE_MODULUS) {
+                Ok(())
+            } else {
+                Err("Invalid checksum")
+            };
+        }
+        validate_luhn(&sanitized_value[..SIREN_LENGTH])?;
+        validate_luhn(&sanitized_value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SIRETs using the FranceSiretValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+/// Removes non-digit characters from the input.
+fn sanitize_input(value: &str) -> String {
+    value.chars().filter(char::is_ascii_digit).collect()
+}
+
+/// Validates the Luhn check digit at the end of the given digits.
+fn validate_luhn(value: &str) -> Result<(), &'static str> {
+    let (payload, check_digit) = value.split_at(value.len() - 1);
+    if check_digit == luhn_check_digit(payload).to_string() {
+        Ok(())
+    } else {
+        Err("Invalid checksum")
+    }
+}
+
+/// A validated SIREN, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Siren(String);
+
+impl Siren {
+    /// Parses and validates the given SIREN, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        FranceSirenValidator::is_valid(value)?;
+        Ok(Siren(sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the SIREN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Siren {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Siren {
+    /// Formats the SIREN in groups of three digits, as in `732 829 320`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", &self.0[..3], &self.0[3..6], &self.0[6..])
+    }
+}
+
+/// A validated SIRET, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Siret(String);
+
+impl Siret {
+    /// Parses and validates the given SIRET, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        FranceSiretValidator::is_valid(value)?;
+        Ok(Siret(sanitize_input(value)))
+    }
+
+    /// Returns the 14 digits of the SIRET.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the SIREN of the company the establishment belongs to.
+    pub fn siren(&self) -> Siren {
+        Siren(self.0[..SIREN_LENGTH].to_string())
+    }
+
+    /// Returns the 5-digit establishment number (NIC).
+    pub fn nic(&self) -> &str {
+        &self.0[SIREN_LENGTH..]
+    }
+}
+
+impl FromStr for Siret {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Siret {
+    /// Formats the SIRET as the formatted SIREN followed by the NIC, as in
+    /// `732 829 320 00074`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.siren(), self.nic())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_luhn() {
+        assert!(validate_luhn("732829320").is_ok());
+        assert!(validate_luhn("73282932000074").is_ok());
+        assert!(validate_luhn("732829321").is_err());
+    }
+}
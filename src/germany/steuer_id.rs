@@ -0,0 +1,163 @@
+/// Germany Steuer-ID Validator
+///
+/// This module provides functionality to validate and parse the steuerliche
+/// Identifikationsnummer (Steuer-ID or IdNr), the 11-digit lifelong tax identifier assigned
+/// to every resident by the Bundeszentralamt für Steuern.
+///
+/// The first digit is never 0. Among the first 10 digits, exactly one digit appears twice
+/// or three times, and every other digit at most once; a digit that appears three times
+/// must not fill three consecutive positions. The last digit is an ISO 7064 MOD 11,10 check
+/// digit over the first 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::germany::{GermanySteuerIdValidator, SteuerId};
+///
+/// assert!(GermanySteuerIdValidator::is_valid("36 574 261 809").is_ok());
+/// assert!(GermanySteuerIdValidator::is_valid("86095742719").is_ok());
+///
+/// let steuer_id = SteuerId::parse("86095742719").unwrap();
+/// assert_eq!(steuer_id.to_string(), "86 095 742 719");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a Steuer-ID. Removes non-digit characters, checks the
+///   length and the distribution of the digits, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::mod11_10_check_digit;
+
+pub struct GermanySteuerIdValidator;
+
+const STEUER_ID_LENGTH: usize = 11;
+
+impl GermanySteuerIdValidator {
+    /// Validates if the given Steuer-ID is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != STEUER_ID_LENGTH {
+            return Err("Invalid length");
+        }
+        if sanitized_value.starts_with('0') || !Self::has_valid_distribution(&sanitized_value[..10])
+        {
+            return Err("Invalid format");
+        }
+        let check_digit = mod11_10_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate Steuer-IDs using the GermanySteuerIdValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Checks that exactly one digit is repeated, twice or three times, and that a digit
+    /// repeated three times does not fill three consecutive positions.
+    fn has_valid_distribution(value: &str) -> bool {
+        let mut counts = [0; 10];
+        for c in value.chars() {
+            counts[c.to_digit(10).expect("Invalid digit in input") as usize] += 1;
+        }
+        let repeated: Vec<usize> = (0..10).filter(|&digit| counts[digit] > 1).collect();
+        match repeated.as_slice() {
+            [digit] if counts[*digit] == 2 => true,
+            [digit] if counts[*digit] == 3 => {
+                let digit = char::from_digit(*digit as u32, 10).expect("Single digit");
+                !value.contains(&digit.to_string().repeat(3))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A validated Steuer-ID, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SteuerId(String);
+
+impl SteuerId {
+    /// Parses and validates the given Steuer-ID, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        GermanySteuerIdValidator::is_valid(value)?;
+        Ok(SteuerId(GermanySteuerIdValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the Steuer-ID.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+}
+
+impl FromStr for SteuerId {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for SteuerId {
+    /// Formats the Steuer-ID as printed on tax notices, as in `86 095 742 719`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            &self.0[..2],
+            &self.0[2..5],
+            &self.0[5..8],
+            &self.0[8..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_valid_distribution() {
+        assert!(GermanySteuerIdValidator::has_valid_distribution(
+            "8609574271"
+        ));
+        assert!(GermanySteuerIdValidator::has_valid_distribution(
+            "1213145678"
+        ));
+        assert!(!GermanySteuerIdValidator::has_valid_distribution(
+            "1112345678"
+        ));
+        assert!(!GermanySteuerIdValidator::has_valid_distribution(
+            "1122345678"
+        ));
+        assert!(!GermanySteuerIdValidator::has_valid_distribution(
+            "1234567890"
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+/// Germany USt-IdNr Validator
+///
+/// This module provides offline validation of the Umsatzsteuer-Identifikationsnummer
+/// (USt-IdNr), the VAT identification number assigned by the Bundeszentralamt für Steuern
+/// to businesses trading within the EU. It is written as `DE` followed by 9 digits, the
+/// first of which is never 0.
+///
+/// The check digit follows ISO 7064 MOD 11,10: a running product starts at 10 and, for
+/// each of the first 8 digits, is added to the digit modulo 10 (with 0 taken as 10),
+/// doubled, and reduced modulo 11; the check digit is the complement of the final product
+/// to 11, where 10 yields 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::germany::{GermanyUstIdNrValidator, UstIdNr};
+///
+/// assert!(GermanyUstIdNrValidator::is_valid("DE 136 695 976").is_ok());
+/// assert!(GermanyUstIdNrValidator::is_valid("136695976").is_ok());
+///
+/// let ust_idnr = UstIdNr::parse("de136695976").unwrap();
+/// assert_eq!(ust_idnr.as_str(), "136695976");
+/// assert_eq!(ust_idnr.to_string(), "DE136695976");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a USt-IdNr. Removes spaces, dots, hyphens and the `DE`
+///   prefix, checks the length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::mod11_10_check_digit;
+
+pub struct GermanyUstIdNrValidator;
+
+const COUNTRY_PREFIX: &str = "DE";
+const UST_IDNR_LENGTH: usize = 9;
+
+impl GermanyUstIdNrValidator {
+    /// Validates if the given USt-IdNr is correct. The input can be a plain or formatted
+    /// string, with or without the `DE` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != UST_IDNR_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) || sanitized_value.starts_with('0')
+        {
+            return Err("Invalid format");
+        }
+        let check_digit = mod11_10_check_digit(&sanitized_value[..8]);
+        if sanitized_value[8..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate USt-IdNrs using the GermanyUstIdNrValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and the `DE` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+}
+
+/// A validated USt-IdNr, stored as its 9 digits, without the `DE` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UstIdNr(String);
+
+impl UstIdNr {
+    /// Parses and validates the given USt-IdNr, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        GermanyUstIdNrValidator::is_valid(value)?;
+        Ok(UstIdNr(GermanyUstIdNrValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the USt-IdNr, without the `DE` prefix.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+}
+
+impl FromStr for UstIdNr {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for UstIdNr {
+    /// Formats the USt-IdNr with its `DE` prefix, as in `DE136695976`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", COUNTRY_PREFIX, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            GermanyUstIdNrValidator::sanitize_input("de 136.695-976"),
+            "136695976"
+        );
+        assert_eq!(
+            GermanyUstIdNrValidator::sanitize_input("136695976"),
+            "136695976"
+        );
+    }
+}
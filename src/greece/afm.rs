@@ -0,0 +1,144 @@
+/// Greece AFM Validator
+///
+/// This module provides functionality to validate and parse the AFM (Αριθμός Φορολογικού
+/// Μητρώου), the 9-digit tax registration number assigned to individuals and businesses by
+/// the Independent Authority for Public Revenue (AADE). It is also the VAT number, with the
+/// `EL` prefix.
+///
+/// The first 8 digits are weighted with decreasing powers of 2, from 256 to 2, and the
+/// check digit is the sum modulo 11, where 10 yields 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::greece::{Afm, GreeceAfmValidator};
+///
+/// assert!(GreeceAfmValidator::is_valid("094259216").is_ok());
+/// assert!(GreeceAfmValidator::is_valid("EL 094259216").is_ok());
+/// assert!(GreeceAfmValidator::is_valid("094259217").is_err());
+///
+/// let afm = Afm::parse("el094259216").unwrap();
+/// assert_eq!(afm.as_str(), "094259216");
+/// assert_eq!(afm.check_digit(), 6);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an AFM. Removes spaces and the `EL` or `GR` prefix, checks
+///   the length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct GreeceAfmValidator;
+
+const COUNTRY_PREFIXES: &[&str] = &["EL", "GR"];
+const AFM_LENGTH: usize = 9;
+const MULTIPLIER_WEIGHTS: &[u32] = &[256, 128, 64, 32, 16, 8, 4, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl GreeceAfmValidator {
+    /// Validates if the given AFM is correct. The input can be a plain string or contain
+    /// spaces, with or without the `EL` or `GR` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != AFM_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..8]);
+        if sanitized_value[8..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate AFMs using the GreeceAfmValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and the `EL` or `GR` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        COUNTRY_PREFIXES
+            .iter()
+            .find_map(|prefix| value.strip_prefix(prefix))
+            .unwrap_or(&value)
+            .to_string()
+    }
+
+    /// Calculates the check digit of the first 8 digits of an AFM.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum % VALIDATION_MODULUS % 10
+    }
+}
+
+/// A validated AFM, stored as its 9 digits, without the country prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Afm(String);
+
+impl Afm {
+    /// Parses and validates the given AFM, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        GreeceAfmValidator::is_valid(value)?;
+        Ok(Afm(GreeceAfmValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the AFM.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+}
+
+impl FromStr for Afm {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Afm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(GreeceAfmValidator::calculate_check_digit("09425921"), 6);
+        assert_eq!(GreeceAfmValidator::calculate_check_digit("12345609"), 0);
+    }
+}
@@ -0,0 +1,167 @@
+/// Greece AMKA Validator
+///
+/// This module provides functionality to validate and parse the AMKA (Αριθμός Μητρώου
+/// Κοινωνικής Ασφάλισης), the 11-digit social security number assigned to everyone who
+/// works or receives healthcare in Greece:
+///
+/// | Positions | Field                                              |
+/// |-----------|----------------------------------------------------|
+/// | 1-6       | Date of birth as `DDMMYY`                          |
+/// | 7-10      | Serial number, whose last digit is odd for men     |
+/// | 11        | Luhn check digit of the first 10 digits            |
+///
+/// The century of birth is not encoded, so the date is accepted if it exists in either the
+/// 1900s or the 2000s.
+///
+/// # Usage
+///
+/// ```
+/// use docval::greece::{Amka, GreeceAmkaValidator};
+/// use docval::Sex;
+///
+/// assert!(GreeceAmkaValidator::is_valid("01018001238").is_ok());
+/// assert!(GreeceAmkaValidator::is_valid("01018001239").is_err());
+///
+/// let amka = Amka::parse("15038512347").unwrap();
+/// assert_eq!(amka.birth_day(), 15);
+/// assert_eq!(amka.birth_month(), 3);
+/// assert_eq!(amka.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an AMKA. Removes spaces, checks the length, format and
+///   date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct GreeceAmkaValidator;
+
+const AMKA_LENGTH: usize = 11;
+
+impl GreeceAmkaValidator {
+    /// Validates if the given AMKA is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != AMKA_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (day, month, year) = Self::birth_date(&sanitized_value);
+        if !is_valid_date(1900 + year, month, day) && !is_valid_date(2000 + year, month, day) {
+            return Err("Invalid date");
+        }
+        let check_digit = luhn_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate AMKAs using the GreeceAmkaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Returns the day, month and 2-digit year of birth.
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        (field(0), field(2), field(4))
+    }
+}
+
+/// A validated AMKA, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Amka(String);
+
+impl Amka {
+    /// Parses and validates the given AMKA, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        GreeceAmkaValidator::is_valid(value)?;
+        Ok(Amka(GreeceAmkaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the AMKA.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        GreeceAmkaValidator::birth_date(&self.0).0 as u8
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        GreeceAmkaValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the last 2 digits of the year of birth, whose century is not encoded.
+    pub fn birth_year_digits(&self) -> u8 {
+        GreeceAmkaValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if (self.0.as_bytes()[9] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+}
+
+impl FromStr for Amka {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Amka {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(GreeceAmkaValidator::birth_date("01018001238"), (1, 1, 80));
+        assert_eq!(GreeceAmkaValidator::birth_date("29020004569"), (29, 2, 0));
+    }
+}
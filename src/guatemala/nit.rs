@@ -0,0 +1,164 @@
+/// Guatemala NIT Validator
+///
+/// This module provides functionality to validate and parse the NIT (Número de
+/// Identificación Tributaria), the tax identifier assigned by the SAT, made of a base of
+/// up to 11 digits followed by a check character, usually formatted as `576937-K`.
+///
+/// The base is weighted from right to left with weights starting at 2 and increasing by
+/// one for each digit. The check character is the complement of the sum modulo 11, where
+/// 11 yields `0` and 10 yields `K`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::guatemala::{GuatemalaNitValidator, Nit};
+///
+/// assert!(GuatemalaNitValidator::is_valid("576937-K").is_ok());
+/// assert!(GuatemalaNitValidator::is_valid("7108-0").is_ok());
+///
+/// let nit = Nit::parse("0576937k").unwrap();
+/// assert_eq!(nit.number(), "576937");
+/// assert_eq!(nit.check_character(), 'K');
+/// assert_eq!(nit.to_string(), "576937-K");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIT. Removes hyphens, spaces and leading zeros, checks
+///   the length and the format, and validates the check character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct GuatemalaNitValidator;
+
+const NIT_MIN_LENGTH: usize = 2;
+const NIT_MAX_LENGTH: usize = 12;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl GuatemalaNitValidator {
+    /// Validates if the given NIT is correct. The input can be a plain or hyphenated
+    /// string, and the `K` check character can be lowercase.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < NIT_MIN_LENGTH || sanitized_value.len() > NIT_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        let (base, check_character) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if !base.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if check_character.starts_with(Self::calculate_check_character(base)) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NITs using the GuatemalaNitValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes hyphens and spaces from the input, as well as leading zeros, and uppercases
+    /// the `K` check character.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, '-' | ' '))
+            .map(|c| c.to_ascii_uppercase())
+            .skip_while(|&c| c == '0')
+            .collect()
+    }
+
+    /// Calculates the check character of the given base.
+    fn calculate_check_character(base: &str) -> char {
+        let sum: u32 = base
+            .chars()
+            .rev()
+            .zip(2..)
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => '0',
+            10 => 'K',
+            digit => char::from_digit(digit, 10).expect("Single digit"),
+        }
+    }
+}
+
+/// A validated NIT, stored without formatting or leading zeros.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nit(String);
+
+impl Nit {
+    /// Parses and validates the given NIT, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        GuatemalaNitValidator::is_valid(value)?;
+        Ok(Nit(GuatemalaNitValidator::sanitize_input(value)))
+    }
+
+    /// Returns the base and check character of the NIT, without formatting.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the base of the NIT, without the check character.
+    pub fn number(&self) -> &str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Returns the check character, a digit or `K`.
+    pub fn check_character(&self) -> char {
+        self.0.chars().last().expect("Validated length")
+    }
+}
+
+impl FromStr for Nit {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nit {
+    /// Formats the NIT as its base and check character separated by a hyphen.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.number(), self.check_character())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_character() {
+        assert_eq!(
+            GuatemalaNitValidator::calculate_check_character("576937"),
+            'K'
+        );
+        assert_eq!(
+            GuatemalaNitValidator::calculate_check_character("7108"),
+            '0'
+        );
+        assert_eq!(
+            GuatemalaNitValidator::calculate_check_character("1234567"),
+            '9'
+        );
+    }
+}
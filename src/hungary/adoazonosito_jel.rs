@@ -0,0 +1,207 @@
+/// Hungary Adóazonosító Jel Validator
+///
+/// This module provides functionality to validate and parse the adóazonosító jel, the
+/// 10-digit tax identification number assigned to individuals by the National Tax and
+/// Customs Administration (NAV):
+///
+/// | Positions | Field                                                 |
+/// |-----------|-------------------------------------------------------|
+/// | 1         | Always `8`                                            |
+/// | 2-6       | Date of birth, as the number of days since 1867-01-01 |
+/// | 7-9       | Serial number among people born on the same day       |
+/// | 10        | Check digit                                           |
+///
+/// The first 9 digits are weighted by their position, from 1 to 9, and the check digit is
+/// the sum modulo 11. Numbers whose remainder is 10 are never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::hungary::{AdoazonositoJel, HungaryAdoazonositoJelValidator};
+///
+/// assert!(HungaryAdoazonositoJelValidator::is_valid("8412721233").is_ok());
+/// assert!(HungaryAdoazonositoJelValidator::is_valid("8412721234").is_err());
+///
+/// let adoazonosito_jel = AdoazonositoJel::parse("8412721233").unwrap();
+/// assert_eq!(adoazonosito_jel.birth_year(), 1980);
+/// assert_eq!(adoazonosito_jel.birth_month(), 1);
+/// assert_eq!(adoazonosito_jel.birth_day(), 1);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an adóazonosító jel. Removes spaces, checks the length,
+///   format and leading digit, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::days_in_month;
+
+pub struct HungaryAdoazonositoJelValidator;
+
+const ADOAZONOSITO_JEL_LENGTH: usize = 10;
+const LEADING_DIGIT: char = '8';
+const EPOCH_YEAR: u32 = 1867;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl HungaryAdoazonositoJelValidator {
+    /// Validates if the given adóazonosító jel is correct. The input can be a plain string
+    /// or contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ADOAZONOSITO_JEL_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit())
+            || !sanitized_value.starts_with(LEADING_DIGIT)
+        {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..9]) {
+            Some(check_digit) if sanitized_value[9..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate adóazonosító jelek using the
+    /// HungaryAdoazonositoJelValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates the check digit of the first 9 digits of an adóazonosító jel, or `None`
+    /// if it would be 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(1..)
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        Some(sum % VALIDATION_MODULUS).filter(|&digit| digit != 10)
+    }
+
+    /// Converts the number of days since 1867-01-01 to a date.
+    fn birth_date(mut days: u32) -> (u32, u32, u32) {
+        let mut year = EPOCH_YEAR;
+        let mut month = 1;
+        while days >= days_in_month(year, month) {
+            days -= days_in_month(year, month);
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+        (year, month, days + 1)
+    }
+}
+
+/// A validated adóazonosító jel, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdoazonositoJel(String);
+
+impl AdoazonositoJel {
+    /// Parses and validates the given adóazonosító jel, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        HungaryAdoazonositoJelValidator::is_valid(value)?;
+        Ok(AdoazonositoJel(
+            HungaryAdoazonositoJelValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 10 digits of the adóazonosító jel.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[6..9].parse().expect("Validated digits")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        let days = self.0[1..6].parse().expect("Validated digits");
+        HungaryAdoazonositoJelValidator::birth_date(days)
+    }
+}
+
+impl FromStr for AdoazonositoJel {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for AdoazonositoJel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(HungaryAdoazonositoJelValidator::birth_date(0), (1867, 1, 1));
+        assert_eq!(
+            HungaryAdoazonositoJelValidator::birth_date(41272),
+            (1980, 1, 1)
+        );
+        assert_eq!(
+            HungaryAdoazonositoJelValidator::birth_date(48636),
+            (2000, 2, 29)
+        );
+    }
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            HungaryAdoazonositoJelValidator::calculate_check_digit("841272123"),
+            Some(3)
+        );
+        assert_eq!(
+            HungaryAdoazonositoJelValidator::calculate_check_digit("841272108"),
+            None
+        );
+    }
+}
@@ -0,0 +1,213 @@
+/// Hungary Adószám Validator
+///
+/// This module provides functionality to validate and parse the adószám, the tax number
+/// assigned to businesses by the National Tax and Customs Administration (NAV). It is
+/// written as `xxxxxxxy-z-ww`, as in `10892639-2-42`:
+///
+/// | Positions | Field                                                    |
+/// |-----------|----------------------------------------------------------|
+/// | 1-7       | Taxpayer number                                          |
+/// | 8         | Check digit                                              |
+/// | 9         | VAT code (áfakód), the VAT status of the taxpayer        |
+/// | 10-11     | County code of the tax directorate                       |
+///
+/// The first 8 digits alone, with the `HU` prefix, form the EU VAT number (közösségi
+/// adószám), and are accepted too. The check digit is the complement modulo 10 of the
+/// first 7 digits weighted by 9, 7, 3, 1, 9, 7 and 3.
+///
+/// # Usage
+///
+/// ```
+/// use docval::hungary::{Adoszam, HungaryAdoszamValidator, VatStatus};
+///
+/// assert!(HungaryAdoszamValidator::is_valid("10892639-2-42").is_ok());
+/// assert!(HungaryAdoszamValidator::is_valid("HU10892639").is_ok());
+///
+/// let adoszam = Adoszam::parse("10892639-2-42").unwrap();
+/// assert_eq!(adoszam.base(), "10892639");
+/// assert_eq!(adoszam.vat_status(), Some(VatStatus::General));
+/// assert_eq!(adoszam.county_code(), Some(42));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an adószám, in the full or 8-digit form. Removes spaces,
+///   hyphens and the `HU` prefix, checks the length, format, VAT code and county code, and
+///   validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct HungaryAdoszamValidator;
+
+/// The VAT statuses of a taxpayer, given by the 9th digit of the adószám.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VatStatus {
+    /// Not subject to VAT, or exempt from it (1).
+    Exempt,
+    /// Subject to VAT under the general rules (2).
+    General,
+    /// Paying the simplified entrepreneurial tax, EVA (3).
+    SimplifiedTax,
+    /// Member of a VAT group (4).
+    GroupMember,
+    /// VAT group (5).
+    Group,
+}
+
+impl VatStatus {
+    /// Returns the VAT status with the given code, if any.
+    pub fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            1 => Some(VatStatus::Exempt),
+            2 => Some(VatStatus::General),
+            3 => Some(VatStatus::SimplifiedTax),
+            4 => Some(VatStatus::GroupMember),
+            5 => Some(VatStatus::Group),
+            _ => None,
+        }
+    }
+}
+
+const COUNTRY_PREFIX: &str = "HU";
+const BASE_LENGTH: usize = 8;
+const ADOSZAM_LENGTH: usize = 11;
+const MULTIPLIER_WEIGHTS: &[u32] = &[9, 7, 3, 1, 9, 7, 3];
+const COUNTY_CODES: &[u8] = &[
+    2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 22, 41, 42, 43, 44, 51,
+];
+
+impl HungaryAdoszamValidator {
+    /// Validates if the given adószám is correct. The input can be a plain or formatted
+    /// string, or the 8-digit form with or without the `HU` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != BASE_LENGTH && sanitized_value.len() != ADOSZAM_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.len() == ADOSZAM_LENGTH {
+            let vat_code = sanitized_value.as_bytes()[8] - b'0';
+            if VatStatus::from_digit(vat_code).is_none() {
+                return Err("Invalid VAT code");
+            }
+            let county_code: u8 = sanitized_value[9..].parse().expect("Validated digits");
+            if !COUNTY_CODES.contains(&county_code) {
+                return Err("Invalid county code");
+            }
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..7]);
+        if sanitized_value[7..8] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate adószámok using the HungaryAdoszamValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace and hyphens from the input, and the `HU` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Calculates the check digit of the first 7 digits of an adószám.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated adószám, stored as its 11 digits, or 8 for the EU VAT form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Adoszam(String);
+
+impl Adoszam {
+    /// Parses and validates the given adószám, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        HungaryAdoszamValidator::is_valid(value)?;
+        Ok(Adoszam(HungaryAdoszamValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 or 8 digits of the adószám.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the first 8 digits, which form the EU VAT number.
+    pub fn base(&self) -> &str {
+        &self.0[..BASE_LENGTH]
+    }
+
+    /// Returns the VAT status of the taxpayer, absent from the 8-digit form.
+    pub fn vat_status(&self) -> Option<VatStatus> {
+        self.0
+            .as_bytes()
+            .get(8)
+            .and_then(|b| VatStatus::from_digit(b - b'0'))
+    }
+
+    /// Returns the county code, absent from the 8-digit form.
+    pub fn county_code(&self) -> Option<u8> {
+        self.0
+            .get(9..)
+            .map(|code| code.parse().expect("Validated digits"))
+    }
+}
+
+impl FromStr for Adoszam {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Adoszam {
+    /// Formats the adószám as in `10892639-2-42`, or the 8-digit form as in `HU10892639`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.len() == ADOSZAM_LENGTH {
+            write!(f, "{}-{}-{}", &self.0[..8], &self.0[8..9], &self.0[9..])
+        } else {
+            write!(f, "{}{}", COUNTRY_PREFIX, self.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(HungaryAdoszamValidator::calculate_check_digit("1089263"), 9);
+        assert_eq!(HungaryAdoszamValidator::calculate_check_digit("1234567"), 6);
+    }
+}
@@ -0,0 +1,225 @@
+/// Iceland Kennitala Validator
+///
+/// This module provides functionality to validate and parse the kennitala, the national
+/// identification number assigned by Registers Iceland to individuals and legal entities,
+/// written as `120174-3399`:
+///
+/// | Positions | Field                                                              |
+/// |-----------|--------------------------------------------------------------------|
+/// | 1-6       | Date of birth as `DDMMYY`, with 40 added to the day for entities   |
+/// | 7-8       | Random digits                                                      |
+/// | 9         | Check digit                                                        |
+/// | 10        | Century: `8` for the 1800s, `9` for the 1900s, `0` for the 2000s   |
+///
+/// For legal entities, the date is the date of registration. The first 8 digits are
+/// weighted by 3, 2, 7, 6, 5, 4, 3 and 2, and the check digit is the complement of the sum
+/// modulo 11, where 11 yields 0. Numbers whose complement is 10 are never issued, and are
+/// rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::iceland::{IcelandKennitalaValidator, Kennitala, KennitalaKind};
+///
+/// assert!(IcelandKennitalaValidator::is_valid("120174-3399").is_ok());
+/// assert!(IcelandKennitalaValidator::is_valid("120174-3389").is_err());
+///
+/// let kennitala = Kennitala::parse("550500-3530").unwrap();
+/// assert_eq!(kennitala.kind(), KennitalaKind::LegalEntity);
+/// assert_eq!(kennitala.birth_year(), 2000);
+/// assert_eq!(kennitala.birth_day(), 15);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a kennitala. Removes spaces and hyphens, checks the
+///   length, format, century digit and date, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+
+pub struct IcelandKennitalaValidator;
+
+/// The kinds of holders of a kennitala.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KennitalaKind {
+    /// Individual, with the date of birth.
+    Individual,
+    /// Legal entity, with 40 added to the day of registration.
+    LegalEntity,
+}
+
+const KENNITALA_LENGTH: usize = 10;
+const LEGAL_ENTITY_DAY_OFFSET: u32 = 40;
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl IcelandKennitalaValidator {
+    /// Validates if the given kennitala is correct. The input can be a plain or hyphenated
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != KENNITALA_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value).ok_or("Invalid format")?;
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..8]) {
+            Some(check_digit) if sanitized_value[8..9] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate kennitölur using the IcelandKennitalaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    /// Decodes the date, removing the legal entity offset from the day, or returns `None`
+    /// for an unknown century digit.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let day: u32 = value[..2].parse().expect("Validated digits");
+        let month = value[2..4].parse().expect("Validated digits");
+        let year: u32 = value[4..6].parse().expect("Validated digits");
+        let century = match value.as_bytes()[9] {
+            b'8' => 1800,
+            b'9' => 1900,
+            b'0' => 2000,
+            _ => return None,
+        };
+        let day = if day > LEGAL_ENTITY_DAY_OFFSET {
+            day - LEGAL_ENTITY_DAY_OFFSET
+        } else {
+            day
+        };
+        Some((century + year, month, day))
+    }
+
+    /// Calculates the check digit of the first 8 digits of a kennitala, or `None` if it
+    /// would be 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => None,
+            11 => Some(0),
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated kennitala, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Kennitala(String);
+
+impl Kennitala {
+    /// Parses and validates the given kennitala, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        IcelandKennitalaValidator::is_valid(value)?;
+        Ok(Kennitala(IcelandKennitalaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the kennitala.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the kennitala belongs to an individual or a legal entity.
+    pub fn kind(&self) -> KennitalaKind {
+        let day: u32 = self.0[..2].parse().expect("Validated digits");
+        if day > LEGAL_ENTITY_DAY_OFFSET {
+            KennitalaKind::LegalEntity
+        } else {
+            KennitalaKind::Individual
+        }
+    }
+
+    /// Returns the year of birth or registration.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth or registration.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth or registration, without the legal entity offset.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        IcelandKennitalaValidator::birth_date(&self.0).expect("Validated century digit")
+    }
+}
+
+impl FromStr for Kennitala {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Kennitala {
+    /// Formats the kennitala as in `120174-3399`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            IcelandKennitalaValidator::birth_date("1201743399"),
+            Some((1974, 1, 12))
+        );
+        assert_eq!(
+            IcelandKennitalaValidator::birth_date("5505003530"),
+            Some((2000, 5, 15))
+        );
+        assert_eq!(
+            IcelandKennitalaValidator::birth_date("0101852118"),
+            Some((1885, 1, 1))
+        );
+        assert_eq!(IcelandKennitalaValidator::birth_date("1201743391"), None);
+    }
+}
@@ -0,0 +1,189 @@
+/// Ireland PPS Number Validator
+///
+/// This module provides functionality to validate and parse the Personal Public Service
+/// Number (PPSN), issued by the Department of Social Protection. It has 7 digits followed by
+/// a check character and, optionally, a second letter.
+///
+/// The digits are weighted from 8 down to 2, and the check character is the sum modulo 23
+/// mapped to a letter, where 0 yields `W` and 1 to 22 yield `A` to `V`. Since 2013, numbers
+/// are issued with a second letter (`A`, then `B`), whose position in the same alphabet is
+/// weighted by 9 and added to the sum. Older numbers may carry a `W`, `T`, `X` or `Z` as the
+/// second letter, which does not take part in the check.
+///
+/// # Usage
+///
+/// ```
+/// use docval::ireland::{IrelandPpsnValidator, Ppsn};
+///
+/// assert!(IrelandPpsnValidator::is_valid("1234567T").is_ok());
+/// assert!(IrelandPpsnValidator::is_valid("1234567FA").is_ok());
+/// assert!(IrelandPpsnValidator::is_valid("1234567TA").is_err());
+///
+/// let ppsn = Ppsn::parse("1234567tw").unwrap();
+/// assert_eq!(ppsn.check_character(), 'T');
+/// assert_eq!(ppsn.second_letter(), Some('W'));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a PPSN. Removes spaces and hyphens, checks the length and
+///   format, and validates the check character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct IrelandPpsnValidator;
+
+const DIGITS_LENGTH: usize = 7;
+const CHECK_CHARACTERS: &str = "WABCDEFGHIJKLMNOPQRSTUV";
+const MULTIPLIER_WEIGHTS: &[u32] = &[8, 7, 6, 5, 4, 3, 2];
+const SECOND_LETTER_WEIGHT: u32 = 9;
+const WEIGHTED_SECOND_LETTERS: &str = "AB";
+const LEGACY_SECOND_LETTERS: &str = "WTXZ";
+const VALIDATION_MODULUS: u32 = 23;
+
+impl IrelandPpsnValidator {
+    /// Validates if the given PPSN is correct. Letters are accepted in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != DIGITS_LENGTH + 1 && sanitized_value.len() != DIGITS_LENGTH + 2
+        {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        let (digits, letters) = sanitized_value.split_at(DIGITS_LENGTH);
+        if !digits.chars().all(|c| c.is_ascii_digit())
+            || !letters.chars().all(|c| c.is_ascii_uppercase())
+        {
+            return Err("Invalid format");
+        }
+        let second_letter = letters.chars().nth(1);
+        if let Some(letter) = second_letter {
+            if !WEIGHTED_SECOND_LETTERS.contains(letter) && !LEGACY_SECOND_LETTERS.contains(letter)
+            {
+                return Err("Invalid format");
+            }
+        }
+        let check_character = Self::calculate_check_character(digits, second_letter);
+        if letters.starts_with(check_character) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate PPSNs using the IrelandPpsnValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Calculates the check character of the given 7 digits, weighting the second letter
+    /// when it is one of those issued since 2013.
+    fn calculate_check_character(digits: &str, second_letter: Option<char>) -> char {
+        let mut sum: u32 = digits
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        if let Some(letter) = second_letter.filter(|&c| WEIGHTED_SECOND_LETTERS.contains(c)) {
+            let position = CHECK_CHARACTERS.find(letter).expect("Validated letter") as u32;
+            sum += position * SECOND_LETTER_WEIGHT;
+        }
+        char::from(CHECK_CHARACTERS.as_bytes()[(sum % VALIDATION_MODULUS) as usize])
+    }
+}
+
+/// A validated PPSN, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ppsn(String);
+
+impl Ppsn {
+    /// Parses and validates the given PPSN, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        IrelandPpsnValidator::is_valid(value)?;
+        Ok(Ppsn(IrelandPpsnValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 8 or 9 characters of the PPSN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 7 digits.
+    pub fn number(&self) -> &str {
+        &self.0[..DIGITS_LENGTH]
+    }
+
+    /// Returns the check character.
+    pub fn check_character(&self) -> char {
+        char::from(self.0.as_bytes()[DIGITS_LENGTH])
+    }
+
+    /// Returns the second letter, if any.
+    pub fn second_letter(&self) -> Option<char> {
+        self.0
+            .as_bytes()
+            .get(DIGITS_LENGTH + 1)
+            .map(|&b| char::from(b))
+    }
+}
+
+impl FromStr for Ppsn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ppsn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_character() {
+        assert_eq!(
+            IrelandPpsnValidator::calculate_check_character("1234567", None),
+            'T'
+        );
+        assert_eq!(
+            IrelandPpsnValidator::calculate_check_character("1234567", Some('A')),
+            'F'
+        );
+        assert_eq!(
+            IrelandPpsnValidator::calculate_check_character("1234567", Some('W')),
+            'T'
+        );
+        assert_eq!(
+            IrelandPpsnValidator::calculate_check_character("0000000", None),
+            'W'
+        );
+    }
+}
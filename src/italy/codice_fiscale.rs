@@ -0,0 +1,275 @@
+/// Italy Codice Fiscale Validator
+///
+/// This module provides functionality to validate and parse the codice fiscale, the
+/// 16-character tax code of individuals, issued by the Agenzia delle Entrate:
+///
+/// | Positions | Field                                                            |
+/// |-----------|------------------------------------------------------------------|
+/// | 1-3       | Letters taken from the surname                                   |
+/// | 4-6       | Letters taken from the given name                                |
+/// | 7-8       | Last two digits of the year of birth                             |
+/// | 9         | Month of birth, as a letter from `ABCDEHLMPRST`                  |
+/// | 10-11     | Day of birth, increased by 40 for women                          |
+/// | 12-15     | Birthplace (codice Belfiore): a comune, or `Z` and a country     |
+/// | 16        | Control character                                                |
+///
+/// When two people would get the same code (omocodia), the digits are replaced, starting
+/// from the rightmost, by the letters `LMNPQRSTUV`, which stand for 0 to 9. The decoding
+/// methods of `CodiceFiscale` undo these substitutions.
+///
+/// The control character is calculated over the first 15 characters: those in odd
+/// positions are converted through a fixed table, those in even positions are converted to
+/// their value (0 to 9 for digits, 0 to 25 for letters), and the sum modulo 26 gives the
+/// letter.
+///
+/// # Usage
+///
+/// ```
+/// use docval::italy::{CodiceFiscale, ItalyCodiceFiscaleValidator};
+/// use docval::Sex;
+///
+/// assert!(ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A562S").is_ok());
+/// assert!(ItalyCodiceFiscaleValidator::is_valid("rss mra 85t10 a56n h").is_ok());
+///
+/// let cf = CodiceFiscale::parse("MLLSNT82P65Z404U").unwrap();
+/// assert_eq!(cf.birth_year(), 82);
+/// assert_eq!(cf.birth_month(), 9);
+/// assert_eq!(cf.birth_day(), 25);
+/// assert_eq!(cf.sex(), Sex::Female);
+/// assert_eq!(cf.birthplace_code(), "Z404");
+/// assert!(cf.is_born_abroad());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a codice fiscale. Removes spaces, checks the length and
+///   the structure, and validates the birthdate and the control character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct ItalyCodiceFiscaleValidator;
+
+const CODICE_FISCALE_LENGTH: usize = 16;
+const MONTH_LETTERS: &str = "ABCDEHLMPRST";
+const OMOCODIA_LETTERS: &str = "LMNPQRSTUV";
+const OMOCODIA_POSITIONS: &[usize] = &[6, 7, 9, 10, 12, 13, 14];
+const FEMALE_DAY_OFFSET: u32 = 40;
+const FOREIGN_BIRTHPLACE_LETTER: char = 'Z';
+const ODD_POSITION_VALUES: &[u32] = &[
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+
+impl ItalyCodiceFiscaleValidator {
+    /// Validates if the given codice fiscale is correct. Letters are accepted in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CODICE_FISCALE_LENGTH {
+            return Err("Invalid length");
+        }
+        let is_valid_format = sanitized_value.char_indices().all(|(i, c)| {
+            if OMOCODIA_POSITIONS.contains(&i) {
+                c.is_ascii_digit() || OMOCODIA_LETTERS.contains(c)
+            } else {
+                c.is_ascii_uppercase()
+            }
+        });
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        let decoded = Self::decode_omocodia(&sanitized_value);
+        let (year, month, day) = Self::birth_date(&decoded).ok_or("Invalid date")?;
+        if !is_valid_date(2000 + year, month, day) {
+            return Err("Invalid date");
+        }
+        if sanitized_value.ends_with(Self::calculate_control_character(&sanitized_value[..15])) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate codici fiscali using the ItalyCodiceFiscaleValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Replaces the omocodia letters with the digits they stand for.
+    fn decode_omocodia(value: &str) -> String {
+        value
+            .char_indices()
+            .map(|(i, c)| match OMOCODIA_LETTERS.find(c) {
+                Some(digit) if OMOCODIA_POSITIONS.contains(&i) => {
+                    char::from_digit(digit as u32, 10).expect("Single digit")
+                }
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// Extracts the two-digit year, the month and the day of birth from a decoded code,
+    /// removing the offset from the days of women. Returns `None` for an unknown month
+    /// letter or a day outside both ranges.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let year = value[6..8].parse().expect("Validated digits");
+        let month = MONTH_LETTERS.find(&value[8..9])? as u32 + 1;
+        let day: u32 = value[9..11].parse().expect("Validated digits");
+        let day = match day {
+            1..=31 => day,
+            41..=71 => day - FEMALE_DAY_OFFSET,
+            _ => return None,
+        };
+        Some((year, month, day))
+    }
+
+    /// Calculates the control character of the first 15 characters.
+    fn calculate_control_character(value: &str) -> char {
+        let sum: u32 = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let index = c.to_digit(36).expect("Invalid character in input");
+                let index = if c.is_ascii_digit() {
+                    index
+                } else {
+                    index - 10
+                };
+                if i % 2 == 0 {
+                    ODD_POSITION_VALUES[index as usize]
+                } else {
+                    index
+                }
+            })
+            .sum();
+        char::from(b'A' + (sum % 26) as u8)
+    }
+}
+
+/// A validated codice fiscale, stored as issued, including any omocodia substitutions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CodiceFiscale(String);
+
+impl CodiceFiscale {
+    /// Parses and validates the given codice fiscale, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ItalyCodiceFiscaleValidator::is_valid(value)?;
+        Ok(CodiceFiscale(ItalyCodiceFiscaleValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 16 characters of the codice fiscale.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether digits were replaced with letters to resolve an omocodia.
+    pub fn is_omocode(&self) -> bool {
+        OMOCODIA_POSITIONS
+            .iter()
+            .any(|&i| !self.0.as_bytes()[i].is_ascii_digit())
+    }
+
+    /// Returns the last two digits of the year of birth. The century is not encoded.
+    pub fn birth_year(&self) -> u8 {
+        self.birth_date().0 as u8
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        let day: u32 = ItalyCodiceFiscaleValidator::decode_omocodia(&self.0)[9..11]
+            .parse()
+            .expect("Validated digits");
+        if day > FEMALE_DAY_OFFSET {
+            Sex::Female
+        } else {
+            Sex::Male
+        }
+    }
+
+    /// Returns the codice Belfiore of the birthplace, with omocodia substitutions undone.
+    pub fn birthplace_code(&self) -> String {
+        ItalyCodiceFiscaleValidator::decode_omocodia(&self.0)[11..15].to_string()
+    }
+
+    /// Returns whether the holder was born abroad, in which case the birthplace code
+    /// identifies a country.
+    pub fn is_born_abroad(&self) -> bool {
+        self.0[11..].starts_with(FOREIGN_BIRTHPLACE_LETTER)
+    }
+
+    /// Returns the control character.
+    pub fn control_character(&self) -> char {
+        char::from(self.0.as_bytes()[15])
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        ItalyCodiceFiscaleValidator::birth_date(&ItalyCodiceFiscaleValidator::decode_omocodia(
+            &self.0,
+        ))
+        .expect("Validated birthdate")
+    }
+}
+
+impl FromStr for CodiceFiscale {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for CodiceFiscale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_omocodia() {
+        assert_eq!(
+            ItalyCodiceFiscaleValidator::decode_omocodia("RSSMRAURTMLARSNL"),
+            "RSSMRA85T10A562L"
+        );
+        assert_eq!(
+            ItalyCodiceFiscaleValidator::decode_omocodia("RSSMRA85T10A562S"),
+            "RSSMRA85T10A562S"
+        );
+    }
+}
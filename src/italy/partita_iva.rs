@@ -0,0 +1,151 @@
+/// Italy Partita IVA Validator
+///
+/// This module provides functionality to validate and parse the partita IVA, the 11-digit
+/// VAT number of businesses, which is also the codice fiscale of legal entities:
+///
+/// | Positions | Field                                                        |
+/// |-----------|--------------------------------------------------------------|
+/// | 1-7       | Taxpayer number, assigned by the office                      |
+/// | 8-10      | Code of the issuing office                                   |
+/// | 11        | Check digit                                                  |
+///
+/// The office code identifies the provincial office, from 001 to 100, or one of the
+/// special codes 120 and 121 (assigned by the central offices), 888 (Ministry of Finance)
+/// and 999 (non-residents). The check digit follows the Luhn algorithm over the first 10
+/// digits.
+///
+/// # Usage
+///
+/// ```
+/// use docval::italy::{ItalyPartitaIvaValidator, PartitaIva};
+///
+/// assert!(ItalyPartitaIvaValidator::is_valid("00743110157").is_ok());
+/// assert!(ItalyPartitaIvaValidator::is_valid("IT 0123456012 4").is_ok());
+///
+/// let partita_iva = PartitaIva::parse("12345679992").unwrap();
+/// assert_eq!(partita_iva.office_code(), 999);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a partita IVA. Removes non-digit characters, checks the
+///   length and the office code, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct ItalyPartitaIvaValidator;
+
+const PARTITA_IVA_LENGTH: usize = 11;
+const SPECIAL_OFFICE_CODES: &[u16] = &[120, 121, 888, 999];
+
+impl ItalyPartitaIvaValidator {
+    /// Validates if the given partita IVA is correct. The input can be a plain or formatted
+    /// string, optionally prefixed with `IT`.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != PARTITA_IVA_LENGTH {
+            return Err("Invalid length");
+        }
+        if sanitized_value[..7].chars().all(|c| c == '0') {
+            return Err("Invalid format");
+        }
+        let office_code = sanitized_value[7..10].parse().expect("Validated digits");
+        if !Self::is_valid_office_code(office_code) {
+            return Err("Invalid office");
+        }
+        let check_digit = luhn_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate partite IVA using the ItalyPartitaIvaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Checks if the given code belongs to a provincial or special office.
+    fn is_valid_office_code(code: u16) -> bool {
+        (1..=100).contains(&code) || SPECIAL_OFFICE_CODES.contains(&code)
+    }
+}
+
+/// A validated partita IVA, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartitaIva(String);
+
+impl PartitaIva {
+    /// Parses and validates the given partita IVA, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ItalyPartitaIvaValidator::is_valid(value)?;
+        Ok(PartitaIva(ItalyPartitaIvaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the partita IVA.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 7-digit taxpayer number.
+    pub fn number(&self) -> &str {
+        &self.0[..7]
+    }
+
+    /// Returns the code of the issuing office.
+    pub fn office_code(&self) -> u16 {
+        self.0[7..10].parse().expect("Validated digits")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+}
+
+impl FromStr for PartitaIva {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for PartitaIva {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_office_code() {
+        assert!(ItalyPartitaIvaValidator::is_valid_office_code(1));
+        assert!(ItalyPartitaIvaValidator::is_valid_office_code(100));
+        assert!(ItalyPartitaIvaValidator::is_valid_office_code(888));
+        assert!(!ItalyPartitaIvaValidator::is_valid_office_code(0));
+        assert!(!ItalyPartitaIvaValidator::is_valid_office_code(101));
+    }
+}
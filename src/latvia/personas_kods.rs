@@ -0,0 +1,202 @@
+/// Latvia Personas Kods Validator
+///
+/// This module provides functionality to validate and parse the personal code (personas
+/// kods) assigned to residents of Latvia. It has 11 digits, written as `161175-19997`, in
+/// one of two formats:
+///
+/// - Codes issued until 30 June 2017 start with the date of birth as `DDMMYY`, followed by
+///   a century digit (`0` for the 1800s, `1` for the 1900s, `2` for the 2000s), a 3-digit
+///   serial number and a check digit. The first 10 digits are weighted by 1, 6, 3, 7, 9,
+///   10, 5, 8, 4 and 2, and the check digit is 1101 minus the sum, modulo 11. Codes whose
+///   check digit would be 10 are never issued, and are rejected.
+/// - Codes issued since 1 July 2017 start with `32` and carry no personal data, so only
+///   their format is validated.
+///
+/// # Usage
+///
+/// ```
+/// use docval::latvia::{LatviaPersonasKodsValidator, PersonasKods};
+///
+/// assert!(LatviaPersonasKodsValidator::is_valid("161175-19997").is_ok());
+/// assert!(LatviaPersonasKodsValidator::is_valid("321234-56789").is_ok());
+/// assert!(LatviaPersonasKodsValidator::is_valid("161175-19998").is_err());
+///
+/// let personas_kods = PersonasKods::parse("16117519997").unwrap();
+/// assert_eq!(personas_kods.birth_year(), Some(1975));
+/// assert!(!personas_kods.is_anonymous());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a personal code. Removes spaces and hyphens, and checks
+///   the length and format; for codes with a date of birth, also checks the century digit
+///   and date, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+
+pub struct LatviaPersonasKodsValidator;
+
+const PERSONAS_KODS_LENGTH: usize = 11;
+const ANONYMOUS_PREFIX: &str = "32";
+const MULTIPLIER_WEIGHTS: &[u32] = &[1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+const CHECK_BASE: u32 = 1101;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl LatviaPersonasKodsValidator {
+    /// Validates if the given personal code is correct. The input can be a plain or
+    /// hyphenated string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != PERSONAS_KODS_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.starts_with(ANONYMOUS_PREFIX) {
+            return Ok(());
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value).ok_or("Invalid format")?;
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..10]) {
+            Some(check_digit) if sanitized_value[10..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate personal codes using the LatviaPersonasKodsValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    /// Decodes the date of birth of a code in the format with personal data, or returns
+    /// `None` for an unknown century digit.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let century = match value.as_bytes()[6] {
+            b'0' => 1800,
+            b'1' => 1900,
+            b'2' => 2000,
+            _ => return None,
+        };
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        Some((century + field(4), field(2), field(0)))
+    }
+
+    /// Calculates the check digit of the first 10 digits of a personal code, or `None` if
+    /// it would be 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        Some((CHECK_BASE - sum) % VALIDATION_MODULUS).filter(|&digit| digit != 10)
+    }
+}
+
+/// A validated personal code, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PersonasKods(String);
+
+impl PersonasKods {
+    /// Parses and validates the given personal code, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        LatviaPersonasKodsValidator::is_valid(value)?;
+        Ok(PersonasKods(LatviaPersonasKodsValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 11 digits of the personal code.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the code is in the format without personal data, issued since 2017.
+    pub fn is_anonymous(&self) -> bool {
+        self.0.starts_with(ANONYMOUS_PREFIX)
+    }
+
+    /// Returns the year of birth, or `None` for codes without personal data.
+    pub fn birth_year(&self) -> Option<u16> {
+        self.birth_date().map(|(year, _, _)| year as u16)
+    }
+
+    /// Returns the month of birth, or `None` for codes without personal data.
+    pub fn birth_month(&self) -> Option<u8> {
+        self.birth_date().map(|(_, month, _)| month as u8)
+    }
+
+    /// Returns the day of birth, or `None` for codes without personal data.
+    pub fn birth_day(&self) -> Option<u8> {
+        self.birth_date().map(|(_, _, day)| day as u8)
+    }
+
+    fn birth_date(&self) -> Option<(u32, u32, u32)> {
+        if self.is_anonymous() {
+            None
+        } else {
+            LatviaPersonasKodsValidator::birth_date(&self.0)
+        }
+    }
+}
+
+impl FromStr for PersonasKods {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for PersonasKods {
+    /// Formats the personal code as in `161175-19997`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            LatviaPersonasKodsValidator::calculate_check_digit("1611751999"),
+            Some(7)
+        );
+        assert_eq!(
+            LatviaPersonasKodsValidator::calculate_check_digit("0101901230"),
+            Some(6)
+        );
+        assert_eq!(
+            LatviaPersonasKodsValidator::calculate_check_digit("0101901001"),
+            None
+        );
+    }
+}
@@ -1,4 +1,389 @@
+pub mod argentina {
+    pub mod cbu;
+    pub mod cuit;
+    pub use cbu::{ArgentinaCbuValidator, Cbu};
+    pub use cuit::{ArgentinaCuitValidator, Cuit, CuitKind};
+}
+
+pub mod austria {
+    pub mod steuernummer;
+    pub mod uid;
+    pub use steuernummer::{AustriaSteuernummerValidator, Steuernummer};
+    pub use uid::{AustriaUidValidator, Uid};
+}
+
+pub mod belgium {
+    pub mod enterprise_number;
+    pub mod national_number;
+    pub use enterprise_number::{
+        BelgiumEnterpriseNumberValidator, EnterpriseNumber, EnterpriseNumberKind,
+    };
+    pub use national_number::{BelgiumNationalNumberValidator, NationalNumber};
+}
+
+pub mod bolivia {
+    pub mod nit;
+    pub use nit::{BoliviaNitValidator, Nit};
+}
+
 pub mod brazil {
+    pub mod access_key;
+    pub mod banking;
+    pub mod boleto;
+    pub mod civil_registry;
+    pub mod cns;
+    pub mod collection_slip;
+    pub mod employer_registry;
+    pub mod judicial_process;
+    pub mod nire;
+    pub mod pix_key;
+    pub mod professional_council;
+    pub mod rg;
+    pub mod state_registration;
+    pub mod suframa;
     pub mod tax_id; // Declare the tax_id module within brazil
+    pub mod uf;
+    pub mod vehicle_plate;
+    pub use access_key::{AccessKey, BrazilAccessKeyValidator, FiscalDocumentModel};
+    pub use banking::{Bank, BrazilBankAccountValidator};
+    pub use boleto::{Boleto, BrazilBoletoValidator};
+    pub use civil_registry::{
+        BrazilCivilRegistryValidator, CertificateBookType, CivilRegistryNumber,
+    };
+    pub use cns::{BrazilCnsValidator, Cns, CnsKind};
+    pub use collection_slip::{BrazilCollectionSlipValidator, CollectionSegment, CollectionSlip};
+    pub use employer_registry::{BrazilCaepfValidator, BrazilCeiValidator, BrazilCnoValidator};
+    pub use judicial_process::{
+        BrazilJudicialProcessValidator, JudicialProcessNumber, JudiciarySegment,
+    };
+    pub use nire::{BrazilNireValidator, Nire};
+    pub use pix_key::{BrazilPixKeyValidator, PixKey, PixKeyKind};
+    pub use professional_council::{
+        BrazilProfessionalCouncilValidator, Council, ProfessionalRegistration,
+    };
+    pub use rg::{BrazilRgValidator, RgValidation};
+    pub use state_registration::BrazilStateRegistrationValidator;
+    pub use suframa::BrazilSuframaValidator;
     pub use tax_id::BrazilTaxIdValidator; // Re-export if needed
+    pub use uf::Uf;
+    pub use vehicle_plate::{BrazilVehiclePlateValidator, VehiclePlateFormat};
+}
+
+pub mod bulgaria {
+    pub mod egn;
+    pub use egn::{BulgariaEgnValidator, Egn};
+}
+
+pub mod canada {
+    pub mod business_number;
+    pub mod sin;
+    pub use business_number::{BusinessNumber, CanadaBusinessNumberValidator, ProgramAccount};
+    pub use sin::{CanadaSinValidator, Sin, SinRegion};
+}
+
+pub mod chile {
+    pub mod rut;
+    pub use rut::{ChileRutValidator, Rut};
+}
+
+pub mod colombia {
+    pub mod nit;
+    pub use nit::{ColombiaNitValidator, Nit};
+}
+
+pub mod costa_rica {
+    pub mod cedula;
+    pub mod cedula_juridica;
+    pub use cedula::{Cedula, CostaRicaCedulaValidator};
+    pub use cedula_juridica::{CedulaJuridica, CostaRicaCedulaJuridicaValidator};
+}
+
+pub mod croatia {
+    pub mod oib;
+    pub use oib::{CroatiaOibValidator, Oib};
+}
+
+pub mod czechia {
+    pub mod ico;
+    pub mod rodne_cislo;
+    pub use ico::{CzechiaIcoValidator, Ico};
+    pub use rodne_cislo::{CzechiaRodneCisloValidator, RodneCislo};
+}
+
+pub mod denmark {
+    pub mod cpr;
+    pub mod cvr;
+    pub use cpr::{Cpr, CprMode, DenmarkCprValidator};
+    pub use cvr::{Cvr, DenmarkCvrValidator};
+}
+
+pub mod dominican_republic {
+    pub mod cedula;
+    pub mod rnc;
+    pub use cedula::{Cedula, DominicanRepublicCedulaValidator};
+    pub use rnc::{DominicanRepublicRncValidator, Rnc, RncKind};
+}
+
+pub mod ecuador {
+    pub mod cedula;
+    pub mod ruc;
+    pub use cedula::{Cedula, EcuadorCedulaValidator};
+    pub use ruc::{EcuadorRucValidator, Ruc, RucKind};
+}
+
+pub mod estonia {
+    pub mod isikukood;
+    pub use isikukood::{EstoniaIsikukoodValidator, Isikukood};
+}
+
+pub mod eu {
+    pub mod member_state;
+    pub mod vat;
+    pub use member_state::MemberState;
+    pub use vat::{EuVatValidator, VatNumber};
+}
+
+pub mod finland {
+    pub mod hetu;
+    pub mod y_tunnus;
+    pub use hetu::{FinlandHetuValidator, Hetu};
+    pub use y_tunnus::{FinlandYTunnusValidator, YTunnus};
+}
+
+pub mod france {
+    pub mod nir;
+    pub mod siren;
+    pub use nir::{FranceNirValidator, Nir};
+    pub use siren::{FranceSirenValidator, FranceSiretValidator, Siren, Siret};
+}
+
+pub mod germany {
+    pub mod steuer_id;
+    pub mod ust_idnr;
+    pub use steuer_id::{GermanySteuerIdValidator, SteuerId};
+    pub use ust_idnr::{GermanyUstIdNrValidator, UstIdNr};
+}
+
+pub mod greece {
+    pub mod afm;
+    pub mod amka;
+    pub use afm::{Afm, GreeceAfmValidator};
+    pub use amka::{Amka, GreeceAmkaValidator};
+}
+
+pub mod guatemala {
+    pub mod nit;
+    pub use nit::{GuatemalaNitValidator, Nit};
+}
+
+pub mod hungary {
+    pub mod adoazonosito_jel;
+    pub mod adoszam;
+    pub use adoazonosito_jel::{AdoazonositoJel, HungaryAdoazonositoJelValidator};
+    pub use adoszam::{Adoszam, HungaryAdoszamValidator, VatStatus};
+}
+
+pub mod iceland {
+    pub mod kennitala;
+    pub use kennitala::{IcelandKennitalaValidator, Kennitala, KennitalaKind};
+}
+
+pub mod ireland {
+    pub mod ppsn;
+    pub use ppsn::{IrelandPpsnValidator, Ppsn};
+}
+
+pub mod italy {
+    pub mod codice_fiscale;
+    pub mod partita_iva;
+    pub use codice_fiscale::{CodiceFiscale, ItalyCodiceFiscaleValidator};
+    pub use partita_iva::{ItalyPartitaIvaValidator, PartitaIva};
+}
+
+pub mod latvia {
+    pub mod personas_kods;
+    pub use personas_kods::{LatviaPersonasKodsValidator, PersonasKods};
+}
+
+pub mod lithuania {
+    pub mod asmens_kodas;
+    pub use asmens_kodas::{AsmensKodas, LithuaniaAsmensKodasValidator};
+}
+
+pub mod luxembourg {
+    pub mod matricule;
+    pub use matricule::{LuxembourgMatriculeValidator, Matricule};
+}
+
+pub mod mexico {
+    pub mod clabe;
+    pub mod curp;
+    pub mod rfc;
+    pub mod state;
+    pub use clabe::{Clabe, MexicoClabeValidator};
+    pub use curp::{Curp, MexicoCurpValidator};
+    pub use rfc::{MexicoRfcValidator, Rfc, RfcKind};
+    pub use state::State;
+}
+
+pub mod netherlands {
+    pub mod bsn;
+    pub use bsn::{Bsn, NetherlandsBsnValidator};
+}
+
+pub mod norway {
+    pub mod fodselsnummer;
+    pub mod organisasjonsnummer;
+    pub use fodselsnummer::{Fodselsnummer, FodselsnummerKind, NorwayFodselsnummerValidator};
+    pub use organisasjonsnummer::{NorwayOrganisasjonsnummerValidator, Organisasjonsnummer};
+}
+
+pub mod paraguay {
+    pub mod ruc;
+    pub use ruc::{ParaguayRucValidator, Ruc};
+}
+
+pub mod peru {
+    pub mod dni;
+    pub mod ruc;
+    pub use dni::{Dni, PeruDniValidator};
+    pub use ruc::{PeruRucValidator, Ruc, RucKind};
+}
+
+pub mod poland {
+    pub mod nip;
+    pub mod pesel;
+    pub mod regon;
+    pub use nip::{Nip, PolandNipValidator};
+    pub use pesel::{Pesel, PolandPeselValidator};
+    pub use regon::{PolandRegonValidator, Regon, RegonKind};
+}
+
+pub mod portugal {
+    pub mod cartao_cidadao;
+    pub mod nif;
+    pub mod niss;
+    pub use cartao_cidadao::{CartaoCidadao, PortugalCartaoCidadaoValidator};
+    pub use nif::{Nif, NifCategory, PortugalNifValidator};
+    pub use niss::{Niss, NissKind, PortugalNissValidator};
+}
+
+pub mod romania {
+    pub mod cnp;
+    pub mod cui;
+    pub use cnp::{Cnp, RomaniaCnpValidator};
+    pub use cui::{Cui, RomaniaCuiValidator};
+}
+
+pub mod russia {
+    pub mod inn;
+    pub mod ogrn;
+    pub mod snils;
+    pub use inn::{Inn, InnKind, RussiaInnValidator};
+    pub use ogrn::{Ogrn, OgrnKind, RussiaOgrnValidator};
+    pub use snils::{RussiaSnilsValidator, Snils};
+}
+
+pub mod slovakia {
+    pub mod ico;
+    pub mod rodne_cislo;
+    pub use ico::{Ico, SlovakiaIcoValidator};
+    pub use rodne_cislo::{RodneCislo, SlovakiaRodneCisloValidator};
+}
+
+pub mod slovenia {
+    pub mod emso;
+    pub use emso::{Emso, SloveniaEmsoValidator};
+}
+
+pub mod spain {
+    pub mod cif;
+    pub mod dni;
+    pub use cif::{Cif, OrganizationType, SpainCifValidator};
+    pub use dni::{IdentityNumber, IdentityNumberKind, SpainDniValidator, SpainNieValidator};
+}
+
+pub mod sweden {
+    pub mod organisationsnummer;
+    pub mod personnummer;
+    pub use organisationsnummer::{Organisationsnummer, SwedenOrganisationsnummerValidator};
+    pub use personnummer::{Personnummer, PersonnummerKind, SwedenPersonnummerValidator};
+}
+
+pub mod switzerland {
+    pub mod ahv;
+    pub mod uid;
+    pub use ahv::{Ahv, SwitzerlandAhvValidator};
+    pub use uid::{SwitzerlandUidValidator, Uid, VatSuffix};
+}
+
+pub mod turkey {
+    pub mod tc_kimlik_no;
+    pub mod vkn;
+    pub use tc_kimlik_no::{TcKimlikNo, TurkeyTcKimlikNoValidator};
+    pub use vkn::{TurkeyVknValidator, Vkn};
+}
+
+pub mod uk {
+    pub mod company_number;
+    pub mod modulus;
+    pub mod nhs_number;
+    pub mod nino;
+    pub mod utr;
+    pub mod vat;
+    pub use company_number::{CompanyNumber, EntityType, Jurisdiction, UkCompanyNumberValidator};
+    pub use modulus::{ModulusTable, UkBankAccountValidator};
+    pub use nhs_number::{NhsNumber, UkNhsNumberValidator};
+    pub use nino::{Nino, UkNinoValidator};
+    pub use utr::{UkUtrValidator, Utr};
+    pub use vat::{UkVatValidator, VatNumberKind, VatRegistrationNumber};
+}
+
+pub mod ukraine {
+    pub mod edrpou;
+    pub mod rntrc;
+    pub use edrpou::{Edrpou, UkraineEdrpouValidator};
+    pub use rntrc::{Rntrc, UkraineRntrcValidator};
+}
+
+pub mod uruguay {
+    pub mod cedula;
+    pub mod rut;
+    pub use cedula::{Cedula, UruguayCedulaValidator};
+    pub use rut::{Rut, UruguayRutValidator};
+}
+
+pub mod us {
+    pub mod dea;
+    pub mod ein;
+    pub mod itin;
+    pub mod mbi;
+    pub mod npi;
+    pub mod routing_number;
+    pub mod ssn;
+    pub mod tin;
+    pub use dea::{DeaNumber, UsDeaValidator};
+    pub use ein::{Ein, EinCampus, UsEinValidator};
+    pub use itin::{Itin, UsItinValidator};
+    pub use mbi::{Mbi, UsMbiValidator};
+    pub use npi::{Npi, UsNpiValidator};
+    pub use routing_number::{RoutingNumber, UsRoutingNumberValidator};
+    pub use ssn::{Ssn, UsSsnValidator};
+    pub use tin::{TinKind, UsTinValidator};
+}
+
+pub mod venezuela {
+    pub mod rif;
+    pub use rif::{Rif, RifCategory, VenezuelaRifValidator};
+}
+
+pub mod western_balkans {
+    pub mod jmbg;
+    pub use jmbg::{Jmbg, JmbgCountry, WesternBalkansJmbgValidator};
 }
+
+pub mod sex;
+pub use sex::Sex;
+
+mod checksum;
+mod date;
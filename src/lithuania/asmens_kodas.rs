@@ -0,0 +1,200 @@
+/// Lithuania Asmens Kodas Validator
+///
+/// This module provides functionality to validate and parse the personal code (asmens
+/// kodas) assigned to residents of Lithuania, written as `33309240064`. It has the same
+/// structure as the Estonian isikukood:
+///
+/// | Positions | Field                                                   |
+/// |-----------|---------------------------------------------------------|
+/// | 1         | Century of birth and sex                                |
+/// | 2-7       | Date of birth as `YYMMDD`                               |
+/// | 8-10      | Serial number                                           |
+/// | 11        | Check digit                                             |
+///
+/// The first digit is odd for men and even for women: `1` and `2` stand for the 1800s, `3`
+/// and `4` for the 1900s, and `5` and `6` for the 2000s. Codes starting with `9` are issued
+/// in exceptional cases, such as an unknown date of birth, so their date is not checked.
+///
+/// The check digit is the remainder modulo 11 of the first 10 digits weighted by 1 to 9
+/// and 1. If it is 10, the sum is recalculated with the weights 3 to 9 and 1 to 3, and a
+/// remainder of 10 again yields 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::lithuania::{AsmensKodas, LithuaniaAsmensKodasValidator};
+/// use docval::Sex;
+///
+/// assert!(LithuaniaAsmensKodasValidator::is_valid("33309240064").is_ok());
+/// assert!(LithuaniaAsmensKodasValidator::is_valid("33309240065").is_err());
+///
+/// let asmens_kodas = AsmensKodas::parse("49001011238").unwrap();
+/// assert_eq!(asmens_kodas.birth_year(), Some(1990));
+/// assert_eq!(asmens_kodas.sex(), Some(Sex::Female));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a personal code. Removes spaces, checks the length,
+///   format, first digit and date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::two_stage_mod11_check_digit;
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct LithuaniaAsmensKodasValidator;
+
+const ASMENS_KODAS_LENGTH: usize = 11;
+const EXCEPTIONAL_PREFIX: u8 = b'9';
+
+impl LithuaniaAsmensKodasValidator {
+    /// Validates if the given personal code is correct. The input can be a plain string or
+    /// contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ASMENS_KODAS_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.as_bytes()[0] != EXCEPTIONAL_PREFIX {
+            let (year, month, day) = Self::birth_date(&sanitized_value).ok_or("Invalid format")?;
+            if !is_valid_date(year, month, day) {
+                return Err("Invalid date");
+            }
+        }
+        if sanitized_value[10..] != two_stage_mod11_check_digit(&sanitized_value[..10]).to_string()
+        {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate personal codes using the LithuaniaAsmensKodasValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Decodes the date of birth, taking the century from the first digit, or returns
+    /// `None` if the first digit does not give a century.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let century = match value.as_bytes()[0] - b'0' {
+            digit @ 1..=6 => 1800 + (u32::from(digit) - 1) / 2 * 100,
+            _ => return None,
+        };
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        Some((century + field(1), field(3), field(5)))
+    }
+}
+
+/// A validated personal code, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsmensKodas(String);
+
+impl AsmensKodas {
+    /// Parses and validates the given personal code, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        LithuaniaAsmensKodasValidator::is_valid(value)?;
+        Ok(AsmensKodas(LithuaniaAsmensKodasValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 11 digits of the personal code.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, or `None` for codes issued in exceptional cases.
+    pub fn birth_year(&self) -> Option<u16> {
+        self.birth_date().map(|(year, _, _)| year as u16)
+    }
+
+    /// Returns the month of birth, or `None` for codes issued in exceptional cases.
+    pub fn birth_month(&self) -> Option<u8> {
+        self.birth_date().map(|(_, month, _)| month as u8)
+    }
+
+    /// Returns the day of birth, or `None` for codes issued in exceptional cases.
+    pub fn birth_day(&self) -> Option<u8> {
+        self.birth_date().map(|(_, _, day)| day as u8)
+    }
+
+    /// Returns the sex of the holder, or `None` for codes issued in exceptional cases.
+    pub fn sex(&self) -> Option<Sex> {
+        match self.0.as_bytes()[0] {
+            EXCEPTIONAL_PREFIX => None,
+            digit if (digit - b'0') % 2 == 1 => Some(Sex::Male),
+            _ => Some(Sex::Female),
+        }
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[7..10].parse().expect("Validated digits")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+
+    fn birth_date(&self) -> Option<(u32, u32, u32)> {
+        LithuaniaAsmensKodasValidator::birth_date(&self.0)
+    }
+}
+
+impl FromStr for AsmensKodas {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for AsmensKodas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            LithuaniaAsmensKodasValidator::birth_date("33309240064"),
+            Some((1933, 9, 24))
+        );
+        assert_eq!(
+            LithuaniaAsmensKodasValidator::birth_date("51012310013"),
+            Some((2010, 12, 31))
+        );
+        assert_eq!(
+            LithuaniaAsmensKodasValidator::birth_date("90000000009"),
+            None
+        );
+    }
+}
@@ -0,0 +1,169 @@
+/// Luxembourg Matricule Validator
+///
+/// This module provides functionality to validate and parse the national identification
+/// number (matricule), assigned by the Centre des technologies de l'information de l'État to
+/// residents of Luxembourg. It has 13 digits:
+///
+/// | Positions | Field                                                |
+/// |-----------|------------------------------------------------------|
+/// | 1-8       | Date of birth as `YYYYMMDD`                          |
+/// | 9-11      | Serial number among people born on the same day      |
+/// | 12        | Luhn check digit of the first 11 digits              |
+/// | 13        | Verhoeff check digit of the first 11 digits          |
+///
+/// # Usage
+///
+/// ```
+/// use docval::luxembourg::{LuxembourgMatriculeValidator, Matricule};
+///
+/// assert!(LuxembourgMatriculeValidator::is_valid("1983 1201 057 47").is_ok());
+/// assert!(LuxembourgMatriculeValidator::is_valid("1983120105748").is_err());
+///
+/// let matricule = Matricule::parse("1983120105747").unwrap();
+/// assert_eq!(matricule.birth_year(), 1983);
+/// assert_eq!(matricule.serial(), 57);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a matricule. Removes spaces, checks the length and the
+///   date of birth, and validates both check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::{luhn_check_digit, verhoeff_check_digit};
+use crate::date::is_valid_date;
+
+pub struct LuxembourgMatriculeValidator;
+
+const MATRICULE_LENGTH: usize = 13;
+const BASE_LENGTH: usize = 11;
+
+impl LuxembourgMatriculeValidator {
+    /// Validates if the given matricule is correct. The input can be a plain string or
+    /// grouped with spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != MATRICULE_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value);
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        let base = &sanitized_value[..BASE_LENGTH];
+        let check_digits = format!("{}{}", luhn_check_digit(base), verhoeff_check_digit(base));
+        if sanitized_value[BASE_LENGTH..] == check_digits {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate matricules using the LuxembourgMatriculeValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let field = |start: usize, end: usize| -> u32 {
+            value[start..end].parse().expect("Validated digits")
+        };
+        (field(0, 4), field(4, 6), field(6, 8))
+    }
+}
+
+/// A validated matricule, stored as its 13 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matricule(String);
+
+impl Matricule {
+    /// Parses and validates the given matricule, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        LuxembourgMatriculeValidator::is_valid(value)?;
+        Ok(Matricule(LuxembourgMatriculeValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 13 digits of the matricule.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        LuxembourgMatriculeValidator::birth_date(&self.0).0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        LuxembourgMatriculeValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        LuxembourgMatriculeValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[8..BASE_LENGTH].parse().expect("Validated digits")
+    }
+
+    /// Returns the Luhn check digit.
+    pub fn luhn_check_digit(&self) -> u8 {
+        self.0.as_bytes()[11] - b'0'
+    }
+
+    /// Returns the Verhoeff check digit.
+    pub fn verhoeff_check_digit(&self) -> u8 {
+        self.0.as_bytes()[12] - b'0'
+    }
+}
+
+impl FromStr for Matricule {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Matricule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            LuxembourgMatriculeValidator::birth_date("1983120105747"),
+            (1983, 12, 1)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+/// Mexico CLABE Validator
+///
+/// This module provides functionality to validate and parse the CLABE (Clave Bancaria
+/// Estandarizada), the 18-digit interbank account number used for SPEI transfers:
+///
+/// - 3 digits: bank code, assigned by the ABM;
+/// - 3 digits: plaza code, identifying the city of the branch that opened the account;
+/// - 11 digits: account number;
+/// - 1 digit: check digit.
+///
+/// The first 17 digits are weighted with the repeating sequence 3, 7 and 1, keeping only
+/// the units of each product, and the check digit is the complement of the sum to 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::mexico::{Clabe, MexicoClabeValidator};
+///
+/// assert!(MexicoClabeValidator::is_valid("032180000118359719").is_ok());
+///
+/// let clabe = Clabe::parse("032 180 00011835971 9").unwrap();
+/// assert_eq!(clabe.bank_code(), "032");
+/// assert_eq!(clabe.plaza_code(), "180");
+/// assert_eq!(clabe.account_number(), "00011835971");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CLABE. Removes non-digit characters, checks the length,
+///   and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct MexicoClabeValidator;
+
+const CLABE_LENGTH: usize = 18;
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 7, 1];
+
+impl MexicoClabeValidator {
+    /// Validates if the given CLABE is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CLABE_LENGTH {
+            return Err("Invalid length");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..17]);
+        if sanitized_value[17..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CLABEs using the MexicoClabeValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the given digits.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS.iter().cycle())
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w % 10)
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated CLABE, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Clabe(String);
+
+impl Clabe {
+    /// Parses and validates the given CLABE, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        MexicoClabeValidator::is_valid(value)?;
+        Ok(Clabe(MexicoClabeValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 18 digits of the CLABE.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the code of the bank that holds the account.
+    pub fn bank_code(&self) -> &str {
+        &self.0[0..3]
+    }
+
+    /// Returns the plaza code of the branch that opened the account.
+    pub fn plaza_code(&self) -> &str {
+        &self.0[3..6]
+    }
+
+    /// Returns the account number.
+    pub fn account_number(&self) -> &str {
+        &self.0[6..17]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[17] - b'0'
+    }
+}
+
+impl FromStr for Clabe {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Clabe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            MexicoClabeValidator::calculate_check_digit("03218000011835971"),
+            9
+        );
+    }
+}
@@ -0,0 +1,232 @@
+/// Mexico CURP Validator
+///
+/// This module provides functionality to validate and parse the CURP (Clave Única de
+/// Registro de Población), the 18-character personal identifier issued by RENAPO:
+///
+/// | Positions | Field                                                          |
+/// |-----------|----------------------------------------------------------------|
+/// | 1-4       | Letters taken from the surnames and the given name             |
+/// | 5-10      | Birthdate, as `YYMMDD`                                         |
+/// | 11        | Sex: `H` (hombre) or `M` (mujer)                               |
+/// | 12-13     | State of birth, or `NE` for people born abroad                 |
+/// | 14-16     | First internal consonants of the surnames and the given name   |
+/// | 17        | Differentiator: a digit for births up to 1999, a letter after  |
+/// | 18        | Check digit                                                    |
+///
+/// As in the RFC, inconvenient words formed by the first 4 letters are replaced by RENAPO,
+/// so they never appear. The check digit is calculated by mapping the first 17 characters
+/// to values from 0 to 36, weighting them from 18 down to 2, and taking the complement of
+/// the sum to 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::mexico::{Curp, MexicoCurpValidator, State};
+/// use docval::Sex;
+///
+/// assert!(MexicoCurpValidator::is_valid("BOXW310820HNERXN09").is_ok());
+///
+/// let curp = Curp::parse("HEGG560427MVZRRL04").unwrap();
+/// assert_eq!(curp.birth_year(), 1956);
+/// assert_eq!(curp.sex(), Sex::Female);
+/// assert_eq!(curp.state(), Some(State::Veracruz));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CURP. Checks the length and the structure, and
+///   validates the birthdate, the sex, the state and the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::State;
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct MexicoCurpValidator;
+
+const CURP_LENGTH: usize = 18;
+const BORN_ABROAD_CODE: &str = "NE";
+const CHECK_CHARACTERS: &str = "0123456789ABCDEFGHIJKLMNÑOPQRSTUVWXYZ";
+const INCONVENIENT_WORDS: &[&str] = &[
+    "BACA", "BAKA", "BUEI", "BUEY", "CACA", "CACO", "CAGA", "CAGO", "CAKA", "CAKO", "COGE", "COGI",
+    "COJA", "COJE", "COJI", "COJO", "COLA", "CULO", "FALO", "FETO", "GETA", "GUEI", "GUEY", "JETA",
+    "JOTO", "KACA", "KACO", "KAGA", "KAGO", "KAKA", "KAKO", "KOGE", "KOGI", "KOJA", "KOJE", "KOJI",
+    "KOJO", "KOLA", "KULO", "LILO", "LOCA", "LOCO", "LOKA", "LOKO", "MAME", "MAMO", "MEAR", "MEAS",
+    "MEON", "MIAR", "MION", "MOCO", "MOKO", "MULA", "MULO", "NACA", "NACO", "PEDA", "PEDO", "PENE",
+    "PIPI", "PITO", "POPO", "PUTA", "PUTO", "QULO", "RATA", "ROBA", "ROBE", "ROBO", "RUIN", "SENO",
+    "TETA", "VACA", "VAGA", "VAGO", "VAKA", "VUEI", "VUEY", "WUEI", "WUEY",
+];
+
+impl MexicoCurpValidator {
+    /// Validates if the given CURP is correct. Letters are accepted in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.chars().count() != CURP_LENGTH {
+            return Err("Invalid length");
+        }
+        let is_letter = |range: std::ops::Range<usize>| {
+            sanitized_value[range]
+                .chars()
+                .all(|c| c.is_ascii_uppercase())
+        };
+        let is_valid_format = sanitized_value.is_ascii()
+            && is_letter(0..4)
+            && sanitized_value[4..10].chars().all(|c| c.is_ascii_digit())
+            && is_letter(10..16)
+            && sanitized_value[16..17]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric())
+            && sanitized_value[17..].chars().all(|c| c.is_ascii_digit());
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        if INCONVENIENT_WORDS.contains(&&sanitized_value[0..4]) {
+            return Err("Invalid name");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value);
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        if !matches!(&sanitized_value[10..11], "H" | "M") {
+            return Err("Invalid sex");
+        }
+        let state = &sanitized_value[11..13];
+        if state != BORN_ABROAD_CODE && State::from_code(state).is_none() {
+            return Err("Invalid state");
+        }
+        if sanitized_value[17..] == Self::calculate_check_digit(&sanitized_value[..17]).to_string()
+        {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CURPs using the MexicoCurpValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes surrounding whitespace and converts the input to uppercase.
+    fn sanitize_input(value: &str) -> String {
+        value.trim().to_ascii_uppercase()
+    }
+
+    /// Extracts the birthdate, using the differentiator to tell the century apart.
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        let century = if value.as_bytes()[16].is_ascii_digit() {
+            1900
+        } else {
+            2000
+        };
+        (century + field(4), field(6), field(8))
+    }
+
+    /// Calculates the check digit of the first 17 characters.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip((2..=18).rev())
+            .map(|(c, w)| {
+                let index = CHECK_CHARACTERS
+                    .chars()
+                    .position(|candidate| candidate == c)
+                    .expect("Invalid character in input");
+                index as u32 * w
+            })
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated CURP, stored in uppercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Curp(String);
+
+impl Curp {
+    /// Parses and validates the given CURP.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        MexicoCurpValidator::is_valid(value)?;
+        Ok(Curp(MexicoCurpValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 18 characters of the CURP.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        MexicoCurpValidator::birth_date(&self.0).0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        MexicoCurpValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        MexicoCurpValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if &self.0[10..11] == "H" {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the state of birth, or `None` for people born abroad.
+    pub fn state(&self) -> Option<State> {
+        State::from_code(&self.0[11..13])
+    }
+
+    /// Returns whether the holder was born abroad.
+    pub fn is_born_abroad(&self) -> bool {
+        &self.0[11..13] == BORN_ABROAD_CODE
+    }
+}
+
+impl FromStr for Curp {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Curp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            MexicoCurpValidator::calculate_check_digit("BOXW310820HNERXN0"),
+            9
+        );
+    }
+}
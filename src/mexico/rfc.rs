@@ -0,0 +1,232 @@
+/// Mexico RFC Validator
+///
+/// This module provides functionality to validate and parse the RFC (Registro Federal de
+/// Contribuyentes), the taxpayer identifier issued by the SAT. It has two forms:
+///
+/// - 13 characters for individuals (personas físicas): 4 letters taken from the names, the
+///   birthdate as `YYMMDD`, and a 3-character homoclave;
+/// - 12 characters for companies (personas morales): 3 letters taken from the company
+///   name, the incorporation date as `YYMMDD`, and a 3-character homoclave.
+///
+/// The letters can include `Ñ` and `&`. When the 4 letters of an individual would form an
+/// inconvenient word, the SAT replaces the last one with `X`, so those words never appear.
+/// The last character of the homoclave is a check character: the other characters are
+/// mapped to values from 0 to 38, weighted from 13 down to 2, and the complement of the sum
+/// modulo 11 is written as a digit, or `A` for 10. The generic RFCs used for the general
+/// public (`XAXX010101000`) and for foreigners (`XEXX010101000`) are also accepted.
+///
+/// # Usage
+///
+/// ```
+/// use docval::mexico::{MexicoRfcValidator, Rfc, RfcKind};
+///
+/// assert!(MexicoRfcValidator::is_valid("GODE561231GR8").is_ok());
+/// assert!(MexicoRfcValidator::is_valid("MAB-930714-8T4").is_ok());
+///
+/// let rfc = Rfc::parse("gode 561231 gr8").unwrap();
+/// assert_eq!(rfc.kind(), RfcKind::Individual);
+/// assert_eq!(rfc.as_str(), "GODE561231GR8");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an RFC of either kind. Removes spaces and hyphens, checks
+///   the length and the structure, and validates the date and the check character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+
+pub struct MexicoRfcValidator;
+
+/// The kinds of taxpayers identified by an RFC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RfcKind {
+    /// Persona física, with a 13-character RFC.
+    Individual,
+    /// Persona moral, with a 12-character RFC.
+    Company,
+}
+
+const INDIVIDUAL_LENGTH: usize = 13;
+const COMPANY_LENGTH: usize = 12;
+const CHECK_CHARACTERS: &str = "0123456789ABCDEFGHIJKLMN&OPQRSTUVWXYZ Ñ";
+const GENERIC_RFCS: &[&str] = &["XAXX010101000", "XEXX010101000"];
+const INCONVENIENT_WORDS: &[&str] = &[
+    "BUEI", "BUEY", "CACA", "CACO", "CAGA", "CAGO", "CAKA", "CAKO", "COGE", "COJA", "COJE", "COJI",
+    "COJO", "CULO", "FETO", "GUEY", "JOTO", "KACA", "KACO", "KAGA", "KAGO", "KAKA", "KOGE", "KOJO",
+    "KULO", "MAME", "MAMO", "MEAR", "MEAS", "MEON", "MION", "MOCO", "MULA", "PEDA", "PEDO", "PENE",
+    "PUTA", "PUTO", "QULO", "RATA", "RUIN",
+];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl MexicoRfcValidator {
+    /// Validates if the given RFC is correct. The input can contain spaces and hyphens, and
+    /// letters in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let chars: Vec<char> = sanitized_value.chars().collect();
+        let letters_length = match chars.len() {
+            INDIVIDUAL_LENGTH => 4,
+            COMPANY_LENGTH => 3,
+            _ => return Err("Invalid length"),
+        };
+        let (letters, rest) = chars.split_at(letters_length);
+        let (date, homoclave) = rest.split_at(6);
+        let is_valid_format = letters
+            .iter()
+            .all(|&c| c.is_ascii_uppercase() || c == 'Ñ' || c == '&')
+            && date.iter().all(char::is_ascii_digit)
+            && homoclave
+                .iter()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        if GENERIC_RFCS.contains(&sanitized_value.as_str()) {
+            return Ok(());
+        }
+        if INCONVENIENT_WORDS.contains(&letters.iter().collect::<String>().as_str()) {
+            return Err("Invalid name");
+        }
+        let date: String = date.iter().collect();
+        let field =
+            |start: usize| -> u32 { date[start..start + 2].parse().expect("Validated digits") };
+        if !is_valid_date(2000 + field(0), field(2), field(4)) {
+            return Err("Invalid date");
+        }
+        if chars[chars.len() - 1] == Self::calculate_check_character(&chars[..chars.len() - 1]) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RFCs using the MexicoRfcValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input and converts it to uppercase.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|&c| c != ' ' && c != '-')
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    /// Calculates the check character of the given characters, which are padded on the left
+    /// with spaces to 12 characters, so that company RFCs are weighted like individual ones.
+    fn calculate_check_character(value: &[char]) -> char {
+        let padding = INDIVIDUAL_LENGTH - 1 - value.len();
+        let sum: u32 = std::iter::repeat_n(' ', padding)
+            .chain(value.iter().copied())
+            .zip((2..=13).rev())
+            .map(|(c, w)| {
+                let index = CHECK_CHARACTERS
+                    .chars()
+                    .position(|candidate| candidate == c)
+                    .expect("Invalid character in input");
+                index as u32 * w
+            })
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => '0',
+            10 => 'A',
+            digit => char::from_digit(digit, 10).expect("Single digit"),
+        }
+    }
+}
+
+/// A validated RFC, stored in uppercase without separators.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rfc(String);
+
+impl Rfc {
+    /// Parses and validates the given RFC, discarding any separators.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        MexicoRfcValidator::is_valid(value)?;
+        Ok(Rfc(MexicoRfcValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 12 or 13 characters of the RFC.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the RFC belongs to an individual or a company.
+    pub fn kind(&self) -> RfcKind {
+        if self.0.chars().count() == INDIVIDUAL_LENGTH {
+            RfcKind::Individual
+        } else {
+            RfcKind::Company
+        }
+    }
+
+    /// Returns whether the RFC is one of the generic RFCs for the general public or for
+    /// foreigners.
+    pub fn is_generic(&self) -> bool {
+        GENERIC_RFCS.contains(&self.0.as_str())
+    }
+
+    /// Returns the letters taken from the name.
+    pub fn name_code(&self) -> String {
+        self.0.chars().take(self.letters_length()).collect()
+    }
+
+    /// Returns the birth or incorporation date, as `YYMMDD`.
+    pub fn date(&self) -> String {
+        self.0.chars().skip(self.letters_length()).take(6).collect()
+    }
+
+    /// Returns the 3-character homoclave, including the check character.
+    pub fn homoclave(&self) -> &str {
+        &self.0[self.0.len() - 3..]
+    }
+
+    fn letters_length(&self) -> usize {
+        match self.kind() {
+            RfcKind::Individual => 4,
+            RfcKind::Company => 3,
+        }
+    }
+}
+
+impl FromStr for Rfc {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Rfc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_character() {
+        let value: Vec<char> = "GODE561231GR".chars().collect();
+        assert_eq!(MexicoRfcValidator::calculate_check_character(&value), '8');
+        let value: Vec<char> = "MAB9307148T".chars().collect();
+        assert_eq!(MexicoRfcValidator::calculate_check_character(&value), '4');
+    }
+}
@@ -0,0 +1,173 @@
+//! Mexican States
+//!
+//! This module provides the `State` enum, listing the 31 states and Mexico City with the
+//! two-letter codes used by RENAPO in the CURP.
+//!
+//! # Usage
+//!
+//! ```
+//! use docval::mexico::State;
+//!
+//! assert_eq!(State::from_code("JC"), Some(State::Jalisco));
+//! assert_eq!(State::CiudadDeMexico.code(), "DF");
+//! assert_eq!(State::NuevoLeon.name(), "Nuevo León");
+//! ```
+
+/// A Mexican federal entity: one of the 31 states or Mexico City.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum State {
+    Aguascalientes,
+    BajaCalifornia,
+    BajaCaliforniaSur,
+    Campeche,
+    Chiapas,
+    Chihuahua,
+    CiudadDeMexico,
+    Coahuila,
+    Colima,
+    Durango,
+    Guanajuato,
+    Guerrero,
+    Hidalgo,
+    Jalisco,
+    Mexico,
+    Michoacan,
+    Morelos,
+    Nayarit,
+    NuevoLeon,
+    Oaxaca,
+    Puebla,
+    Queretaro,
+    QuintanaRoo,
+    SanLuisPotosi,
+    Sinaloa,
+    Sonora,
+    Tabasco,
+    Tamaulipas,
+    Tlaxcala,
+    Veracruz,
+    Yucatan,
+    Zacatecas,
+}
+
+impl State {
+    /// All federal entities, in alphabetical order of their names.
+    pub const ALL: [State; 32] = [
+        State::Aguascalientes,
+        State::BajaCalifornia,
+        State::BajaCaliforniaSur,
+        State::Campeche,
+        State::Chiapas,
+        State::Chihuahua,
+        State::CiudadDeMexico,
+        State::Coahuila,
+        State::Colima,
+        State::Durango,
+        State::Guanajuato,
+        State::Guerrero,
+        State::Hidalgo,
+        State::Jalisco,
+        State::Mexico,
+        State::Michoacan,
+        State::Morelos,
+        State::Nayarit,
+        State::NuevoLeon,
+        State::Oaxaca,
+        State::Puebla,
+        State::Queretaro,
+        State::QuintanaRoo,
+        State::SanLuisPotosi,
+        State::Sinaloa,
+        State::Sonora,
+        State::Tabasco,
+        State::Tamaulipas,
+        State::Tlaxcala,
+        State::Veracruz,
+        State::Yucatan,
+        State::Zacatecas,
+    ];
+
+    /// Returns the two-letter RENAPO code of the entity.
+    pub fn code(&self) -> &'static str {
+        match self {
+            State::Aguascalientes => "AS",
+            State::BajaCalifornia => "BC",
+            State::BajaCaliforniaSur => "BS",
+            State::Campeche => "CC",
+            State::Chiapas => "CS",
+            State::Chihuahua => "CH",
+            State::CiudadDeMexico => "DF",
+            State::Coahuila => "CL",
+            State::Colima => "CM",
+            State::Durango => "DG",
+            State::Guanajuato => "GT",
+            State::Guerrero => "GR",
+            State::Hidalgo => "HG",
+            State::Jalisco => "JC",
+            State::Mexico => "MC",
+            State::Michoacan => "MN",
+            State::Morelos => "MS",
+            State::Nayarit => "NT",
+            State::NuevoLeon => "NL",
+            State::Oaxaca => "OC",
+            State::Puebla => "PL",
+            State::Queretaro => "QT",
+            State::QuintanaRoo => "QR",
+            State::SanLuisPotosi => "SP",
+            State::Sinaloa => "SL",
+            State::Sonora => "SR",
+            State::Tabasco => "TC",
+            State::Tamaulipas => "TS",
+            State::Tlaxcala => "TL",
+            State::Veracruz => "VZ",
+            State::Yucatan => "YN",
+            State::Zacatecas => "ZS",
+        }
+    }
+
+    /// Returns the entity with the given RENAPO code, if any.
+    pub fn from_code(code: &str) -> Option<State> {
+        State::ALL
+            .iter()
+            .copied()
+            .find(|state| state.code() == code)
+    }
+
+    /// Returns the name of the entity.
+    pub fn name(&self) -> &'static str {
+        match self {
+            State::Aguascalientes => "Aguascalientes",
+            State::BajaCalifornia => "Baja California",
+            State::BajaCaliforniaSur => "Baja California Sur",
+            State::Campeche => "Campeche",
+            State::Chiapas => "Chiapas",
+            State::Chihuahua => "Chihuahua",
+            State::CiudadDeMexico => "Ciudad de México",
+            State::Coahuila => "Coahuila",
+            State::Colima => "Colima",
+            State::Durango => "Durango",
+            State::Guanajuato => "Guanajuato",
+            State::Guerrero => "Guerrero",
+            State::Hidalgo => "Hidalgo",
+            State::Jalisco => "Jalisco",
+            State::Mexico => "México",
+            State::Michoacan => "Michoacán",
+            State::Morelos => "Morelos",
+            State::Nayarit => "Nayarit",
+            State::NuevoLeon => "Nuevo León",
+            State::Oaxaca => "Oaxaca",
+            State::Puebla => "Puebla",
+            State::Queretaro => "Querétaro",
+            State::QuintanaRoo => "Quintana Roo",
+            State::SanLuisPotosi => "San Luis Potosí",
+            State::Sinaloa => "Sinaloa",
+            State::Sonora => "Sonora",
+            State::Tabasco => "Tabasco",
+            State::Tamaulipas => "Tamaulipas",
+            State::Tlaxcala => "Tlaxcala",
+            State::Veracruz => "Veracruz",
+            State::Yucatan => "Yucatán",
+            State::Zacatecas => "Zacatecas",
+        }
+    }
+}
@@ -0,0 +1,139 @@
+/// Netherlands BSN Validator
+///
+/// This module provides functionality to validate and parse the Burgerservicenummer (BSN),
+/// the citizen service number assigned to residents of the Netherlands. It has 9 digits,
+/// often written as `1234.56.782`; older 8-digit numbers are padded with a leading zero.
+///
+/// The check is the "elfproef" (11-test): the first 8 digits are weighted from 9 down to 2
+/// and the last one by -1, and the sum must be a multiple of 11.
+///
+/// # Usage
+///
+/// ```
+/// use docval::netherlands::{Bsn, NetherlandsBsnValidator};
+///
+/// assert!(NetherlandsBsnValidator::is_valid("1234.56.782").is_ok());
+/// assert!(NetherlandsBsnValidator::is_valid("12345672").is_ok());
+///
+/// let bsn = Bsn::parse("12345672").unwrap();
+/// assert_eq!(bsn.as_str(), "012345672");
+/// assert_eq!(bsn.to_string(), "0123.45.672");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a BSN. Removes spaces, dots and hyphens, pads 8-digit
+///   numbers, checks the format, and validates the 11-test.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct NetherlandsBsnValidator;
+
+const BSN_LENGTH: usize = 9;
+const LEGACY_BSN_LENGTH: usize = 8;
+const MULTIPLIER_WEIGHTS: &[i32] = &[9, 8, 7, 6, 5, 4, 3, 2, -1];
+const VALIDATION_MODULUS: i32 = 11;
+
+impl NetherlandsBsnValidator {
+    /// Validates if the given BSN is correct. The input can be a plain or formatted string,
+    /// with 8 or 9 digits.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != BSN_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit())
+            || sanitized_value.chars().all(|c| c == '0')
+        {
+            return Err("Invalid format");
+        }
+        if Self::calculate_sum(&sanitized_value) % VALIDATION_MODULUS == 0 {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate BSNs using the NetherlandsBsnValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and pads 8-digit numbers with a
+    /// leading zero.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .collect();
+        if value.len() == LEGACY_BSN_LENGTH {
+            format!("0{}", value)
+        } else {
+            value
+        }
+    }
+
+    /// Calculates the weighted sum of the 9 digits of a BSN.
+    fn calculate_sum(value: &str) -> i32 {
+        value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") as i32 * w)
+            .sum()
+    }
+}
+
+/// A validated BSN, stored as its 9 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bsn(String);
+
+impl Bsn {
+    /// Parses and validates the given BSN, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        NetherlandsBsnValidator::is_valid(value)?;
+        Ok(Bsn(NetherlandsBsnValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the BSN, including the padding of 8-digit numbers.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Bsn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Bsn {
+    /// Formats the BSN as in `1234.56.782`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", &self.0[..4], &self.0[4..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_sum() {
+        assert_eq!(NetherlandsBsnValidator::calculate_sum("123456782"), 154);
+        assert_eq!(NetherlandsBsnValidator::calculate_sum("111222333"), 66);
+        assert_eq!(NetherlandsBsnValidator::calculate_sum("000000009"), -9);
+    }
+}
@@ -0,0 +1,252 @@
+/// Norway Fødselsnummer Validator
+///
+/// This module provides functionality to validate and parse the national identity number
+/// (fødselsnummer) assigned to residents of Norway, and the D-number assigned to people
+/// who stay or work in Norway for a limited time. Both have 11 digits:
+///
+/// | Positions | Field                                                              |
+/// |-----------|--------------------------------------------------------------------|
+/// | 1-6       | Date of birth as `DDMMYY`, with 40 added to the day for D-numbers  |
+/// | 7-9       | Individual number: odd last digit for men, even for women          |
+/// | 10-11     | Check digits                                                       |
+///
+/// The century of birth is given by the individual number:
+///
+/// | Individual number | Years           |
+/// |-------------------|-----------------|
+/// | 000-499           | 1900-1999       |
+/// | 500-749           | 1854-1899       |
+/// | 500-999           | 2000-2039       |
+/// | 900-999           | 1940-1999       |
+///
+/// Each check digit is the complement modulo 11 of a weighted sum of the preceding digits,
+/// where 11 yields 0. Numbers whose complement is 10 are never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::norway::{Fodselsnummer, FodselsnummerKind, NorwayFodselsnummerValidator};
+/// use docval::Sex;
+///
+/// assert!(NorwayFodselsnummerValidator::is_valid("150785 45620").is_ok());
+/// assert!(NorwayFodselsnummerValidator::is_valid("15078545621").is_err());
+///
+/// let fodselsnummer = Fodselsnummer::parse("41019012393").unwrap();
+/// assert_eq!(fodselsnummer.kind(), FodselsnummerKind::DNumber);
+/// assert_eq!(fodselsnummer.birth_year(), 1990);
+/// assert_eq!(fodselsnummer.birth_day(), 1);
+/// assert_eq!(fodselsnummer.sex(), Sex::Male);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a fødselsnummer or D-number. Removes spaces, checks the
+///   length, format and date of birth, and validates both check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct NorwayFodselsnummerValidator;
+
+/// The kinds of numbers sharing the fødselsnummer format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FodselsnummerKind {
+    /// National identity number (fødselsnummer), assigned to residents.
+    BirthNumber,
+    /// D-number, with 40 added to the day of birth.
+    DNumber,
+}
+
+const FODSELSNUMMER_LENGTH: usize = 11;
+const D_NUMBER_DAY_OFFSET: u32 = 40;
+const FIRST_CHECK_WEIGHTS: &[u32] = &[3, 7, 6, 1, 8, 9, 4, 5, 2];
+const SECOND_CHECK_WEIGHTS: &[u32] = &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl NorwayFodselsnummerValidator {
+    /// Validates if the given fødselsnummer or D-number is correct. The input can be a plain
+    /// string or contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != FODSELSNUMMER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::birth_date(&sanitized_value) {
+            Some((year, month, day)) if is_valid_date(year, month, day) => {}
+            _ => return Err("Invalid date"),
+        }
+        let first_check_digit =
+            Self::calculate_check_digit(&sanitized_value[..9], FIRST_CHECK_WEIGHTS);
+        let second_check_digit =
+            Self::calculate_check_digit(&sanitized_value[..10], SECOND_CHECK_WEIGHTS);
+        match (first_check_digit, second_check_digit) {
+            (Some(first), Some(second)) if sanitized_value[9..] == format!("{first}{second}") => {
+                Ok(())
+            }
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate fødselsnummers using the NorwayFodselsnummerValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Decodes the date of birth, removing the D-number offset from the day, or returns
+    /// `None` if the individual number belongs to no century for the year.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let day: u32 = value[..2].parse().expect("Validated digits");
+        let month = value[2..4].parse().expect("Validated digits");
+        let year: u32 = value[4..6].parse().expect("Validated digits");
+        let individual_number: u32 = value[6..9].parse().expect("Validated digits");
+        let century = Self::century(year, individual_number)?;
+        let day = if day > D_NUMBER_DAY_OFFSET {
+            day - D_NUMBER_DAY_OFFSET
+        } else {
+            day
+        };
+        Some((century + year, month, day))
+    }
+
+    /// Returns the century of birth given by the individual number, if any.
+    fn century(year: u32, individual_number: u32) -> Option<u32> {
+        match (individual_number, year) {
+            (0..=499, _) => Some(1900),
+            (500..=749, 54..=99) => Some(1800),
+            (500..=999, 0..=39) => Some(2000),
+            (900..=999, 40..=99) => Some(1900),
+            _ => None,
+        }
+    }
+
+    /// Calculates a check digit with the given weights, or `None` if it would be 10.
+    fn calculate_check_digit(value: &str, weights: &[u32]) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(weights)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => None,
+            11 => Some(0),
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated fødselsnummer or D-number, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fodselsnummer(String);
+
+impl Fodselsnummer {
+    /// Parses and validates the given fødselsnummer or D-number, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        NorwayFodselsnummerValidator::is_valid(value)?;
+        Ok(Fodselsnummer(NorwayFodselsnummerValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 11 digits of the number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether this is a fødselsnummer or a D-number.
+    pub fn kind(&self) -> FodselsnummerKind {
+        let day: u32 = self.0[..2].parse().expect("Validated digits");
+        if day > D_NUMBER_DAY_OFFSET {
+            FodselsnummerKind::DNumber
+        } else {
+            FodselsnummerKind::BirthNumber
+        }
+    }
+
+    /// Returns the year of birth, whose century is given by the individual number.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth, without the D-number offset.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the individual number.
+    pub fn individual_number(&self) -> u16 {
+        self.0[6..9].parse().expect("Validated digits")
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if self.individual_number() % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the 2 check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.0[9..].parse().expect("Validated digits")
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        NorwayFodselsnummerValidator::birth_date(&self.0).expect("Validated date")
+    }
+}
+
+impl FromStr for Fodselsnummer {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Fodselsnummer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_century() {
+        assert_eq!(NorwayFodselsnummerValidator::century(85, 456), Some(1900));
+        assert_eq!(NorwayFodselsnummerValidator::century(85, 600), Some(1800));
+        assert_eq!(NorwayFodselsnummerValidator::century(0, 501), Some(2000));
+        assert_eq!(NorwayFodselsnummerValidator::century(70, 950), Some(1900));
+        assert_eq!(NorwayFodselsnummerValidator::century(45, 600), None);
+        assert_eq!(NorwayFodselsnummerValidator::century(45, 800), None);
+    }
+}
@@ -0,0 +1,167 @@
+/// Norway Organisasjonsnummer Validator
+///
+/// This module provides functionality to validate and parse the organisation number
+/// (organisasjonsnummer) assigned by the Brønnøysund Register Centre to legal entities in
+/// Norway. It has 9 digits, starting with 8 or 9, and is written as `923 609 016`. Entities
+/// registered for VAT use it as their VAT number, with the `NO` prefix and the `MVA`
+/// suffix, as in `NO 923 609 016 MVA`.
+///
+/// The first 8 digits are weighted by 3, 2, 7, 6, 5, 4, 3 and 2, and the check digit is the
+/// complement of the sum modulo 11, where 11 yields 0. Numbers whose complement is 10 are
+/// never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::norway::{NorwayOrganisasjonsnummerValidator, Organisasjonsnummer};
+///
+/// assert!(NorwayOrganisasjonsnummerValidator::is_valid("923 609 016").is_ok());
+/// assert!(NorwayOrganisasjonsnummerValidator::is_valid("NO 923 609 016 MVA").is_ok());
+/// assert!(NorwayOrganisasjonsnummerValidator::is_valid("923 609 017").is_err());
+///
+/// let organisasjonsnummer = Organisasjonsnummer::parse("NO923609016MVA").unwrap();
+/// assert_eq!(organisasjonsnummer.as_str(), "923609016");
+/// assert_eq!(organisasjonsnummer.vat_number(), "NO923609016MVA");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an organisation number. Removes spaces and the `NO` prefix
+///   and `MVA` suffix of the VAT form, checks the length and format, and validates the check
+///   digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct NorwayOrganisasjonsnummerValidator;
+
+const ORGANISASJONSNUMMER_LENGTH: usize = 9;
+const COUNTRY_PREFIX: &str = "NO";
+const VAT_SUFFIX: &str = "MVA";
+const FIRST_DIGITS: &[char] = &['8', '9'];
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl NorwayOrganisasjonsnummerValidator {
+    /// Validates if the given organisation number is correct. The input can be a plain or
+    /// formatted string, in the plain or the VAT form.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ORGANISASJONSNUMMER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit())
+            || !sanitized_value.starts_with(FIRST_DIGITS)
+        {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..8]) {
+            Some(check_digit) if sanitized_value[8..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate organisation numbers using the
+    /// NorwayOrganisasjonsnummerValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and the `NO` prefix and `MVA` suffix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let value = value.strip_prefix(COUNTRY_PREFIX).unwrap_or(&value);
+        value.strip_suffix(VAT_SUFFIX).unwrap_or(value).to_string()
+    }
+
+    /// Calculates the check digit of the first 8 digits of an organisation number, or
+    /// `None` if it would be 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => None,
+            11 => Some(0),
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated organisation number, stored as its 9 digits, without the VAT prefix and
+/// suffix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Organisasjonsnummer(String);
+
+impl Organisasjonsnummer {
+    /// Parses and validates the given organisation number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        NorwayOrganisasjonsnummerValidator::is_valid(value)?;
+        Ok(Organisasjonsnummer(
+            NorwayOrganisasjonsnummerValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 9 digits of the organisation number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the organisation number in its VAT form, as in `NO923609016MVA`.
+    pub fn vat_number(&self) -> String {
+        format!("{COUNTRY_PREFIX}{}{VAT_SUFFIX}", self.0)
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+}
+
+impl FromStr for Organisasjonsnummer {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Organisasjonsnummer {
+    /// Formats the organisation number as in `923 609 016`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", &self.0[..3], &self.0[3..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            NorwayOrganisasjonsnummerValidator::calculate_check_digit("92360901"),
+            Some(6)
+        );
+        assert_eq!(
+            NorwayOrganisasjonsnummerValidator::calculate_check_digit("91234567"),
+            None
+        );
+    }
+}
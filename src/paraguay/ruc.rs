@@ -0,0 +1,146 @@
+/// Paraguay RUC Validator
+///
+/// This module provides functionality to validate and parse the RUC (Registro Único de
+/// Contribuyentes), the tax identifier assigned by the DNIT, formatted as `XXXXXXXX-D`. The
+/// base is the cédula of the person, or a number starting with `80` for companies, and has
+/// a variable length of 4 to 8 digits.
+///
+/// The base is weighted from right to left with weights starting at 2 and increasing by
+/// one up to 11, after which they start again at 2. The check digit is the complement of
+/// the sum modulo 11, where 10 and 11 yield 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::paraguay::{ParaguayRucValidator, Ruc};
+///
+/// assert!(ParaguayRucValidator::is_valid("80028061-0").is_ok());
+///
+/// let ruc = Ruc::parse("12345679").unwrap();
+/// assert_eq!(ruc.number(), "1234567");
+/// assert_eq!(ruc.check_digit(), 9);
+/// assert_eq!(ruc.to_string(), "1234567-9");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a RUC. Removes non-digit characters, checks the length,
+///   and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct ParaguayRucValidator;
+
+const RUC_MIN_LENGTH: usize = 5;
+const RUC_MAX_LENGTH: usize = 9;
+const MAX_WEIGHT: u32 = 11;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl ParaguayRucValidator {
+    /// Validates if the given RUC, including its check digit, is correct. The input can be a
+    /// plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < RUC_MIN_LENGTH || sanitized_value.len() > RUC_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        let (base, check_digit) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if check_digit == Self::calculate_check_digit(base).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RUCs using the ParaguayRucValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the given base.
+    fn calculate_check_digit(base: &str) -> u32 {
+        let sum: u32 = base
+            .chars()
+            .rev()
+            .zip((2..=MAX_WEIGHT).cycle())
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 | 11 => 0,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated RUC, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ruc(String);
+
+impl Ruc {
+    /// Parses and validates the given RUC, including its check digit, discarding any
+    /// formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        ParaguayRucValidator::is_valid(value)?;
+        Ok(Ruc(ParaguayRucValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the RUC, including the check digit.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the base of the RUC, without the check digit.
+    pub fn number(&self) -> &str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0[self.0.len() - 1..]
+            .parse()
+            .expect("Validated digits")
+    }
+}
+
+impl FromStr for Ruc {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ruc {
+    /// Formats the RUC as `XXXXXXXX-D`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.number(), self.check_digit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(ParaguayRucValidator::calculate_check_digit("80028061"), 0);
+        assert_eq!(ParaguayRucValidator::calculate_check_digit("80016096"), 7);
+        assert_eq!(ParaguayRucValidator::calculate_check_digit("4401"), 6);
+    }
+}
@@ -0,0 +1,166 @@
+/// Peru DNI Validator
+///
+/// This module provides functionality to validate and parse the DNI (Documento Nacional de
+/// Identidad), whose 8-digit number, also called CUI (Código Único de Identificación), is
+/// assigned by the RENIEC. The number carries no check digit of its own, but the physical
+/// document prints a check character after it, as `12345678-1`, which can be either a
+/// digit or a letter, depending on the document version.
+///
+/// The digits are weighted with `32765432`, and the remainder of the sum modulo 11 selects
+/// the check character in `65432110987` for digits, or in `KJIHGFEDCBA` for letters.
+///
+/// # Usage
+///
+/// ```
+/// use docval::peru::{Dni, PeruDniValidator};
+///
+/// assert!(PeruDniValidator::is_valid("12345678").is_ok());
+/// assert!(PeruDniValidator::is_valid("12345678-1").is_ok());
+/// assert!(PeruDniValidator::is_valid("12345678-E").is_ok());
+///
+/// let dni = Dni::parse("12345678 1").unwrap();
+/// assert_eq!(dni.number(), "12345678");
+/// assert_eq!(dni.check_digit(), '1');
+/// assert_eq!(dni.check_letter(), 'E');
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a DNI. Removes hyphens and spaces, checks the length and
+///   the format, and validates the check character if present.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PeruDniValidator;
+
+const DNI_LENGTH: usize = 8;
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const CHECK_DIGITS: &[u8] = b"65432110987";
+const CHECK_LETTERS: &[u8] = b"KJIHGFEDCBA";
+const VALIDATION_MODULUS: u32 = 11;
+
+impl PeruDniValidator {
+    /// Validates if the given DNI is correct. The input can be the 8-digit number alone or
+    /// followed by its check digit or letter.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != DNI_LENGTH && sanitized_value.len() != DNI_LENGTH + 1 {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        let (number, check_character) = sanitized_value.split_at(DNI_LENGTH);
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match check_character.chars().next() {
+            None => Ok(()),
+            Some(c)
+                if c == Self::calculate_check_digit(number)
+                    || c == Self::calculate_check_letter(number) =>
+            {
+                Ok(())
+            }
+            Some(_) => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate DNIs using the PeruDniValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes hyphens and spaces from the input, and uppercases the check letter.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, '-' | ' '))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Returns the remainder of the weighted sum of the number modulo 11.
+    fn weighted_remainder(number: &str) -> usize {
+        let sum: u32 = number
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (sum % VALIDATION_MODULUS) as usize
+    }
+
+    /// Calculates the check digit of the number.
+    fn calculate_check_digit(number: &str) -> char {
+        CHECK_DIGITS[Self::weighted_remainder(number)] as char
+    }
+
+    /// Calculates the check letter of the number.
+    fn calculate_check_letter(number: &str) -> char {
+        CHECK_LETTERS[Self::weighted_remainder(number)] as char
+    }
+}
+
+/// A validated DNI, stored as its 8-digit number without the check character.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Dni(String);
+
+impl Dni {
+    /// Parses and validates the given DNI, discarding the check character, if any.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PeruDniValidator::is_valid(value)?;
+        let sanitized_value = PeruDniValidator::sanitize_input(value);
+        Ok(Dni(sanitized_value[..DNI_LENGTH].to_string()))
+    }
+
+    /// Returns the 8 digits of the DNI.
+    pub fn number(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit printed on the document.
+    pub fn check_digit(&self) -> char {
+        PeruDniValidator::calculate_check_digit(&self.0)
+    }
+
+    /// Returns the check letter printed on the document, in versions that use letters.
+    pub fn check_letter(&self) -> char {
+        PeruDniValidator::calculate_check_letter(&self.0)
+    }
+}
+
+impl FromStr for Dni {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Dni {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_remainder() {
+        assert_eq!(PeruDniValidator::weighted_remainder("12345678"), 6);
+        assert_eq!(PeruDniValidator::weighted_remainder("44444444"), 7);
+    }
+}
@@ -0,0 +1,171 @@
+/// Peru RUC Validator
+///
+/// This module provides functionality to validate and parse the RUC (Registro Único de
+/// Contribuyentes), the 11-digit tax identifier assigned by the SUNAT:
+///
+/// - 2 digits: entity type. `10` identifies natural persons with a DNI, `15` and `17`
+///   natural persons identified by other documents, and `20` legal entities;
+/// - 8 digits: the DNI of the person for type `10`, or a sequential number otherwise;
+/// - 1 digit: check digit.
+///
+/// The first 10 digits are weighted with `5432765432`, and the check digit is the
+/// complement of the sum modulo 11, keeping only its units digit.
+///
+/// # Usage
+///
+/// ```
+/// use docval::peru::{PeruRucValidator, Ruc, RucKind};
+///
+/// assert!(PeruRucValidator::is_valid("20131280069").is_ok());
+///
+/// let ruc = Ruc::parse("10123456781").unwrap();
+/// assert_eq!(ruc.kind(), RucKind::Person);
+/// assert_eq!(ruc.dni(), Some("12345678"));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a RUC. Removes non-digit characters, checks the length
+///   and the entity type, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PeruRucValidator;
+
+/// The kinds of taxpayers identified by the entity type prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RucKind {
+    /// Natural person (prefixes 10, 15 and 17).
+    Person,
+    /// Legal entity (prefix 20).
+    Company,
+}
+
+impl RucKind {
+    /// Returns the kind identified by the given entity type prefix, if any.
+    pub fn from_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            10 | 15 | 17 => Some(RucKind::Person),
+            20 => Some(RucKind::Company),
+            _ => None,
+        }
+    }
+}
+
+const RUC_LENGTH: usize = 11;
+const DNI_PREFIX: u8 = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl PeruRucValidator {
+    /// Validates if the given RUC is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != RUC_LENGTH {
+            return Err("Invalid length");
+        }
+        let prefix = sanitized_value[..2].parse().expect("Validated digits");
+        if RucKind::from_prefix(prefix).is_none() {
+            return Err("Invalid prefix");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RUCs using the PeruRucValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the first 10 digits.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (VALIDATION_MODULUS - sum % VALIDATION_MODULUS) % 10
+    }
+}
+
+/// A validated RUC, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ruc(String);
+
+impl Ruc {
+    /// Parses and validates the given RUC, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PeruRucValidator::is_valid(value)?;
+        Ok(Ruc(PeruRucValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the RUC.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the entity type prefix.
+    pub fn prefix(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+
+    /// Returns whether the RUC belongs to a natural person or a company.
+    pub fn kind(&self) -> RucKind {
+        RucKind::from_prefix(self.prefix()).expect("Validated prefix")
+    }
+
+    /// Returns the DNI embedded in the RUC of a person registered with it (prefix `10`), or
+    /// `None` otherwise.
+    pub fn dni(&self) -> Option<&str> {
+        if self.prefix() == DNI_PREFIX {
+            Some(&self.0[2..10])
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Ruc {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ruc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(PeruRucValidator::calculate_check_digit("2013128006"), 9);
+        assert_eq!(PeruRucValidator::calculate_check_digit("2050000000"), 8);
+    }
+}
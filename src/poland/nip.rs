@@ -0,0 +1,162 @@
+/// Poland NIP Validator
+///
+/// This module provides functionality to validate and parse the NIP (Numer Identyfikacji
+/// Podatkowej), the 10-digit tax identification number assigned to businesses and
+/// taxpayers in Poland. Its first 3 digits identify the tax office that issued it. It is
+/// written with hyphens, either as `123-456-32-18` or as `123-45-63-218`, and is used as
+/// the VAT number with the `PL` prefix.
+///
+/// The first 9 digits are weighted by 6, 5, 7, 2, 3, 4, 5, 6 and 7, and the check digit is
+/// the sum modulo 11. Numbers whose remainder is 10 are never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::poland::{Nip, PolandNipValidator};
+///
+/// assert!(PolandNipValidator::is_valid("123-456-32-18").is_ok());
+/// assert!(PolandNipValidator::is_valid("PL 5260250274").is_ok());
+///
+/// let nip = Nip::parse("123-45-63-218").unwrap();
+/// assert_eq!(nip.tax_office(), "123");
+/// assert_eq!(nip.to_string(), "123-456-32-18");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIP. Removes spaces, hyphens and the `PL` prefix, checks
+///   the length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PolandNipValidator;
+
+const COUNTRY_PREFIX: &str = "PL";
+const NIP_LENGTH: usize = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[6, 5, 7, 2, 3, 4, 5, 6, 7];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl PolandNipValidator {
+    /// Validates if the given NIP is correct. The input can be a plain or hyphenated string,
+    /// with or without the `PL` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NIP_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..9]) {
+            Some(check_digit) if sanitized_value[9..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NIPs using the PolandNipValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input, and the `PL` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Calculates the check digit of the first 9 digits of a NIP, or `None` if it would be
+    /// 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        Some(sum % VALIDATION_MODULUS).filter(|&digit| digit != 10)
+    }
+}
+
+/// A validated NIP, stored as its 10 digits, without the `PL` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nip(String);
+
+impl Nip {
+    /// Parses and validates the given NIP, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PolandNipValidator::is_valid(value)?;
+        Ok(Nip(PolandNipValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the NIP.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 3-digit code of the tax office that issued the NIP.
+    pub fn tax_office(&self) -> &str {
+        &self.0[..3]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+}
+
+impl FromStr for Nip {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nip {
+    /// Formats the NIP as in `123-456-32-18`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}-{}",
+            &self.0[..3],
+            &self.0[3..6],
+            &self.0[6..8],
+            &self.0[8..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            PolandNipValidator::calculate_check_digit("123456321"),
+            Some(8)
+        );
+        assert_eq!(
+            PolandNipValidator::calculate_check_digit("526025027"),
+            Some(4)
+        );
+        assert_eq!(PolandNipValidator::calculate_check_digit("123456789"), None);
+    }
+}
@@ -0,0 +1,216 @@
+/// Poland PESEL Validator
+///
+/// This module provides functionality to validate and parse the PESEL (Powszechny
+/// Elektroniczny System Ewidencji Ludności), the 11-digit identification number assigned to
+/// residents of Poland:
+///
+/// | Positions | Field                                                       |
+/// |-----------|-------------------------------------------------------------|
+/// | 1-6       | Date of birth as `YYMMDD`, with the century in the month    |
+/// | 7-9       | Serial number                                               |
+/// | 10        | Sex: odd for men, even for women                            |
+/// | 11        | Check digit                                                 |
+///
+/// The century of birth is encoded by adding an offset to the month:
+///
+/// | Century | Months  |
+/// |---------|---------|
+/// | 1800s   | 81-92   |
+/// | 1900s   | 01-12   |
+/// | 2000s   | 21-32   |
+/// | 2100s   | 41-52   |
+/// | 2200s   | 61-72   |
+///
+/// The first 10 digits are weighted by the repeating sequence 1, 3, 7 and 9, and the check
+/// digit is the complement of the sum modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::poland::{Pesel, PolandPeselValidator};
+/// use docval::Sex;
+///
+/// assert!(PolandPeselValidator::is_valid("44051401359").is_ok());
+/// assert!(PolandPeselValidator::is_valid("44051401358").is_err());
+///
+/// let pesel = Pesel::parse("02270803624").unwrap();
+/// assert_eq!(pesel.birth_year(), 2002);
+/// assert_eq!(pesel.birth_month(), 7);
+/// assert_eq!(pesel.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a PESEL. Removes spaces, checks the length, format and
+///   date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct PolandPeselValidator;
+
+const PESEL_LENGTH: usize = 11;
+const MULTIPLIER_WEIGHTS: &[u32] = &[1, 3, 7, 9, 1, 3, 7, 9, 1, 3];
+
+impl PolandPeselValidator {
+    /// Validates if the given PESEL is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != PESEL_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::birth_date(&sanitized_value) {
+            Some((year, month, day)) if is_valid_date(year, month, day) => {}
+            _ => return Err("Invalid date"),
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate PESELs using the PolandPeselValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Decodes the date of birth, taking the century from the month offset. Returns `None`
+    /// if the month is outside every century range.
+    fn birth_date(value: &str) -> Option<(u32, u32, u32)> {
+        let field =
+            |start: usize| -> u32 { value[start..start + 2].parse().expect("Validated digits") };
+        let (year, encoded_month, day) = (field(0), field(2), field(4));
+        let century = match encoded_month / 20 {
+            0 => 1900,
+            1 => 2000,
+            2 => 2100,
+            3 => 2200,
+            _ => 1800,
+        };
+        let month = encoded_month % 20;
+        if month == 0 || month > 12 {
+            return None;
+        }
+        Some((century + year, month, day))
+    }
+
+    /// Calculates the check digit of the first 10 digits of a PESEL.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated PESEL, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pesel(String);
+
+impl Pesel {
+    /// Parses and validates the given PESEL, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PolandPeselValidator::is_valid(value)?;
+        Ok(Pesel(PolandPeselValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the PESEL.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, whose century is given by the month offset.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth, without the century offset.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if (self.0.as_bytes()[9] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        PolandPeselValidator::birth_date(&self.0).expect("Validated date")
+    }
+}
+
+impl FromStr for Pesel {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Pesel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            PolandPeselValidator::birth_date("44051401359"),
+            Some((1944, 5, 14))
+        );
+        assert_eq!(
+            PolandPeselValidator::birth_date("90921100006"),
+            Some((1890, 12, 11))
+        );
+        assert_eq!(
+            PolandPeselValidator::birth_date("24510100016"),
+            Some((2124, 11, 1))
+        );
+        assert_eq!(PolandPeselValidator::birth_date("44131401359"), None);
+    }
+}
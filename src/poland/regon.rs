@@ -0,0 +1,187 @@
+/// Poland REGON Validator
+///
+/// This module provides functionality to validate and parse the REGON (Rejestr Gospodarki
+/// Narodowej), the statistical number assigned by Statistics Poland to businesses and their
+/// local units. It comes in two forms:
+///
+/// - 9 digits, identifying the business, whose last digit is a check digit weighted by 8,
+///   9, 2, 3, 4, 5, 6 and 7;
+/// - 14 digits, identifying a local unit: the 9-digit REGON of the business, a 4-digit unit
+///   number and a check digit weighted by 2, 4, 8, 5, 0, 9, 7, 3, 6, 1, 2, 4 and 8.
+///
+/// In both forms the check digit is the weighted sum modulo 11, where 10 yields 0. A
+/// 14-digit REGON is only valid if the 9-digit REGON it embeds is valid too.
+///
+/// # Usage
+///
+/// ```
+/// use docval::poland::{PolandRegonValidator, Regon, RegonKind};
+///
+/// assert!(PolandRegonValidator::is_valid("123456785").is_ok());
+/// assert!(PolandRegonValidator::is_valid("12345678512347").is_ok());
+///
+/// let regon = Regon::parse("12345678512347").unwrap();
+/// assert_eq!(regon.kind(), RegonKind::LocalUnit);
+/// assert_eq!(regon.root(), "123456785");
+/// assert_eq!(regon.local_unit(), Some("1234"));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a REGON, in the 9- or 14-digit form. Removes spaces and
+///   hyphens, checks the length and format, and validates the check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PolandRegonValidator;
+
+/// The forms of a REGON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegonKind {
+    /// A business, identified by 9 digits.
+    Business,
+    /// A local unit of a business, identified by 14 digits.
+    LocalUnit,
+}
+
+const BUSINESS_LENGTH: usize = 9;
+const LOCAL_UNIT_LENGTH: usize = 14;
+const BUSINESS_WEIGHTS: &[u32] = &[8, 9, 2, 3, 4, 5, 6, 7];
+const LOCAL_UNIT_WEIGHTS: &[u32] = &[2, 4, 8, 5, 0, 9, 7, 3, 6, 1, 2, 4, 8];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl PolandRegonValidator {
+    /// Validates if the given REGON is correct. The input can be a plain string or contain
+    /// spaces and hyphens.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != BUSINESS_LENGTH && sanitized_value.len() != LOCAL_UNIT_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if !Self::is_valid_checksum(&sanitized_value[..BUSINESS_LENGTH], BUSINESS_WEIGHTS) {
+            return Err("Invalid checksum");
+        }
+        if sanitized_value.len() == LOCAL_UNIT_LENGTH
+            && !Self::is_valid_checksum(&sanitized_value, LOCAL_UNIT_WEIGHTS)
+        {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate REGONs using the PolandRegonValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    /// Checks the last digit of the value against the weighted sum of the preceding ones.
+    fn is_valid_checksum(value: &str, weights: &[u32]) -> bool {
+        let (digits, check_digit) = value.split_at(value.len() - 1);
+        let sum: u32 = digits
+            .chars()
+            .zip(weights)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        check_digit == (sum % VALIDATION_MODULUS % 10).to_string()
+    }
+}
+
+/// A validated REGON, stored as its 9 or 14 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Regon(String);
+
+impl Regon {
+    /// Parses and validates the given REGON, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PolandRegonValidator::is_valid(value)?;
+        Ok(Regon(PolandRegonValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 or 14 digits of the REGON.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the REGON identifies a business or one of its local units.
+    pub fn kind(&self) -> RegonKind {
+        if self.0.len() == LOCAL_UNIT_LENGTH {
+            RegonKind::LocalUnit
+        } else {
+            RegonKind::Business
+        }
+    }
+
+    /// Returns the 9-digit REGON of the business.
+    pub fn root(&self) -> &str {
+        &self.0[..BUSINESS_LENGTH]
+    }
+
+    /// Returns the 4-digit number of the local unit, if any.
+    pub fn local_unit(&self) -> Option<&str> {
+        match self.kind() {
+            RegonKind::LocalUnit => Some(&self.0[BUSINESS_LENGTH..LOCAL_UNIT_LENGTH - 1]),
+            RegonKind::Business => None,
+        }
+    }
+}
+
+impl FromStr for Regon {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Regon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_checksum() {
+        assert!(PolandRegonValidator::is_valid_checksum(
+            "123456785",
+            BUSINESS_WEIGHTS
+        ));
+        assert!(PolandRegonValidator::is_valid_checksum(
+            "100000050",
+            BUSINESS_WEIGHTS
+        ));
+        assert!(PolandRegonValidator::is_valid_checksum(
+            "12345678512347",
+            LOCAL_UNIT_WEIGHTS
+        ));
+        assert!(!PolandRegonValidator::is_valid_checksum(
+            "123456786",
+            BUSINESS_WEIGHTS
+        ));
+    }
+}
@@ -0,0 +1,206 @@
+/// Portugal Cartão de Cidadão Validator
+///
+/// This module provides functionality to validate and parse the document number of the
+/// Cartão de Cidadão, the Portuguese citizen card, printed as `DDDDDDDD C AAT`:
+///
+/// | Positions | Field                                                       |
+/// |-----------|-------------------------------------------------------------|
+/// | 1-8       | Civil identification number (NIC)                           |
+/// | 9         | Check digit of the civil identification number              |
+/// | 10-11     | Version of the card, letters or digits                      |
+/// | 12        | Check digit of the whole document number                    |
+///
+/// The check digit of the civil identification number is the same as on the former
+/// Bilhete de Identidade: the digits are weighted from 9 down to 2, and the check digit is
+/// the complement of the sum modulo 11, where 10 and 11 yield 0.
+///
+/// The final check digit covers all 12 characters, letters included: each letter is
+/// converted to a number from `A = 10` to `Z = 35`, and from the right, every second value
+/// is doubled, reduced by 9 once if it exceeds 9, and the total must be a multiple of 10.
+/// Letters can therefore contribute values above 9, which is not the same as running the
+/// Luhn algorithm over the digits of the converted string.
+///
+/// # Usage
+///
+/// ```
+/// use docval::portugal::{CartaoCidadao, PortugalCartaoCidadaoValidator};
+///
+/// assert!(PortugalCartaoCidadaoValidator::is_valid("12345678 9 ZZ1").is_ok());
+/// assert!(PortugalCartaoCidadaoValidator::is_valid("00000000-0-zz4").is_ok());
+///
+/// let card = CartaoCidadao::parse("123456789ZX5").unwrap();
+/// assert_eq!(card.civil_number(), "12345678");
+/// assert_eq!(card.version(), "ZX");
+/// assert_eq!(card.to_string(), "12345678 9 ZX5");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a document number. Removes spaces and hyphens, checks the
+///   length and format, and validates both check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PortugalCartaoCidadaoValidator;
+
+const DOCUMENT_NUMBER_LENGTH: usize = 12;
+const CIVIL_NUMBER_LENGTH: usize = 8;
+const CIVIL_NUMBER_MULTIPLIER_WEIGHTS: &[u32] = &[9, 8, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl PortugalCartaoCidadaoValidator {
+    /// Validates if the given document number is correct. The input can be a plain or
+    /// formatted string (with spaces or hyphens).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != DOCUMENT_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        let is_valid_format = sanitized_value.char_indices().all(|(i, c)| match i {
+            9 | 10 => c.is_ascii_alphanumeric(),
+            _ => c.is_ascii_digit(),
+        });
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        let civil_check_digit =
+            Self::calculate_civil_check_digit(&sanitized_value[..CIVIL_NUMBER_LENGTH]);
+        if sanitized_value[8..9] != civil_check_digit.to_string() {
+            return Err("Invalid checksum");
+        }
+        if Self::document_checksum(&sanitized_value).is_multiple_of(10) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate document numbers using the PortugalCartaoCidadaoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Calculates the check digit of the 8-digit civil identification number.
+    fn calculate_civil_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(CIVIL_NUMBER_MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 | 11 => 0,
+            digit => digit,
+        }
+    }
+
+    /// Calculates the sum over the whole document number, which must be a multiple of 10.
+    fn document_checksum(value: &str) -> u32 {
+        value
+            .chars()
+            .rev()
+            .enumerate()
+            .map(|(i, c)| {
+                let value = c.to_digit(36).expect("Invalid character in input");
+                if i % 2 == 1 {
+                    match value * 2 {
+                        doubled if doubled > 9 => doubled - 9,
+                        doubled => doubled,
+                    }
+                } else {
+                    value
+                }
+            })
+            .sum()
+    }
+}
+
+/// A validated Cartão de Cidadão document number, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CartaoCidadao(String);
+
+impl CartaoCidadao {
+    /// Parses and validates the given document number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PortugalCartaoCidadaoValidator::is_valid(value)?;
+        Ok(CartaoCidadao(
+            PortugalCartaoCidadaoValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 12 characters of the document number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 8-digit civil identification number.
+    pub fn civil_number(&self) -> &str {
+        &self.0[..CIVIL_NUMBER_LENGTH]
+    }
+
+    /// Returns the check digit of the civil identification number.
+    pub fn civil_check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+
+    /// Returns the 2-character version of the card.
+    pub fn version(&self) -> &str {
+        &self.0[9..11]
+    }
+
+    /// Returns the check digit of the whole document number.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[11] - b'0'
+    }
+}
+
+impl FromStr for CartaoCidadao {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for CartaoCidadao {
+    /// Formats the document number as printed on the card, as in `12345678 9 ZZ1`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", &self.0[..8], &self.0[8..9], &self.0[9..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_checksum() {
+        assert_eq!(
+            PortugalCartaoCidadaoValidator::document_checksum("000000000ZZ4"),
+            100
+        );
+        assert_eq!(
+            PortugalCartaoCidadaoValidator::document_checksum("000000000AB7"),
+            30
+        );
+    }
+}
@@ -0,0 +1,218 @@
+/// Portugal NIF Validator
+///
+/// This module provides functionality to validate and parse the NIF (Número de
+/// Identificação Fiscal), the 9-digit tax number assigned by the Autoridade Tributária.
+/// Legal entities are given a NIPC (Número de Identificação de Pessoa Coletiva), which has
+/// the same format. The leading digits identify the category of the taxpayer:
+///
+/// | Prefix     | Category                                         |
+/// |------------|--------------------------------------------------|
+/// | 1, 2, 3    | Individual                                       |
+/// | 45         | Non-resident individual                          |
+/// | 5          | Company                                          |
+/// | 6          | Public entity                                    |
+/// | 70, 74, 75 | Undivided inheritance                            |
+/// | 71         | Non-resident legal entity                        |
+/// | 72         | Investment fund                                  |
+/// | 77, 78, 79 | Officious, VAT refund or exceptional assignment  |
+/// | 8          | Sole trader (no longer assigned)                 |
+/// | 90, 91     | Condominium or irregular company                 |
+/// | 98         | Non-resident without a permanent establishment   |
+/// | 99         | Civil company without legal personality          |
+///
+/// The first 8 digits are weighted from 9 down to 2, and the check digit is the complement
+/// of the sum modulo 11, where 10 and 11 yield 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::portugal::{Nif, NifCategory, PortugalNifValidator};
+///
+/// assert!(PortugalNifValidator::is_valid("501 964 843").is_ok());
+/// assert!(PortugalNifValidator::is_valid("123456789").is_ok());
+///
+/// let nif = Nif::parse("PT501964843").unwrap();
+/// assert_eq!(nif.category(), NifCategory::Company);
+/// assert_eq!(nif.to_string(), "501 964 843");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NIF. Removes non-digit characters, checks the length
+///   and the category prefix, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PortugalNifValidator;
+
+/// The categories of taxpayers, identified by the leading digits of the NIF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NifCategory {
+    /// Resident individual (1, 2 or 3).
+    Individual,
+    /// Non-resident individual (45).
+    NonResidentIndividual,
+    /// Company (5).
+    Company,
+    /// Public administration entity (6).
+    PublicEntity,
+    /// Undivided inheritance (70, 74 or 75).
+    Inheritance,
+    /// Non-resident legal entity (71).
+    NonResidentEntity,
+    /// Investment fund (72).
+    InvestmentFund,
+    /// Officious, VAT refund or exceptional assignment (77, 78 or 79).
+    SpecialAssignment,
+    /// Sole trader (8), no longer assigned.
+    SoleTrader,
+    /// Condominium or irregular company (90 or 91).
+    Condominium,
+    /// Non-resident without a permanent establishment (98).
+    NonResidentWithoutEstablishment,
+    /// Civil company without legal personality (99).
+    CivilCompany,
+}
+
+impl NifCategory {
+    /// Returns the category of the NIF starting with the given digits, if any.
+    pub fn from_prefix(value: &str) -> Option<Self> {
+        let mut digits = value.chars();
+        let category = match (digits.next()?, digits.next()) {
+            ('1' | '2' | '3', _) => NifCategory::Individual,
+            ('4', Some('5')) => NifCategory::NonResidentIndividual,
+            ('5', _) => NifCategory::Company,
+            ('6', _) => NifCategory::PublicEntity,
+            ('7', Some('0' | '4' | '5')) => NifCategory::Inheritance,
+            ('7', Some('1')) => NifCategory::NonResidentEntity,
+            ('7', Some('2')) => NifCategory::InvestmentFund,
+            ('7', Some('7' | '8' | '9')) => NifCategory::SpecialAssignment,
+            ('8', _) => NifCategory::SoleTrader,
+            ('9', Some('0' | '1')) => NifCategory::Condominium,
+            ('9', Some('8')) => NifCategory::NonResidentWithoutEstablishment,
+            ('9', Some('9')) => NifCategory::CivilCompany,
+            _ => return None,
+        };
+        Some(category)
+    }
+
+    /// Returns whether the category identifies a natural person rather than an entity.
+    pub fn is_individual(&self) -> bool {
+        matches!(
+            self,
+            NifCategory::Individual | NifCategory::NonResidentIndividual | NifCategory::SoleTrader
+        )
+    }
+}
+
+const NIF_LENGTH: usize = 9;
+const MULTIPLIER_WEIGHTS: &[u32] = &[9, 8, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl PortugalNifValidator {
+    /// Validates if the given NIF is correct. The input can be a plain or formatted string,
+    /// optionally prefixed with `PT`.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NIF_LENGTH {
+            return Err("Invalid length");
+        }
+        if NifCategory::from_prefix(&sanitized_value).is_none() {
+            return Err("Invalid category");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..8]);
+        if sanitized_value[8..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NIFs using the PortugalNifValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the first 8 digits of a NIF.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 | 11 => 0,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated NIF, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nif(String);
+
+impl Nif {
+    /// Parses and validates the given NIF, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PortugalNifValidator::is_valid(value)?;
+        Ok(Nif(PortugalNifValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 digits of the NIF.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the category of the taxpayer.
+    pub fn category(&self) -> NifCategory {
+        NifCategory::from_prefix(&self.0).expect("Validated category")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[8] - b'0'
+    }
+}
+
+impl FromStr for Nif {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nif {
+    /// Formats the NIF in groups of three digits, as in `501 964 843`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", &self.0[..3], &self.0[3..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(PortugalNifValidator::calculate_check_digit("50196484"), 3);
+        assert_eq!(PortugalNifValidator::calculate_check_digit("98000000"), 9);
+        assert_eq!(PortugalNifValidator::calculate_check_digit("50000000"), 0);
+    }
+}
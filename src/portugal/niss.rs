@@ -0,0 +1,162 @@
+/// Portugal NISS Validator
+///
+/// This module provides functionality to validate and parse the NISS (Número de
+/// Identificação da Segurança Social), the 11-digit social security number required in
+/// payroll filings. The first digit identifies the holder: `1` for individuals and `2` for
+/// collective entities, such as employers.
+///
+/// The first 10 digits are weighted with the prime numbers `29, 23, 19, 17, 13, 11, 7, 5,
+/// 3, 2`, and the check digit is 9 minus the units digit of the sum.
+///
+/// # Usage
+///
+/// ```
+/// use docval::portugal::{Niss, NissKind, PortugalNissValidator};
+///
+/// assert!(PortugalNissValidator::is_valid("11084129855").is_ok());
+/// assert!(PortugalNissValidator::is_valid("2000 0000 001").is_ok());
+///
+/// let niss = Niss::parse("11084129855").unwrap();
+/// assert_eq!(niss.kind(), NissKind::Individual);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NISS. Removes non-digit characters, checks the length
+///   and the leading digit, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct PortugalNissValidator;
+
+/// The kinds of NISS holders, identified by the first digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NissKind {
+    /// Individual (1).
+    Individual,
+    /// Collective entity (2).
+    Entity,
+}
+
+impl NissKind {
+    /// Returns the kind of holder with the given leading digit, if any.
+    pub fn from_digit(digit: char) -> Option<Self> {
+        match digit {
+            '1' => Some(NissKind::Individual),
+            '2' => Some(NissKind::Entity),
+            _ => None,
+        }
+    }
+}
+
+const NISS_LENGTH: usize = 11;
+const MULTIPLIER_WEIGHTS: &[u32] = &[29, 23, 19, 17, 13, 11, 7, 5, 3, 2];
+
+impl PortugalNissValidator {
+    /// Validates if the given NISS is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NISS_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.starts_with(['1', '2']) {
+            return Err("Invalid prefix");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..10]);
+        if sanitized_value[10..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NISS numbers using the PortugalNissValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the first 10 digits of a NISS.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        9 - sum % 10
+    }
+}
+
+/// A validated NISS, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Niss(String);
+
+impl Niss {
+    /// Parses and validates the given NISS, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        PortugalNissValidator::is_valid(value)?;
+        Ok(Niss(PortugalNissValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the NISS.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the NISS belongs to an individual or to a collective entity.
+    pub fn kind(&self) -> NissKind {
+        NissKind::from_digit(self.0.chars().next().expect("Validated length"))
+            .expect("Validated prefix")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[10] - b'0'
+    }
+}
+
+impl FromStr for Niss {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Niss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            PortugalNissValidator::calculate_check_digit("1108412985"),
+            5
+        );
+        assert_eq!(
+            PortugalNissValidator::calculate_check_digit("1700000000"),
+            9
+        );
+    }
+}
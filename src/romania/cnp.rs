@@ -0,0 +1,230 @@
+/// Romania CNP Validator
+///
+/// This module provides functionality to validate and parse the CNP (Cod Numeric Personal),
+/// the 13-digit personal numeric code assigned to residents of Romania:
+///
+/// | Positions | Field                                                     |
+/// |-----------|-----------------------------------------------------------|
+/// | 1         | Sex and century of birth                                  |
+/// | 2-7       | Date of birth as `YYMMDD`                                 |
+/// | 8-9       | County code, where 41-48 are the sectors of Bucharest     |
+/// | 10-12     | Serial number                                             |
+/// | 13        | Check digit                                               |
+///
+/// The first digit is odd for men and even for women, and gives the century: 1 and 2 for
+/// the 1900s, 3 and 4 for the 1800s, 5 and 6 for the 2000s. Foreign residents get 7 or 8,
+/// and foreign citizens 9, none of which encode the century; their date of birth is checked
+/// as if in the 2000s.
+///
+/// The first 12 digits are weighted by `279146358279`, and the check digit is the sum
+/// modulo 11, where 10 yields 1.
+///
+/// # Usage
+///
+/// ```
+/// use docval::romania::{Cnp, RomaniaCnpValidator};
+/// use docval::Sex;
+///
+/// assert!(RomaniaCnpValidator::is_valid("1800101221144").is_ok());
+/// assert!(RomaniaCnpValidator::is_valid("1800101221145").is_err());
+///
+/// let cnp = Cnp::parse("6091231012345").unwrap();
+/// assert_eq!(cnp.birth_year(), Some(2009));
+/// assert_eq!(cnp.sex(), Some(Sex::Female));
+/// assert_eq!(cnp.county_code(), 1);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CNP. Removes spaces, checks the length, format, date of
+///   birth and county code, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct RomaniaCnpValidator;
+
+const CNP_LENGTH: usize = 13;
+const MULTIPLIER_WEIGHTS: &[u32] = &[2, 7, 9, 1, 4, 6, 3, 5, 8, 2, 7, 9];
+const VALIDATION_MODULUS: u32 = 11;
+const COUNTY_CODES: &[u8] = &[
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 51, 52,
+    70,
+];
+
+impl RomaniaCnpValidator {
+    /// Validates if the given CNP is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CNP_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) || sanitized_value.starts_with('0')
+        {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value);
+        let year = year.unwrap_or(2000 + Self::field(&sanitized_value, 1));
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        let county_code = Self::field(&sanitized_value, 7) as u8;
+        if !COUNTY_CODES.contains(&county_code) {
+            return Err("Invalid county code");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..12]);
+        if sanitized_value[12..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CNPs using the RomaniaCnpValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    fn field(value: &str, start: usize) -> u32 {
+        value[start..start + 2].parse().expect("Validated digits")
+    }
+
+    /// Decodes the date of birth, where the year is `None` if the first digit does not
+    /// encode the century.
+    fn birth_date(value: &str) -> (Option<u32>, u32, u32) {
+        let century = match value.as_bytes()[0] {
+            b'1' | b'2' => Some(1900),
+            b'3' | b'4' => Some(1800),
+            b'5' | b'6' => Some(2000),
+            _ => None,
+        };
+        (
+            century.map(|century| century + Self::field(value, 1)),
+            Self::field(value, 3),
+            Self::field(value, 5),
+        )
+    }
+
+    /// Calculates the check digit of the first 12 digits of a CNP.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match sum % VALIDATION_MODULUS {
+            10 => 1,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated CNP, stored as its 13 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cnp(String);
+
+impl Cnp {
+    /// Parses and validates the given CNP, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        RomaniaCnpValidator::is_valid(value)?;
+        Ok(Cnp(RomaniaCnpValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 13 digits of the CNP.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth, or `None` for foreigners, whose CNP does not encode the
+    /// century.
+    pub fn birth_year(&self) -> Option<u16> {
+        RomaniaCnpValidator::birth_date(&self.0)
+            .0
+            .map(|year| year as u16)
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        RomaniaCnpValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        RomaniaCnpValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the sex of the holder, or `None` for foreign citizens.
+    pub fn sex(&self) -> Option<Sex> {
+        match self.0.as_bytes()[0] {
+            b'9' => None,
+            digit if (digit - b'0') % 2 == 1 => Some(Sex::Male),
+            _ => Some(Sex::Female),
+        }
+    }
+
+    /// Returns the county code, where 41-48 are the sectors of Bucharest.
+    pub fn county_code(&self) -> u8 {
+        RomaniaCnpValidator::field(&self.0, 7) as u8
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[9..12].parse().expect("Validated digits")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[12] - b'0'
+    }
+}
+
+impl FromStr for Cnp {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cnp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            RomaniaCnpValidator::calculate_check_digit("180010122114"),
+            4
+        );
+        assert_eq!(
+            RomaniaCnpValidator::calculate_check_digit("504022912343"),
+            1
+        );
+    }
+}
@@ -0,0 +1,146 @@
+/// Romania CUI Validator
+///
+/// This module provides functionality to validate and parse the CUI (Cod Unic de
+/// Înregistrare), also known as CIF (Cod de Identificare Fiscală), the fiscal code
+/// assigned to businesses registered in Romania. It has 2 to 10 digits and does not start
+/// with 0. Businesses registered for VAT use it with the `RO` prefix.
+///
+/// The digits before the check digit are padded with zeros to 9 digits and weighted by
+/// `753217532`; the check digit is ten times the sum, modulo 11 and then modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::romania::{Cui, RomaniaCuiValidator};
+///
+/// assert!(RomaniaCuiValidator::is_valid("18547290").is_ok());
+/// assert!(RomaniaCuiValidator::is_valid("RO 18547290").is_ok());
+/// assert!(RomaniaCuiValidator::is_valid("18547291").is_err());
+///
+/// let cui = Cui::parse("ro18547290").unwrap();
+/// assert_eq!(cui.as_str(), "18547290");
+/// assert_eq!(cui.check_digit(), 0);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CUI. Removes spaces and the `RO` prefix, checks the
+///   length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct RomaniaCuiValidator;
+
+const COUNTRY_PREFIX: &str = "RO";
+const MIN_LENGTH: usize = 2;
+const MAX_LENGTH: usize = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[7, 5, 3, 2, 1, 7, 5, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl RomaniaCuiValidator {
+    /// Validates if the given CUI is correct. The input can be a plain string or contain
+    /// spaces, with or without the `RO` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < MIN_LENGTH || sanitized_value.len() > MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) || sanitized_value.starts_with('0')
+        {
+            return Err("Invalid format");
+        }
+        let (base, check_digit) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if check_digit == Self::calculate_check_digit(base).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CUIs using the RomaniaCuiValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and the `RO` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Calculates the check digit of the given digits, padded with zeros to 9 digits.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = format!("{:0>9}", value)
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum * 10 % VALIDATION_MODULUS % 10
+    }
+}
+
+/// A validated CUI, stored as its digits, without the `RO` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cui(String);
+
+impl Cui {
+    /// Parses and validates the given CUI, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        RomaniaCuiValidator::is_valid(value)?;
+        Ok(Cui(RomaniaCuiValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the CUI, without the `RO` prefix.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[self.0.len() - 1] - b'0'
+    }
+}
+
+impl FromStr for Cui {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cui {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(RomaniaCuiValidator::calculate_check_digit("1854729"), 0);
+        assert_eq!(RomaniaCuiValidator::calculate_check_digit("123456789"), 7);
+        assert_eq!(RomaniaCuiValidator::calculate_check_digit("1"), 9);
+    }
+}
@@ -0,0 +1,183 @@
+/// Russia INN Validator
+///
+/// This module provides functionality to validate and parse the INN (Идентификационный
+/// номер налогоплательщика), the taxpayer identification number assigned by the Federal
+/// Tax Service of Russia. It starts with the 4-digit code of the tax office that issued
+/// it, and comes in two forms:
+///
+/// | Length | Holder       | Check digits |
+/// |--------|--------------|--------------|
+/// | 10     | Legal entity | 1            |
+/// | 12     | Individual   | 2            |
+///
+/// Each check digit is the weighted sum of the preceding digits modulo 11, and then modulo
+/// 10. The 10-digit form uses the weights 2, 4, 10, 3, 5, 9, 4, 6 and 8; the 12-digit form
+/// uses 7, 2, 4, 10, 3, 5, 9, 4, 6 and 8 for its first check digit, and 3 followed by the
+/// same weights for its second one.
+///
+/// # Usage
+///
+/// ```
+/// use docval::russia::{Inn, InnKind, RussiaInnValidator};
+///
+/// assert!(RussiaInnValidator::is_valid("7707083893").is_ok());
+/// assert!(RussiaInnValidator::is_valid("500100732259").is_ok());
+/// assert!(RussiaInnValidator::is_valid("7707083894").is_err());
+///
+/// let inn = Inn::parse("500100732259").unwrap();
+/// assert_eq!(inn.kind(), InnKind::Individual);
+/// assert_eq!(inn.tax_office(), "5001");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an INN. Removes spaces, checks the length and format, and
+///   validates the check digits of the 10-digit or 12-digit form.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct RussiaInnValidator;
+
+/// The kinds of holders of an INN, told apart by its length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InnKind {
+    /// Legal entity, with a 10-digit INN.
+    LegalEntity,
+    /// Individual, including individual entrepreneurs, with a 12-digit INN.
+    Individual,
+}
+
+const LEGAL_ENTITY_LENGTH: usize = 10;
+const INDIVIDUAL_LENGTH: usize = 12;
+const LEGAL_ENTITY_WEIGHTS: &[u32] = &[2, 4, 10, 3, 5, 9, 4, 6, 8];
+const INDIVIDUAL_FIRST_WEIGHTS: &[u32] = &[7, 2, 4, 10, 3, 5, 9, 4, 6, 8];
+const INDIVIDUAL_SECOND_WEIGHTS: &[u32] = &[3, 7, 2, 4, 10, 3, 5, 9, 4, 6, 8];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl RussiaInnValidator {
+    /// Validates if the given INN is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != LEGAL_ENTITY_LENGTH
+            && sanitized_value.len() != INDIVIDUAL_LENGTH
+        {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let expected_check_digits = if sanitized_value.len() == LEGAL_ENTITY_LENGTH {
+            Self::calculate_check_digit(&sanitized_value, LEGAL_ENTITY_WEIGHTS).to_string()
+        } else {
+            format!(
+                "{}{}",
+                Self::calculate_check_digit(&sanitized_value, INDIVIDUAL_FIRST_WEIGHTS),
+                Self::calculate_check_digit(&sanitized_value, INDIVIDUAL_SECOND_WEIGHTS)
+            )
+        };
+        if !sanitized_value.ends_with(&expected_check_digits) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate INNs using the RussiaInnValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates a check digit from the leading digits of the INN, as many as there are
+    /// weights.
+    fn calculate_check_digit(value: &str, weights: &[u32]) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip(weights)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum % VALIDATION_MODULUS % 10
+    }
+}
+
+/// A validated INN, stored as its 10 or 12 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Inn(String);
+
+impl Inn {
+    /// Parses and validates the given INN, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        RussiaInnValidator::is_valid(value)?;
+        Ok(Inn(RussiaInnValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the INN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the INN belongs to a legal entity or an individual.
+    pub fn kind(&self) -> InnKind {
+        if self.0.len() == LEGAL_ENTITY_LENGTH {
+            InnKind::LegalEntity
+        } else {
+            InnKind::Individual
+        }
+    }
+
+    /// Returns the 4-digit code of the tax office that issued the INN, whose first 2 digits
+    /// are the code of the federal subject.
+    pub fn tax_office(&self) -> &str {
+        &self.0[..4]
+    }
+}
+
+impl FromStr for Inn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Inn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            RussiaInnValidator::calculate_check_digit("770708389", LEGAL_ENTITY_WEIGHTS),
+            3
+        );
+        assert_eq!(
+            RussiaInnValidator::calculate_check_digit("5001007322", INDIVIDUAL_FIRST_WEIGHTS),
+            5
+        );
+        assert_eq!(
+            RussiaInnValidator::calculate_check_digit("50010073225", INDIVIDUAL_SECOND_WEIGHTS),
+            9
+        );
+    }
+}
@@ -0,0 +1,186 @@
+/// Russia OGRN Validator
+///
+/// This module provides functionality to validate and parse the primary state registration
+/// number assigned to legal entities (OGRN, Основной государственный регистрационный
+/// номер) and to individual entrepreneurs (OGRNIP) in the Unified State Registers of
+/// Russia:
+///
+/// | Field                     | OGRN (13 digits) | OGRNIP (15 digits) |
+/// |---------------------------|------------------|--------------------|
+/// | Record type               | `1` or `5`       | `3`                |
+/// | Last 2 digits of the year | 2-3              | 2-3                |
+/// | Region code               | 4-5              | 4-5                |
+/// | Tax office                | 6-7              | 6-7                |
+/// | Record number             | 8-12             | 8-14               |
+/// | Check digit               | 13               | 15                 |
+///
+/// The registers were created in 2002, so the year of registration is always in the 2000s.
+/// The check digit is the number formed by the preceding digits modulo 11 for an OGRN, or
+/// modulo 13 for an OGRNIP, and then modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::russia::{Ogrn, OgrnKind, RussiaOgrnValidator};
+///
+/// assert!(RussiaOgrnValidator::is_valid("1027700132195").is_ok());
+/// assert!(RussiaOgrnValidator::is_valid("304500116000157").is_ok());
+/// assert!(RussiaOgrnValidator::is_valid("1027700132196").is_err());
+///
+/// let ogrn = Ogrn::parse("304500116000157").unwrap();
+/// assert_eq!(ogrn.kind(), OgrnKind::IndividualEntrepreneur);
+/// assert_eq!(ogrn.registration_year(), 2004);
+/// assert_eq!(ogrn.region_code(), 50);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an OGRN or OGRNIP. Removes spaces, checks the length,
+///   format and record type, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct RussiaOgrnValidator;
+
+/// The kinds of holders of a primary state registration number, told apart by its length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OgrnKind {
+    /// Legal entity, with a 13-digit OGRN.
+    LegalEntity,
+    /// Individual entrepreneur, with a 15-digit OGRNIP.
+    IndividualEntrepreneur,
+}
+
+const OGRN_LENGTH: usize = 13;
+const OGRNIP_LENGTH: usize = 15;
+const OGRN_RECORD_TYPES: &[char] = &['1', '5'];
+const OGRNIP_RECORD_TYPE: char = '3';
+const OGRN_MODULUS: u64 = 11;
+const OGRNIP_MODULUS: u64 = 13;
+const FIRST_REGISTRATION_CENTURY: u16 = 2000;
+
+impl RussiaOgrnValidator {
+    /// Validates if the given OGRN or OGRNIP is correct. The input can be a plain string or
+    /// contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let (record_types, modulus) = match sanitized_value.len() {
+            OGRN_LENGTH => (OGRN_RECORD_TYPES, OGRN_MODULUS),
+            OGRNIP_LENGTH => (&[OGRNIP_RECORD_TYPE][..], OGRNIP_MODULUS),
+            _ => return Err("Invalid length"),
+        };
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit())
+            || !sanitized_value.starts_with(record_types)
+        {
+            return Err("Invalid format");
+        }
+        let last_index = sanitized_value.len() - 1;
+        let number: u64 = sanitized_value[..last_index]
+            .parse()
+            .expect("Validated digits");
+        if sanitized_value[last_index..] != (number % modulus % 10).to_string() {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate OGRNs and OGRNIPs using the RussiaOgrnValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
+/// A validated OGRN or OGRNIP, stored as its 13 or 15 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ogrn(String);
+
+impl Ogrn {
+    /// Parses and validates the given OGRN or OGRNIP, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        RussiaOgrnValidator::is_valid(value)?;
+        Ok(Ogrn(RussiaOgrnValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the OGRN or OGRNIP.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether this is the OGRN of a legal entity or the OGRNIP of an individual
+    /// entrepreneur.
+    pub fn kind(&self) -> OgrnKind {
+        if self.0.len() == OGRN_LENGTH {
+            OgrnKind::LegalEntity
+        } else {
+            OgrnKind::IndividualEntrepreneur
+        }
+    }
+
+    /// Returns the year of registration.
+    pub fn registration_year(&self) -> u16 {
+        FIRST_REGISTRATION_CENTURY + self.0[1..3].parse::<u16>().expect("Validated digits")
+    }
+
+    /// Returns the 2-digit code of the region of registration.
+    pub fn region_code(&self) -> u8 {
+        self.0[3..5].parse().expect("Validated digits")
+    }
+
+    /// Returns the 4-digit code of the tax office that made the registration, starting with
+    /// the region code.
+    pub fn tax_office(&self) -> &str {
+        &self.0[3..7]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[self.0.len() - 1] - b'0'
+    }
+}
+
+impl FromStr for Ogrn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ogrn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            RussiaOgrnValidator::sanitize_input("1 02 77 00 13219 5"),
+            "1027700132195"
+        );
+        assert_eq!(
+            RussiaOgrnValidator::sanitize_input("\t304500116000157\n"),
+            "304500116000157"
+        );
+    }
+}
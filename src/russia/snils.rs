@@ -0,0 +1,158 @@
+/// Russia SNILS Validator
+///
+/// This module provides functionality to validate and parse the SNILS (Страховой номер
+/// индивидуального лицевого счёта), the 11-digit individual insurance account number
+/// assigned by the Social Fund of Russia, written as `112-233-445 95`. It is made of a
+/// 9-digit number and 2 check digits.
+///
+/// The digits of the number are weighted by 9 down to 1. A sum below 100 is the check
+/// number itself, and a sum of 100 or 101 gives `00`. Larger sums are reduced modulo 101,
+/// with a remainder of 100 also giving `00`. Check digits are only defined for numbers
+/// above `001-001-998`, so they are not validated for lower numbers.
+///
+/// # Usage
+///
+/// ```
+/// use docval::russia::{RussiaSnilsValidator, Snils};
+///
+/// assert!(RussiaSnilsValidator::is_valid("112-233-445 95").is_ok());
+/// assert!(RussiaSnilsValidator::is_valid("112-233-445 96").is_err());
+///
+/// let snils = Snils::parse("11223344595").unwrap();
+/// assert_eq!(snils.check_digits(), 95);
+/// assert_eq!(snils.to_string(), "112-233-445 95");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a SNILS. Removes spaces and hyphens, checks the length and
+///   format, and validates the check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct RussiaSnilsValidator;
+
+const SNILS_LENGTH: usize = 11;
+const MAX_UNCHECKED_NUMBER: u32 = 1_001_998;
+const VALIDATION_MODULUS: u32 = 101;
+const ZERO_CHECK_SUMS: [u32; 2] = [100, 101];
+
+impl RussiaSnilsValidator {
+    /// Validates if the given SNILS is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != SNILS_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let number: u32 = sanitized_value[..9].parse().expect("Validated digits");
+        if number <= MAX_UNCHECKED_NUMBER {
+            return Ok(());
+        }
+        let check_digits: u32 = sanitized_value[9..].parse().expect("Validated digits");
+        if check_digits != Self::calculate_check_digits(&sanitized_value[..9]) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SNILS numbers using the RussiaSnilsValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    /// Calculates the check number of the first 9 digits of a SNILS.
+    fn calculate_check_digits(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip((1..=9).rev())
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match sum {
+            0..=99 => sum,
+            _ if ZERO_CHECK_SUMS.contains(&sum) => 0,
+            _ => sum % VALIDATION_MODULUS % 100,
+        }
+    }
+}
+
+/// A validated SNILS, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snils(String);
+
+impl Snils {
+    /// Parses and validates the given SNILS, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        RussiaSnilsValidator::is_valid(value)?;
+        Ok(Snils(RussiaSnilsValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the SNILS.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 2 check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.0[9..].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for Snils {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Snils {
+    /// Formats the SNILS as in `112-233-445 95`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{} {}",
+            &self.0[..3],
+            &self.0[3..6],
+            &self.0[6..9],
+            &self.0[9..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digits() {
+        assert_eq!(
+            RussiaSnilsValidator::calculate_check_digits("112233445"),
+            95
+        );
+        assert_eq!(RussiaSnilsValidator::calculate_check_digits("087654303"), 0);
+        assert_eq!(RussiaSnilsValidator::calculate_check_digits("999999999"), 1);
+    }
+}
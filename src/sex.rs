@@ -0,0 +1,19 @@
+//! Sex
+//!
+//! This module provides the `Sex` enum, shared by validators of personal identifiers that
+//! encode the sex of the holder, such as the Mexican CURP.
+//!
+//! # Usage
+//!
+//! ```
+//! use docval::Sex;
+//!
+//! assert_ne!(Sex::Female, Sex::Male);
+//! ```
+
+/// The sex of a person, as encoded in a personal identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sex {
+    Female,
+    Male,
+}
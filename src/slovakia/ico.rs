@@ -0,0 +1,84 @@
+/// Slovakia IČO Validator
+///
+/// This module provides functionality to validate and parse the IČO (identifikačné číslo
+/// organizácie), the 8-digit identification number assigned by the Statistical Office of
+/// the Slovak Republic to businesses, public bodies and self-employed people. It shares
+/// its check digit with the Czech IČO, and is validated with the `CzechiaIcoValidator`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::slovakia::{Ico, SlovakiaIcoValidator};
+///
+/// assert!(SlovakiaIcoValidator::is_valid("35 757 442").is_ok());
+/// assert!(SlovakiaIcoValidator::is_valid("35757443").is_err());
+///
+/// let ico = Ico::parse("35757442").unwrap();
+/// assert_eq!(ico.check_digit(), 2);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an IČO, following the Czech rules.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::czechia::{self, CzechiaIcoValidator};
+
+pub struct SlovakiaIcoValidator;
+
+impl SlovakiaIcoValidator {
+    /// Validates if the given IČO is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        CzechiaIcoValidator::is_valid(value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate IČOs using the SlovakiaIcoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+/// A validated Slovak IČO, stored as its 8 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ico(czechia::Ico);
+
+impl Ico {
+    /// Parses and validates the given IČO, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        czechia::Ico::parse(value).map(Ico)
+    }
+
+    /// Returns the 8 digits of the IČO.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.check_digit()
+    }
+}
+
+impl FromStr for Ico {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ico {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
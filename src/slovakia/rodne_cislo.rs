@@ -0,0 +1,110 @@
+/// Slovakia Rodné Číslo Validator
+///
+/// This module provides functionality to validate and parse the rodné číslo, the birth
+/// number assigned to residents of Slovakia. Both countries kept the Czechoslovak birth
+/// number after 1993, so it follows the same rules as the Czech one, and is validated with
+/// the `CzechiaRodneCisloValidator`: a date of birth as `YYMMDD` with 50 added to the month
+/// for women, a serial number, and a check digit that makes the number divisible by 11,
+/// absent from numbers issued before 1954.
+///
+/// # Usage
+///
+/// ```
+/// use docval::slovakia::{RodneCislo, SlovakiaRodneCisloValidator};
+/// use docval::Sex;
+///
+/// assert!(SlovakiaRodneCisloValidator::is_valid("736028/0004").is_ok());
+/// assert!(SlovakiaRodneCisloValidator::is_valid("736028/0005").is_err());
+///
+/// let rodne_cislo = RodneCislo::parse("7360280004").unwrap();
+/// assert_eq!(rodne_cislo.birth_year(), 1973);
+/// assert_eq!(rodne_cislo.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a rodné číslo, following the Czech rules.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::czechia::{self, CzechiaRodneCisloValidator};
+use crate::Sex;
+
+pub struct SlovakiaRodneCisloValidator;
+
+impl SlovakiaRodneCisloValidator {
+    /// Validates if the given rodné číslo is correct. The input can be a plain string or
+    /// contain the slash.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        CzechiaRodneCisloValidator::is_valid(value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate rodné čísla using the SlovakiaRodneCisloValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+/// A validated Slovak rodné číslo, stored as its 9 or 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RodneCislo(czechia::RodneCislo);
+
+impl RodneCislo {
+    /// Parses and validates the given rodné číslo, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        czechia::RodneCislo::parse(value).map(RodneCislo)
+    }
+
+    /// Returns the 9 or 10 digits of the rodné číslo.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        self.0.birth_year()
+    }
+
+    /// Returns the month of birth, without the offsets.
+    pub fn birth_month(&self) -> u8 {
+        self.0.birth_month()
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.0.birth_day()
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        self.0.sex()
+    }
+
+    /// Returns the check digit, absent from numbers issued before 1954.
+    pub fn check_digit(&self) -> Option<u8> {
+        self.0.check_digit()
+    }
+}
+
+impl FromStr for RodneCislo {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for RodneCislo {
+    /// Formats the rodné číslo with a slash after the date of birth, as in `736028/0004`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,210 @@
+/// Slovenia EMŠO Validator
+///
+/// This module provides functionality to validate and parse the EMŠO (Enotna matična
+/// številka občana), the 13-digit unique master citizen number assigned to residents of
+/// Slovenia. It keeps the structure of the Yugoslav JMBG:
+///
+/// | Positions | Field                                                          |
+/// |-----------|----------------------------------------------------------------|
+/// | 1-7       | Date of birth as `DDMMYYY`, with the last 3 digits of the year |
+/// | 8-9       | Region of registration, `50` for Slovenia                      |
+/// | 10-12     | Serial number: 000-499 for men, 500-999 for women              |
+/// | 13        | Check digit                                                    |
+///
+/// Years `800` to `999` stand for 1800 to 1999, and lower ones for the 2000s.
+/// Numbers registered before independence keep the region of the former republic that
+/// issued them, so any region is accepted.
+///
+/// The first 12 digits are weighted by 7, 6, 5, 4, 3 and 2, twice, and the check digit is
+/// the complement of the sum modulo 11, where 11 yields 0. Numbers whose complement is 10
+/// are never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::slovenia::{Emso, SloveniaEmsoValidator};
+/// use docval::Sex;
+///
+/// assert!(SloveniaEmsoValidator::is_valid("0101980500129").is_ok());
+/// assert!(SloveniaEmsoValidator::is_valid("0101980500128").is_err());
+///
+/// let emso = Emso::parse("0101980505007").unwrap();
+/// assert_eq!(emso.birth_year(), 1980);
+/// assert_eq!(emso.region_code(), 50);
+/// assert_eq!(emso.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an EMŠO. Removes spaces, checks the length, format and
+///   date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::is_valid_date;
+use crate::Sex;
+
+pub struct SloveniaEmsoValidator;
+
+const EMSO_LENGTH: usize = 13;
+const FIRST_MILLENNIUM_YEAR: u32 = 800;
+const FIRST_FEMALE_SERIAL: u16 = 500;
+const MULTIPLIER_WEIGHTS: &[u32] = &[7, 6, 5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl SloveniaEmsoValidator {
+    /// Validates if the given EMŠO is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != EMSO_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let (year, month, day) = Self::birth_date(&sanitized_value);
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..12]) {
+            Some(check_digit) if sanitized_value[12..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate EMŠOs using the SloveniaEmsoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Decodes the date of birth, completing the year from its last 3 digits.
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let day = value[..2].parse().expect("Validated digits");
+        let month = value[2..4].parse().expect("Validated digits");
+        let year: u32 = value[4..7].parse().expect("Validated digits");
+        if year >= FIRST_MILLENNIUM_YEAR {
+            (1000 + year, month, day)
+        } else {
+            (2000 + year, month, day)
+        }
+    }
+
+    /// Calculates the check digit of the first 12 digits of an EMŠO, or `None` if it would
+    /// be 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => None,
+            11 => Some(0),
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated EMŠO, stored as its 13 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Emso(String);
+
+impl Emso {
+    /// Parses and validates the given EMŠO, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        SloveniaEmsoValidator::is_valid(value)?;
+        Ok(Emso(SloveniaEmsoValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 13 digits of the EMŠO.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        SloveniaEmsoValidator::birth_date(&self.0).0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        SloveniaEmsoValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        SloveniaEmsoValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the 2-digit code of the region of registration.
+    pub fn region_code(&self) -> u8 {
+        self.0[7..9].parse().expect("Validated digits")
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0[9..12].parse().expect("Validated digits")
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if self.serial() < FIRST_FEMALE_SERIAL {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[12] - b'0'
+    }
+}
+
+impl FromStr for Emso {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Emso {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(
+            SloveniaEmsoValidator::birth_date("0101980500129"),
+            (1980, 1, 1)
+        );
+        assert_eq!(
+            SloveniaEmsoValidator::birth_date("0101005500010"),
+            (2005, 1, 1)
+        );
+    }
+}
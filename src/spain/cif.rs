@@ -0,0 +1,257 @@
+/// Spain CIF Validator
+///
+/// This module provides functionality to validate and parse the CIF (Código de
+/// Identificación Fiscal), the tax number of legal entities, now formally part of the NIF.
+/// A CIF is composed of a letter identifying the type of organization, 7 digits, the first
+/// two of which are the province code, and a control character.
+///
+/// The control value is the Luhn check digit of the 7 digits. Depending on the type of
+/// organization, it is written as the digit itself, as the letter at that position in
+/// `JABCDEFGHI`, or in either form:
+///
+/// | Letters                | Control character |
+/// |------------------------|-------------------|
+/// | A, B, E, H             | Digit             |
+/// | N, P, Q, R, S, W       | Letter            |
+/// | C, D, F, G, J, U, V    | Digit or letter   |
+///
+/// # Usage
+///
+/// ```
+/// use docval::spain::{Cif, OrganizationType, SpainCifValidator};
+///
+/// assert!(SpainCifValidator::is_valid("A-13585625").is_ok());
+/// assert!(SpainCifValidator::is_valid("Q2826000H").is_ok());
+///
+/// let cif = Cif::parse("b12345674").unwrap();
+/// assert_eq!(cif.organization_type(), OrganizationType::LimitedCompany);
+/// assert_eq!(cif.province_code(), "12");
+/// assert_eq!(cif.to_string(), "B12345674");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a CIF. Removes spaces, dots and hyphens, checks the
+///   length, the organization type and the format, and validates the control character.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::dni::sanitize_input;
+use crate::checksum::luhn_check_digit;
+
+pub struct SpainCifValidator;
+
+/// The types of organizations, identified by the first letter of the CIF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrganizationType {
+    /// Sociedad anónima (A).
+    PublicLimitedCompany,
+    /// Sociedad de responsabilidad limitada (B).
+    LimitedCompany,
+    /// Sociedad colectiva (C).
+    GeneralPartnership,
+    /// Sociedad comanditaria (D).
+    LimitedPartnership,
+    /// Comunidad de bienes y herencia yacente (E).
+    JointOwnership,
+    /// Sociedad cooperativa (F).
+    Cooperative,
+    /// Asociación o fundación (G).
+    Association,
+    /// Comunidad de propietarios en régimen de propiedad horizontal (H).
+    HomeownersAssociation,
+    /// Sociedad civil (J).
+    CivilPartnership,
+    /// Entidad extranjera (N).
+    ForeignEntity,
+    /// Corporación local (P).
+    LocalCorporation,
+    /// Organismo público (Q).
+    PublicBody,
+    /// Congregación o institución religiosa (R).
+    ReligiousInstitution,
+    /// Órgano de la Administración del Estado y de las comunidades autónomas (S).
+    GovernmentBody,
+    /// Unión temporal de empresas (U).
+    JointVenture,
+    /// Otros tipos no definidos en el resto de claves (V).
+    Other,
+    /// Establecimiento permanente de entidad no residente en España (W).
+    PermanentEstablishment,
+}
+
+impl OrganizationType {
+    /// Returns the organization type with the given letter, if any.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'A' => Some(OrganizationType::PublicLimitedCompany),
+            'B' => Some(OrganizationType::LimitedCompany),
+            'C' => Some(OrganizationType::GeneralPartnership),
+            'D' => Some(OrganizationType::LimitedPartnership),
+            'E' => Some(OrganizationType::JointOwnership),
+            'F' => Some(OrganizationType::Cooperative),
+            'G' => Some(OrganizationType::Association),
+            'H' => Some(OrganizationType::HomeownersAssociation),
+            'J' => Some(OrganizationType::CivilPartnership),
+            'N' => Some(OrganizationType::ForeignEntity),
+            'P' => Some(OrganizationType::LocalCorporation),
+            'Q' => Some(OrganizationType::PublicBody),
+            'R' => Some(OrganizationType::ReligiousInstitution),
+            'S' => Some(OrganizationType::GovernmentBody),
+            'U' => Some(OrganizationType::JointVenture),
+            'V' => Some(OrganizationType::Other),
+            'W' => Some(OrganizationType::PermanentEstablishment),
+            _ => None,
+        }
+    }
+
+    /// Returns the form the control character must take for this organization type.
+    fn control_kind(&self) -> ControlKind {
+        match self {
+            OrganizationType::PublicLimitedCompany
+            | OrganizationType::LimitedCompany
+            | OrganizationType::JointOwnership
+            | OrganizationType::HomeownersAssociation => ControlKind::Digit,
+            OrganizationType::ForeignEntity
+            | OrganizationType::LocalCorporation
+            | OrganizationType::PublicBody
+            | OrganizationType::ReligiousInstitution
+            | OrganizationType::GovernmentBody
+            | OrganizationType::PermanentEstablishment => ControlKind::Letter,
+            _ => ControlKind::Either,
+        }
+    }
+}
+
+/// The forms a control character can take.
+enum ControlKind {
+    Digit,
+    Letter,
+    Either,
+}
+
+const CIF_LENGTH: usize = 9;
+const CONTROL_LETTERS: &str = "JABCDEFGHI";
+
+impl SpainCifValidator {
+    /// Validates if the given CIF is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != CIF_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        let mut chars = sanitized_value.chars();
+        let organization_type = chars
+            .next()
+            .and_then(OrganizationType::from_letter)
+            .ok_or("Invalid type")?;
+        let digits = &sanitized_value[1..8];
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let control = chars.last().expect("Validated length");
+        let check_digit = luhn_check_digit(digits);
+        let digit = char::from_digit(check_digit, 10).expect("Single digit");
+        let letter = CONTROL_LETTERS
+            .chars()
+            .nth(check_digit as usize)
+            .expect("Single digit");
+        let is_valid = match organization_type.control_kind() {
+            ControlKind::Digit => control == digit,
+            ControlKind::Letter => control == letter,
+            ControlKind::Either => control == digit || control == letter,
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate CIFs using the SpainCifValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+/// A validated CIF, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cif(String);
+
+impl Cif {
+    /// Parses and validates the given CIF, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        SpainCifValidator::is_valid(value)?;
+        Ok(Cif(sanitize_input(value)))
+    }
+
+    /// Returns the 9 characters of the CIF.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the type of organization.
+    pub fn organization_type(&self) -> OrganizationType {
+        OrganizationType::from_letter(self.0.chars().next().expect("Validated length"))
+            .expect("Validated organization type")
+    }
+
+    /// Returns the 2-digit code of the province the CIF was assigned in.
+    pub fn province_code(&self) -> &str {
+        &self.0[1..3]
+    }
+
+    /// Returns the control character, either a digit or a letter.
+    pub fn control_character(&self) -> char {
+        char::from(self.0.as_bytes()[8])
+    }
+}
+
+impl FromStr for Cif {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cif {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_control_kind() {
+        assert!(matches!(
+            OrganizationType::LimitedCompany.control_kind(),
+            ControlKind::Digit
+        ));
+        assert!(matches!(
+            OrganizationType::PublicBody.control_kind(),
+            ControlKind::Letter
+        ));
+        assert!(matches!(
+            OrganizationType::Association.control_kind(),
+            ControlKind::Either
+        ));
+    }
+}
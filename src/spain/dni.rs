@@ -0,0 +1,207 @@
+/// Spain DNI and NIE Validators
+///
+/// This module provides functionality to validate and parse the identity numbers of
+/// individuals in Spain, which also serve as their tax number (NIF):
+///
+/// - DNI (Documento Nacional de Identidad), issued to Spanish citizens: 8 digits followed
+///   by a control letter, as in `12345678Z`;
+/// - NIE (Número de Identidad de Extranjero), issued to foreigners: `X`, `Y` or `Z`
+///   followed by 7 digits and a control letter, as in `X1234567L`.
+///
+/// The control letter is taken from the table `TRWAGMYFPDXBNJZSQVHLCKE` at the position
+/// given by the number modulo 23. For a NIE, the leading letter is first replaced with
+/// `0`, `1` or `2` respectively.
+///
+/// # Usage
+///
+/// ```
+/// use docval::spain::{IdentityNumber, IdentityNumberKind, SpainDniValidator, SpainNieValidator};
+///
+/// assert!(SpainDniValidator::is_valid("12345678-Z").is_ok());
+/// assert!(SpainNieValidator::is_valid("X-1234567-L").is_ok());
+///
+/// let id = IdentityNumber::parse("y1234567x").unwrap();
+/// assert_eq!(id.kind(), IdentityNumberKind::Nie);
+/// assert_eq!(id.to_string(), "Y1234567X");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point of each validator. Removes spaces, dots and hyphens, checks the
+///   length and format, and validates the control letter.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct SpainDniValidator;
+pub struct SpainNieValidator;
+
+/// The kinds of personal identity numbers, told apart by their first character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdentityNumberKind {
+    /// Documento Nacional de Identidad, starting with a digit.
+    Dni,
+    /// Número de Identidad de Extranjero, starting with `X`, `Y` or `Z`.
+    Nie,
+}
+
+const ID_LENGTH: usize = 9;
+const CONTROL_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+const NIE_PREFIXES: &str = "XYZ";
+
+impl SpainDniValidator {
+    /// Validates if the given DNI is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        match validate(value)? {
+            IdentityNumberKind::Dni => Ok(()),
+            IdentityNumberKind::Nie => Err("Invalid format"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate DNIs using the SpainDniValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+impl SpainNieValidator {
+    /// Validates if the given NIE is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        match validate(value)? {
+            IdentityNumberKind::Nie => Ok(()),
+            IdentityNumberKind::Dni => Err("Invalid format"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NIEs using the SpainNieValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+}
+
+/// Validates a DNI or NIE, returning which of the two it is.
+fn validate(value: &str) -> Result<IdentityNumberKind, &'static str> {
+    let sanitized_value = sanitize_input(value);
+    if sanitized_value.is_empty() {
+        return Err("Invalid input");
+    }
+    if sanitized_value.len() != ID_LENGTH {
+        return Err("Invalid length");
+    }
+    if !sanitized_value.is_ascii() {
+        return Err("Invalid format");
+    }
+    let (number, control_letter) = sanitized_value.split_at(8);
+    let (kind, digits) = match number.split_at(1) {
+        (prefix, digits) if NIE_PREFIXES.contains(prefix) => (IdentityNumberKind::Nie, digits),
+        _ => (IdentityNumberKind::Dni, number),
+    };
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err("Invalid format");
+    }
+    if control_letter.as_bytes()[0] == calculate_control_letter(numeric_value(number)) {
+        Ok(kind)
+    } else {
+        Err("Invalid checksum")
+    }
+}
+
+/// Removes spaces, dots and hyphens from the input, and uppercases it.
+pub(super) fn sanitize_input(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '.' | '-'))
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Returns the value of the 8 characters before the control letter, replacing the NIE
+/// prefix with its digit.
+fn numeric_value(number: &str) -> u32 {
+    number.chars().fold(0, |value, c| {
+        let digit = match NIE_PREFIXES.find(c) {
+            Some(position) => position as u32,
+            None => c.to_digit(10).expect("Invalid digit in input"),
+        };
+        value * 10 + digit
+    })
+}
+
+/// Calculates the control letter of the given number.
+fn calculate_control_letter(number: u32) -> u8 {
+    CONTROL_LETTERS[(number % CONTROL_LETTERS.len() as u32) as usize]
+}
+
+/// A validated DNI or NIE, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdentityNumber(String);
+
+impl IdentityNumber {
+    /// Parses and validates the given DNI or NIE, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        validate(value)?;
+        Ok(IdentityNumber(sanitize_input(value)))
+    }
+
+    /// Returns the 9 characters of the identity number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the identity number is a DNI or a NIE.
+    pub fn kind(&self) -> IdentityNumberKind {
+        if self.0.starts_with(|c: char| NIE_PREFIXES.contains(c)) {
+            IdentityNumberKind::Nie
+        } else {
+            IdentityNumberKind::Dni
+        }
+    }
+
+    /// Returns the number the control letter is calculated from, with the NIE prefix
+    /// replaced with its digit.
+    pub fn number(&self) -> u32 {
+        numeric_value(&self.0[..8])
+    }
+
+    /// Returns the control letter.
+    pub fn control_letter(&self) -> char {
+        char::from(self.0.as_bytes()[8])
+    }
+}
+
+impl FromStr for IdentityNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for IdentityNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_value() {
+        assert_eq!(numeric_value("12345678"), 12345678);
+        assert_eq!(numeric_value("Y1234567"), 11234567);
+        assert_eq!(numeric_value("Z0000000"), 20000000);
+    }
+}
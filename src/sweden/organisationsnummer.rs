@@ -0,0 +1,158 @@
+/// Sweden Organisationsnummer Validator
+///
+/// This module provides functionality to validate and parse the organisation number
+/// (organisationsnummer) assigned to legal entities in Sweden, written as `NNNNNN-NNNN`. The
+/// first digit gives the group of the legal entity, and the third digit is at least 2, so
+/// that the number can never be mistaken for a personal identity number, whose third digit
+/// starts the month of birth. The last digit is the Luhn check digit of the first 9.
+///
+/// Some registries, such as the tax agency's, store organisation numbers in the 12-digit
+/// format of personal identity numbers, prefixed with `16`. Both forms are accepted.
+///
+/// # Usage
+///
+/// ```
+/// use docval::sweden::{Organisationsnummer, SwedenOrganisationsnummerValidator};
+///
+/// assert!(SwedenOrganisationsnummerValidator::is_valid("556012-5790").is_ok());
+/// assert!(SwedenOrganisationsnummerValidator::is_valid("16556012-5790").is_ok());
+/// assert!(SwedenOrganisationsnummerValidator::is_valid("556012-5791").is_err());
+///
+/// let organisationsnummer = Organisationsnummer::parse("165560125790").unwrap();
+/// assert_eq!(organisationsnummer.as_str(), "5560125790");
+/// assert_eq!(organisationsnummer.group(), 5);
+/// assert_eq!(organisationsnummer.to_string(), "556012-5790");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an organisation number. Removes spaces and hyphens, strips
+///   the `16` prefix of the 12-digit form, checks the length, format and third digit, and
+///   validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct SwedenOrganisationsnummerValidator;
+
+const ORGANISATIONSNUMMER_LENGTH: usize = 10;
+const REGISTRY_PREFIX: &str = "16";
+const MIN_THIRD_DIGIT: u8 = b'2';
+
+impl SwedenOrganisationsnummerValidator {
+    /// Validates if the given organisation number is correct. The input can be a plain or
+    /// formatted string, in the 10-digit or the 12-digit form.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ORGANISATIONSNUMMER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.as_bytes()[2] < MIN_THIRD_DIGIT {
+            return Err("Invalid format");
+        }
+        if sanitized_value[9..] != luhn_check_digit(&sanitized_value[..9]).to_string() {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate organisation numbers using the
+    /// SwedenOrganisationsnummerValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces and hyphens from the input, and the `16` prefix of the 12-digit form.
+    fn sanitize_input(value: &str) -> String {
+        let sanitized_value: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect();
+        match sanitized_value.strip_prefix(REGISTRY_PREFIX) {
+            Some(number) if number.len() == ORGANISATIONSNUMMER_LENGTH => number.to_string(),
+            _ => sanitized_value,
+        }
+    }
+}
+
+/// A validated organisation number, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Organisationsnummer(String);
+
+impl Organisationsnummer {
+    /// Parses and validates the given organisation number, discarding any formatting and the
+    /// `16` prefix.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        SwedenOrganisationsnummerValidator::is_valid(value)?;
+        Ok(Organisationsnummer(
+            SwedenOrganisationsnummerValidator::sanitize_input(value),
+        ))
+    }
+
+    /// Returns the 10 digits of the organisation number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the group digit, which tells the kind of legal entity, such as 5 for limited
+    /// companies or 8 for non-profit associations and foundations.
+    pub fn group(&self) -> u8 {
+        self.0.as_bytes()[0] - b'0'
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+}
+
+impl FromStr for Organisationsnummer {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Organisationsnummer {
+    /// Formats the organisation number as in `556012-5790`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            SwedenOrganisationsnummerValidator::sanitize_input("16556012-5790"),
+            "5560125790"
+        );
+        assert_eq!(
+            SwedenOrganisationsnummerValidator::sanitize_input("556012-5790"),
+            "5560125790"
+        );
+        assert_eq!(
+            SwedenOrganisationsnummerValidator::sanitize_input("1655601257"),
+            "1655601257"
+        );
+    }
+}
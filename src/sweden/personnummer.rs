@@ -0,0 +1,271 @@
+/// Sweden Personnummer Validator
+///
+/// This module provides functionality to validate and parse the personal identity number
+/// (personnummer) assigned to residents of Sweden, and the coordination number
+/// (samordningsnummer) assigned to people who are not or have not been registered as
+/// residents. Both are written as `YYMMDD-NNNC` or, with the full year, `YYYYMMDD-NNNC`:
+///
+/// | Positions | Field                                                                        |
+/// |-----------|------------------------------------------------------------------------------|
+/// | 1-6       | Date of birth as `YYMMDD`, with 60 added to the day for coordination numbers |
+/// | 7-9       | Birth number: odd third digit for men, even for women                        |
+/// | 10        | Check digit                                                                  |
+///
+/// In the short form the century is left out, and the separator tells it apart: a `-` is
+/// used until the holder turns 100, and a `+` from then on. The check digit is the Luhn
+/// check digit of the first 9 digits of the short form.
+///
+/// # Usage
+///
+/// ```
+/// use docval::sweden::{Personnummer, PersonnummerKind, SwedenPersonnummerValidator};
+/// use docval::Sex;
+///
+/// assert!(SwedenPersonnummerValidator::is_valid("811218-9876").is_ok());
+/// assert!(SwedenPersonnummerValidator::is_valid("19811218-9876").is_ok());
+/// assert!(SwedenPersonnummerValidator::is_valid("811218-9875").is_err());
+///
+/// let personnummer = Personnummer::parse("701063-2391").unwrap();
+/// assert_eq!(personnummer.kind(), PersonnummerKind::Coordination);
+/// assert_eq!(personnummer.birth_day(), 3);
+/// assert_eq!(personnummer.sex(), Sex::Male);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a personal identity or coordination number. Removes
+///   spaces, checks the length, format and date of birth, and validates the check digit.
+///   The century of the short form is resolved against the current year.
+///
+/// - `is_valid_at(value: &str, reference_year: u16) -> Result<(), &'static str>`:
+///   Validates a personal identity or coordination number, resolving the century of the
+///   short form against the given year.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+use crate::date::{current_year, is_valid_date};
+use crate::Sex;
+
+pub struct SwedenPersonnummerValidator;
+
+/// The kinds of numbers sharing the personnummer format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PersonnummerKind {
+    /// Personal identity number (personnummer), assigned to registered residents.
+    Personal,
+    /// Coordination number (samordningsnummer), with 60 added to the day of birth.
+    Coordination,
+}
+
+const SHORT_LENGTH: usize = 10;
+const FULL_LENGTH: usize = 12;
+const SEPARATOR_OFFSET: usize = 4;
+const SEPARATOR: char = '-';
+const CENTENARIAN_SEPARATOR: char = '+';
+const COORDINATION_DAY_OFFSET: u32 = 60;
+
+impl SwedenPersonnummerValidator {
+    /// Validates if the given personal identity or coordination number is correct. The
+    /// input can be a plain or formatted string, in the short or full form.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::is_valid_at(value, current_year() as u16)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate personnummers using the SwedenPersonnummerValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given personal identity or coordination number is correct, taking
+    /// the century of a short form number from the given reference year.
+    pub fn is_valid_at(value: &str, reference_year: u16) -> Result<(), &'static str> {
+        Self::normalize(value, reference_year).map(|_| ())
+    }
+
+    /// Validates the input and returns it as the 12 digits of the full form.
+    fn normalize(value: &str, reference_year: u16) -> Result<String, &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let digits: String = sanitized_value
+            .chars()
+            .filter(|&c| c != SEPARATOR && c != CENTENARIAN_SEPARATOR)
+            .collect();
+        if digits.len() != SHORT_LENGTH && digits.len() != FULL_LENGTH {
+            return Err("Invalid length");
+        }
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let separator = match sanitized_value.len() - digits.len() {
+            0 => None,
+            1 => sanitized_value.chars().nth(digits.len() - SEPARATOR_OFFSET),
+            _ => return Err("Invalid format"),
+        };
+        if separator.is_some_and(|c| c != SEPARATOR && c != CENTENARIAN_SEPARATOR) {
+            return Err("Invalid format");
+        }
+        let full_value = if digits.len() == SHORT_LENGTH {
+            let centenarian = separator == Some(CENTENARIAN_SEPARATOR);
+            let century = Self::century(&digits, reference_year, centenarian);
+            format!("{century:02}{digits}")
+        } else if separator == Some(CENTENARIAN_SEPARATOR) {
+            return Err("Invalid format");
+        } else {
+            digits
+        };
+        let (year, month, day) = Self::birth_date(&full_value);
+        if !is_valid_date(year, month, day) {
+            return Err("Invalid date");
+        }
+        if full_value[11..] != luhn_check_digit(&full_value[2..11]).to_string() {
+            return Err("Invalid checksum");
+        }
+        Ok(full_value)
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Returns the century of a short form number: the latest one that puts the year of
+    /// birth at or before the reference year, or the one before for centenarians.
+    fn century(value: &str, reference_year: u16, centenarian: bool) -> u16 {
+        let year: u16 = value[..2].parse().expect("Validated digits");
+        let mut century = reference_year / 100;
+        if year > reference_year % 100 {
+            century -= 1;
+        }
+        if centenarian {
+            century -= 1;
+        }
+        century
+    }
+
+    /// Decodes the date of birth of a full form number, removing the coordination offset
+    /// from the day.
+    fn birth_date(value: &str) -> (u32, u32, u32) {
+        let year = value[..4].parse().expect("Validated digits");
+        let month = value[4..6].parse().expect("Validated digits");
+        let day: u32 = value[6..8].parse().expect("Validated digits");
+        if day > COORDINATION_DAY_OFFSET {
+            (year, month, day - COORDINATION_DAY_OFFSET)
+        } else {
+            (year, month, day)
+        }
+    }
+}
+
+/// A validated personal identity or coordination number, stored as the 12 digits of its full
+/// form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Personnummer(String);
+
+impl Personnummer {
+    /// Parses and validates the given number, resolving the century of a short form number
+    /// against the current year.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        Self::parse_at(value, current_year() as u16)
+    }
+
+    /// Parses and validates the given number, resolving the century of a short form number
+    /// against the given reference year.
+    pub fn parse_at(value: &str, reference_year: u16) -> Result<Self, &'static str> {
+        SwedenPersonnummerValidator::normalize(value, reference_year).map(Personnummer)
+    }
+
+    /// Returns the 12 digits of the full form.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether this is a personal identity or a coordination number.
+    pub fn kind(&self) -> PersonnummerKind {
+        let day: u32 = self.0[6..8].parse().expect("Validated digits");
+        if day > COORDINATION_DAY_OFFSET {
+            PersonnummerKind::Coordination
+        } else {
+            PersonnummerKind::Personal
+        }
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        SwedenPersonnummerValidator::birth_date(&self.0).0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        SwedenPersonnummerValidator::birth_date(&self.0).1 as u8
+    }
+
+    /// Returns the day of birth, without the coordination offset.
+    pub fn birth_day(&self) -> u8 {
+        SwedenPersonnummerValidator::birth_date(&self.0).2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if (self.0.as_bytes()[10] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[11] - b'0'
+    }
+}
+
+impl FromStr for Personnummer {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Personnummer {
+    /// Formats the number in its full form, as in `19811218-9876`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..8], &self.0[8..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_century() {
+        assert_eq!(
+            SwedenPersonnummerValidator::century("8112189876", 2026, false),
+            19
+        );
+        assert_eq!(
+            SwedenPersonnummerValidator::century("0002291235", 2026, false),
+            20
+        );
+        assert_eq!(
+            SwedenPersonnummerValidator::century("0002291235", 2026, true),
+            19
+        );
+        assert_eq!(
+            SwedenPersonnummerValidator::century("2602291235", 2026, false),
+            20
+        );
+    }
+}
@@ -0,0 +1,160 @@
+/// Switzerland AHV Number Validator
+///
+/// This module provides functionality to validate and parse the social insurance number
+/// (AHV-Nummer, numéro AVS, numero AVS), the 13-digit identifier issued by the Central
+/// Compensation Office since 2008. It is conventionally written in groups separated by dots,
+/// as in `756.9217.0769.85`.
+///
+/// Every number starts with `756`, the ISO 3166-1 numeric code of Switzerland, followed by
+/// 9 random digits and an EAN-13 check digit: the first 12 digits are weighted alternately by
+/// 1 and 3, and the check digit brings the sum to a multiple of 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::switzerland::{Ahv, SwitzerlandAhvValidator};
+///
+/// assert!(SwitzerlandAhvValidator::is_valid("756.9217.0769.85").is_ok());
+/// assert!(SwitzerlandAhvValidator::is_valid("7569217076986").is_err());
+///
+/// let ahv = Ahv::parse("7569217076985").unwrap();
+/// assert_eq!(ahv.check_digit(), 5);
+/// assert_eq!(ahv.to_string(), "756.9217.0769.85");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an AHV number. Removes spaces and dots, checks the length,
+///   format and country prefix, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct SwitzerlandAhvValidator;
+
+const AHV_LENGTH: usize = 13;
+const COUNTRY_PREFIX: &str = "756";
+
+impl SwitzerlandAhvValidator {
+    /// Validates if the given AHV number is correct. The input can be a plain string or
+    /// grouped with dots or spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != AHV_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if !sanitized_value.starts_with(COUNTRY_PREFIX) {
+            return Err("Invalid prefix");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[..12]);
+        if sanitized_value[12..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate AHV numbers using the SwitzerlandAhvValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace and dots from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '.')
+            .collect()
+    }
+
+    /// Calculates the EAN-13 check digit of the first 12 digits of an AHV number.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let digit = c.to_digit(10).expect("Invalid digit in input");
+                if i % 2 == 0 {
+                    digit
+                } else {
+                    digit * 3
+                }
+            })
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated AHV number, stored as its 13 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ahv(String);
+
+impl Ahv {
+    /// Parses and validates the given AHV number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        SwitzerlandAhvValidator::is_valid(value)?;
+        Ok(Ahv(SwitzerlandAhvValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 13 digits of the AHV number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[12] - b'0'
+    }
+}
+
+impl FromStr for Ahv {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ahv {
+    /// Formats the AHV number with dots, as in `756.9217.0769.85`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}.{}",
+            &self.0[..3],
+            &self.0[3..7],
+            &self.0[7..11],
+            &self.0[11..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            SwitzerlandAhvValidator::calculate_check_digit("756921707698"),
+            5
+        );
+        assert_eq!(
+            SwitzerlandAhvValidator::calculate_check_digit("756000000000"),
+            2
+        );
+    }
+}
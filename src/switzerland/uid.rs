@@ -0,0 +1,234 @@
+/// Switzerland UID Validator
+///
+/// This module provides functionality to validate and parse the business identification
+/// number (Unternehmens-Identifikationsnummer, UID), assigned by the Federal Statistical
+/// Office to every enterprise active in Switzerland. It is made of the `CHE` prefix and 9
+/// digits, written as in `CHE-116.281.710`.
+///
+/// The first 8 digits are weighted by 5, 4, 3, 2, 7, 6, 5 and 4, and the check digit is the
+/// complement of the sum modulo 11, where 11 yields 0. Numbers whose complement is 10 are
+/// never issued, and are rejected.
+///
+/// Enterprises registered for VAT use their UID followed by a suffix in one of the national
+/// languages:
+///
+/// | Suffix | Language |
+/// |--------|----------|
+/// | MWST   | German   |
+/// | TVA    | French   |
+/// | IVA    | Italian  |
+///
+/// # Usage
+///
+/// ```
+/// use docval::switzerland::{SwitzerlandUidValidator, Uid, VatSuffix};
+///
+/// assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.710").is_ok());
+/// assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.710 MWST").is_ok());
+/// assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.711").is_err());
+///
+/// let uid = Uid::parse("che116281710tva").unwrap();
+/// assert_eq!(uid.number(), "116281710");
+/// assert_eq!(uid.vat_suffix(), Some(VatSuffix::Tva));
+/// assert_eq!(uid.to_string(), "CHE-116.281.710 TVA");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a UID. Removes spaces, dots and hyphens, checks the `CHE`
+///   prefix, the optional VAT suffix, the length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct SwitzerlandUidValidator;
+
+/// The suffixes that mark a UID as a VAT number, one per national language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VatSuffix {
+    /// Mehrwertsteuer, in German (MWST).
+    Mwst,
+    /// Taxe sur la valeur ajoutée, in French (TVA).
+    Tva,
+    /// Imposta sul valore aggiunto, in Italian (IVA).
+    Iva,
+}
+
+impl VatSuffix {
+    /// Returns the VAT suffix with the given code, if any.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "MWST" => Some(VatSuffix::Mwst),
+            "TVA" => Some(VatSuffix::Tva),
+            "IVA" => Some(VatSuffix::Iva),
+            _ => None,
+        }
+    }
+
+    /// Returns the code of the VAT suffix.
+    pub fn code(&self) -> &'static str {
+        match self {
+            VatSuffix::Mwst => "MWST",
+            VatSuffix::Tva => "TVA",
+            VatSuffix::Iva => "IVA",
+        }
+    }
+}
+
+const COUNTRY_PREFIX: &str = "CHE";
+const UID_LENGTH: usize = 9;
+const VAT_SUFFIXES: &[&str] = &["MWST", "TVA", "IVA"];
+const MULTIPLIER_WEIGHTS: &[u32] = &[5, 4, 3, 2, 7, 6, 5, 4];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl SwitzerlandUidValidator {
+    /// Validates if the given UID is correct. The input can be a plain or formatted string,
+    /// optionally followed by a VAT suffix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let (number, _) = Self::split(&sanitized_value)?;
+        if number.len() != UID_LENGTH {
+            return Err("Invalid length");
+        }
+        if !number.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&number[..8]) {
+            Some(check_digit) if number[8..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate UIDs using the SwitzerlandUidValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and converts it to uppercase.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Splits a sanitized UID into its number, without the `CHE` prefix, and its VAT suffix.
+    fn split(value: &str) -> Result<(&str, Option<VatSuffix>), &'static str> {
+        let number = value.strip_prefix(COUNTRY_PREFIX).ok_or("Invalid prefix")?;
+        for suffix in VAT_SUFFIXES {
+            if let Some(number) = number.strip_suffix(suffix) {
+                return Ok((number, VatSuffix::from_code(suffix)));
+            }
+        }
+        Ok((number, None))
+    }
+
+    /// Calculates the check digit of the first 8 digits of a UID, or `None` if it would be
+    /// 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => None,
+            11 => Some(0),
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated UID, stored with its `CHE` prefix and VAT suffix, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Uid(String);
+
+impl Uid {
+    /// Parses and validates the given UID, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        SwitzerlandUidValidator::is_valid(value)?;
+        Ok(Uid(SwitzerlandUidValidator::sanitize_input(value)))
+    }
+
+    /// Returns the UID as in `CHE116281710` or `CHE116281710MWST`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 9 digits of the UID.
+    pub fn number(&self) -> &str {
+        SwitzerlandUidValidator::split(&self.0)
+            .expect("Validated prefix")
+            .0
+    }
+
+    /// Returns the VAT suffix, if the UID is used as a VAT number.
+    pub fn vat_suffix(&self) -> Option<VatSuffix> {
+        SwitzerlandUidValidator::split(&self.0)
+            .expect("Validated prefix")
+            .1
+    }
+}
+
+impl FromStr for Uid {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Uid {
+    /// Formats the UID as in `CHE-116.281.710`, or `CHE-116.281.710 MWST` for VAT numbers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.number();
+        write!(
+            f,
+            "{}-{}.{}.{}",
+            COUNTRY_PREFIX,
+            &number[..3],
+            &number[3..6],
+            &number[6..]
+        )?;
+        match self.vat_suffix() {
+            Some(suffix) => write!(f, " {}", suffix.code()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            SwitzerlandUidValidator::calculate_check_digit("11628171"),
+            Some(0)
+        );
+        assert_eq!(
+            SwitzerlandUidValidator::calculate_check_digit("10945651"),
+            Some(3)
+        );
+        assert_eq!(
+            SwitzerlandUidValidator::calculate_check_digit("12345678"),
+            Some(8)
+        );
+        assert_eq!(
+            SwitzerlandUidValidator::calculate_check_digit("10000016"),
+            None
+        );
+    }
+}
@@ -0,0 +1,142 @@
+/// Turkey T.C. Kimlik No Validator
+///
+/// This module provides functionality to validate and parse the T.C. Kimlik No (Türkiye
+/// Cumhuriyeti Kimlik Numarası), the 11-digit national identification number assigned to
+/// citizens of Turkey. It does not start with 0, and its last 2 digits are check digits:
+///
+/// - The 10th digit is 7 times the sum of the digits in odd positions (1st, 3rd, 5th, 7th
+///   and 9th), minus the sum of the digits in even positions (2nd, 4th, 6th and 8th),
+///   modulo 10.
+/// - The 11th digit is the sum of the first 10 digits, modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::turkey::{TcKimlikNo, TurkeyTcKimlikNoValidator};
+///
+/// assert!(TurkeyTcKimlikNoValidator::is_valid("10000000146").is_ok());
+/// assert!(TurkeyTcKimlikNoValidator::is_valid("10000000147").is_err());
+///
+/// let tc_kimlik_no = TcKimlikNo::parse("12345678950").unwrap();
+/// assert_eq!(tc_kimlik_no.check_digits(), 50);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a T.C. Kimlik No. Removes spaces, checks the length and
+///   format, and validates both check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct TurkeyTcKimlikNoValidator;
+
+const TC_KIMLIK_NO_LENGTH: usize = 11;
+const ODD_POSITION_MULTIPLIER: i32 = 7;
+const VALIDATION_MODULUS: i32 = 10;
+
+impl TurkeyTcKimlikNoValidator {
+    /// Validates if the given T.C. Kimlik No is correct. The input can be a plain string or
+    /// contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != TC_KIMLIK_NO_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) || sanitized_value.starts_with('0')
+        {
+            return Err("Invalid format");
+        }
+        if sanitized_value[9..] != Self::calculate_check_digits(&sanitized_value[..9]) {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate T.C. Kimlik Nos using the TurkeyTcKimlikNoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates the 2 check digits of the first 9 digits of a T.C. Kimlik No.
+    fn calculate_check_digits(value: &str) -> String {
+        let digits: Vec<i32> = value
+            .chars()
+            .map(|c| c.to_digit(10).expect("Invalid digit in input") as i32)
+            .collect();
+        let odd_sum: i32 = digits.iter().step_by(2).sum();
+        let even_sum: i32 = digits.iter().skip(1).step_by(2).sum();
+        let tenth_digit =
+            (ODD_POSITION_MULTIPLIER * odd_sum - even_sum).rem_euclid(VALIDATION_MODULUS);
+        let eleventh_digit = (odd_sum + even_sum + tenth_digit) % VALIDATION_MODULUS;
+        format!("{tenth_digit}{eleventh_digit}")
+    }
+}
+
+/// A validated T.C. Kimlik No, stored as its 11 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TcKimlikNo(String);
+
+impl TcKimlikNo {
+    /// Parses and validates the given T.C. Kimlik No, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        TurkeyTcKimlikNoValidator::is_valid(value)?;
+        Ok(TcKimlikNo(TurkeyTcKimlikNoValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 11 digits of the T.C. Kimlik No.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 2 check digits.
+    pub fn check_digits(&self) -> u8 {
+        self.0[9..].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for TcKimlikNo {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for TcKimlikNo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digits() {
+        assert_eq!(
+            TurkeyTcKimlikNoValidator::calculate_check_digits("100000001"),
+            "46"
+        );
+        assert_eq!(
+            TurkeyTcKimlikNoValidator::calculate_check_digits("987654321"),
+            "50"
+        );
+    }
+}
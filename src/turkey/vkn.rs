@@ -0,0 +1,139 @@
+/// Turkey VKN Validator
+///
+/// This module provides functionality to validate and parse the VKN (Vergi Kimlik
+/// Numarası), the 10-digit tax identification number assigned by the Turkish Revenue
+/// Administration to companies and other legal entities.
+///
+/// The check digit is calculated from the first 9 digits, numbered from 1 starting at the
+/// rightmost one. Each digit is rotated by adding its position modulo 10; a non-zero result
+/// is multiplied by 2 raised to the position, and reduced modulo 9, with 0 taken as 9. The
+/// check digit is the complement to 10 of the sum of these values, modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::turkey::{TurkeyVknValidator, Vkn};
+///
+/// assert!(TurkeyVknValidator::is_valid("4540536920").is_ok());
+/// assert!(TurkeyVknValidator::is_valid("4540536921").is_err());
+///
+/// let vkn = Vkn::parse("454 053 692 0").unwrap();
+/// assert_eq!(vkn.as_str(), "4540536920");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a VKN. Removes spaces, checks the length and format, and
+///   validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct TurkeyVknValidator;
+
+const VKN_LENGTH: usize = 10;
+
+impl TurkeyVknValidator {
+    /// Validates if the given VKN is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != VKN_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value[9..] != Self::calculate_check_digit(&sanitized_value[..9]).to_string() {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate VKNs using the TurkeyVknValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates the check digit of the first 9 digits of a VKN.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .rev()
+            .zip(1..)
+            .map(|(c, position)| {
+                match (c.to_digit(10).expect("Invalid digit in input") + position) % 10 {
+                    0 => 0,
+                    rotated => match rotated * 2u32.pow(position) % 9 {
+                        0 => 9,
+                        reduced => reduced,
+                    },
+                }
+            })
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated VKN, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Vkn(String);
+
+impl Vkn {
+    /// Parses and validates the given VKN, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        TurkeyVknValidator::is_valid(value)?;
+        Ok(Vkn(TurkeyVknValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the VKN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+}
+
+impl FromStr for Vkn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Vkn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(TurkeyVknValidator::calculate_check_digit("454053692"), 0);
+        assert_eq!(TurkeyVknValidator::calculate_check_digit("987654321"), 7);
+        assert_eq!(TurkeyVknValidator::calculate_check_digit("999999999"), 4);
+    }
+}
@@ -0,0 +1,368 @@
+/// United Kingdom Company Number Validator
+///
+/// This module provides functionality to validate and parse the company registration
+/// number assigned by Companies House. Its canonical form has 8 characters: either 8
+/// digits, for companies registered in England and Wales, or a 2-letter prefix followed by
+/// 6 digits. Numbers are often quoted without their leading zeros, as in `SC12345`, and are
+/// padded back to `SC012345`. The prefix identifies the register and the type of entity:
+///
+/// | Entity type                          | England and Wales | Scotland | Northern Ireland |
+/// |--------------------------------------|-------------------|----------|------------------|
+/// | Company                              | (none)            | SC       | NI               |
+/// | Limited liability partnership        | OC                | SO       | NC               |
+/// | Limited partnership                  | LP                | SL       | NL               |
+/// | Overseas company                     | FC                | SF       | NF               |
+/// | Royal charter body                   | RC                | SR       | NR               |
+/// | Industrial and provident society     | IP                | SP       | NP               |
+/// | Assurance company                    | AC                | SA       | NA               |
+/// | Charitable incorporated organisation | CE                | CS       |                  |
+/// | European economic interest grouping  | GE                | GS       | GN               |
+/// | Unregistered company                 | ZC                | SZ       |                  |
+/// | Societas Europaea                    | SE                |          |                  |
+///
+/// There is no check digit, so only the format and the prefix are validated.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uk::{CompanyNumber, EntityType, Jurisdiction, UkCompanyNumberValidator};
+///
+/// assert!(UkCompanyNumberValidator::is_valid("00445790").is_ok());
+/// assert!(UkCompanyNumberValidator::is_valid("XX123456").is_err());
+///
+/// let company_number = CompanyNumber::parse("sc12345").unwrap();
+/// assert_eq!(company_number.as_str(), "SC012345");
+/// assert_eq!(company_number.jurisdiction(), Jurisdiction::Scotland);
+/// assert_eq!(company_number.entity_type(), EntityType::Company);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a company number. Removes spaces, checks the prefix and
+///   the number of digits, and rejects numbers made only of zeros.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UkCompanyNumberValidator;
+
+/// The registers kept by Companies House.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Jurisdiction {
+    /// England and Wales, registered in Cardiff.
+    EnglandAndWales,
+    /// Scotland, registered in Edinburgh.
+    Scotland,
+    /// Northern Ireland, registered in Belfast.
+    NorthernIreland,
+}
+
+impl Jurisdiction {
+    /// Returns the jurisdiction of company numbers with the given prefix, if any. Numbers
+    /// without a prefix are identified by an empty string.
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        Self::lookup(prefix).map(|(jurisdiction, _)| jurisdiction)
+    }
+
+    /// Returns the jurisdiction and entity type registered under the given prefix.
+    fn lookup(prefix: &str) -> Option<(Self, EntityType)> {
+        PREFIXES
+            .iter()
+            .find(|(p, _, _)| *p == prefix)
+            .map(|&(_, jurisdiction, entity_type)| (jurisdiction, entity_type))
+    }
+}
+
+/// The types of entity registered by Companies House.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityType {
+    /// Limited or unlimited company (no prefix, SC or NI).
+    Company,
+    /// Limited liability partnership (OC, SO or NC).
+    LimitedLiabilityPartnership,
+    /// Limited partnership (LP, SL or NL).
+    LimitedPartnership,
+    /// Overseas company with a UK establishment (FC, SF or NF).
+    OverseasCompany,
+    /// Body incorporated by royal charter (RC, SR or NR).
+    RoyalCharter,
+    /// Industrial and provident society (IP, SP or NP).
+    IndustrialAndProvidentSociety,
+    /// Assurance company (AC, SA or NA).
+    AssuranceCompany,
+    /// Charitable incorporated organisation (CE or CS).
+    CharitableIncorporatedOrganisation,
+    /// European economic interest grouping (GE, GS or GN).
+    EuropeanEconomicInterestGrouping,
+    /// Unregistered company (ZC or SZ).
+    UnregisteredCompany,
+    /// Societas Europaea (SE).
+    SocietasEuropaea,
+}
+
+impl EntityType {
+    /// Returns the entity type of company numbers with the given prefix, if any. Numbers
+    /// without a prefix are identified by an empty string.
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        Jurisdiction::lookup(prefix).map(|(_, entity_type)| entity_type)
+    }
+}
+
+const COMPANY_NUMBER_LENGTH: usize = 8;
+const PREFIX_LENGTH: usize = 2;
+const PREFIXES: &[(&str, Jurisdiction, EntityType)] = &[
+    ("", Jurisdiction::EnglandAndWales, EntityType::Company),
+    ("SC", Jurisdiction::Scotland, EntityType::Company),
+    ("NI", Jurisdiction::NorthernIreland, EntityType::Company),
+    (
+        "OC",
+        Jurisdiction::EnglandAndWales,
+        EntityType::LimitedLiabilityPartnership,
+    ),
+    (
+        "SO",
+        Jurisdiction::Scotland,
+        EntityType::LimitedLiabilityPartnership,
+    ),
+    (
+        "NC",
+        Jurisdiction::NorthernIreland,
+        EntityType::LimitedLiabilityPartnership,
+    ),
+    (
+        "LP",
+        Jurisdiction::EnglandAndWales,
+        EntityType::LimitedPartnership,
+    ),
+    ("SL", Jurisdiction::Scotland, EntityType::LimitedPartnership),
+    (
+        "NL",
+        Jurisdiction::NorthernIreland,
+        EntityType::LimitedPartnership,
+    ),
+    (
+        "FC",
+        Jurisdiction::EnglandAndWales,
+        EntityType::OverseasCompany,
+    ),
+    ("SF", Jurisdiction::Scotland, EntityType::OverseasCompany),
+    (
+        "NF",
+        Jurisdiction::NorthernIreland,
+        EntityType::OverseasCompany,
+    ),
+    (
+        "RC",
+        Jurisdiction::EnglandAndWales,
+        EntityType::RoyalCharter,
+    ),
+    ("SR", Jurisdiction::Scotland, EntityType::RoyalCharter),
+    (
+        "NR",
+        Jurisdiction::NorthernIreland,
+        EntityType::RoyalCharter,
+    ),
+    (
+        "IP",
+        Jurisdiction::EnglandAndWales,
+        EntityType::IndustrialAndProvidentSociety,
+    ),
+    (
+        "SP",
+        Jurisdiction::Scotland,
+        EntityType::IndustrialAndProvidentSociety,
+    ),
+    (
+        "NP",
+        Jurisdiction::NorthernIreland,
+        EntityType::IndustrialAndProvidentSociety,
+    ),
+    (
+        "AC",
+        Jurisdiction::EnglandAndWales,
+        EntityType::AssuranceCompany,
+    ),
+    ("SA", Jurisdiction::Scotland, EntityType::AssuranceCompany),
+    (
+        "NA",
+        Jurisdiction::NorthernIreland,
+        EntityType::AssuranceCompany,
+    ),
+    (
+        "CE",
+        Jurisdiction::EnglandAndWales,
+        EntityType::CharitableIncorporatedOrganisation,
+    ),
+    (
+        "CS",
+        Jurisdiction::Scotland,
+        EntityType::CharitableIncorporatedOrganisation,
+    ),
+    (
+        "GE",
+        Jurisdiction::EnglandAndWales,
+        EntityType::EuropeanEconomicInterestGrouping,
+    ),
+    (
+        "GS",
+        Jurisdiction::Scotland,
+        EntityType::EuropeanEconomicInterestGrouping,
+    ),
+    (
+        "GN",
+        Jurisdiction::NorthernIreland,
+        EntityType::EuropeanEconomicInterestGrouping,
+    ),
+    (
+        "ZC",
+        Jurisdiction::EnglandAndWales,
+        EntityType::UnregisteredCompany,
+    ),
+    (
+        "SZ",
+        Jurisdiction::Scotland,
+        EntityType::UnregisteredCompany,
+    ),
+    (
+        "SE",
+        Jurisdiction::EnglandAndWales,
+        EntityType::SocietasEuropaea,
+    ),
+];
+
+impl UkCompanyNumberValidator {
+    /// Validates if the given company number is correct. Letters are accepted in any case,
+    /// and leading zeros may be omitted.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::canonicalize(value).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate company numbers using the UkCompanyNumberValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Validates the given company number and returns it in its canonical 8-character
+    /// form, padding the digits with leading zeros.
+    fn canonicalize(value: &str) -> Result<String, &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        let prefix_length = sanitized_value
+            .chars()
+            .take_while(|c| c.is_ascii_uppercase())
+            .count();
+        if prefix_length != 0 && prefix_length != PREFIX_LENGTH {
+            return Err("Invalid format");
+        }
+        let (prefix, digits) = sanitized_value.split_at(prefix_length);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if prefix.len() + digits.len() > COMPANY_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        if Jurisdiction::lookup(prefix).is_none() {
+            return Err("Invalid prefix");
+        }
+        if digits.chars().all(|c| c == '0') {
+            return Err("Invalid format");
+        }
+        let width = COMPANY_NUMBER_LENGTH - prefix.len();
+        Ok(format!("{}{:0>width$}", prefix, digits, width = width))
+    }
+}
+
+/// A validated company number, stored in its canonical 8-character form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompanyNumber(String);
+
+impl CompanyNumber {
+    /// Parses and validates the given company number, padding it with leading zeros.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkCompanyNumberValidator::canonicalize(value).map(CompanyNumber)
+    }
+
+    /// Returns the 8 characters of the company number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the 2-letter prefix, if any.
+    pub fn prefix(&self) -> Option<&str> {
+        let prefix = &self.0[..PREFIX_LENGTH];
+        if prefix.chars().all(|c| c.is_ascii_uppercase()) {
+            Some(prefix)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sequential number, without the prefix.
+    pub fn number(&self) -> u32 {
+        let digits = &self.0[self.prefix().map_or(0, str::len)..];
+        digits.parse().expect("Validated digits")
+    }
+
+    /// Returns the register in which the entity is incorporated.
+    pub fn jurisdiction(&self) -> Jurisdiction {
+        Jurisdiction::from_prefix(self.prefix().unwrap_or_default()).expect("Validated prefix")
+    }
+
+    /// Returns the type of entity.
+    pub fn entity_type(&self) -> EntityType {
+        EntityType::from_prefix(self.prefix().unwrap_or_default()).expect("Validated prefix")
+    }
+}
+
+impl FromStr for CompanyNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for CompanyNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(
+            UkCompanyNumberValidator::canonicalize("445790"),
+            Ok("00445790".to_string())
+        );
+        assert_eq!(
+            UkCompanyNumberValidator::canonicalize("oc 1"),
+            Ok("OC000001".to_string())
+        );
+        assert_eq!(
+            UkCompanyNumberValidator::canonicalize("NI000001"),
+            Ok("NI000001".to_string())
+        );
+    }
+}
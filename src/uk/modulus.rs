@@ -0,0 +1,424 @@
+/// United Kingdom Bank Account Validator
+///
+/// This module provides functionality to pre-validate UK sort codes and account numbers
+/// following the Vocalink modulus checking specification, which banks publish so that
+/// payments to mistyped accounts can be rejected before submission. The 6-digit sort code
+/// and the 8-digit account number form 14 digits, labelled `uvwxyz abcdefgh`, which are
+/// multiplied by the weights of the rules registered for the sort code:
+///
+/// | Algorithm | Check                                                             |
+/// |-----------|-------------------------------------------------------------------|
+/// | `MOD10`   | The weighted sum is a multiple of 10                              |
+/// | `MOD11`   | The weighted sum is a multiple of 11                              |
+/// | `DBLAL`   | The sum of the digits of the products is a multiple of 10         |
+///
+/// A sort code has up to two rules, which must both pass, and each rule may carry one of
+/// the 14 exceptions of the specification: adding 27 to the total (1), alternative weights
+/// (2, 7 and 10), skipping the second check (3), matching the remainder (4), check digits
+/// `g` and `h` (5), foreign currency accounts (6), sort code substitutions (5, 8 and 9),
+/// accepting either check (10 and 11, 12 and 13), and shifting 7-digit accounts (14).
+/// Sort codes without rules cannot be checked, and are rejected with an `Unknown sort code`
+/// error, so that callers can tell them apart from accounts that fail the check.
+///
+/// The rules come from Vocalink's `valacdos.txt` weight table and `scsubtab.txt` sort code
+/// substitution table, which are updated several times a year and are distributed by
+/// Vocalink rather than with this crate. The bundled tables only cover the sort codes of the
+/// specification's worked examples; load the current files with `ModulusTable::parse` to
+/// check arbitrary accounts.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uk::{ModulusTable, UkBankAccountValidator};
+///
+/// assert!(UkBankAccountValidator::is_valid("08-99-99", "66374958").is_ok());
+/// assert!(UkBankAccountValidator::is_valid("08-99-99", "66374959").is_err());
+/// assert_eq!(
+///     UkBankAccountValidator::is_valid("40-00-00", "12345678"),
+///     Err("Unknown sort code")
+/// );
+///
+/// let table = ModulusTable::parse(
+///     "089999 089999 MOD10    0    0    0    0    0    0    7    1    3    7    1    3    7    1",
+///     "",
+/// )
+/// .unwrap();
+/// assert!(UkBankAccountValidator::is_valid_with(&table, "089999", "66374958").is_ok());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(sort_code: &str, account_number: &str) -> Result<(), &'static str>`:
+///   Validates a sort code and account number against the bundled tables.
+///
+/// - `is_valid_with(table: &ModulusTable, sort_code: &str, account_number: &str) -> Result<(), &'static str>`:
+///   Validates a sort code and account number against the given tables.
+use std::sync::OnceLock;
+
+pub struct UkBankAccountValidator;
+
+const SORT_CODE_LENGTH: usize = 6;
+const ACCOUNT_NUMBER_LENGTH: usize = 8;
+const ACCOUNT_NUMBER_MIN_LENGTH: usize = 6;
+const WEIGHTS_LENGTH: usize = SORT_CODE_LENGTH + ACCOUNT_NUMBER_LENGTH;
+const BUNDLED_WEIGHTS: &str = include_str!("valacdos.txt");
+const BUNDLED_SUBSTITUTIONS: &str = include_str!("scsubtab.txt");
+
+// Positions of the account number digits within the 14 weighted digits.
+const A: usize = 6;
+const B: usize = 7;
+const C: usize = 8;
+const G: usize = 12;
+const H: usize = 13;
+
+const EXCEPTION_1_ADDITION: u32 = 27;
+const EXCEPTION_2_WEIGHTS: [u32; WEIGHTS_LENGTH] = [0, 0, 1, 2, 5, 3, 6, 4, 8, 7, 10, 9, 3, 1];
+const EXCEPTION_2_WEIGHTS_G_9: [u32; WEIGHTS_LENGTH] = [0, 0, 0, 0, 0, 0, 0, 0, 8, 7, 10, 9, 3, 1];
+const EXCEPTION_8_SORT_CODE: &str = "090126";
+const EXCEPTION_9_SORT_CODE: &str = "309634";
+
+/// The algorithms of the modulus checking specification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModulusAlgorithm {
+    /// Weighted sum modulo 10 (`MOD10`).
+    Mod10,
+    /// Weighted sum modulo 11 (`MOD11`).
+    Mod11,
+    /// Sum of the digits of the products modulo 10 (`DBLAL`).
+    DoubleAlternate,
+}
+
+impl ModulusAlgorithm {
+    /// Returns the algorithm with the given name in the weight table, if any.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "MOD10" => Some(ModulusAlgorithm::Mod10),
+            "MOD11" => Some(ModulusAlgorithm::Mod11),
+            "DBLAL" => Some(ModulusAlgorithm::DoubleAlternate),
+            _ => None,
+        }
+    }
+
+    fn modulus(&self) -> u32 {
+        match self {
+            ModulusAlgorithm::Mod11 => 11,
+            ModulusAlgorithm::Mod10 | ModulusAlgorithm::DoubleAlternate => 10,
+        }
+    }
+}
+
+/// A row of the weight table, applying to a range of sort codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ModulusRule {
+    start: u32,
+    end: u32,
+    algorithm: ModulusAlgorithm,
+    weights: [u32; WEIGHTS_LENGTH],
+    exception: Option<u8>,
+}
+
+/// The Vocalink weight and sort code substitution tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModulusTable {
+    rules: Vec<ModulusRule>,
+    substitutions: Vec<(u32, u32)>,
+}
+
+impl ModulusTable {
+    /// Returns the tables bundled with the crate.
+    pub fn bundled() -> &'static ModulusTable {
+        static BUNDLED: OnceLock<ModulusTable> = OnceLock::new();
+        BUNDLED.get_or_init(|| {
+            ModulusTable::parse(BUNDLED_WEIGHTS, BUNDLED_SUBSTITUTIONS)
+                .expect("Valid bundled tables")
+        })
+    }
+
+    /// Parses the contents of the `valacdos.txt` weight table and the `scsubtab.txt` sort
+    /// code substitution table, as published by Vocalink. Blank lines are ignored.
+    pub fn parse(weights: &str, substitutions: &str) -> Result<Self, &'static str> {
+        let rules = weights
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Self::parse_rule)
+            .collect::<Result<Vec<_>, _>>()?;
+        let substitutions = substitutions
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(
+                |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                    [original, substitute] => Ok((
+                        Self::parse_sort_code(original)?,
+                        Self::parse_sort_code(substitute)?,
+                    )),
+                    _ => Err("Invalid table"),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ModulusTable {
+            rules,
+            substitutions,
+        })
+    }
+
+    /// Parses a row of the weight table: the first and last sort codes of the range, the
+    /// algorithm, the 14 weights, and an optional exception.
+    fn parse_rule(line: &str) -> Result<ModulusRule, &'static str> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != 3 + WEIGHTS_LENGTH && fields.len() != 4 + WEIGHTS_LENGTH {
+            return Err("Invalid table");
+        }
+        let start = Self::parse_sort_code(fields[0])?;
+        let end = Self::parse_sort_code(fields[1])?;
+        let algorithm = ModulusAlgorithm::from_name(fields[2]).ok_or("Invalid table")?;
+        let mut weights = [0; WEIGHTS_LENGTH];
+        for (weight, field) in weights.iter_mut().zip(&fields[3..]) {
+            *weight = field.parse().map_err(|_| "Invalid table")?;
+        }
+        let exception = match fields.get(3 + WEIGHTS_LENGTH) {
+            Some(field) => match field.parse() {
+                Ok(exception @ 1..=14) => Some(exception),
+                _ => return Err("Invalid table"),
+            },
+            None => None,
+        };
+        Ok(ModulusRule {
+            start,
+            end,
+            algorithm,
+            weights,
+            exception,
+        })
+    }
+
+    fn parse_sort_code(value: &str) -> Result<u32, &'static str> {
+        if value.len() != SORT_CODE_LENGTH || !value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid table");
+        }
+        Ok(value.parse().expect("Validated digits"))
+    }
+
+    /// Returns the rules applying to the given sort code, in table order.
+    fn rules(&self, sort_code: u32) -> Vec<&ModulusRule> {
+        self.rules
+            .iter()
+            .filter(|rule| (rule.start..=rule.end).contains(&sort_code))
+            .collect()
+    }
+
+    /// Returns the sort code to use in place of the given one for exception 5.
+    fn substitute(&self, sort_code: u32) -> Option<u32> {
+        self.substitutions
+            .iter()
+            .find(|(original, _)| *original == sort_code)
+            .map(|&(_, substitute)| substitute)
+    }
+}
+
+impl UkBankAccountValidator {
+    /// Validates if the given account number is correct for the given sort code, using the
+    /// bundled tables. The sort code can be formatted as in `08-99-99`, and account numbers
+    /// with 6 or 7 digits are padded with zeros on the left.
+    pub fn is_valid(sort_code: &str, account_number: &str) -> Result<(), &'static str> {
+        Self::is_valid_with(ModulusTable::bundled(), sort_code, account_number)
+    }
+
+    /// Validates if the given account number is correct for the given sort code, using the
+    /// given tables.
+    pub fn is_valid_with(
+        table: &ModulusTable,
+        sort_code: &str,
+        account_number: &str,
+    ) -> Result<(), &'static str> {
+        let sort_code = Self::sanitize_input(sort_code);
+        let account_number = Self::sanitize_input(account_number);
+        if sort_code.is_empty() || account_number.is_empty() {
+            return Err("Invalid input");
+        }
+        if sort_code.len() != SORT_CODE_LENGTH
+            || !(ACCOUNT_NUMBER_MIN_LENGTH..=ACCOUNT_NUMBER_LENGTH).contains(&account_number.len())
+        {
+            return Err("Invalid length");
+        }
+        if !sort_code.chars().all(|c| c.is_ascii_digit())
+            || !account_number.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err("Invalid format");
+        }
+        let digits = Self::digits(&format!(
+            "{}{:0>width$}",
+            sort_code,
+            account_number,
+            width = ACCOUNT_NUMBER_LENGTH
+        ));
+        Self::check(table, &digits)
+    }
+
+    /// Removes spaces and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    fn digits(value: &str) -> [u32; WEIGHTS_LENGTH] {
+        let mut digits = [0; WEIGHTS_LENGTH];
+        for (digit, c) in digits.iter_mut().zip(value.chars()) {
+            *digit = c.to_digit(10).expect("Invalid digit in input");
+        }
+        digits
+    }
+
+    /// Runs the checks of the rules registered for the sort code in the given digits.
+    fn check(table: &ModulusTable, digits: &[u32; WEIGHTS_LENGTH]) -> Result<(), &'static str> {
+        let sort_code = digits[..SORT_CODE_LENGTH]
+            .iter()
+            .fold(0, |number, digit| number * 10 + digit);
+        let rules = table.rules(sort_code);
+        let is_foreign_currency = (4..=8).contains(&digits[A]) && digits[G] == digits[H];
+        if is_foreign_currency && rules.iter().any(|rule| rule.exception == Some(6)) {
+            return Ok(());
+        }
+        let is_valid = match rules[..] {
+            [] => return Err("Unknown sort code"),
+            [rule] => Self::check_rule(table, rule, digits),
+            [first, second, ..] => match (first.exception, second.exception) {
+                (Some(2), Some(9)) => {
+                    Self::check_rule(table, first, digits)
+                        || Self::check_rule(
+                            table,
+                            second,
+                            &Self::substituted(digits, EXCEPTION_9_SORT_CODE),
+                        )
+                }
+                (Some(10), Some(11)) | (Some(12), Some(13)) => {
+                    Self::check_rule(table, first, digits)
+                        || Self::check_rule(table, second, digits)
+                }
+                _ => {
+                    Self::check_rule(table, first, digits)
+                        && Self::check_rule(table, second, digits)
+                }
+            },
+        };
+        if is_valid {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    /// Runs the check of a single rule, applying its exception.
+    fn check_rule(
+        table: &ModulusTable,
+        rule: &ModulusRule,
+        digits: &[u32; WEIGHTS_LENGTH],
+    ) -> bool {
+        let mut digits = *digits;
+        let mut weights = rule.weights;
+        match rule.exception {
+            Some(2) if digits[A] != 0 => {
+                weights = if digits[G] == 9 {
+                    EXCEPTION_2_WEIGHTS_G_9
+                } else {
+                    EXCEPTION_2_WEIGHTS
+                };
+            }
+            Some(3) if matches!(digits[C], 6 | 9) => return true,
+            Some(5) => {
+                let sort_code = digits[..SORT_CODE_LENGTH]
+                    .iter()
+                    .fold(0, |number, digit| number * 10 + digit);
+                if let Some(substitute) = table.substitute(sort_code) {
+                    digits = Self::substituted(&digits, &format!("{:06}", substitute));
+                }
+            }
+            Some(7) if digits[G] == 9 => weights[..=B].fill(0),
+            Some(8) => digits = Self::substituted(&digits, EXCEPTION_8_SORT_CODE),
+            Some(10) if matches!((digits[A], digits[B]), (0 | 9, 9)) && digits[G] == 9 => {
+                weights[..=B].fill(0)
+            }
+            _ => {}
+        }
+        if Self::check_weights(rule, &weights, &digits) {
+            return true;
+        }
+        // Exception 14: 7-digit accounts may carry a trailing 0, 1 or 9, which is dropped
+        // before checking them again.
+        if rule.exception == Some(14) && matches!(digits[H], 0 | 1 | 9) {
+            let mut shifted = digits;
+            shifted.copy_within(A..H, B);
+            shifted[A] = 0;
+            return Self::check_weights(rule, &weights, &shifted);
+        }
+        false
+    }
+
+    /// Computes the weighted total of the digits, and checks its remainder according to the
+    /// algorithm and exception of the rule.
+    fn check_weights(
+        rule: &ModulusRule,
+        weights: &[u32; WEIGHTS_LENGTH],
+        digits: &[u32; WEIGHTS_LENGTH],
+    ) -> bool {
+        let products = digits
+            .iter()
+            .zip(weights)
+            .map(|(digit, weight)| digit * weight);
+        let mut total: u32 = match rule.algorithm {
+            ModulusAlgorithm::DoubleAlternate => products.map(|p| p / 10 + p % 10).sum(),
+            ModulusAlgorithm::Mod10 | ModulusAlgorithm::Mod11 => products.sum(),
+        };
+        if rule.exception == Some(1) {
+            total += EXCEPTION_1_ADDITION;
+        }
+        let modulus = rule.algorithm.modulus();
+        let remainder = total % modulus;
+        match rule.exception {
+            Some(4) => remainder == digits[G] * 10 + digits[H],
+            Some(5) => {
+                let check_digit = match rule.algorithm {
+                    ModulusAlgorithm::Mod11 => digits[G],
+                    ModulusAlgorithm::Mod10 | ModulusAlgorithm::DoubleAlternate => digits[H],
+                };
+                match remainder {
+                    0 => check_digit == 0,
+                    1 if rule.algorithm == ModulusAlgorithm::Mod11 => false,
+                    _ => modulus - remainder == check_digit,
+                }
+            }
+            _ => remainder == 0,
+        }
+    }
+
+    /// Returns the digits with the sort code replaced by the given one.
+    fn substituted(digits: &[u32; WEIGHTS_LENGTH], sort_code: &str) -> [u32; WEIGHTS_LENGTH] {
+        let mut digits = *digits;
+        for (digit, c) in digits.iter_mut().zip(sort_code.chars()) {
+            *digit = c.to_digit(10).expect("Invalid digit in input");
+        }
+        digits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule() {
+        let rule = ModulusTable::parse_rule(
+            "118765 118765 DBLAL    0    0    2    1    2    1    2    1    2    1    2    1    2    1    1",
+        )
+        .unwrap();
+        assert_eq!(rule.start, 118765);
+        assert_eq!(rule.algorithm, ModulusAlgorithm::DoubleAlternate);
+        assert_eq!(rule.weights[2], 2);
+        assert_eq!(rule.exception, Some(1));
+        assert_eq!(
+            ModulusTable::parse_rule("118765 118765 DBLAL 0 0 2 1"),
+            Err("Invalid table")
+        );
+    }
+}
@@ -0,0 +1,150 @@
+/// United Kingdom NHS Number Validator
+///
+/// This module provides functionality to validate and parse the NHS number, the 10-digit
+/// patient identifier used by the National Health Service in England, Wales and the Isle of
+/// Man. It is conventionally written in groups of 3, 3 and 4 digits, as in `943 476 5919`.
+///
+/// The first 9 digits are weighted from 10 down to 2, and the check digit is the
+/// complement of the sum modulo 11, where 11 yields 0. Numbers whose complement is 10 are
+/// never issued, and are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uk::{NhsNumber, UkNhsNumberValidator};
+///
+/// assert!(UkNhsNumberValidator::is_valid("943 476 5919").is_ok());
+/// assert!(UkNhsNumberValidator::is_valid("9434765918").is_err());
+///
+/// let nhs_number = NhsNumber::parse("9434765919").unwrap();
+/// assert_eq!(nhs_number.check_digit(), 9);
+/// assert_eq!(nhs_number.to_string(), "943 476 5919");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an NHS number. Removes spaces and hyphens, checks the
+///   length and format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UkNhsNumberValidator;
+
+const NHS_NUMBER_LENGTH: usize = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[10, 9, 8, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl UkNhsNumberValidator {
+    /// Validates if the given NHS number is correct. The input can be a plain string or
+    /// grouped as `3-3-4` digits.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NHS_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..9]) {
+            Some(check_digit) if sanitized_value[9..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NHS numbers using the UkNhsNumberValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace and hyphens from the input.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect()
+    }
+
+    /// Calculates the check digit of the first 9 digits of an NHS number, or `None` if it
+    /// would be 10.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 => None,
+            11 => Some(0),
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated NHS number, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NhsNumber(String);
+
+impl NhsNumber {
+    /// Parses and validates the given NHS number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkNhsNumberValidator::is_valid(value)?;
+        Ok(NhsNumber(UkNhsNumberValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the NHS number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+}
+
+impl FromStr for NhsNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for NhsNumber {
+    /// Formats the NHS number in groups of 3, 3 and 4 digits, as in `943 476 5919`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", &self.0[..3], &self.0[3..6], &self.0[6..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            UkNhsNumberValidator::calculate_check_digit("943476591"),
+            Some(9)
+        );
+        assert_eq!(
+            UkNhsNumberValidator::calculate_check_digit("987654321"),
+            Some(0)
+        );
+        assert_eq!(
+            UkNhsNumberValidator::calculate_check_digit("123456713"),
+            None
+        );
+    }
+}
@@ -0,0 +1,173 @@
+/// United Kingdom National Insurance Number Validator
+///
+/// This module provides functionality to validate and parse the National Insurance number
+/// (NINO), issued by HMRC and the DWP, written as two prefix letters, six digits and a
+/// suffix letter, as in `QQ 12 34 56 C`. There is no check digit, but the letters are
+/// restricted:
+///
+/// - neither prefix letter can be `D`, `F`, `I`, `Q`, `U` or `V`, and the second cannot be
+///   `O`;
+/// - the prefixes `BG`, `GB`, `KN`, `NK`, `NT`, `TN` and `ZZ` are never allocated, as they
+///   are reserved for administrative use;
+/// - the suffix is `A`, `B`, `C` or `D`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uk::{Nino, UkNinoValidator};
+///
+/// assert!(UkNinoValidator::is_valid("AB 12 34 56 C").is_ok());
+/// assert!(UkNinoValidator::is_valid("GB123456A").is_err());
+///
+/// let nino = Nino::parse("ab123456c").unwrap();
+/// assert_eq!(nino.prefix(), "AB");
+/// assert_eq!(nino.to_string(), "AB 12 34 56 C");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a NINO. Removes spaces, checks the length and format,
+///   and validates the prefix and suffix letters.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UkNinoValidator;
+
+const NINO_LENGTH: usize = 9;
+const INVALID_PREFIX_LETTERS: &str = "DFIQUV";
+const INVALID_SECOND_PREFIX_LETTERS: &str = "O";
+const ADMINISTRATIVE_PREFIXES: &[&str] = &["BG", "GB", "KN", "NK", "NT", "TN", "ZZ"];
+const SUFFIX_LETTERS: &str = "ABCD";
+
+impl UkNinoValidator {
+    /// Validates if the given NINO is correct. Letters are accepted in any case, and spaces
+    /// are ignored.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NINO_LENGTH {
+            return Err("Invalid length");
+        }
+        let is_valid_format = sanitized_value.char_indices().all(|(i, c)| match i {
+            0 | 1 | 8 => c.is_ascii_uppercase(),
+            _ => c.is_ascii_digit(),
+        });
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        if !Self::is_valid_prefix(&sanitized_value[..2]) {
+            return Err("Invalid prefix");
+        }
+        if !SUFFIX_LETTERS.contains(&sanitized_value[8..]) {
+            return Err("Invalid suffix");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NINOs using the UkNinoValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and uppercases it.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Checks if the given two letters can be allocated as a prefix.
+    fn is_valid_prefix(prefix: &str) -> bool {
+        let mut letters = prefix.chars();
+        let (first, second) = (
+            letters.next().expect("Validated length"),
+            letters.next().expect("Validated length"),
+        );
+        !INVALID_PREFIX_LETTERS.contains(first)
+            && !INVALID_PREFIX_LETTERS.contains(second)
+            && !INVALID_SECOND_PREFIX_LETTERS.contains(second)
+            && !ADMINISTRATIVE_PREFIXES.contains(&prefix)
+    }
+}
+
+/// A validated National Insurance number, stored without spaces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Nino(String);
+
+impl Nino {
+    /// Parses and validates the given NINO, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkNinoValidator::is_valid(value)?;
+        Ok(Nino(UkNinoValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 characters of the NINO.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the two prefix letters.
+    pub fn prefix(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Returns the six digits.
+    pub fn number(&self) -> &str {
+        &self.0[2..8]
+    }
+
+    /// Returns the suffix letter.
+    pub fn suffix(&self) -> char {
+        char::from(self.0.as_bytes()[8])
+    }
+}
+
+impl FromStr for Nino {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Nino {
+    /// Formats the NINO in pairs, as in `AB 12 34 56 C`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            &self.0[..2],
+            &self.0[2..4],
+            &self.0[4..6],
+            &self.0[6..8],
+            &self.0[8..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_prefix() {
+        assert!(UkNinoValidator::is_valid_prefix("AB"));
+        assert!(UkNinoValidator::is_valid_prefix("OA"));
+        assert!(!UkNinoValidator::is_valid_prefix("AO"));
+        assert!(!UkNinoValidator::is_valid_prefix("DA"));
+        assert!(!UkNinoValidator::is_valid_prefix("AV"));
+        assert!(!UkNinoValidator::is_valid_prefix("TN"));
+    }
+}
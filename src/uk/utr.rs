@@ -0,0 +1,140 @@
+/// United Kingdom UTR Validator
+///
+/// This module provides functionality to validate and parse the Unique Taxpayer Reference
+/// (UTR), the 10-digit number issued by HMRC for Self Assessment and Corporation Tax, and
+/// quoted in CIS returns. It is often written in two groups, as in `19558 39661`, and
+/// sometimes followed by a `K` in HMRC correspondence.
+///
+/// Unlike most check digits, the UTR's is its leading digit: the remaining 9 digits are
+/// multiplied by the weights 6, 7, 8, 9, 10, 5, 4, 3 and 2, and the sum modulo 11 is
+/// mapped to the check digit through the table `2 1 9 8 7 6 5 4 3 2 1`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uk::{UkUtrValidator, Utr};
+///
+/// assert!(UkUtrValidator::is_valid("19558 39661").is_ok());
+/// assert!(UkUtrValidator::is_valid("1234567890").is_err());
+///
+/// let utr = Utr::parse("1955839661K").unwrap();
+/// assert_eq!(utr.check_digit(), 1);
+/// assert_eq!(utr.to_string(), "19558 39661");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a UTR. Removes spaces and a trailing `K`, checks the
+///   length and format, and validates the leading check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UkUtrValidator;
+
+const UTR_LENGTH: usize = 10;
+const WEIGHTS: [u32; 9] = [6, 7, 8, 9, 10, 5, 4, 3, 2];
+const CHECK_DIGITS: [u8; 11] = [2, 1, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+impl UkUtrValidator {
+    /// Validates if the given UTR is correct. The input can be a plain or formatted string,
+    /// optionally followed by a `K`.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != UTR_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let check_digit = Self::calculate_check_digit(&sanitized_value[1..]);
+        if sanitized_value.as_bytes()[0] - b'0' == check_digit {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate UTRs using the UkUtrValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input, and a trailing `K` if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        match value.strip_suffix(['K', 'k']) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Calculates the leading check digit from the 9 digits that follow it.
+    fn calculate_check_digit(digits: &str) -> u8 {
+        let sum: u32 = digits
+            .chars()
+            .zip(WEIGHTS.iter())
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        CHECK_DIGITS[(sum % 11) as usize]
+    }
+}
+
+/// A validated UTR, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Utr(String);
+
+impl Utr {
+    /// Parses and validates the given UTR, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkUtrValidator::is_valid(value)?;
+        Ok(Utr(UkUtrValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the UTR.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the leading check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[0] - b'0'
+    }
+}
+
+impl FromStr for Utr {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Utr {
+    /// Formats the UTR in two groups of five, as in `19558 39661`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", &self.0[..5], &self.0[5..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(UkUtrValidator::calculate_check_digit("955839661"), 1);
+        assert_eq!(UkUtrValidator::calculate_check_digit("234567890"), 2);
+        assert_eq!(UkUtrValidator::calculate_check_digit("000000000"), 2);
+    }
+}
@@ -0,0 +1,233 @@
+/// United Kingdom VAT Number Validator
+///
+/// This module provides functionality to validate and parse the VAT registration number
+/// issued by HMRC, written with the `GB` prefix. It comes in four formats:
+///
+/// | Format            | Example              | Issued to                                  |
+/// |-------------------|----------------------|--------------------------------------------|
+/// | 9 digits          | `GB 980 7806 84`     | Standard registrations                     |
+/// | 12 digits         | `GB 980 7806 84 001` | Branches, with a 3-digit branch identifier |
+/// | `GD` + 3 digits   | `GBGD001`            | Government departments (000 to 499)        |
+/// | `HA` + 3 digits   | `GBHA599`            | Health authorities (500 to 999)            |
+///
+/// The first 7 digits are weighted from 8 down to 2, and the weighted sum plus the last 2
+/// digits must be a multiple of 97. Numbers issued since 2010 use the "9755" variant
+/// instead, where the sum plus 55 must be a multiple of 97, that is, the sum leaves a
+/// remainder of 42; both are accepted, except for numbers starting below `100`, which
+/// predate it. The branch identifier is not checked.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uk::{UkVatValidator, VatNumberKind, VatRegistrationNumber};
+///
+/// assert!(UkVatValidator::is_valid("GB 980 7806 84").is_ok());
+/// assert!(UkVatValidator::is_valid("GBGD001").is_ok());
+///
+/// let vat = VatRegistrationNumber::parse("980780684001").unwrap();
+/// assert_eq!(vat.kind(), VatNumberKind::Branch);
+/// assert_eq!(vat.branch(), Some("001"));
+/// assert_eq!(vat.to_string(), "GB 980 7806 84 001");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a VAT number. Removes spaces, dots, hyphens and the `GB`
+///   prefix, identifies the format, and validates the check digits.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UkVatValidator;
+
+/// The formats of VAT registration numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VatNumberKind {
+    /// Standard registration, with 9 digits.
+    Standard,
+    /// Branch of a registered business, with 12 digits.
+    Branch,
+    /// Government department, with `GD` and a number below 500.
+    GovernmentDepartment,
+    /// Health authority, with `HA` and a number from 500.
+    HealthAuthority,
+}
+
+const COUNTRY_PREFIX: &str = "GB";
+const STANDARD_LENGTH: usize = 9;
+const BRANCH_LENGTH: usize = 12;
+const SPECIAL_LENGTH: usize = 5;
+const MULTIPLIER_WEIGHTS: &[u32] = &[8, 7, 6, 5, 4, 3, 2, 10, 1];
+const VALIDATION_MODULUS: u32 = 97;
+const VALID_REMAINDERS: &[u32] = &[0, 42];
+const FIRST_9755_PREFIX: u32 = 100;
+
+impl UkVatValidator {
+    /// Validates if the given VAT number is correct. The input can be a plain or formatted
+    /// string, with or without the `GB` prefix.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if !sanitized_value.is_ascii() {
+            return Err("Invalid format");
+        }
+        match Self::kind(&sanitized_value)? {
+            VatNumberKind::Standard | VatNumberKind::Branch => {
+                if Self::is_valid_checksum(&sanitized_value[..STANDARD_LENGTH]) {
+                    Ok(())
+                } else {
+                    Err("Invalid checksum")
+                }
+            }
+            VatNumberKind::GovernmentDepartment | VatNumberKind::HealthAuthority => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate VAT numbers using the UkVatValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes spaces, dots and hyphens from the input, and the `GB` prefix if present.
+    fn sanitize_input(value: &str) -> String {
+        let value: String = value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '.' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        match value.strip_prefix(COUNTRY_PREFIX) {
+            Some(number) => number.to_string(),
+            None => value,
+        }
+    }
+
+    /// Identifies the format of the given ASCII VAT number, without the `GB` prefix.
+    fn kind(value: &str) -> Result<VatNumberKind, &'static str> {
+        match value.len() {
+            STANDARD_LENGTH | BRANCH_LENGTH => {
+                if !value.chars().all(|c| c.is_ascii_digit()) {
+                    return Err("Invalid format");
+                }
+                if value.len() == STANDARD_LENGTH {
+                    Ok(VatNumberKind::Standard)
+                } else {
+                    Ok(VatNumberKind::Branch)
+                }
+            }
+            SPECIAL_LENGTH => {
+                let (prefix, digits) = value.split_at(2);
+                if !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return Err("Invalid format");
+                }
+                let number: u32 = digits.parse().expect("Validated digits");
+                match prefix {
+                    "GD" if number < 500 => Ok(VatNumberKind::GovernmentDepartment),
+                    "HA" if number >= 500 => Ok(VatNumberKind::HealthAuthority),
+                    _ => Err("Invalid format"),
+                }
+            }
+            _ => Err("Invalid length"),
+        }
+    }
+
+    /// Checks the 9 digits of a standard VAT number against both the modulus 97 and the
+    /// modulus 9755 algorithms.
+    fn is_valid_checksum(value: &str) -> bool {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        let remainder = sum % VALIDATION_MODULUS;
+        let prefix: u32 = value[..3].parse().expect("Validated digits");
+        if prefix >= FIRST_9755_PREFIX {
+            VALID_REMAINDERS.contains(&remainder)
+        } else {
+            remainder == 0
+        }
+    }
+}
+
+/// A validated VAT registration number, stored without the `GB` prefix and formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VatRegistrationNumber(String);
+
+impl VatRegistrationNumber {
+    /// Parses and validates the given VAT number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkVatValidator::is_valid(value)?;
+        Ok(VatRegistrationNumber(UkVatValidator::sanitize_input(value)))
+    }
+
+    /// Returns the VAT number, without the `GB` prefix.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the format of the VAT number.
+    pub fn kind(&self) -> VatNumberKind {
+        UkVatValidator::kind(&self.0).expect("Validated format")
+    }
+
+    /// Returns the 3-digit branch identifier, for branch numbers.
+    pub fn branch(&self) -> Option<&str> {
+        match self.kind() {
+            VatNumberKind::Branch => Some(&self.0[STANDARD_LENGTH..]),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for VatRegistrationNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for VatRegistrationNumber {
+    /// Formats the VAT number with its `GB` prefix, grouping the digits as in
+    /// `GB 980 7806 84`, or `GB 980 7806 84 001` for branches.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind() {
+            VatNumberKind::Standard | VatNumberKind::Branch => {
+                write!(
+                    f,
+                    "{} {} {} {}",
+                    COUNTRY_PREFIX,
+                    &self.0[..3],
+                    &self.0[3..7],
+                    &self.0[7..9]
+                )?;
+                match self.branch() {
+                    Some(branch) => write!(f, " {}", branch),
+                    None => Ok(()),
+                }
+            }
+            _ => write!(f, "{}{}", COUNTRY_PREFIX, self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_checksum() {
+        assert!(UkVatValidator::is_valid_checksum("980780684"));
+        assert!(UkVatValidator::is_valid_checksum("241727613"));
+        assert!(UkVatValidator::is_valid_checksum("000000097"));
+        assert!(!UkVatValidator::is_valid_checksum("123456789"));
+    }
+}
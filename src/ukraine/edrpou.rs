@@ -0,0 +1,148 @@
+/// Ukraine EDRPOU Validator
+///
+/// This module provides functionality to validate and parse the EDRPOU code (код ЄДРПОУ),
+/// the 8-digit identifier of legal entities in the Unified State Register of Enterprises
+/// and Organizations of Ukraine. Its last digit is a check digit, calculated from the
+/// first 7 digits in up to two stages:
+///
+/// 1. The digits are weighted by 1, 2, 3, 4, 5, 6 and 7, or by 7, 1, 2, 3, 4, 5 and 6 for
+///    codes starting with 3, 4 or 5, and their sum is taken modulo 11.
+/// 2. If the result is 10, each weight is increased by 2 and the sum is taken modulo 11
+///    again, with a result of 10 giving a check digit of 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::ukraine::{Edrpou, UkraineEdrpouValidator};
+///
+/// assert!(UkraineEdrpouValidator::is_valid("32855961").is_ok());
+/// assert!(UkraineEdrpouValidator::is_valid("32855962").is_err());
+///
+/// let edrpou = Edrpou::parse("00032129").unwrap();
+/// assert_eq!(edrpou.check_digit(), 9);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an EDRPOU code. Removes spaces, checks the length and
+///   format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UkraineEdrpouValidator;
+
+const EDRPOU_LENGTH: usize = 8;
+const WEIGHTS: [u32; 7] = [1, 2, 3, 4, 5, 6, 7];
+const SHIFTED_WEIGHTS: [u32; 7] = [7, 1, 2, 3, 4, 5, 6];
+const SHIFTED_WEIGHTS_PREFIXES: [char; 3] = ['3', '4', '5'];
+const SECOND_STAGE_INCREMENT: u32 = 2;
+const VALIDATION_MODULUS: u32 = 11;
+
+impl UkraineEdrpouValidator {
+    /// Validates if the given EDRPOU code is correct. The input can be a plain string or
+    /// contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != EDRPOU_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        let check_digit: u32 = sanitized_value[7..].parse().expect("Validated digits");
+        if Self::calculate_check_digit(&sanitized_value[..7]) != check_digit {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate EDRPOU codes using the UkraineEdrpouValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates the check digit of the first 7 digits of an EDRPOU code.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let weights = if value.starts_with(SHIFTED_WEIGHTS_PREFIXES) {
+            SHIFTED_WEIGHTS
+        } else {
+            WEIGHTS
+        };
+        let remainder = |increment: u32| -> u32 {
+            let sum: u32 = value
+                .chars()
+                .zip(weights)
+                .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") * (w + increment))
+                .sum();
+            sum % VALIDATION_MODULUS
+        };
+        match remainder(0) {
+            10 => remainder(SECOND_STAGE_INCREMENT) % 10,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated EDRPOU code, stored as its 8 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Edrpou(String);
+
+impl Edrpou {
+    /// Parses and validates the given EDRPOU code, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkraineEdrpouValidator::is_valid(value)?;
+        Ok(Edrpou(UkraineEdrpouValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 8 digits of the EDRPOU code.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[7] - b'0'
+    }
+}
+
+impl FromStr for Edrpou {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Edrpou {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(UkraineEdrpouValidator::calculate_check_digit("3285596"), 1);
+        assert_eq!(UkraineEdrpouValidator::calculate_check_digit("1000006"), 2);
+        assert_eq!(UkraineEdrpouValidator::calculate_check_digit("3000000"), 5);
+    }
+}
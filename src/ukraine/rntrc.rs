@@ -0,0 +1,200 @@
+/// Ukraine RNTRC Validator
+///
+/// This module provides functionality to validate and parse the RNTRC (Реєстраційний номер
+/// облікової картки платника податків), the 10-digit individual tax number assigned by the
+/// State Tax Service of Ukraine:
+///
+/// | Positions | Field                                                 |
+/// |-----------|-------------------------------------------------------|
+/// | 1-5       | Date of birth, as the number of days since 1899-12-31 |
+/// | 6-8       | Serial number                                         |
+/// | 9         | Sex digit: odd for men, even for women                |
+/// | 10        | Check digit                                           |
+///
+/// The check digit is the sum of the first 9 digits weighted by -1, 5, 7, 9, 4, 6, 10, 5
+/// and 7, modulo 11, and then modulo 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::ukraine::{Rntrc, UkraineRntrcValidator};
+/// use docval::Sex;
+///
+/// assert!(UkraineRntrcValidator::is_valid("3000000014").is_ok());
+/// assert!(UkraineRntrcValidator::is_valid("3000000015").is_err());
+///
+/// let rntrc = Rntrc::parse("1759013776").unwrap();
+/// assert_eq!(rntrc.birth_year(), 1948);
+/// assert_eq!(rntrc.birth_month(), 2);
+/// assert_eq!(rntrc.birth_day(), 28);
+/// assert_eq!(rntrc.sex(), Sex::Male);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an RNTRC. Removes spaces, checks the length, format and
+///   encoded date of birth, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::date::days_in_month;
+use crate::Sex;
+
+pub struct UkraineRntrcValidator;
+
+const RNTRC_LENGTH: usize = 10;
+const EPOCH_YEAR: u32 = 1900;
+const WEIGHTS: [i32; 9] = [-1, 5, 7, 9, 4, 6, 10, 5, 7];
+const VALIDATION_MODULUS: i32 = 11;
+
+impl UkraineRntrcValidator {
+    /// Validates if the given RNTRC is correct. The input can be a plain string or contain
+    /// spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != RNTRC_LENGTH {
+            return Err("Invalid length");
+        }
+        if !sanitized_value.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if sanitized_value.starts_with("00000") {
+            return Err("Invalid date");
+        }
+        let check_digit: i32 = sanitized_value[9..].parse().expect("Validated digits");
+        if Self::calculate_check_digit(&sanitized_value[..9]) != check_digit {
+            return Err("Invalid checksum");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RNTRCs using the UkraineRntrcValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes whitespace from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Calculates the check digit of the first 9 digits of an RNTRC.
+    fn calculate_check_digit(value: &str) -> i32 {
+        let sum: i32 = value
+            .chars()
+            .zip(WEIGHTS)
+            .map(|(c, w)| c.to_digit(10).expect("Invalid digit in input") as i32 * w)
+            .sum();
+        sum.rem_euclid(VALIDATION_MODULUS) % 10
+    }
+
+    /// Converts the number of days since 1899-12-31 to a date.
+    fn birth_date(days: u32) -> (u32, u32, u32) {
+        let mut days = days - 1;
+        let mut year = EPOCH_YEAR;
+        let mut month = 1;
+        while days >= days_in_month(year, month) {
+            days -= days_in_month(year, month);
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+        (year, month, days + 1)
+    }
+}
+
+/// A validated RNTRC, stored as its 10 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rntrc(String);
+
+impl Rntrc {
+    /// Parses and validates the given RNTRC, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UkraineRntrcValidator::is_valid(value)?;
+        Ok(Rntrc(UkraineRntrcValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the RNTRC.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        self.birth_date().0 as u16
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.birth_date().1 as u8
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.birth_date().2 as u8
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        if (self.0.as_bytes()[8] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        }
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.as_bytes()[9] - b'0'
+    }
+
+    fn birth_date(&self) -> (u32, u32, u32) {
+        UkraineRntrcValidator::birth_date(self.0[..5].parse().expect("Validated digits"))
+    }
+}
+
+impl FromStr for Rntrc {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Rntrc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birth_date() {
+        assert_eq!(UkraineRntrcValidator::birth_date(1), (1900, 1, 1));
+        assert_eq!(UkraineRntrcValidator::birth_date(17590), (1948, 2, 28));
+        assert_eq!(UkraineRntrcValidator::birth_date(45000), (2023, 3, 16));
+    }
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(UkraineRntrcValidator::calculate_check_digit("300000001"), 4);
+        assert_eq!(UkraineRntrcValidator::calculate_check_digit("123456789"), 9);
+    }
+}
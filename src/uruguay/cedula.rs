@@ -0,0 +1,154 @@
+/// Uruguay Cédula de Identidad Validator
+///
+/// This module provides functionality to validate and parse the number of the Uruguayan
+/// identity card (CI), made of up to 7 digits followed by a check digit, and formatted as
+/// `1.234.567-2`. Older numbers have fewer digits, and are padded with zeros on the left
+/// to 7 digits before calculating the check digit.
+///
+/// The padded digits are weighted with `2987634`, and the check digit is the complement
+/// to 10 of the last digit of the sum.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uruguay::{Cedula, UruguayCedulaValidator};
+///
+/// assert!(UruguayCedulaValidator::is_valid("1.234.567-2").is_ok());
+/// assert!(UruguayCedulaValidator::is_valid("123.456-1").is_ok());
+///
+/// let cedula = Cedula::parse("12345672").unwrap();
+/// assert_eq!(cedula.number(), 1234567);
+/// assert_eq!(cedula.check_digit(), 2);
+/// assert_eq!(cedula.to_string(), "1.234.567-2");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a cédula. Removes non-digit characters, checks the
+///   length, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UruguayCedulaValidator;
+
+const CEDULA_MIN_LENGTH: usize = 6;
+const CEDULA_MAX_LENGTH: usize = 8;
+const MULTIPLIER_WEIGHTS: &[u32] = &[2, 9, 8, 7, 6, 3, 4];
+
+impl UruguayCedulaValidator {
+    /// Validates if the given cédula is correct. The input can be a plain or formatted
+    /// string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() < CEDULA_MIN_LENGTH || sanitized_value.len() > CEDULA_MAX_LENGTH {
+            return Err("Invalid length");
+        }
+        let (number, check_digit) = sanitized_value.split_at(sanitized_value.len() - 1);
+        if check_digit == Self::calculate_check_digit(number).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate cédulas using the UruguayCedulaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the number, padded with zeros to 7 digits.
+    fn calculate_check_digit(number: &str) -> u32 {
+        let padded = format!("{:0>7}", number);
+        let sum: u32 = padded
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+/// A validated cédula, stored without formatting or padding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cedula(String);
+
+impl Cedula {
+    /// Parses and validates the given cédula, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UruguayCedulaValidator::is_valid(value)?;
+        Ok(Cedula(UruguayCedulaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the digits of the cédula, including the check digit.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the number of the cédula, without the check digit.
+    pub fn number(&self) -> u32 {
+        self.0[..self.0.len() - 1]
+            .parse()
+            .expect("Validated digits")
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0[self.0.len() - 1..]
+            .parse()
+            .expect("Validated digits")
+    }
+}
+
+impl FromStr for Cedula {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Cedula {
+    /// Formats the cédula as `1.234.567-2`, with the number grouped in thousands.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.number();
+        if number >= 1_000_000 {
+            write!(
+                f,
+                "{}.{:03}.{:03}",
+                number / 1_000_000,
+                number / 1_000 % 1_000,
+                number % 1_000
+            )?;
+        } else {
+            write!(f, "{}.{:03}", number / 1_000, number % 1_000)?;
+        }
+        write!(f, "-{}", self.check_digit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(UruguayCedulaValidator::calculate_check_digit("1234567"), 2);
+        assert_eq!(UruguayCedulaValidator::calculate_check_digit("123456"), 1);
+    }
+}
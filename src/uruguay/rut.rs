@@ -0,0 +1,164 @@
+/// Uruguay RUT Validator
+///
+/// This module provides functionality to validate and parse the RUT (Registro Único
+/// Tributario), the 12-digit tax identifier assigned by the DGI, formatted as
+/// `XX-XXXXXX-XXX-X`:
+///
+/// - 2 digits: registration office, from `01` to `21`;
+/// - 6 digits: sequential number, which cannot be all zeros;
+/// - 3 digits: always `001`;
+/// - 1 digit: check digit.
+///
+/// The first 11 digits are weighted with `43298765432`, and the check digit is the
+/// complement of the sum modulo 11, where 11 yields 0. A complement of 10 is never
+/// assigned.
+///
+/// # Usage
+///
+/// ```
+/// use docval::uruguay::{Rut, UruguayRutValidator};
+///
+/// assert!(UruguayRutValidator::is_valid("21-100342-001-7").is_ok());
+///
+/// let rut = Rut::parse("211003420017").unwrap();
+/// assert_eq!(rut.office(), 21);
+/// assert_eq!(rut.to_string(), "21-100342-001-7");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a RUT. Removes non-digit characters, checks the length
+///   and the structure, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UruguayRutValidator;
+
+const RUT_LENGTH: usize = 12;
+const MAX_OFFICE: u8 = 21;
+const FIXED_SUFFIX: &str = "001";
+const MULTIPLIER_WEIGHTS: &[u32] = &[4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl UruguayRutValidator {
+    /// Validates if the given RUT is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != RUT_LENGTH {
+            return Err("Invalid length");
+        }
+        let office: u8 = sanitized_value[..2].parse().expect("Validated digits");
+        if office == 0 || office > MAX_OFFICE {
+            return Err("Invalid office");
+        }
+        if sanitized_value[2..8] == *"000000" || sanitized_value[8..11] != *FIXED_SUFFIX {
+            return Err("Invalid format");
+        }
+        match Self::calculate_check_digit(&sanitized_value[..11]) {
+            Some(check_digit) if sanitized_value[11..] == check_digit.to_string() => Ok(()),
+            _ => Err("Invalid checksum"),
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RUTs using the UruguayRutValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+
+    /// Calculates the check digit of the first 11 digits, or `None` if no digit can be
+    /// assigned to them.
+    fn calculate_check_digit(value: &str) -> Option<u32> {
+        let sum: u32 = value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            11 => Some(0),
+            10 => None,
+            digit => Some(digit),
+        }
+    }
+}
+
+/// A validated RUT, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rut(String);
+
+impl Rut {
+    /// Parses and validates the given RUT, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UruguayRutValidator::is_valid(value)?;
+        Ok(Rut(UruguayRutValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 12 digits of the RUT.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the code of the registration office.
+    pub fn office(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+
+    /// Returns the sequential number.
+    pub fn number(&self) -> &str {
+        &self.0[2..8]
+    }
+}
+
+impl FromStr for Rut {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Rut {
+    /// Formats the RUT as `XX-XXXXXX-XXX-X`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}-{}",
+            &self.0[..2],
+            &self.0[2..8],
+            &self.0[8..11],
+            &self.0[11..]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            UruguayRutValidator::calculate_check_digit("21100342001"),
+            Some(7)
+        );
+        assert_eq!(
+            UruguayRutValidator::calculate_check_digit("21513890001"),
+            None
+        );
+    }
+}
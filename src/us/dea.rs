@@ -0,0 +1,147 @@
+/// United States DEA Registration Number Validator
+///
+/// This module provides functionality to validate and parse the registration numbers
+/// issued by the Drug Enforcement Administration to those who handle controlled substances,
+/// and which prescriptions for them must carry. The number has 9 characters:
+///
+/// - a letter identifying the type of registrant, such as `A`, `B`, `F` or `G` for
+///   practitioners and pharmacies, `M` for mid-level practitioners, or `X` for the
+///   buprenorphine treatment program;
+/// - the initial of the registrant's last name, or `9` for registrants identified by a
+///   business name;
+/// - 6 digits and a check digit: the sum of the 1st, 3rd and 5th digits, plus twice the
+///   sum of the 2nd, 4th and 6th, must end with the check digit.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{DeaNumber, UsDeaValidator};
+///
+/// assert!(UsDeaValidator::is_valid("AB1234563").is_ok());
+/// assert!(UsDeaValidator::is_valid("AB1234564").is_err());
+///
+/// let dea = DeaNumber::parse("ab1234563").unwrap();
+/// assert_eq!(dea.registrant_type(), 'A');
+/// assert_eq!(dea.initial(), 'B');
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a DEA number, in any case.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UsDeaValidator;
+
+const DEA_LENGTH: usize = 9;
+const REGISTRANT_TYPES: &str = "ABCDEFGHJKLMPRSTUX";
+const BUSINESS_NAME_INITIAL: char = '9';
+
+impl UsDeaValidator {
+    /// Validates if the given DEA number is correct. Letters are accepted in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.chars().count() != DEA_LENGTH {
+            return Err("Invalid length");
+        }
+        let mut chars = sanitized_value.chars();
+        let registrant_type = chars.next().expect("Validated length");
+        let initial = chars.next().expect("Validated length");
+        let digits = chars.as_str();
+        if !digits.chars().all(|c| c.is_ascii_digit())
+            || !(initial.is_ascii_uppercase() || initial == BUSINESS_NAME_INITIAL)
+        {
+            return Err("Invalid format");
+        }
+        if !REGISTRANT_TYPES.contains(registrant_type) {
+            return Err("Invalid registrant type");
+        }
+        if digits[6..] == Self::calculate_check_digit(&digits[..6]).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate DEA numbers using the UsDeaValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes surrounding whitespace and converts the input to uppercase.
+    fn sanitize_input(value: &str) -> String {
+        value.trim().to_ascii_uppercase()
+    }
+
+    /// Calculates the check digit of the given 6 digits.
+    fn calculate_check_digit(value: &str) -> u32 {
+        let sum: u32 = value
+            .chars()
+            .zip([1, 2].iter().cycle())
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        sum % 10
+    }
+}
+
+/// A validated DEA registration number, stored in uppercase.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeaNumber(String);
+
+impl DeaNumber {
+    /// Parses and validates the given DEA number.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsDeaValidator::is_valid(value)?;
+        Ok(DeaNumber(UsDeaValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 9 characters of the DEA number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the letter identifying the type of registrant.
+    pub fn registrant_type(&self) -> char {
+        self.0.as_bytes()[0] as char
+    }
+
+    /// Returns the initial of the registrant's last name, or `9` for business names.
+    pub fn initial(&self) -> char {
+        self.0.as_bytes()[1] as char
+    }
+}
+
+impl FromStr for DeaNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for DeaNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(UsDeaValidator::calculate_check_digit("123456"), 3);
+    }
+}
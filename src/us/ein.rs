@@ -0,0 +1,180 @@
+/// United States EIN Validator
+///
+/// This module provides functionality to validate and parse the Employer Identification
+/// Number assigned by the IRS to businesses, formatted as `XX-XXXXXXX`. The EIN has no
+/// check digit; its two-digit prefix identifies the IRS campus, or the online application,
+/// that assigned it, and prefixes that were never assigned are rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{Ein, EinCampus, UsEinValidator};
+///
+/// assert!(UsEinValidator::is_valid("12-3456789").is_ok());
+/// assert!(UsEinValidator::is_valid("07-3456789").is_err());
+///
+/// let ein = Ein::parse("203456789").unwrap();
+/// assert_eq!(ein.campus(), EinCampus::Internet);
+/// assert_eq!(ein.to_string(), "20-3456789");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an EIN, given as 9 digits with or without the dash.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UsEinValidator;
+
+/// The IRS campuses, and the online application, that assign EINs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EinCampus {
+    Andover,
+    Atlanta,
+    Austin,
+    Brookhaven,
+    Cincinnati,
+    Fresno,
+    /// EINs assigned online.
+    Internet,
+    KansasCity,
+    Memphis,
+    Ogden,
+    Philadelphia,
+    SmallBusinessAdministration,
+}
+
+impl EinCampus {
+    /// Returns the campus that assigns EINs with the given prefix, if any.
+    pub fn from_prefix(prefix: u8) -> Option<Self> {
+        match prefix {
+            10 | 12 => Some(EinCampus::Andover),
+            60 | 67 => Some(EinCampus::Atlanta),
+            50 | 53 => Some(EinCampus::Austin),
+            1..=6 | 11 | 13 | 14 | 16 | 21..=23 | 25 | 34 | 51 | 52 | 54..=59 | 65 => {
+                Some(EinCampus::Brookhaven)
+            }
+            30 | 32 | 35..=38 | 61 => Some(EinCampus::Cincinnati),
+            15 | 24 => Some(EinCampus::Fresno),
+            20 | 26 | 27 | 45..=47 | 81..=84 => Some(EinCampus::Internet),
+            40 | 44 => Some(EinCampus::KansasCity),
+            94 | 95 => Some(EinCampus::Memphis),
+            80 | 90 => Some(EinCampus::Ogden),
+            33 | 39 | 41..=43 | 48 | 62..=64 | 66 | 68 | 71..=77 | 85..=88 | 91..=93 | 98 | 99 => {
+                Some(EinCampus::Philadelphia)
+            }
+            31 => Some(EinCampus::SmallBusinessAdministration),
+            _ => None,
+        }
+    }
+}
+
+const EIN_LENGTH: usize = 9;
+const DASHED_EIN_LENGTH: usize = 10;
+const DASH_POSITION: usize = 2;
+
+impl UsEinValidator {
+    /// Validates if the given EIN is correct. The input can be plain (`123456789`) or dashed
+    /// (`12-3456789`).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value)?;
+        let prefix = sanitized_value[..2].parse().expect("Validated digits");
+        if EinCampus::from_prefix(prefix).is_none() {
+            return Err("Invalid prefix");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate EINs using the UsEinValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Checks the plain or dashed format, returning the 9 digits.
+    fn sanitize_input(value: &str) -> Result<String, &'static str> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("Invalid input");
+        }
+        let is_valid_format = match value.len() {
+            EIN_LENGTH => value.chars().all(|c| c.is_ascii_digit()),
+            DASHED_EIN_LENGTH => value.chars().enumerate().all(|(i, c)| {
+                if i == DASH_POSITION {
+                    c == '-'
+                } else {
+                    c.is_ascii_digit()
+                }
+            }),
+            _ => return Err("Invalid length"),
+        };
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        Ok(value.chars().filter(char::is_ascii_digit).collect())
+    }
+}
+
+/// A validated EIN, stored without the dash.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ein(String);
+
+impl Ein {
+    /// Parses and validates the given EIN, given with or without the dash.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsEinValidator::is_valid(value)?;
+        Ok(Ein(UsEinValidator::sanitize_input(value)?))
+    }
+
+    /// Returns the 9 digits of the EIN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the two-digit prefix.
+    pub fn prefix(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+
+    /// Returns the campus that assigned the EIN.
+    pub fn campus(&self) -> EinCampus {
+        EinCampus::from_prefix(self.prefix()).expect("Validated prefix")
+    }
+}
+
+impl FromStr for Ein {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ein {
+    /// Formats the EIN as `XX-XXXXXXX`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..2], &self.0[2..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_prefix() {
+        assert_eq!(
+            EinCampus::from_prefix(31),
+            Some(EinCampus::SmallBusinessAdministration)
+        );
+        assert_eq!(EinCampus::from_prefix(0), None);
+        assert_eq!(EinCampus::from_prefix(89), None);
+    }
+}
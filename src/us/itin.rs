@@ -0,0 +1,116 @@
+/// United States ITIN Validator
+///
+/// This module provides functionality to validate and parse the Individual Taxpayer
+/// Identification Number, issued by the IRS to individuals who need a taxpayer number but
+/// are not eligible for an SSN. It is formatted like an SSN, as `9XX-GG-XXXX`, and told
+/// apart from it by its area number, which always starts with 9, and by its group number,
+/// which must be in one of the ranges assigned by the IRS: `50`-`65`, `70`-`88`, `90`-`92`
+/// and `94`-`99`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{Itin, UsItinValidator};
+///
+/// assert!(UsItinValidator::is_valid("912-70-1234").is_ok());
+/// assert!(UsItinValidator::is_valid("912-89-1234").is_err());
+///
+/// let itin = Itin::parse("912701234").unwrap();
+/// assert_eq!(itin.to_string(), "912-70-1234");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an ITIN, given as 9 digits with or without dashes.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::UsSsnValidator;
+
+pub struct UsItinValidator;
+
+pub(super) const ITIN_AREA_PREFIX: char = '9';
+
+impl UsItinValidator {
+    /// Validates if the given ITIN is correct. The input can be plain (`912701234`) or dashed
+    /// (`912-70-1234`).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = UsSsnValidator::sanitize_input(value)?;
+        if !sanitized_value.starts_with(ITIN_AREA_PREFIX) {
+            return Err("Invalid area number");
+        }
+        let group = sanitized_value[3..5].parse().expect("Validated digits");
+        if !Self::is_itin_group(group) {
+            return Err("Invalid group number");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate ITINs using the UsItinValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Checks if the group number is in one of the ranges assigned to ITINs.
+    fn is_itin_group(group: u8) -> bool {
+        matches!(group, 50..=65 | 70..=88 | 90..=92 | 94..=99)
+    }
+}
+
+/// A validated ITIN, stored without dashes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Itin(String);
+
+impl Itin {
+    /// Parses and validates the given ITIN, given with or without dashes.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsItinValidator::is_valid(value)?;
+        Ok(Itin(UsSsnValidator::sanitize_input(value)?))
+    }
+
+    /// Returns the 9 digits of the ITIN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the ITIN with all but the last four digits masked, as `***-**-1234`.
+    pub fn masked(&self) -> String {
+        format!("***-**-{}", &self.0[5..9])
+    }
+}
+
+impl FromStr for Itin {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Itin {
+    /// Formats the ITIN as `9XX-GG-XXXX`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", &self.0[0..3], &self.0[3..5], &self.0[5..9])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_itin_group() {
+        assert!(UsItinValidator::is_itin_group(50));
+        assert!(UsItinValidator::is_itin_group(99));
+        assert!(!UsItinValidator::is_itin_group(66));
+        assert!(!UsItinValidator::is_itin_group(93));
+    }
+}
@@ -0,0 +1,176 @@
+/// United States MBI Validator
+///
+/// This module provides functionality to validate and parse the Medicare Beneficiary
+/// Identifier, the 11-character identifier that replaced the SSN-based HICN on Medicare
+/// cards. The MBI has no check digit; each position accepts a specific class of characters:
+///
+/// | Position  | 1   | 2 | 3  | 4 | 5 | 6  | 7 | 8 | 9 | 10 | 11 |
+/// |-----------|-----|---|----|---|---|----|---|---|---|----|----|
+/// | Character | 1-9 | A | AN | N | A | AN | N | A | A | N  | N  |
+///
+/// where `N` is a digit, `A` is a letter other than S, L, O, I, B and Z, which are excluded
+/// to avoid confusion with digits, and `AN` is either. Cards print the MBI with dashes after
+/// the 4th and 7th characters, which are accepted but not stored.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{Mbi, UsMbiValidator};
+///
+/// assert!(UsMbiValidator::is_valid("1EG4-TE5-MK73").is_ok());
+/// assert!(UsMbiValidator::is_valid("1EG4-TE5-MK7S").is_err());
+///
+/// let mbi = Mbi::parse("1eg4te5mk73").unwrap();
+/// assert_eq!(mbi.as_str(), "1EG4TE5MK73");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an MBI, given with or without dashes, in any case.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UsMbiValidator;
+
+/// The classes of characters accepted at each position of an MBI.
+#[derive(Clone, Copy)]
+enum CharacterClass {
+    /// A digit other than 0.
+    NonZero,
+    Numeric,
+    Alphabetic,
+    Alphanumeric,
+}
+
+const MBI_LENGTH: usize = 11;
+const DASHED_MBI_LENGTH: usize = 13;
+const DASH_POSITIONS: &[usize] = &[4, 8];
+const EXCLUDED_LETTERS: &str = "SLOIBZ";
+const POSITION_CLASSES: [CharacterClass; MBI_LENGTH] = [
+    CharacterClass::NonZero,
+    CharacterClass::Alphabetic,
+    CharacterClass::Alphanumeric,
+    CharacterClass::Numeric,
+    CharacterClass::Alphabetic,
+    CharacterClass::Alphanumeric,
+    CharacterClass::Numeric,
+    CharacterClass::Alphabetic,
+    CharacterClass::Alphabetic,
+    CharacterClass::Numeric,
+    CharacterClass::Numeric,
+];
+
+impl CharacterClass {
+    /// Checks if the given uppercase character belongs to the class.
+    fn contains(&self, c: char) -> bool {
+        let is_letter = c.is_ascii_uppercase() && !EXCLUDED_LETTERS.contains(c);
+        match self {
+            CharacterClass::NonZero => matches!(c, '1'..='9'),
+            CharacterClass::Numeric => c.is_ascii_digit(),
+            CharacterClass::Alphabetic => is_letter,
+            CharacterClass::Alphanumeric => is_letter || c.is_ascii_digit(),
+        }
+    }
+}
+
+impl UsMbiValidator {
+    /// Validates if the given MBI is correct. The input can be plain (`1EG4TE5MK73`) or
+    /// dashed (`1EG4-TE5-MK73`), in any case.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value)?;
+        let is_valid_format = sanitized_value
+            .chars()
+            .zip(POSITION_CLASSES.iter())
+            .all(|(c, class)| class.contains(c));
+        if is_valid_format {
+            Ok(())
+        } else {
+            Err("Invalid format")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate MBIs using the UsMbiValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Checks the position of the dashes, if any, returning the 11 characters in uppercase.
+    fn sanitize_input(value: &str) -> Result<String, &'static str> {
+        let value = value.trim().to_ascii_uppercase();
+        if value.is_empty() {
+            return Err("Invalid input");
+        }
+        match value.len() {
+            MBI_LENGTH => Ok(value),
+            DASHED_MBI_LENGTH => {
+                let dashes_valid = value
+                    .char_indices()
+                    .all(|(i, c)| (c == '-') == DASH_POSITIONS.contains(&i));
+                if dashes_valid {
+                    Ok(value.replace('-', ""))
+                } else {
+                    Err("Invalid format")
+                }
+            }
+            _ => Err("Invalid length"),
+        }
+    }
+}
+
+/// A validated MBI, stored in uppercase without dashes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mbi(String);
+
+impl Mbi {
+    /// Parses and validates the given MBI, discarding the dashes.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsMbiValidator::is_valid(value)?;
+        Ok(Mbi(UsMbiValidator::sanitize_input(value)?))
+    }
+
+    /// Returns the 11 characters of the MBI.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the MBI with dashes, as printed on Medicare cards.
+    pub fn to_dashed(&self) -> String {
+        format!("{}-{}-{}", &self.0[0..4], &self.0[4..7], &self.0[7..11])
+    }
+}
+
+impl FromStr for Mbi {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Mbi {
+    /// Formats the MBI without dashes, as it must be sent in transactions.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_character_class() {
+        assert!(CharacterClass::Alphabetic.contains('A'));
+        assert!(!CharacterClass::Alphabetic.contains('O'));
+        assert!(!CharacterClass::NonZero.contains('0'));
+        assert!(CharacterClass::Alphanumeric.contains('0'));
+    }
+}
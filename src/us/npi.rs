@@ -0,0 +1,121 @@
+/// United States NPI Validator
+///
+/// This module provides functionality to validate and parse the National Provider
+/// Identifier, the 10-digit number that identifies health care providers in HIPAA
+/// transactions. Its last digit is a Luhn check digit, calculated as if the first 9 digits
+/// were prefixed with `80840`, the card issuer identifier assigned to US health applications
+/// under ISO 7812.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{Npi, UsNpiValidator};
+///
+/// assert!(UsNpiValidator::is_valid("1234567893").is_ok());
+/// assert!(UsNpiValidator::is_valid("1234567890").is_err());
+///
+/// let npi = Npi::parse("1234567893").unwrap();
+/// assert_eq!(npi.as_card_number(), "808401234567893");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an NPI. Removes non-digit characters, checks the length,
+///   and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::checksum::luhn_check_digit;
+
+pub struct UsNpiValidator;
+
+const NPI_LENGTH: usize = 10;
+const HEALTH_APPLICATIONS_PREFIX: &str = "80840";
+
+impl UsNpiValidator {
+    /// Validates if the given NPI is correct. The input can be a plain or formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != NPI_LENGTH {
+            return Err("Invalid length");
+        }
+        let check_digit = luhn_check_digit(&format!(
+            "{}{}",
+            HEALTH_APPLICATIONS_PREFIX,
+            &sanitized_value[..9]
+        ));
+        if sanitized_value[9..] == check_digit.to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate NPIs using the UsNpiValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+/// A validated NPI, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Npi(String);
+
+impl Npi {
+    /// Parses and validates the given NPI, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsNpiValidator::is_valid(value)?;
+        Ok(Npi(UsNpiValidator::sanitize_input(value)))
+    }
+
+    /// Returns the 10 digits of the NPI.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the NPI with the `80840` prefix, as used when it is printed on an ISO 7812
+    /// identification card.
+    pub fn as_card_number(&self) -> String {
+        format!("{}{}", HEALTH_APPLICATIONS_PREFIX, self.0)
+    }
+}
+
+impl FromStr for Npi {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Npi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(UsNpiValidator::sanitize_input("123-456-7893"), "1234567893");
+    }
+}
@@ -0,0 +1,155 @@
+/// United States ABA Routing Number Validator
+///
+/// This module provides functionality to validate and parse the 9-digit ABA routing
+/// transit number, which identifies the financial institution in checks and ACH transfers.
+/// The first two digits must be one of the prefixes assigned by the Federal Reserve:
+///
+/// - `00`: United States Government;
+/// - `01`-`12`: the twelve Federal Reserve districts;
+/// - `21`-`32`: thrift institutions, in the district given by subtracting 20;
+/// - `61`-`72`: electronic transactions, in the district given by subtracting 60;
+/// - `80`: traveler's checks.
+///
+/// The digits are weighted with the repeating sequence 3, 7 and 1, and their sum must be a
+/// multiple of 10.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{RoutingNumber, UsRoutingNumberValidator};
+///
+/// assert!(UsRoutingNumberValidator::is_valid("021000021").is_ok());
+///
+/// let routing_number = RoutingNumber::parse("322271627").unwrap();
+/// assert_eq!(routing_number.federal_reserve_district(), Some(12));
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a routing number. Removes non-digit characters, checks
+///   the length and the prefix, and validates the checksum.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UsRoutingNumberValidator;
+
+const ROUTING_NUMBER_LENGTH: usize = 9;
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 7, 1, 3, 7, 1, 3, 7, 1];
+
+impl UsRoutingNumberValidator {
+    /// Validates if the given routing number is correct. The input can be a plain or
+    /// formatted string.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.len() != ROUTING_NUMBER_LENGTH {
+            return Err("Invalid length");
+        }
+        let prefix = sanitized_value[..2].parse().expect("Validated digits");
+        if !matches!(prefix, 0..=12 | 21..=32 | 61..=72 | 80) {
+            return Err("Invalid prefix");
+        }
+        let sum: u32 = sanitized_value
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum();
+        if sum.is_multiple_of(10) {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate routing numbers using the UsRoutingNumberValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes non-digit characters from the input.
+    fn sanitize_input(value: &str) -> String {
+        value.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+/// A validated ABA routing number, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RoutingNumber(String);
+
+impl RoutingNumber {
+    /// Parses and validates the given routing number, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsRoutingNumberValidator::is_valid(value)?;
+        Ok(RoutingNumber(UsRoutingNumberValidator::sanitize_input(
+            value,
+        )))
+    }
+
+    /// Returns the 9 digits of the routing number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the two-digit prefix.
+    pub fn prefix(&self) -> u8 {
+        self.0[..2].parse().expect("Validated digits")
+    }
+
+    /// Returns the Federal Reserve district (1 to 12), or `None` for the government and
+    /// traveler's check prefixes.
+    pub fn federal_reserve_district(&self) -> Option<u8> {
+        match self.prefix() {
+            prefix @ 1..=12 => Some(prefix),
+            prefix @ 21..=32 => Some(prefix - 20),
+            prefix @ 61..=72 => Some(prefix - 60),
+            _ => None,
+        }
+    }
+
+    /// Returns the identifier of the institution within its Federal Reserve prefix.
+    pub fn institution_id(&self) -> &str {
+        &self.0[4..8]
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0[8..].parse().expect("Validated digits")
+    }
+}
+
+impl FromStr for RoutingNumber {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for RoutingNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            UsRoutingNumberValidator::sanitize_input("0110-0001-5"),
+            "011000015"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+/// United States SSN Validator
+///
+/// This module provides functionality to validate and parse the Social Security Number,
+/// formatted as `AAA-GG-SSSS`. Since the randomization of 2011, SSNs carry no check digit
+/// nor geographic meaning, so validation is structural:
+///
+/// - the area number (`AAA`) cannot be `000`, `666` or in the `900`-`999` range, which is
+///   reserved for ITINs;
+/// - the group number (`GG`) cannot be `00`;
+/// - the serial number (`SSSS`) cannot be `0000`.
+///
+/// Numbers that were famously advertised and later voided by the Social Security
+/// Administration, such as the one printed on the Woolworth wallet insert, are also rejected.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{Ssn, UsSsnValidator};
+///
+/// assert!(UsSsnValidator::is_valid("123-45-6789").is_ok());
+/// assert!(UsSsnValidator::is_valid("666-45-6789").is_err());
+///
+/// let ssn = Ssn::parse("123456789").unwrap();
+/// assert_eq!(ssn.to_string(), "123-45-6789");
+/// assert_eq!(ssn.masked(), "***-**-6789");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate an SSN, given as 9 digits with or without dashes.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct UsSsnValidator;
+
+const SSN_LENGTH: usize = 9;
+const DASHED_SSN_LENGTH: usize = 11;
+const DASH_POSITIONS: &[usize] = &[3, 6];
+const ADVERTISED_SSNS: &[&str] = &["078051120", "219099999", "457555462"];
+
+impl UsSsnValidator {
+    /// Validates if the given SSN is correct. The input can be plain (`123456789`) or dashed
+    /// (`123-45-6789`).
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value)?;
+        let area = &sanitized_value[0..3];
+        if area == "000" || area == "666" || area.starts_with('9') {
+            return Err("Invalid area number");
+        }
+        if &sanitized_value[3..5] == "00" {
+            return Err("Invalid group number");
+        }
+        if &sanitized_value[5..9] == "0000" {
+            return Err("Invalid serial number");
+        }
+        if ADVERTISED_SSNS.contains(&sanitized_value.as_str()) {
+            return Err("Voided number");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SSNs using the UsSsnValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Checks the plain or dashed format, returning the 9 digits. Shared with the ITIN, which
+    /// is formatted the same way.
+    pub(super) fn sanitize_input(value: &str) -> Result<String, &'static str> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("Invalid input");
+        }
+        let is_valid_format = match value.len() {
+            SSN_LENGTH => value.chars().all(|c| c.is_ascii_digit()),
+            DASHED_SSN_LENGTH => value.chars().enumerate().all(|(i, c)| {
+                if DASH_POSITIONS.contains(&i) {
+                    c == '-'
+                } else {
+                    c.is_ascii_digit()
+                }
+            }),
+            _ => return Err("Invalid length"),
+        };
+        if !is_valid_format {
+            return Err("Invalid format");
+        }
+        Ok(value.chars().filter(char::is_ascii_digit).collect())
+    }
+}
+
+/// A validated SSN, stored without dashes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ssn(String);
+
+impl Ssn {
+    /// Parses and validates the given SSN, given with or without dashes.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        UsSsnValidator::is_valid(value)?;
+        Ok(Ssn(UsSsnValidator::sanitize_input(value)?))
+    }
+
+    /// Returns the 9 digits of the SSN.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the area number.
+    pub fn area(&self) -> &str {
+        &self.0[0..3]
+    }
+
+    /// Returns the group number.
+    pub fn group(&self) -> &str {
+        &self.0[3..5]
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> &str {
+        &self.0[5..9]
+    }
+
+    /// Returns the SSN with all but the serial number masked, as `***-**-6789`.
+    pub fn masked(&self) -> String {
+        format!("***-**-{}", self.serial())
+    }
+}
+
+impl FromStr for Ssn {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Ssn {
+    /// Formats the SSN as `AAA-GG-SSSS`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.area(), self.group(), self.serial())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_input() {
+        assert_eq!(
+            UsSsnValidator::sanitize_input("123-45-6789"),
+            Ok("123456789".to_string())
+        );
+        assert_eq!(
+            UsSsnValidator::sanitize_input("123-456-789"),
+            Err("Invalid format")
+        );
+    }
+}
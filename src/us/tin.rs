@@ -0,0 +1,67 @@
+/// United States Individual TIN Identification
+///
+/// This module tells apart the two taxpayer identification numbers issued to individuals,
+/// the SSN and the ITIN, which share the same `AAA-GG-SSSS` format. Area numbers starting
+/// with 9 are never assigned as SSNs, so they identify an ITIN, which is then validated
+/// with the `UsItinValidator`; any other number is validated with the `UsSsnValidator`.
+///
+/// # Usage
+///
+/// ```
+/// use docval::us::{TinKind, UsTinValidator};
+///
+/// assert_eq!(UsTinValidator::identify("123-45-6789"), Ok(TinKind::Ssn));
+/// assert_eq!(UsTinValidator::identify("912-70-1234"), Ok(TinKind::Itin));
+/// assert!(UsTinValidator::identify("912-40-1234").is_err());
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Validates a number that is either a valid SSN or a valid ITIN.
+///
+/// - `identify(value: &str) -> Result<TinKind, &'static str>`:
+///   Identifies and validates an SSN or ITIN.
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use super::itin::ITIN_AREA_PREFIX;
+use super::{UsItinValidator, UsSsnValidator};
+
+pub struct UsTinValidator;
+
+/// The kinds of taxpayer identification numbers issued to individuals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TinKind {
+    /// Social Security Number.
+    Ssn,
+    /// Individual Taxpayer Identification Number.
+    Itin,
+}
+
+impl UsTinValidator {
+    /// Validates if the given value is a valid SSN or ITIN.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        Self::identify(value).map(|_| ())
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate SSNs and ITINs using the UsTinValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Identifies whether the given value is an SSN or an ITIN, and validates it according
+    /// to the rules of its kind.
+    pub fn identify(value: &str) -> Result<TinKind, &'static str> {
+        let sanitized_value = UsSsnValidator::sanitize_input(value)?;
+        if sanitized_value.starts_with(ITIN_AREA_PREFIX) {
+            UsItinValidator::is_valid(value).map(|_| TinKind::Itin)
+        } else {
+            UsSsnValidator::is_valid(value).map(|_| TinKind::Ssn)
+        }
+    }
+}
@@ -0,0 +1,217 @@
+/// Venezuela RIF Validator
+///
+/// This module provides functionality to validate and parse the RIF (Registro de
+/// Información Fiscal), the tax identifier assigned by the SENIAT, formatted as
+/// `J-12345678-9`:
+///
+/// - 1 letter: category of the registrant, `V`, `E`, `J`, `P` or `G`;
+/// - 8 digits: the cédula of the person, or a sequential number;
+/// - 1 digit: check digit.
+///
+/// The letter is given a value of 4, 8, 12, 16 or 20 respectively, the 8 digits are
+/// weighted with `32765432`, and the check digit is the complement of the sum modulo 11,
+/// where 10 and 11 yield 0.
+///
+/// # Usage
+///
+/// ```
+/// use docval::venezuela::{Rif, RifCategory, VenezuelaRifValidator};
+///
+/// assert!(VenezuelaRifValidator::is_valid("J-00012345-4").is_ok());
+///
+/// let rif = Rif::parse("v123456781").unwrap();
+/// assert_eq!(rif.category(), RifCategory::Venezuelan);
+/// assert_eq!(rif.to_string(), "V-12345678-1");
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a RIF. Removes hyphens and spaces, checks the length, the
+///   category letter and the format, and validates the check digit.
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+pub struct VenezuelaRifValidator;
+
+/// The categories of registrants identified by the first letter of the RIF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RifCategory {
+    /// Venezuelan natural person (`V`).
+    Venezuelan,
+    /// Foreign natural person with a cédula (`E`).
+    Foreigner,
+    /// Legal entity (`J`).
+    LegalEntity,
+    /// Natural person identified by a passport (`P`).
+    Passport,
+    /// Government entity (`G`).
+    Government,
+}
+
+impl RifCategory {
+    /// Returns the category identified by the given letter, compared case-insensitively, if
+    /// any.
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter.to_ascii_uppercase() {
+            'V' => Some(RifCategory::Venezuelan),
+            'E' => Some(RifCategory::Foreigner),
+            'J' => Some(RifCategory::LegalEntity),
+            'P' => Some(RifCategory::Passport),
+            'G' => Some(RifCategory::Government),
+            _ => None,
+        }
+    }
+
+    /// Returns the letter of the category.
+    pub fn letter(&self) -> char {
+        match self {
+            RifCategory::Venezuelan => 'V',
+            RifCategory::Foreigner => 'E',
+            RifCategory::LegalEntity => 'J',
+            RifCategory::Passport => 'P',
+            RifCategory::Government => 'G',
+        }
+    }
+
+    /// Returns the value the letter adds to the weighted sum of the digits.
+    fn weight(&self) -> u32 {
+        match self {
+            RifCategory::Venezuelan => 4,
+            RifCategory::Foreigner => 8,
+            RifCategory::LegalEntity => 12,
+            RifCategory::Passport => 16,
+            RifCategory::Government => 20,
+        }
+    }
+}
+
+const RIF_LENGTH: usize = 10;
+const MULTIPLIER_WEIGHTS: &[u32] = &[3, 2, 7, 6, 5, 4, 3, 2];
+const VALIDATION_MODULUS: u32 = 11;
+
+impl VenezuelaRifValidator {
+    /// Validates if the given RIF is correct. The input can be a plain or formatted string,
+    /// and the letter can be lowercase.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        let sanitized_value = Self::sanitize_input(value);
+        if sanitized_value.is_empty() {
+            return Err("Invalid input");
+        }
+        if sanitized_value.chars().count() != RIF_LENGTH {
+            return Err("Invalid length");
+        }
+        let category = sanitized_value
+            .chars()
+            .next()
+            .and_then(RifCategory::from_letter)
+            .ok_or("Invalid category")?;
+        let digits = &sanitized_value[1..];
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid format");
+        }
+        if digits[8..] == Self::calculate_check_digit(category, &digits[..8]).to_string() {
+            Ok(())
+        } else {
+            Err("Invalid checksum")
+        }
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate RIFs using the VenezuelaRifValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Removes hyphens and spaces from the input, and uppercases the letter.
+    fn sanitize_input(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, '-' | ' '))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Calculates the check digit of the 8 digits for the given category.
+    fn calculate_check_digit(category: RifCategory, digits: &str) -> u32 {
+        let sum: u32 = digits
+            .chars()
+            .zip(MULTIPLIER_WEIGHTS)
+            .map(|(c, &w)| c.to_digit(10).expect("Invalid digit in input") * w)
+            .sum::<u32>()
+            + category.weight();
+        match VALIDATION_MODULUS - sum % VALIDATION_MODULUS {
+            10 | 11 => 0,
+            digit => digit,
+        }
+    }
+}
+
+/// A validated RIF, stored without formatting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rif(String);
+
+impl Rif {
+    /// Parses and validates the given RIF, discarding any formatting.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        VenezuelaRifValidator::is_valid(value)?;
+        Ok(Rif(VenezuelaRifValidator::sanitize_input(value)))
+    }
+
+    /// Returns the letter and digits of the RIF.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the category of the registrant.
+    pub fn category(&self) -> RifCategory {
+        self.0
+            .chars()
+            .next()
+            .and_then(RifCategory::from_letter)
+            .expect("Validated category")
+    }
+
+    /// Returns the 8 digits of the number, without the check digit.
+    pub fn number(&self) -> &str {
+        &self.0[1..9]
+    }
+}
+
+impl FromStr for Rif {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Rif {
+    /// Formats the RIF as `J-12345678-9`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", &self.0[..1], &self.0[1..9], &self.0[9..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_check_digit() {
+        assert_eq!(
+            VenezuelaRifValidator::calculate_check_digit(RifCategory::LegalEntity, "00012345"),
+            4
+        );
+        assert_eq!(
+            VenezuelaRifValidator::calculate_check_digit(RifCategory::Passport, "12345678"),
+            0
+        );
+    }
+}
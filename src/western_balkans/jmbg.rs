@@ -0,0 +1,224 @@
+/// Western Balkans JMBG Validator
+///
+/// This module provides functionality to validate and parse the JMBG (Jedinstveni matični
+/// broj građana), the 13-digit unique master citizen number introduced in Yugoslavia and
+/// still issued in Serbia, Bosnia and Herzegovina, North Macedonia and Montenegro. It has
+/// the same structure as the Slovenian EMŠO:
+///
+/// | Positions | Field                                                          |
+/// |-----------|----------------------------------------------------------------|
+/// | 1-7       | Date of birth as `DDMMYYY`, with the last 3 digits of the year |
+/// | 8-9       | Region of registration                                         |
+/// | 10-12     | Serial number: 000-499 for men, 500-999 for women              |
+/// | 13        | Check digit                                                    |
+///
+/// The region codes are shared by all the countries, each of which owns a range:
+///
+/// | Codes | Country                                                           |
+/// |-------|-------------------------------------------------------------------|
+/// | 10-19 | Bosnia and Herzegovina                                            |
+/// | 20-29 | Montenegro                                                        |
+/// | 41-49 | North Macedonia                                                   |
+/// | 70-99 | Serbia: 71-79 central Serbia, 80-89 Vojvodina, 91-96 Kosovo       |
+///
+/// Codes `00` to `09` are given to foreigners, and `30` to `39` and `50` to `59` to people
+/// registered in Croatia and Slovenia before their independence.
+///
+/// Both numbers follow the same rules, so a JMBG is validated with the
+/// `SloveniaEmsoValidator`: years `800` to `999` stand for 1800 to 1999, and the check digit
+/// is the complement modulo 11 of the first 12 digits weighted by 7, 6, 5, 4, 3 and 2,
+/// twice.
+///
+/// # Usage
+///
+/// ```
+/// use docval::western_balkans::{Jmbg, JmbgCountry, WesternBalkansJmbgValidator};
+/// use docval::Sex;
+///
+/// assert!(WesternBalkansJmbgValidator::is_valid("0101990710008").is_ok());
+/// assert!(WesternBalkansJmbgValidator::is_valid_for("0101990710008", JmbgCountry::Serbia).is_ok());
+/// assert!(
+///     WesternBalkansJmbgValidator::is_valid_for("0101990710008", JmbgCountry::Montenegro)
+///         .is_err()
+/// );
+///
+/// let jmbg = Jmbg::parse("1505985175002").unwrap();
+/// assert_eq!(jmbg.birth_year(), 1985);
+/// assert_eq!(jmbg.country(), Some(JmbgCountry::BosniaAndHerzegovina));
+/// assert_eq!(jmbg.sex(), Sex::Female);
+/// ```
+///
+/// # Methods
+///
+/// - `is_valid(value: &str) -> Result<(), &'static str>`:
+///   Main entry point to validate a JMBG from any region, following the Slovenian rules.
+///
+/// - `is_valid_for(value: &str, country: JmbgCountry) -> Result<(), &'static str>`:
+///   Validates a JMBG and checks that its region belongs to the given country.
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[cfg(feature = "validator-integration")]
+use validator::ValidationError;
+
+use crate::slovenia::{self, SloveniaEmsoValidator};
+use crate::Sex;
+
+pub struct WesternBalkansJmbgValidator;
+
+/// The countries that issue JMBGs, each decoding its own range of region codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JmbgCountry {
+    /// Region codes `10` to `19`.
+    BosniaAndHerzegovina,
+    /// Region codes `20` to `29`.
+    Montenegro,
+    /// Region codes `41` to `49`.
+    NorthMacedonia,
+    /// Region codes `70` to `99`.
+    Serbia,
+}
+
+impl JmbgCountry {
+    /// Returns the country that owns the given region code, if any.
+    pub fn from_region_code(code: u8) -> Option<Self> {
+        [
+            JmbgCountry::BosniaAndHerzegovina,
+            JmbgCountry::Montenegro,
+            JmbgCountry::NorthMacedonia,
+            JmbgCountry::Serbia,
+        ]
+        .into_iter()
+        .find(|country| country.region_codes().contains(&code))
+    }
+
+    /// Returns the range of region codes owned by the country.
+    pub fn region_codes(&self) -> RangeInclusive<u8> {
+        match self {
+            JmbgCountry::BosniaAndHerzegovina => 10..=19,
+            JmbgCountry::Montenegro => 20..=29,
+            JmbgCountry::NorthMacedonia => 41..=49,
+            JmbgCountry::Serbia => 70..=99,
+        }
+    }
+}
+
+impl WesternBalkansJmbgValidator {
+    /// Validates if the given JMBG is correct, whatever its region. The input can be a plain
+    /// string or contain spaces.
+    pub fn is_valid(value: &str) -> Result<(), &'static str> {
+        SloveniaEmsoValidator::is_valid(value)
+    }
+
+    #[cfg(feature = "validator-integration")]
+    /// Custom function to validate JMBGs using the WesternBalkansJmbgValidator.
+    pub fn validator(value: &str) -> Result<(), ValidationError> {
+        match Self::is_valid(value) {
+            Ok(()) => Ok(()),
+            Err(err_msg) => Err(ValidationError::new(err_msg)),
+        }
+    }
+
+    /// Validates if the given JMBG is correct and was registered in a region of the given
+    /// country.
+    pub fn is_valid_for(value: &str, country: JmbgCountry) -> Result<(), &'static str> {
+        Jmbg::parse_for(value, country).map(|_| ())
+    }
+}
+
+/// A validated JMBG, stored as its 13 digits.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Jmbg(slovenia::Emso);
+
+impl Jmbg {
+    /// Parses and validates the given JMBG, discarding any spaces.
+    pub fn parse(value: &str) -> Result<Self, &'static str> {
+        slovenia::Emso::parse(value).map(Jmbg)
+    }
+
+    /// Parses and validates the given JMBG, checking that it was registered in a region of
+    /// the given country.
+    pub fn parse_for(value: &str, country: JmbgCountry) -> Result<Self, &'static str> {
+        let jmbg = Self::parse(value)?;
+        if !country.region_codes().contains(&jmbg.region_code()) {
+            return Err("Invalid region");
+        }
+        Ok(jmbg)
+    }
+
+    /// Returns the 13 digits of the JMBG.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the year of birth.
+    pub fn birth_year(&self) -> u16 {
+        self.0.birth_year()
+    }
+
+    /// Returns the month of birth.
+    pub fn birth_month(&self) -> u8 {
+        self.0.birth_month()
+    }
+
+    /// Returns the day of birth.
+    pub fn birth_day(&self) -> u8 {
+        self.0.birth_day()
+    }
+
+    /// Returns the 2-digit code of the region of registration.
+    pub fn region_code(&self) -> u8 {
+        self.0.region_code()
+    }
+
+    /// Returns the country owning the region of registration, or `None` for foreigners and
+    /// the regions of Croatia and Slovenia.
+    pub fn country(&self) -> Option<JmbgCountry> {
+        JmbgCountry::from_region_code(self.region_code())
+    }
+
+    /// Returns the serial number.
+    pub fn serial(&self) -> u16 {
+        self.0.serial()
+    }
+
+    /// Returns the sex of the holder.
+    pub fn sex(&self) -> Sex {
+        self.0.sex()
+    }
+
+    /// Returns the check digit.
+    pub fn check_digit(&self) -> u8 {
+        self.0.check_digit()
+    }
+}
+
+impl FromStr for Jmbg {
+    type Err = &'static str;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl fmt::Display for Jmbg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_region_code() {
+        assert_eq!(
+            JmbgCountry::from_region_code(15),
+            Some(JmbgCountry::BosniaAndHerzegovina)
+        );
+        assert_eq!(JmbgCountry::from_region_code(71), Some(JmbgCountry::Serbia));
+        assert_eq!(JmbgCountry::from_region_code(35), None);
+    }
+}
@@ -0,0 +1,50 @@
+use docval::argentina::{ArgentinaCbuValidator, Cbu};
+
+#[test]
+fn test_valid_cbu() {
+    assert!(ArgentinaCbuValidator::is_valid("2850590940090418135201").is_ok());
+    assert!(ArgentinaCbuValidator::is_valid("0170099220000067797370").is_ok());
+    assert!(ArgentinaCbuValidator::is_valid("01700992 20000067797370").is_ok());
+}
+
+#[test]
+fn test_invalid_first_block() {
+    assert_eq!(
+        ArgentinaCbuValidator::is_valid("2850590840090418135201"),
+        Err("Invalid bank and branch checksum")
+    );
+}
+
+#[test]
+fn test_invalid_second_block() {
+    assert_eq!(
+        ArgentinaCbuValidator::is_valid("2850590940090418135202"),
+        Err("Invalid account checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(ArgentinaCbuValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        ArgentinaCbuValidator::is_valid("285059094009041813520"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cbu() {
+    let cbu: Cbu = "0170099220000067797370".parse().unwrap();
+    assert_eq!(cbu.as_str(), "0170099220000067797370");
+    assert_eq!(cbu.bank_code(), "017");
+    assert_eq!(cbu.branch_code(), "0099");
+    assert_eq!(cbu.account_number(), "2000006779737");
+    assert_eq!(cbu.to_string(), "0170099220000067797370");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cbu() {
+    assert!(ArgentinaCbuValidator::validator("2850590940090418135201").is_ok());
+    assert!(ArgentinaCbuValidator::validator("2850590940090418135200").is_err());
+}
@@ -0,0 +1,71 @@
+use docval::argentina::{ArgentinaCuitValidator, Cuit, CuitKind};
+use docval::Sex;
+
+#[test]
+fn test_valid_cuit() {
+    assert!(ArgentinaCuitValidator::is_valid("20-12345678-6").is_ok());
+    assert!(ArgentinaCuitValidator::is_valid("27172543591").is_ok());
+    assert!(ArgentinaCuitValidator::is_valid("30-71234567-1").is_ok());
+    assert!(ArgentinaCuitValidator::is_valid("33-50000001-0").is_ok());
+    assert!(ArgentinaCuitValidator::is_valid("20-00000006-0").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        ArgentinaCuitValidator::is_valid("20-12345678-7"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        ArgentinaCuitValidator::is_valid("20-00000001-0"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        ArgentinaCuitValidator::is_valid("21-12345678-6"),
+        Err("Invalid prefix")
+    );
+    assert!(ArgentinaCuitValidator::is_valid("35-12345678-6").is_err());
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(ArgentinaCuitValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        ArgentinaCuitValidator::is_valid("20-1234567-6"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_person() {
+    let cuit: Cuit = "20123456786".parse().unwrap();
+    assert_eq!(cuit.as_str(), "20123456786");
+    assert_eq!(cuit.prefix(), 20);
+    assert_eq!(cuit.kind(), CuitKind::Person);
+    assert_eq!(cuit.dni(), Some("12345678"));
+    assert_eq!(cuit.sex(), Some(Sex::Male));
+    assert_eq!(cuit.to_string(), "20-12345678-6");
+
+    let cuit = Cuit::parse("23-12345678-5").unwrap();
+    assert_eq!(cuit.kind(), CuitKind::Person);
+    assert_eq!(cuit.sex(), None);
+}
+
+#[test]
+fn test_parse_company() {
+    let cuit = Cuit::parse("34-12345678-7").unwrap();
+    assert_eq!(cuit.kind(), CuitKind::Company);
+    assert_eq!(cuit.dni(), None);
+    assert_eq!(cuit.sex(), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cuit() {
+    assert!(ArgentinaCuitValidator::validator("20-12345678-6").is_ok());
+    assert!(ArgentinaCuitValidator::validator("20-12345678-0").is_err());
+}
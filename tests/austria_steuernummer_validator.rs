@@ -0,0 +1,79 @@
+use docval::austria::{AustriaSteuernummerValidator, Steuernummer};
+
+#[test]
+fn test_valid_steuernummer() {
+    assert!(AustriaSteuernummerValidator::is_valid("59-119/9013").is_ok());
+    assert!(AustriaSteuernummerValidator::is_valid("591199013").is_ok());
+    assert!(AustriaSteuernummerValidator::is_valid("03 123 4560").is_ok());
+    assert!(AustriaSteuernummerValidator::is_valid("09-123/4567").is_ok());
+    assert!(AustriaSteuernummerValidator::is_valid("12-345/6782").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("59-119/9014"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("091234568"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_tax_office() {
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("00-119/9013"),
+        Err("Invalid tax office")
+    );
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("99-119/9013"),
+        Err("Invalid tax office")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("59.119.9013"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("59-119/901X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("59-119/901"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        AustriaSteuernummerValidator::is_valid("59-119/90130"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_steuernummer() {
+    let steuernummer: Steuernummer = "59 119 9013".parse().unwrap();
+    assert_eq!(steuernummer.as_str(), "591199013");
+    assert_eq!(steuernummer.tax_office(), "59");
+    assert_eq!(steuernummer.serial(), "119901");
+    assert_eq!(steuernummer.check_digit(), 3);
+    assert_eq!(steuernummer.to_string(), "59-119/9013");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_steuernummer() {
+    assert!(AustriaSteuernummerValidator::validator("59-119/9013").is_ok());
+    assert!(AustriaSteuernummerValidator::validator("59-119/9014").is_err());
+}
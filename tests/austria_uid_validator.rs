@@ -0,0 +1,67 @@
+use docval::austria::{AustriaUidValidator, Uid};
+
+#[test]
+fn test_valid_uid() {
+    assert!(AustriaUidValidator::is_valid("ATU13585627").is_ok());
+    assert!(AustriaUidValidator::is_valid("ATU 1358 5627").is_ok());
+    assert!(AustriaUidValidator::is_valid("atu13585627").is_ok());
+    assert!(AustriaUidValidator::is_valid("U13585627").is_ok());
+    assert!(AustriaUidValidator::is_valid("ATU12345675").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        AustriaUidValidator::is_valid("ATU13585628"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        AustriaUidValidator::is_valid("ATU12345678"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        AustriaUidValidator::is_valid("AT13585627"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        AustriaUidValidator::is_valid("DEU13585627"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        AustriaUidValidator::is_valid("ATU1358562X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(AustriaUidValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(AustriaUidValidator::is_valid("AT"), Err("Invalid input"));
+    assert_eq!(
+        AustriaUidValidator::is_valid("ATU1358562"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        AustriaUidValidator::is_valid("ATU135856270"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_uid() {
+    let uid: Uid = "AT U13 585 627".parse().unwrap();
+    assert_eq!(uid.as_str(), "13585627");
+    assert_eq!(uid.check_digit(), 7);
+    assert_eq!(uid.to_string(), "ATU13585627");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_uid() {
+    assert!(AustriaUidValidator::validator("ATU13585627").is_ok());
+    assert!(AustriaUidValidator::validator("ATU13585628").is_err());
+}
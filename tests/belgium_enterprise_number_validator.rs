@@ -0,0 +1,76 @@
+use docval::belgium::{BelgiumEnterpriseNumberValidator, EnterpriseNumber, EnterpriseNumberKind};
+
+#[test]
+fn test_valid_enterprise_number() {
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("0403062714").is_ok());
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("0403.062.714").is_ok());
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("BE 0202.303.693").is_ok());
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("be0403062714").is_ok());
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("1000000021").is_ok());
+}
+
+#[test]
+fn test_valid_establishment_number() {
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("2.123.456.791").is_ok());
+    assert!(BelgiumEnterpriseNumberValidator::is_valid("2123456791").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("0403062715"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("2123456719"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("9000000092"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("040306271X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("NL0403062714"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("403062714"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BelgiumEnterpriseNumberValidator::is_valid("04030627140"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_enterprise_number() {
+    let enterprise_number: EnterpriseNumber = "BE0403062714".parse().unwrap();
+    assert_eq!(enterprise_number.as_str(), "0403062714");
+    assert_eq!(enterprise_number.kind(), EnterpriseNumberKind::Enterprise);
+    assert_eq!(enterprise_number.check_digits(), 14);
+    assert_eq!(enterprise_number.to_string(), "0403.062.714");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_enterprise_number() {
+    assert!(BelgiumEnterpriseNumberValidator::validator("0403062714").is_ok());
+    assert!(BelgiumEnterpriseNumberValidator::validator("0403062715").is_err());
+}
@@ -0,0 +1,103 @@
+use docval::belgium::{BelgiumNationalNumberValidator, NationalNumber};
+use docval::Sex;
+
+#[test]
+fn test_valid_national_number() {
+    assert!(BelgiumNationalNumberValidator::is_valid("85073003328").is_ok());
+    assert!(BelgiumNationalNumberValidator::is_valid("85.07.30-033.28").is_ok());
+    assert!(BelgiumNationalNumberValidator::is_valid("93070104584").is_ok());
+    assert!(BelgiumNationalNumberValidator::is_valid("85 07 30 033 28").is_ok());
+}
+
+#[test]
+fn test_valid_national_number_born_after_2000() {
+    assert!(BelgiumNationalNumberValidator::is_valid("17010100171").is_ok());
+    assert!(BelgiumNationalNumberValidator::is_valid("00010100204").is_ok());
+    assert!(BelgiumNationalNumberValidator::is_valid("00022900145").is_ok());
+}
+
+#[test]
+fn test_valid_national_number_with_unknown_date() {
+    assert!(BelgiumNationalNumberValidator::is_valid("40000012338").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("85073003329"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("17010100170"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("85133003370"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("85073203365"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("00022900116"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("85073000061"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("8507300332X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("8507300332"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BelgiumNationalNumberValidator::is_valid("850730033280"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_national_number() {
+    let national_number: NationalNumber = "85.07.30-033.28".parse().unwrap();
+    assert_eq!(national_number.as_str(), "85073003328");
+    assert_eq!(national_number.birth_year(), 1985);
+    assert_eq!(national_number.birth_month(), Some(7));
+    assert_eq!(national_number.birth_day(), Some(30));
+    assert_eq!(national_number.sex(), Sex::Male);
+    assert_eq!(national_number.serial(), 33);
+    assert_eq!(national_number.check_digits(), 28);
+    assert_eq!(national_number.to_string(), "85.07.30-033.28");
+
+    let national_number: NationalNumber = "40000012338".parse().unwrap();
+    assert_eq!(national_number.birth_year(), 1940);
+    assert_eq!(national_number.birth_month(), None);
+    assert_eq!(national_number.birth_day(), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_national_number() {
+    assert!(BelgiumNationalNumberValidator::validator("85073003328").is_ok());
+    assert!(BelgiumNationalNumberValidator::validator("85073003329").is_err());
+}
@@ -0,0 +1,60 @@
+use docval::bolivia::{BoliviaNitValidator, Nit};
+
+#[test]
+fn test_valid_nit() {
+    assert!(BoliviaNitValidator::is_valid("1020703021").is_ok());
+    assert!(BoliviaNitValidator::is_valid("1234567897").is_ok());
+    assert!(BoliviaNitValidator::is_valid("10286270258").is_ok());
+    assert!(BoliviaNitValidator::is_valid("1543210").is_ok());
+}
+
+#[test]
+fn test_valid_nit_with_ten_mapped_to_one() {
+    assert!(BoliviaNitValidator::is_valid("10000021").is_ok());
+    assert!(BoliviaNitValidator::is_valid("1020703031").is_ok());
+    assert_eq!(
+        BoliviaNitValidator::is_valid("10000020"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        BoliviaNitValidator::is_valid("1020703024"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        BoliviaNitValidator::is_valid("1234567890"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(BoliviaNitValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        BoliviaNitValidator::is_valid("154320"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BoliviaNitValidator::is_valid("1234567890123"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nit() {
+    let nit: Nit = "1020703021".parse().unwrap();
+    assert_eq!(nit.as_str(), "1020703021");
+    assert_eq!(nit.number(), "102070302");
+    assert_eq!(nit.check_digit(), 1);
+    assert_eq!(nit.to_string(), "1020703021");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nit() {
+    assert!(BoliviaNitValidator::validator("1020703021").is_ok());
+    assert!(BoliviaNitValidator::validator("1020703020").is_err());
+}
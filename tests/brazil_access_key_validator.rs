@@ -0,0 +1,92 @@
+use docval::brazil::{AccessKey, BrazilAccessKeyValidator, FiscalDocumentModel, Uf};
+
+#[test]
+fn test_valid_access_key() {
+    let key = "35170612345678000195550010000000011000000014";
+    assert!(BrazilAccessKeyValidator::is_valid(key).is_ok());
+}
+
+#[test]
+fn test_valid_access_key_with_formatting() {
+    let key = "5319 0312 3456 7800 0195 6500 2000 0012 3411 2345 6780";
+    assert!(BrazilAccessKeyValidator::is_valid(key).is_ok());
+}
+
+#[test]
+fn test_invalid_access_key_checksum() {
+    let key = "35170612345678000195550010000000011000000015";
+    assert!(BrazilAccessKeyValidator::is_valid(key).is_err());
+}
+
+#[test]
+fn test_invalid_access_key_length() {
+    assert!(BrazilAccessKeyValidator::is_valid("").is_err());
+    assert!(
+        BrazilAccessKeyValidator::is_valid("3517061234567800019555001000000001100000001").is_err()
+    );
+}
+
+#[test]
+fn test_invalid_access_key_state_code() {
+    let key = "99170612345678000195550010000000011000000014";
+    assert_eq!(
+        BrazilAccessKeyValidator::is_valid(key),
+        Err("Invalid state code")
+    );
+}
+
+#[test]
+fn test_invalid_access_key_month() {
+    let key = "35171312345678000195550010000000011000000014";
+    assert_eq!(
+        BrazilAccessKeyValidator::is_valid(key),
+        Err("Invalid emission date")
+    );
+}
+
+#[test]
+fn test_invalid_access_key_model() {
+    let key = "35170612345678000195990010000000011000000010";
+    assert_eq!(
+        BrazilAccessKeyValidator::is_valid(key),
+        Err("Invalid model")
+    );
+}
+
+#[test]
+fn test_parse_access_key() {
+    let key = AccessKey::parse("42200712345678000195570010000000421876543211").unwrap();
+    assert_eq!(key.uf(), Uf::SC);
+    assert_eq!(key.year(), 2020);
+    assert_eq!(key.month(), 7);
+    assert_eq!(key.issuer_id(), "12345678000195");
+    assert_eq!(key.model(), FiscalDocumentModel::CTe);
+    assert_eq!(key.series(), 1);
+    assert_eq!(key.number(), 42);
+    assert_eq!(key.emission_type(), 1);
+    assert_eq!(key.numeric_code(), "87654321");
+    assert_eq!(key.check_digit(), 1);
+}
+
+#[test]
+fn test_display_access_key() {
+    let key: AccessKey = "35170612345678000195550010000000011000000014"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        key.to_string(),
+        "3517 0612 3456 7800 0195 5500 1000 0000 0110 0000 0014"
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_access_key() {
+    assert!(
+        BrazilAccessKeyValidator::validator("35170612345678000195550010000000011000000014").is_ok()
+    );
+    assert!(
+        BrazilAccessKeyValidator::validator("35170612345678000195550010000000011000000015")
+            .is_err()
+    );
+}
@@ -0,0 +1,98 @@
+use docval::brazil::{Bank, BrazilBankAccountValidator};
+
+#[test]
+fn test_bank_from_code() {
+    assert_eq!(Bank::from_code(1), Some(Bank::BancoDoBrasil));
+    assert_eq!(Bank::from_code(341), Some(Bank::Itau));
+    assert_eq!(Bank::from_code(999), None);
+    assert_eq!(Bank::Caixa.code(), 104);
+}
+
+#[test]
+fn test_valid_branches() {
+    assert!(BrazilBankAccountValidator::is_valid_branch("001", "1584-9").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_branch("001", "1584").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_branch("237", "1425-7").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_branch("341", "2545").is_ok());
+}
+
+#[test]
+fn test_invalid_branches() {
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_branch("001", "1584-8"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_branch("341", "2545-1"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_branch("001", ""),
+        Err("Invalid input")
+    );
+}
+
+#[test]
+fn test_unsupported_bank() {
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_branch("999", "1584"),
+        Err("Unsupported bank")
+    );
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_branch("12a", "1584"),
+        Err("Invalid bank code")
+    );
+}
+
+#[test]
+fn test_banco_do_brasil_accounts() {
+    assert!(BrazilBankAccountValidator::is_valid_account("001", "1584-9", "00210169-6").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_account("1", "1584", "210169-6").is_ok());
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_account("001", "1584-9", "00210169-5"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_bradesco_accounts() {
+    assert!(BrazilBankAccountValidator::is_valid_account("237", "1425-7", "0238069-2").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_account("237", "1425", "0238069-3").is_err());
+}
+
+#[test]
+fn test_itau_accounts() {
+    assert!(BrazilBankAccountValidator::is_valid_account("341", "2545", "02366-1").is_ok());
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_account("341", "2546", "02366-1"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_caixa_accounts() {
+    assert!(BrazilBankAccountValidator::is_valid_account("104", "2004", "001.00000448-6").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_account("104", "2004", "001.00000448-7").is_err());
+}
+
+#[test]
+fn test_santander_accounts() {
+    assert!(BrazilBankAccountValidator::is_valid_account("033", "2006", "01008407-4").is_ok());
+    assert!(BrazilBankAccountValidator::is_valid_account("033", "2006", "01008407-5").is_err());
+}
+
+#[test]
+fn test_invalid_accounts() {
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_account("341", "2545", "1234567-8"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_account("341", "2545", "0A366-1"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        BrazilBankAccountValidator::is_valid_account("341", "2545", "-"),
+        Err("Invalid input")
+    );
+}
@@ -0,0 +1,91 @@
+use docval::brazil::{Boleto, BrazilBoletoValidator};
+
+const LINE: &str = "34191091233456781234156789010008199990000123456";
+const BARCODE: &str = "34191999900001234561091234567812345678901000";
+
+#[test]
+fn test_valid_typeable_line() {
+    assert!(BrazilBoletoValidator::is_valid(LINE).is_ok());
+    assert!(
+        BrazilBoletoValidator::is_valid("23793381286000782713661793000003400000000000000").is_ok()
+    );
+}
+
+#[test]
+fn test_valid_typeable_line_with_formatting() {
+    let line = "00190.00009 01234.567806 00000.017186 8 10000000010000";
+    assert!(BrazilBoletoValidator::is_valid(line).is_ok());
+}
+
+#[test]
+fn test_invalid_field_checksum() {
+    let line = "34191091243456781234156789010008199990000123456";
+    assert_eq!(
+        BrazilBoletoValidator::is_valid(line),
+        Err("Invalid field checksum")
+    );
+}
+
+#[test]
+fn test_invalid_general_checksum() {
+    let line = "34191091233456781234156789010008299990000123456";
+    assert_eq!(
+        BrazilBoletoValidator::is_valid(line),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_typeable_line_length() {
+    assert!(BrazilBoletoValidator::is_valid("").is_err());
+    assert!(BrazilBoletoValidator::is_valid(&LINE[..46]).is_err());
+}
+
+#[test]
+fn test_valid_barcode() {
+    assert!(BrazilBoletoValidator::is_valid_barcode(BARCODE).is_ok());
+    assert!(BrazilBoletoValidator::is_valid_barcode(
+        "34192999900001234561091234567812345678901000"
+    )
+    .is_err());
+}
+
+#[test]
+fn test_convert_between_representations() {
+    assert_eq!(
+        BrazilBoletoValidator::to_barcode(LINE),
+        Ok(BARCODE.to_string())
+    );
+    assert_eq!(
+        BrazilBoletoValidator::to_typeable_line(BARCODE),
+        Ok(LINE.to_string())
+    );
+}
+
+#[test]
+fn test_parse_boleto() {
+    let boleto = Boleto::parse(LINE).unwrap();
+    assert_eq!(boleto.bank_code(), "341");
+    assert_eq!(boleto.currency_code(), 9);
+    assert_eq!(boleto.due_date_factor(), 9999);
+    assert_eq!(boleto.amount(), 123456);
+    assert_eq!(boleto.free_field(), "1091234567812345678901000");
+    assert_eq!(boleto.barcode(), BARCODE);
+    assert_eq!(Boleto::parse(BARCODE), Ok(boleto));
+}
+
+#[test]
+fn test_display_boleto() {
+    let boleto: Boleto = BARCODE.parse().unwrap();
+    assert_eq!(
+        boleto.to_string(),
+        "34191.09123 34567.812341 56789.010008 1 99990000123456"
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_boleto() {
+    assert!(BrazilBoletoValidator::validator(LINE).is_ok());
+    assert!(BrazilBoletoValidator::validator(BARCODE).is_err());
+}
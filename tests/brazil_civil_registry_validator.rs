@@ -0,0 +1,70 @@
+use docval::brazil::{BrazilCivilRegistryValidator, CertificateBookType, CivilRegistryNumber};
+
+#[test]
+fn test_valid_matricula() {
+    assert!(BrazilCivilRegistryValidator::is_valid("65944702559015199679468255959016").is_ok());
+    assert!(BrazilCivilRegistryValidator::is_valid("10453901552013100012021000012321").is_ok());
+}
+
+#[test]
+fn test_valid_matricula_with_formatting() {
+    assert!(
+        BrazilCivilRegistryValidator::is_valid("115618 01 55 2019 1 00005 034 0000123 31").is_ok()
+    );
+    assert!(
+        BrazilCivilRegistryValidator::is_valid("115618.01.55.2019.1.00005.034.0000123-31").is_ok()
+    );
+}
+
+#[test]
+fn test_invalid_matricula_checksum() {
+    assert_eq!(
+        BrazilCivilRegistryValidator::is_valid("65944702559015199679468255959017"),
+        Err("Invalid checksum")
+    );
+    assert!(BrazilCivilRegistryValidator::is_valid("65944702559015199679468255959026").is_err());
+}
+
+#[test]
+fn test_invalid_matricula_book_type() {
+    assert_eq!(
+        BrazilCivilRegistryValidator::is_valid("10453901552013000012021000012321"),
+        Err("Invalid book type")
+    );
+}
+
+#[test]
+fn test_invalid_matricula_length() {
+    assert!(BrazilCivilRegistryValidator::is_valid("").is_err());
+    assert!(BrazilCivilRegistryValidator::is_valid("1045390155201310001202100001232").is_err());
+}
+
+#[test]
+fn test_parse_matricula() {
+    let matricula = CivilRegistryNumber::parse("65944702559015199679468255959016").unwrap();
+    assert_eq!(matricula.registry_office_code(), "659447");
+    assert_eq!(matricula.collection(), 2);
+    assert_eq!(matricula.service_type(), 55);
+    assert_eq!(matricula.year(), 9015);
+    assert_eq!(matricula.book_type(), CertificateBookType::Birth);
+    assert_eq!(matricula.book(), 99679);
+    assert_eq!(matricula.page(), 468);
+    assert_eq!(matricula.term(), 2559590);
+    assert_eq!(matricula.check_digits(), 16);
+}
+
+#[test]
+fn test_display_matricula() {
+    let matricula: CivilRegistryNumber = "10453901552013100012021000012321".parse().unwrap();
+    assert_eq!(
+        matricula.to_string(),
+        "104539 01 55 2013 1 00012 021 0000123 21"
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_matricula() {
+    assert!(BrazilCivilRegistryValidator::validator("10453901552013100012021000012321").is_ok());
+    assert!(BrazilCivilRegistryValidator::validator("10453901552013100012021000012322").is_err());
+}
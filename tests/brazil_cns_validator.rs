@@ -0,0 +1,75 @@
+use docval::brazil::{BrazilCnsValidator, Cns, CnsKind};
+
+#[test]
+fn test_valid_definitive_cns() {
+    assert!(BrazilCnsValidator::is_valid("170123456780008").is_ok());
+    assert!(BrazilCnsValidator::is_valid("274839201510003").is_ok());
+}
+
+#[test]
+fn test_valid_definitive_cns_with_adjusted_check_digit() {
+    assert!(BrazilCnsValidator::is_valid("286981984630018").is_ok());
+    assert!(BrazilCnsValidator::is_valid("209152487050018").is_ok());
+}
+
+#[test]
+fn test_valid_provisional_cns() {
+    assert!(BrazilCnsValidator::is_valid("700000000000005").is_ok());
+    assert!(BrazilCnsValidator::is_valid("898001028000016").is_ok());
+}
+
+#[test]
+fn test_valid_cns_with_formatting() {
+    assert!(BrazilCnsValidator::is_valid("898 0010 2800 0016").is_ok());
+}
+
+#[test]
+fn test_invalid_cns_checksum() {
+    assert!(BrazilCnsValidator::is_valid("170123456780009").is_err());
+    assert!(BrazilCnsValidator::is_valid("898001028000017").is_err());
+}
+
+#[test]
+fn test_invalid_definitive_cns_structure() {
+    // Passes the weighted sum but does not follow the PIS-derived layout.
+    assert!(BrazilCnsValidator::is_valid("170123456780105").is_err());
+}
+
+#[test]
+fn test_invalid_cns_prefix() {
+    assert!(BrazilCnsValidator::is_valid("300000000000050").is_err());
+}
+
+#[test]
+fn test_invalid_cns_length() {
+    assert!(BrazilCnsValidator::is_valid("").is_err());
+    assert!(BrazilCnsValidator::is_valid("70000000000000").is_err());
+}
+
+#[test]
+fn test_cns_kind() {
+    assert_eq!(
+        BrazilCnsValidator::kind("170123456780008"),
+        Ok(CnsKind::Definitive)
+    );
+    assert_eq!(
+        BrazilCnsValidator::kind("700000000000005"),
+        Ok(CnsKind::Provisional)
+    );
+}
+
+#[test]
+fn test_parse_cns() {
+    let cns = Cns::parse("170 1234 5678 0008").unwrap();
+    assert_eq!(cns.as_str(), "170123456780008");
+    assert_eq!(cns.kind(), CnsKind::Definitive);
+    assert_eq!(cns.to_string(), "170 1234 5678 0008");
+    assert!("170123456780009".parse::<Cns>().is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cns() {
+    assert!(BrazilCnsValidator::validator("898 0010 2800 0016").is_ok());
+    assert!(BrazilCnsValidator::validator("898 0010 2800 0017").is_err());
+}
@@ -0,0 +1,101 @@
+use docval::brazil::{BrazilCollectionSlipValidator, CollectionSegment, CollectionSlip};
+
+const MOD10_LINE: &str = "826500000011234500012021012310000000000000000018";
+const MOD10_BARCODE: &str = "82650000001234500012020123100000000000000001";
+const MOD11_LINE: &str = "858600000004500000481230456789012341567890123457";
+const MOD11_BARCODE: &str = "85860000000500000481234567890123456789012345";
+const CNPJ_LINE: &str = "866700000015000012345674800000000003000000000018";
+
+#[test]
+fn test_valid_mod10_typeable_line() {
+    assert!(BrazilCollectionSlipValidator::is_valid(MOD10_LINE).is_ok());
+}
+
+#[test]
+fn test_valid_mod11_typeable_line() {
+    assert!(BrazilCollectionSlipValidator::is_valid(MOD11_LINE).is_ok());
+}
+
+#[test]
+fn test_valid_typeable_line_with_formatting() {
+    let line = "82650000001-1 23450001202-1 01231000000-0 00000000001-8";
+    assert!(BrazilCollectionSlipValidator::is_valid(line).is_ok());
+}
+
+#[test]
+fn test_invalid_block_checksum() {
+    let line = "826500000011234500012022012310000000000000000018";
+    assert_eq!(
+        BrazilCollectionSlipValidator::is_valid(line),
+        Err("Invalid block checksum")
+    );
+}
+
+#[test]
+fn test_invalid_general_checksum() {
+    let barcode = "82660000001234500012020123100000000000000001";
+    assert_eq!(
+        BrazilCollectionSlipValidator::is_valid_barcode(barcode),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_product_identifier() {
+    let barcode = "34191999900001234561091234567812345678901000";
+    assert!(BrazilCollectionSlipValidator::is_valid_barcode(barcode).is_err());
+}
+
+#[test]
+fn test_invalid_typeable_line_length() {
+    assert!(BrazilCollectionSlipValidator::is_valid("").is_err());
+    assert!(BrazilCollectionSlipValidator::is_valid(&MOD10_LINE[..47]).is_err());
+}
+
+#[test]
+fn test_convert_between_representations() {
+    assert_eq!(
+        BrazilCollectionSlipValidator::to_barcode(MOD11_LINE),
+        Ok(MOD11_BARCODE.to_string())
+    );
+    assert_eq!(
+        BrazilCollectionSlipValidator::to_typeable_line(MOD10_BARCODE),
+        Ok(MOD10_LINE.to_string())
+    );
+}
+
+#[test]
+fn test_parse_collection_slip() {
+    let slip = CollectionSlip::parse(MOD11_LINE).unwrap();
+    assert_eq!(slip.segment(), CollectionSegment::GovernmentBodies);
+    assert!(slip.is_effective_amount());
+    assert_eq!(slip.amount(), 5000);
+    assert_eq!(slip.company_code(), "0048");
+    assert_eq!(slip.free_field(), "1234567890123456789012345");
+    assert_eq!(CollectionSlip::parse(MOD11_BARCODE), Ok(slip));
+}
+
+#[test]
+fn test_parse_collection_slip_identified_by_cnpj() {
+    let slip = CollectionSlip::parse(CNPJ_LINE).unwrap();
+    assert_eq!(slip.segment(), CollectionSegment::IdentifiedByCnpj);
+    assert!(slip.is_effective_amount());
+    assert_eq!(slip.amount(), 10000);
+    assert_eq!(slip.company_code(), "12345678");
+}
+
+#[test]
+fn test_display_collection_slip() {
+    let slip: CollectionSlip = MOD10_BARCODE.parse().unwrap();
+    assert_eq!(
+        slip.to_string(),
+        "82650000001-1 23450001202-1 01231000000-0 00000000001-8"
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_collection_slip() {
+    assert!(BrazilCollectionSlipValidator::validator(MOD10_LINE).is_ok());
+    assert!(BrazilCollectionSlipValidator::validator(MOD10_BARCODE).is_err());
+}
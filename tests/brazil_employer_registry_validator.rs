@@ -0,0 +1,63 @@
+use docval::brazil::{BrazilCaepfValidator, BrazilCeiValidator, BrazilCnoValidator};
+
+#[test]
+fn test_valid_cei() {
+    assert!(BrazilCeiValidator::is_valid("111111111118").is_ok());
+    assert!(BrazilCeiValidator::is_valid("10.041.23456/74").is_ok());
+}
+
+#[test]
+fn test_invalid_cei_checksum() {
+    assert_eq!(
+        BrazilCeiValidator::is_valid("10.041.23456/75"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_cei_length() {
+    assert!(BrazilCeiValidator::is_valid("").is_err());
+    assert!(BrazilCeiValidator::is_valid("10.041.23456/7").is_err());
+}
+
+#[test]
+fn test_valid_cno() {
+    assert!(BrazilCnoValidator::is_valid("51.201.05137/04").is_ok());
+    assert!(BrazilCnoValidator::is_valid("900000000001").is_ok());
+}
+
+#[test]
+fn test_invalid_cno() {
+    assert!(BrazilCnoValidator::is_valid("900000000002").is_err());
+    assert!(BrazilCnoValidator::is_valid("9000000000011").is_err());
+}
+
+#[test]
+fn test_valid_caepf() {
+    assert!(BrazilCaepfValidator::is_valid("12345678900100").is_ok());
+    assert!(BrazilCaepfValidator::is_valid("293.001.100/001-62").is_ok());
+    assert!(BrazilCaepfValidator::is_valid("000.000.001/001-57").is_ok());
+}
+
+#[test]
+fn test_invalid_caepf_checksum() {
+    assert_eq!(
+        BrazilCaepfValidator::is_valid("293.001.100/001-50"),
+        Err("Invalid checksum")
+    );
+    assert!(BrazilCaepfValidator::is_valid("123.456.789/001-88").is_err());
+}
+
+#[test]
+fn test_invalid_caepf_length() {
+    assert!(BrazilCaepfValidator::is_valid("").is_err());
+    assert!(BrazilCaepfValidator::is_valid("293.001.100/001-6").is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_employer_registries() {
+    assert!(BrazilCeiValidator::validator("10.041.23456/74").is_ok());
+    assert!(BrazilCnoValidator::validator("51.201.05137/05").is_err());
+    assert!(BrazilCaepfValidator::validator("293.001.100/001-62").is_ok());
+}
@@ -0,0 +1,62 @@
+use docval::brazil::{BrazilJudicialProcessValidator, JudicialProcessNumber, JudiciarySegment};
+
+#[test]
+fn test_valid_process_number() {
+    assert!(BrazilJudicialProcessValidator::is_valid("00000017820208260100").is_ok());
+    assert!(BrazilJudicialProcessValidator::is_valid("12345671320195020001").is_ok());
+}
+
+#[test]
+fn test_valid_process_number_with_formatting() {
+    assert!(BrazilJudicialProcessValidator::is_valid("0710802-06.2018.4.01.3400").is_ok());
+    assert!(BrazilJudicialProcessValidator::is_valid("0001234-25.2023.1.00.0000").is_ok());
+}
+
+#[test]
+fn test_invalid_process_number_checksum() {
+    assert_eq!(
+        BrazilJudicialProcessValidator::is_valid("0000001-79.2020.8.26.0100"),
+        Err("Invalid checksum")
+    );
+    assert!(BrazilJudicialProcessValidator::is_valid("0000002-78.2020.8.26.0100").is_err());
+}
+
+#[test]
+fn test_invalid_process_number_segment() {
+    assert_eq!(
+        BrazilJudicialProcessValidator::is_valid("0000001-78.2020.0.26.0100"),
+        Err("Invalid segment")
+    );
+}
+
+#[test]
+fn test_invalid_process_number_length() {
+    assert!(BrazilJudicialProcessValidator::is_valid("").is_err());
+    assert!(BrazilJudicialProcessValidator::is_valid("0000001-78.2020.8.26.010").is_err());
+}
+
+#[test]
+fn test_parse_process_number() {
+    let process = JudicialProcessNumber::parse("1234567-13.2019.5.02.0001").unwrap();
+    assert_eq!(process.as_str(), "12345671320195020001");
+    assert_eq!(process.sequence(), 1234567);
+    assert_eq!(process.check_digits(), 13);
+    assert_eq!(process.year(), 2019);
+    assert_eq!(process.segment(), JudiciarySegment::Labor);
+    assert_eq!(process.tribunal(), 2);
+    assert_eq!(process.origin(), 1);
+}
+
+#[test]
+fn test_display_process_number() {
+    let process: JudicialProcessNumber = "07108020620184013400".parse().unwrap();
+    assert_eq!(process.segment(), JudiciarySegment::Federal);
+    assert_eq!(process.to_string(), "0710802-06.2018.4.01.3400");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_process_number() {
+    assert!(BrazilJudicialProcessValidator::validator("0000001-78.2020.8.26.0100").is_ok());
+    assert!(BrazilJudicialProcessValidator::validator("0000001-77.2020.8.26.0100").is_err());
+}
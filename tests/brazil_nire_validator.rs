@@ -0,0 +1,52 @@
+use docval::brazil::{BrazilNireValidator, Nire, Uf};
+
+#[test]
+fn test_valid_nire() {
+    assert!(BrazilNireValidator::is_valid("33300032061").is_ok());
+    assert!(BrazilNireValidator::is_valid("53300000638").is_ok());
+    assert!(BrazilNireValidator::is_valid("35300010230").is_ok());
+    assert!(BrazilNireValidator::is_valid("35300368941").is_ok());
+    assert!(BrazilNireValidator::is_valid("33.3.0001976-6").is_ok());
+}
+
+#[test]
+fn test_invalid_state_code() {
+    assert_eq!(
+        BrazilNireValidator::is_valid("99300032061"),
+        Err("Invalid state code")
+    );
+    assert_eq!(
+        BrazilNireValidator::is_valid("00300032061"),
+        Err("Invalid state code")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(BrazilNireValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        BrazilNireValidator::is_valid("3330003206"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BrazilNireValidator::is_valid("333000320610"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nire() {
+    let nire: Nire = "53.3.0000063-8".parse().unwrap();
+    assert_eq!(nire.uf(), Uf::DF);
+    assert_eq!(nire.legal_nature(), 3);
+    assert_eq!(nire.sequence(), 63);
+    assert_eq!(nire.check_digit(), 8);
+    assert_eq!(nire.as_str(), "53300000638");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nire() {
+    assert!(BrazilNireValidator::validator("33300032061").is_ok());
+    assert!(BrazilNireValidator::validator("99300032061").is_err());
+}
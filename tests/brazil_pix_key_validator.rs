@@ -0,0 +1,111 @@
+use docval::brazil::{BrazilPixKeyValidator, PixKey, PixKeyKind};
+
+#[test]
+fn test_valid_cpf_key() {
+    assert_eq!(
+        BrazilPixKeyValidator::kind("12345678909"),
+        Ok(PixKeyKind::Cpf)
+    );
+    assert_eq!(
+        BrazilPixKeyValidator::kind("123.456.789-09"),
+        Ok(PixKeyKind::Cpf)
+    );
+}
+
+#[test]
+fn test_valid_cnpj_key() {
+    assert_eq!(
+        BrazilPixKeyValidator::kind("12.345.678/0001-95"),
+        Ok(PixKeyKind::Cnpj)
+    );
+}
+
+#[test]
+fn test_invalid_tax_id_key() {
+    assert!(BrazilPixKeyValidator::is_valid("000.000.000-00").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("123456789").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("123 456 789 09").is_err());
+}
+
+#[test]
+fn test_valid_phone_key() {
+    assert_eq!(
+        BrazilPixKeyValidator::kind("+5561912345678"),
+        Ok(PixKeyKind::Phone)
+    );
+    assert_eq!(
+        BrazilPixKeyValidator::kind("+556132345678"),
+        Ok(PixKeyKind::Phone)
+    );
+}
+
+#[test]
+fn test_invalid_phone_key() {
+    assert!(BrazilPixKeyValidator::is_valid("+15551234567").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("+550912345678").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("+556191234567890").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("+55 61 91234-5678").is_err());
+}
+
+#[test]
+fn test_valid_email_key() {
+    assert_eq!(
+        BrazilPixKeyValidator::kind("pix@example.com"),
+        Ok(PixKeyKind::Email)
+    );
+    assert_eq!(
+        BrazilPixKeyValidator::kind("first.last+pix@mail.example.com.br"),
+        Ok(PixKeyKind::Email)
+    );
+}
+
+#[test]
+fn test_invalid_email_key() {
+    assert!(BrazilPixKeyValidator::is_valid("@example.com").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("pix@example..com").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("pix@-example.com").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("pix@@example.com").is_err());
+    let long_email = format!("{}@example.com", "a".repeat(70));
+    assert!(BrazilPixKeyValidator::is_valid(&long_email).is_err());
+}
+
+#[test]
+fn test_valid_evp_key() {
+    assert_eq!(
+        BrazilPixKeyValidator::kind("123e4567-e89b-42d3-a456-426614174000"),
+        Ok(PixKeyKind::Evp)
+    );
+}
+
+#[test]
+fn test_invalid_evp_key() {
+    // UUID version 1.
+    assert!(BrazilPixKeyValidator::is_valid("123e4567-e89b-12d3-a456-426614174000").is_err());
+    // Invalid variant.
+    assert!(BrazilPixKeyValidator::is_valid("123e4567-e89b-42d3-c456-426614174000").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("123e4567-e89b-42d3-a456-42661417400g").is_err());
+}
+
+#[test]
+fn test_invalid_key_input() {
+    assert!(BrazilPixKeyValidator::is_valid("").is_err());
+    assert!(BrazilPixKeyValidator::is_valid("not a key").is_err());
+}
+
+#[test]
+fn test_parse_pix_key() {
+    let key = PixKey::parse("123.456.789-09").unwrap();
+    assert_eq!(key.kind(), PixKeyKind::Cpf);
+    assert_eq!(key.as_str(), "12345678909");
+
+    let key: PixKey = "Pix@Example.com".parse().unwrap();
+    assert_eq!(key.kind(), PixKeyKind::Email);
+    assert_eq!(key.to_string(), "pix@example.com");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_pix_key() {
+    assert!(BrazilPixKeyValidator::validator("+5561912345678").is_ok());
+    assert!(BrazilPixKeyValidator::validator("+55619123").is_err());
+}
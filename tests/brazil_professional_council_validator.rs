@@ -0,0 +1,105 @@
+use docval::brazil::{BrazilProfessionalCouncilValidator, Council, ProfessionalRegistration, Uf};
+
+#[test]
+fn test_valid_registrations() {
+    assert!(BrazilProfessionalCouncilValidator::is_valid("CRM/SP 123456").is_ok());
+    assert!(BrazilProfessionalCouncilValidator::is_valid("CRM-SP 12345").is_ok());
+    assert!(BrazilProfessionalCouncilValidator::is_valid("OAB 123.456/RJ").is_ok());
+    assert!(BrazilProfessionalCouncilValidator::is_valid("CREA-SP 5061234567").is_ok());
+    assert!(BrazilProfessionalCouncilValidator::is_valid("CRC/MG 098765/O").is_ok());
+    assert!(BrazilProfessionalCouncilValidator::is_valid("cro/ba 4321").is_ok());
+}
+
+#[test]
+fn test_valid_registrations_for_council() {
+    assert!(
+        BrazilProfessionalCouncilValidator::is_valid_for_council("123456/SP", Council::Crm).is_ok()
+    );
+    assert!(
+        BrazilProfessionalCouncilValidator::is_valid_for_council("SP 123456", Council::Crf).is_ok()
+    );
+    assert!(BrazilProfessionalCouncilValidator::is_valid_for_council(
+        "CRM/SP 123456",
+        Council::Crm
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_invalid_council() {
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("123456/SP"),
+        Err("Invalid council")
+    );
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid_for_council("OAB/SP 123456", Council::Crm),
+        Err("Invalid council")
+    );
+}
+
+#[test]
+fn test_invalid_state() {
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("CRM/XX 123456"),
+        Err("Invalid state")
+    );
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("CRM 123456"),
+        Err("Invalid state")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("CRM/SP 1234567"),
+        Err("Invalid length")
+    );
+    assert!(BrazilProfessionalCouncilValidator::is_valid("CREA/SP 12345678901").is_err());
+}
+
+#[test]
+fn test_invalid_suffix() {
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("CRM/SP 123456-E"),
+        Err("Invalid suffix")
+    );
+    assert!(BrazilProfessionalCouncilValidator::is_valid("OAB/SP 123456-E").is_ok());
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("CRM/SP 123_456"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        BrazilProfessionalCouncilValidator::is_valid("CRM/SP 123 456"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_parse_registration() {
+    let registration: ProfessionalRegistration = "CRC/MG 098765/O".parse().unwrap();
+    assert_eq!(registration.council(), Council::Crc);
+    assert_eq!(registration.uf(), Uf::MG);
+    assert_eq!(registration.number(), "098765");
+    assert_eq!(registration.suffix(), Some('O'));
+    assert_eq!(registration.to_string(), "CRC/MG 098765-O");
+
+    let registration =
+        ProfessionalRegistration::parse_for_council("12345/DF", Council::Crm).unwrap();
+    assert_eq!(registration.to_string(), "CRM/DF 12345");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_professional_registration() {
+    assert!(BrazilProfessionalCouncilValidator::validator("CRM/SP 123456").is_ok());
+    assert!(BrazilProfessionalCouncilValidator::validator("CRM/SP").is_err());
+}
@@ -0,0 +1,82 @@
+use docval::brazil::{BrazilRgValidator, RgValidation, Uf};
+
+#[test]
+fn test_valid_sp_rg() {
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("246781312", Uf::SP),
+        Ok(RgValidation::ChecksumVerified)
+    );
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("39.087.542-9", Uf::SP),
+        Ok(RgValidation::ChecksumVerified)
+    );
+}
+
+#[test]
+fn test_valid_sp_rg_with_x_check_digit() {
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("10.000.006-X", Uf::SP),
+        Ok(RgValidation::ChecksumVerified)
+    );
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("10.000.006-x", Uf::SP),
+        Ok(RgValidation::ChecksumVerified)
+    );
+}
+
+#[test]
+fn test_valid_sp_rg_with_zero_check_digit() {
+    assert!(BrazilRgValidator::is_valid_for_state("10.000.001-0", Uf::SP).is_ok());
+}
+
+#[test]
+fn test_invalid_sp_rg_checksum() {
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("24.678.131-4", Uf::SP),
+        Err("Invalid checksum")
+    );
+    assert!(BrazilRgValidator::is_valid_for_state("10.000.005-X", Uf::SP).is_err());
+}
+
+#[test]
+fn test_invalid_sp_rg_length() {
+    assert!(BrazilRgValidator::is_valid_for_state("4.678.131-2", Uf::SP).is_err());
+}
+
+#[test]
+fn test_format_only_for_other_states() {
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("12.345.678-9", Uf::RJ),
+        Ok(RgValidation::FormatOnly)
+    );
+    assert_eq!(
+        BrazilRgValidator::is_valid_for_state("1234567", Uf::MG),
+        Ok(RgValidation::FormatOnly)
+    );
+}
+
+#[test]
+fn test_invalid_rg_format() {
+    assert!(BrazilRgValidator::is_valid("12.X45.678-9").is_err());
+    assert!(BrazilRgValidator::is_valid("12.345.678-A").is_err());
+}
+
+#[test]
+fn test_invalid_rg_length() {
+    assert!(BrazilRgValidator::is_valid("").is_err());
+    assert!(BrazilRgValidator::is_valid("1234").is_err());
+    assert!(BrazilRgValidator::is_valid("123456789012345").is_err());
+}
+
+#[test]
+fn test_plausible_rg() {
+    assert!(BrazilRgValidator::is_valid("24.678.131-2").is_ok());
+    assert!(BrazilRgValidator::is_valid("MG-12.345.678").is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rg() {
+    assert!(BrazilRgValidator::validator("24.678.131-2").is_ok());
+    assert!(BrazilRgValidator::validator("24.6").is_err());
+}
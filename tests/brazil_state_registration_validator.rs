@@ -0,0 +1,140 @@
+use docval::brazil::{BrazilStateRegistrationValidator, Uf};
+
+const VALID_REGISTRATIONS: &[(&str, Uf)] = &[
+    ("01.004.823/001-12", Uf::AC),
+    ("240000048", Uf::AL),
+    ("030123459", Uf::AP),
+    ("99.999.999-0", Uf::AM),
+    ("123456-63", Uf::BA),
+    ("612345-57", Uf::BA),
+    ("1000003-06", Uf::BA),
+    ("06000001-5", Uf::CE),
+    ("07.300001.001-09", Uf::DF),
+    ("999999990", Uf::ES),
+    ("10.987.654-7", Uf::GO),
+    ("12000038-5", Uf::MA),
+    ("0013000001-9", Uf::MT),
+    ("283040513", Uf::MS),
+    ("062.307.904/0081", Uf::MG),
+    ("15-999999-5", Uf::PA),
+    ("06000001-5", Uf::PB),
+    ("123.45678-50", Uf::PR),
+    ("0321418-40", Uf::PE),
+    ("18.1.001.0000004-9", Uf::PE),
+    ("012345679", Uf::PI),
+    ("99.999.99-3", Uf::RJ),
+    ("20.040.040-1", Uf::RN),
+    ("20.0.040.040-0", Uf::RN),
+    ("224/3658792", Uf::RS),
+    ("0000000062521-3", Uf::RO),
+    ("101.62521-3", Uf::RO),
+    ("24006628-1", Uf::RR),
+    ("251.040.852", Uf::SC),
+    ("110.042.490.114", Uf::SP),
+    ("27123456-3", Uf::SE),
+    ("29.01.022783-6", Uf::TO),
+];
+
+#[test]
+fn test_valid_registrations_for_every_state() {
+    for &(registration, uf) in VALID_REGISTRATIONS {
+        assert!(
+            BrazilStateRegistrationValidator::is_valid_for_state(registration, uf).is_ok(),
+            "{} should be valid for {}",
+            registration,
+            uf
+        );
+    }
+}
+
+#[test]
+fn test_invalid_checksum_for_every_state() {
+    for &(registration, uf) in VALID_REGISTRATIONS {
+        let mut tampered: Vec<char> = registration.chars().collect();
+        let last = tampered.pop().unwrap().to_digit(10).unwrap();
+        tampered.push(char::from_digit((last + 1) % 10, 10).unwrap());
+        let tampered: String = tampered.into_iter().collect();
+        assert!(
+            BrazilStateRegistrationValidator::is_valid_for_state(&tampered, uf).is_err(),
+            "{} should be invalid for {}",
+            tampered,
+            uf
+        );
+    }
+}
+
+#[test]
+fn test_rural_producer_registration() {
+    assert!(
+        BrazilStateRegistrationValidator::is_valid_for_state("P-01100424.3/002", Uf::SP).is_ok()
+    );
+    assert!(
+        BrazilStateRegistrationValidator::is_valid_for_state("P-01100424.4/002", Uf::SP).is_err()
+    );
+    assert!(
+        BrazilStateRegistrationValidator::is_valid_for_state("P-01100424.3/02", Uf::SP).is_err()
+    );
+}
+
+#[test]
+fn test_invalid_registration_length() {
+    assert!(BrazilStateRegistrationValidator::is_valid_for_state("11004249011", Uf::SP).is_err());
+    assert!(BrazilStateRegistrationValidator::is_valid_for_state("0100482300", Uf::AC).is_err());
+}
+
+#[test]
+fn test_invalid_registration_prefix() {
+    // Correct check digits for the Acre layout, but Acre registrations start with 01.
+    assert!(BrazilStateRegistrationValidator::is_valid_for_state("0730000100109", Uf::AC).is_err());
+}
+
+#[test]
+fn test_invalid_registration_input() {
+    assert!(BrazilStateRegistrationValidator::is_valid("").is_err());
+    assert!(BrazilStateRegistrationValidator::is_valid("ISENTO").is_err());
+    assert!(BrazilStateRegistrationValidator::is_valid_for_state("P011004243002", Uf::MG).is_err());
+}
+
+#[test]
+fn test_is_valid_for_any_state() {
+    assert!(BrazilStateRegistrationValidator::is_valid("110.042.490.114").is_ok());
+    assert!(BrazilStateRegistrationValidator::is_valid("110.042.490.115").is_err());
+}
+
+#[test]
+fn test_detect_state() {
+    assert_eq!(
+        BrazilStateRegistrationValidator::detect_state("01.004.823/001-12"),
+        Ok(Uf::AC)
+    );
+    assert_eq!(
+        BrazilStateRegistrationValidator::detect_state("062.307.904/0081"),
+        Ok(Uf::MG)
+    );
+    assert_eq!(
+        BrazilStateRegistrationValidator::detect_state("P-01100424.3/002"),
+        Ok(Uf::SP)
+    );
+}
+
+#[test]
+fn test_detect_state_ambiguous() {
+    let states = BrazilStateRegistrationValidator::matching_states("999999990");
+    assert!(states.contains(&Uf::AM));
+    assert!(states.contains(&Uf::ES));
+    assert!(BrazilStateRegistrationValidator::detect_state("999999990").is_err());
+}
+
+#[test]
+fn test_parse_uf() {
+    assert_eq!("mg".parse::<Uf>(), Ok(Uf::MG));
+    assert!("XX".parse::<Uf>().is_err());
+    assert_eq!(Uf::RS.to_string(), "RS");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_state_registration() {
+    assert!(BrazilStateRegistrationValidator::validator("062.307.904/0081").is_ok());
+    assert!(BrazilStateRegistrationValidator::validator("062.307.904/0082").is_err());
+}
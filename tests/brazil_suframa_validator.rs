@@ -0,0 +1,34 @@
+use docval::brazil::BrazilSuframaValidator;
+
+#[test]
+fn test_valid_suframa() {
+    assert!(BrazilSuframaValidator::is_valid("200106023").is_ok());
+    assert!(BrazilSuframaValidator::is_valid("101580100").is_ok());
+    assert!(BrazilSuframaValidator::is_valid("20.0106.02-3").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        BrazilSuframaValidator::is_valid("200106024"),
+        Err("Invalid checksum")
+    );
+    assert!(BrazilSuframaValidator::is_valid("101580101").is_err());
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(BrazilSuframaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        BrazilSuframaValidator::is_valid("20010602"),
+        Err("Invalid length")
+    );
+    assert!(BrazilSuframaValidator::is_valid("2001060230").is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_suframa() {
+    assert!(BrazilSuframaValidator::validator("200106023").is_ok());
+    assert!(BrazilSuframaValidator::validator("200106020").is_err());
+}
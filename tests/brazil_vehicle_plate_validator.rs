@@ -0,0 +1,80 @@
+use docval::brazil::{BrazilVehiclePlateValidator, VehiclePlateFormat};
+
+#[test]
+fn test_valid_legacy_plate() {
+    assert!(BrazilVehiclePlateValidator::is_valid("ABC-1234").is_ok());
+    assert!(BrazilVehiclePlateValidator::is_valid("abc1234").is_ok());
+}
+
+#[test]
+fn test_valid_mercosul_plate() {
+    assert!(BrazilVehiclePlateValidator::is_valid("BRA2E19").is_ok());
+    assert!(BrazilVehiclePlateValidator::is_valid("bra 2e19").is_ok());
+}
+
+#[test]
+fn test_invalid_plate_length() {
+    assert!(BrazilVehiclePlateValidator::is_valid("ABC-123").is_err());
+    assert!(BrazilVehiclePlateValidator::is_valid("ABC-12345").is_err());
+}
+
+#[test]
+fn test_invalid_plate_format() {
+    assert!(BrazilVehiclePlateValidator::is_valid("AB1-1234").is_err());
+    assert!(BrazilVehiclePlateValidator::is_valid("ABC-A234").is_err());
+    assert!(BrazilVehiclePlateValidator::is_valid("ABC1CC4").is_err());
+}
+
+#[test]
+fn test_invalid_plate_input() {
+    assert!(BrazilVehiclePlateValidator::is_valid("").is_err());
+    assert!(BrazilVehiclePlateValidator::is_valid("ÁBC1234").is_err());
+}
+
+#[test]
+fn test_detect_format() {
+    assert_eq!(
+        BrazilVehiclePlateValidator::detect_format("ABC-1234"),
+        Ok(VehiclePlateFormat::Legacy)
+    );
+    assert_eq!(
+        BrazilVehiclePlateValidator::detect_format("BRA2E19"),
+        Ok(VehiclePlateFormat::Mercosul)
+    );
+}
+
+#[test]
+fn test_convert_to_mercosul() {
+    assert_eq!(
+        BrazilVehiclePlateValidator::to_mercosul("ABC-1234"),
+        Ok("ABC1C34".to_string())
+    );
+    assert_eq!(
+        BrazilVehiclePlateValidator::to_mercosul("BRA2E19"),
+        Ok("BRA2E19".to_string())
+    );
+}
+
+#[test]
+fn test_convert_to_legacy() {
+    assert_eq!(
+        BrazilVehiclePlateValidator::to_legacy("ABC1C34"),
+        Ok("ABC-1234".to_string())
+    );
+    assert_eq!(
+        BrazilVehiclePlateValidator::to_legacy("abc1234"),
+        Ok("ABC-1234".to_string())
+    );
+}
+
+#[test]
+fn test_convert_to_legacy_without_equivalent() {
+    assert!(BrazilVehiclePlateValidator::to_legacy("ABC1K34").is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_vehicle_plate() {
+    assert!(BrazilVehiclePlateValidator::validator("BRA2E19").is_ok());
+    assert!(BrazilVehiclePlateValidator::validator("BRA-2E1").is_err());
+}
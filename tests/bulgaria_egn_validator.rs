@@ -0,0 +1,93 @@
+use docval::bulgaria::{BulgariaEgnValidator, Egn};
+use docval::Sex;
+
+#[test]
+fn test_valid_egn() {
+    assert!(BulgariaEgnValidator::is_valid("8001010008").is_ok());
+    assert!(BulgariaEgnValidator::is_valid("800101 0008").is_ok());
+    assert!(BulgariaEgnValidator::is_valid("8001010040").is_ok());
+    assert!(BulgariaEgnValidator::is_valid("0042291239").is_ok());
+}
+
+#[test]
+fn test_valid_egn_other_centuries() {
+    assert!(BulgariaEgnValidator::is_valid("7523169263").is_ok());
+    assert!(BulgariaEgnValidator::is_valid("0529030101").is_ok());
+    assert!(BulgariaEgnValidator::is_valid("7542011030").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("7523169264"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("8001010041"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("0022291234"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("8013010008"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("8061010008"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("800101000X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("80-01-0100"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(BulgariaEgnValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("800101000"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        BulgariaEgnValidator::is_valid("80010100080"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_egn() {
+    let egn: Egn = "7523169263".parse().unwrap();
+    assert_eq!(egn.as_str(), "7523169263");
+    assert_eq!(egn.birth_year(), 1875);
+    assert_eq!(egn.birth_month(), 3);
+    assert_eq!(egn.birth_day(), 16);
+    assert_eq!(egn.sex(), Sex::Male);
+    assert_eq!(egn.check_digit(), 3);
+    assert_eq!(egn.to_string(), "7523169263");
+
+    let egn: Egn = "0042291239".parse().unwrap();
+    assert_eq!(egn.birth_year(), 2000);
+    assert_eq!(egn.sex(), Sex::Female);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_egn() {
+    assert!(BulgariaEgnValidator::validator("7523169263").is_ok());
+    assert!(BulgariaEgnValidator::validator("7523169264").is_err());
+}
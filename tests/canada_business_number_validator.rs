@@ -0,0 +1,79 @@
+use docval::canada::{BusinessNumber, CanadaBusinessNumberValidator, ProgramAccount};
+
+#[test]
+fn test_valid_bn9() {
+    assert!(CanadaBusinessNumberValidator::is_valid("123456782").is_ok());
+    assert!(CanadaBusinessNumberValidator::is_valid("130 454 283").is_ok());
+}
+
+#[test]
+fn test_valid_bn15() {
+    assert!(CanadaBusinessNumberValidator::is_valid("123456782RT0001").is_ok());
+    assert!(CanadaBusinessNumberValidator::is_valid("123456782 rp 0002").is_ok());
+    assert!(CanadaBusinessNumberValidator::is_valid("130454283-RR-0001").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid("123456789"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid("123456789RT0001"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_program_identifier() {
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid("123456782XX0001"),
+        Err("Invalid program identifier")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid("123456782RT00A1"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid("12345678A"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        CanadaBusinessNumberValidator::is_valid("123456782RT001"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_business_number() {
+    let bn: BusinessNumber = "123456782 RT 0001".parse().unwrap();
+    assert_eq!(bn.as_str(), "123456782RT0001");
+    assert_eq!(bn.root(), "123456782");
+    assert_eq!(bn.program_account(), Some(ProgramAccount::GstHst));
+    assert_eq!(bn.reference_number(), Some("0001"));
+
+    let bn = BusinessNumber::parse("123456782").unwrap();
+    assert_eq!(bn.program_account(), None);
+    assert_eq!(bn.reference_number(), None);
+    assert_eq!(ProgramAccount::GstHst.code(), "RT");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_business_number() {
+    assert!(CanadaBusinessNumberValidator::validator("123456782RT0001").is_ok());
+    assert!(CanadaBusinessNumberValidator::validator("123456782RT").is_err());
+}
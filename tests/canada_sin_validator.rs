@@ -0,0 +1,61 @@
+use docval::canada::{CanadaSinValidator, Sin, SinRegion};
+
+#[test]
+fn test_valid_sin() {
+    assert!(CanadaSinValidator::is_valid("123456782").is_ok());
+    assert!(CanadaSinValidator::is_valid("130 454 283").is_ok());
+    assert!(CanadaSinValidator::is_valid("712-345-677").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        CanadaSinValidator::is_valid("123456789"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        CanadaSinValidator::is_valid("046 454 286"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        CanadaSinValidator::is_valid("800000002"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(CanadaSinValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        CanadaSinValidator::is_valid("12345678"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_temporary_sin() {
+    let sin = Sin::parse("900 000 001").unwrap();
+    assert!(sin.is_temporary());
+    assert_eq!(sin.region(), None);
+}
+
+#[test]
+fn test_parse_sin() {
+    let sin: Sin = "456-789-015".parse().unwrap();
+    assert_eq!(sin.as_str(), "456789015");
+    assert_eq!(sin.region(), Some(SinRegion::Ontario));
+    assert!(!sin.is_temporary());
+    assert_eq!(sin.masked(), "*** *** 015");
+    assert_eq!(sin.to_string(), "456 789 015");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_sin() {
+    assert!(CanadaSinValidator::validator("123 456 782").is_ok());
+    assert!(CanadaSinValidator::validator("123 456 783").is_err());
+}
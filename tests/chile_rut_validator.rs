@@ -0,0 +1,78 @@
+use docval::chile::{ChileRutValidator, Rut};
+
+#[test]
+fn test_valid_rut() {
+    assert!(ChileRutValidator::is_valid("12.345.678-5").is_ok());
+    assert!(ChileRutValidator::is_valid("12345678-5").is_ok());
+    assert!(ChileRutValidator::is_valid("123456785").is_ok());
+    assert!(ChileRutValidator::is_valid("7.654.321-6").is_ok());
+    assert!(ChileRutValidator::is_valid("76.086.428-5").is_ok());
+}
+
+#[test]
+fn test_valid_rut_with_k() {
+    assert!(ChileRutValidator::is_valid("10.000.013-K").is_ok());
+    assert!(ChileRutValidator::is_valid("1.000.005-k").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        ChileRutValidator::is_valid("12.345.678-9"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        ChileRutValidator::is_valid("12.345.678-K"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        ChileRutValidator::is_valid("12.345.67A-5"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        ChileRutValidator::is_valid("1.234.567-É"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        ChileRutValidator::is_valid("12,345,678-5"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(ChileRutValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        ChileRutValidator::is_valid("345.678-5"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        ChileRutValidator::is_valid("112.345.678-5"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rut() {
+    let rut: Rut = "123456785".parse().unwrap();
+    assert_eq!(rut.as_str(), "123456785");
+    assert_eq!(rut.number(), 12345678);
+    assert_eq!(rut.check_character(), '5');
+    assert_eq!(rut.to_string(), "12.345.678-5");
+
+    let rut = Rut::parse("1000005-k").unwrap();
+    assert_eq!(rut.as_str(), "1000005K");
+    assert_eq!(rut.check_character(), 'K');
+    assert_eq!(rut.to_string(), "1.000.005-K");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rut() {
+    assert!(ChileRutValidator::validator("12.345.678-5").is_ok());
+    assert!(ChileRutValidator::validator("12.345.678-0").is_err());
+}
@@ -0,0 +1,61 @@
+use docval::colombia::{ColombiaNitValidator, Nit};
+
+#[test]
+fn test_valid_nit() {
+    assert!(ColombiaNitValidator::is_valid("800.197.268-4").is_ok());
+    assert!(ColombiaNitValidator::is_valid("900123456-8").is_ok());
+    assert!(ColombiaNitValidator::is_valid("8600343137").is_ok());
+    assert!(ColombiaNitValidator::is_valid("899.999.034-1").is_ok());
+    assert!(ColombiaNitValidator::is_valid("12.345.678-8").is_ok());
+    assert!(ColombiaNitValidator::is_valid("1.020.304.050-8").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        ColombiaNitValidator::is_valid("900.123.456-7"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        ColombiaNitValidator::is_valid("800.197.268-0"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(ColombiaNitValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        ColombiaNitValidator::is_valid("1.234.567-8"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        ColombiaNitValidator::is_valid("12345678901234567"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nit() {
+    let nit: Nit = "800.197.268-4".parse().unwrap();
+    assert_eq!(nit.as_str(), "8001972684");
+    assert_eq!(nit.number(), "800197268");
+    assert_eq!(nit.check_digit(), 4);
+    assert_eq!(nit.to_string(), "800.197.268-4");
+}
+
+#[test]
+fn test_display_grouping() {
+    assert_eq!(
+        Nit::parse("10203040508").unwrap().to_string(),
+        "1.020.304.050-8"
+    );
+    assert_eq!(Nit::parse("123456788").unwrap().to_string(), "12.345.678-8");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nit() {
+    assert!(ColombiaNitValidator::validator("800.197.268-4").is_ok());
+    assert!(ColombiaNitValidator::validator("800.197.268-5").is_err());
+}
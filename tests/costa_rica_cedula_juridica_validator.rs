@@ -0,0 +1,71 @@
+use docval::costa_rica::{CedulaJuridica, CostaRicaCedulaJuridicaValidator};
+
+#[test]
+fn test_valid_cedula_juridica() {
+    assert!(CostaRicaCedulaJuridicaValidator::is_valid("3-101-123456").is_ok());
+    assert!(CostaRicaCedulaJuridicaValidator::is_valid("3-002-045678").is_ok());
+    assert!(CostaRicaCedulaJuridicaValidator::is_valid("2-100-042005").is_ok());
+    assert!(CostaRicaCedulaJuridicaValidator::is_valid("4000042138").is_ok());
+    assert!(CostaRicaCedulaJuridicaValidator::is_valid("5001000123").is_ok());
+}
+
+#[test]
+fn test_invalid_class() {
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("1-101-123456"),
+        Err("Invalid class")
+    );
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("6-001-123456"),
+        Err("Invalid class")
+    );
+}
+
+#[test]
+fn test_invalid_type() {
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("2-101-123456"),
+        Err("Invalid type")
+    );
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("3-111-123456"),
+        Err("Invalid type")
+    );
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("4-001-123456"),
+        Err("Invalid type")
+    );
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("5-000-123456"),
+        Err("Invalid type")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        CostaRicaCedulaJuridicaValidator::is_valid("3-101-12345"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cedula_juridica() {
+    let cedula: CedulaJuridica = "3101123456".parse().unwrap();
+    assert_eq!(cedula.as_str(), "3101123456");
+    assert_eq!(cedula.class(), 3);
+    assert_eq!(cedula.entity_type(), "101");
+    assert_eq!(cedula.number(), "123456");
+    assert_eq!(cedula.to_string(), "3-101-123456");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cedula_juridica() {
+    assert!(CostaRicaCedulaJuridicaValidator::validator("3-101-123456").is_ok());
+    assert!(CostaRicaCedulaJuridicaValidator::validator("3-201-123456").is_err());
+}
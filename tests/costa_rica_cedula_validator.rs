@@ -0,0 +1,70 @@
+use docval::costa_rica::{Cedula, CostaRicaCedulaValidator};
+
+#[test]
+fn test_valid_cedula() {
+    assert!(CostaRicaCedulaValidator::is_valid("1-0234-0567").is_ok());
+    assert!(CostaRicaCedulaValidator::is_valid("102340567").is_ok());
+    assert!(CostaRicaCedulaValidator::is_valid("01-0234-0567").is_ok());
+    assert!(CostaRicaCedulaValidator::is_valid("0102340567").is_ok());
+    assert!(CostaRicaCedulaValidator::is_valid("8-0123-4567").is_ok());
+}
+
+#[test]
+fn test_valid_unpadded_cedula() {
+    assert!(CostaRicaCedulaValidator::is_valid("1-234-567").is_ok());
+    assert!(CostaRicaCedulaValidator::is_valid("5 12 1").is_ok());
+}
+
+#[test]
+fn test_invalid_province() {
+    assert_eq!(
+        CostaRicaCedulaValidator::is_valid("0-0234-0567"),
+        Err("Invalid province")
+    );
+    assert_eq!(
+        CostaRicaCedulaValidator::is_valid("002340567"),
+        Err("Invalid province")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        CostaRicaCedulaValidator::is_valid("1-02A4-0567"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        CostaRicaCedulaValidator::is_valid("1-02345-0567"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(CostaRicaCedulaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        CostaRicaCedulaValidator::is_valid("10234056"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        CostaRicaCedulaValidator::is_valid("1102340567"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cedula() {
+    let cedula: Cedula = "1-234-567".parse().unwrap();
+    assert_eq!(cedula.as_str(), "102340567");
+    assert_eq!(cedula.province(), 1);
+    assert_eq!(cedula.volume(), "0234");
+    assert_eq!(cedula.entry(), "0567");
+    assert_eq!(cedula.to_string(), "1-0234-0567");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cedula() {
+    assert!(CostaRicaCedulaValidator::validator("1-0234-0567").is_ok());
+    assert!(CostaRicaCedulaValidator::validator("0-0234-0567").is_err());
+}
@@ -0,0 +1,63 @@
+use docval::croatia::{CroatiaOibValidator, Oib};
+
+#[test]
+fn test_valid_oib() {
+    assert!(CroatiaOibValidator::is_valid("94577403194").is_ok());
+    assert!(CroatiaOibValidator::is_valid("33392005961").is_ok());
+    assert!(CroatiaOibValidator::is_valid("69619365785").is_ok());
+    assert!(CroatiaOibValidator::is_valid("12345678903").is_ok());
+    assert!(CroatiaOibValidator::is_valid("HR94577403194").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        CroatiaOibValidator::is_valid("94577403195"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        CroatiaOibValidator::is_valid("HR12345678901"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        CroatiaOibValidator::is_valid("9457740319X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        CroatiaOibValidator::is_valid("SI945774031"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(CroatiaOibValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(CroatiaOibValidator::is_valid("HR"), Err("Invalid input"));
+    assert_eq!(
+        CroatiaOibValidator::is_valid("9457740319"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        CroatiaOibValidator::is_valid("945774031940"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_oib() {
+    let oib: Oib = "HR 945 774 031 94".parse().unwrap();
+    assert_eq!(oib.as_str(), "94577403194");
+    assert_eq!(oib.check_digit(), 4);
+    assert_eq!(oib.to_string(), "94577403194");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_oib() {
+    assert!(CroatiaOibValidator::validator("94577403194").is_ok());
+    assert!(CroatiaOibValidator::validator("94577403195").is_err());
+}
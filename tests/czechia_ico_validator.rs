@@ -0,0 +1,59 @@
+use docval::czechia::{CzechiaIcoValidator, Ico};
+
+#[test]
+fn test_valid_ico() {
+    assert!(CzechiaIcoValidator::is_valid("25596641").is_ok());
+    assert!(CzechiaIcoValidator::is_valid("255 96 641").is_ok());
+    assert!(CzechiaIcoValidator::is_valid("00006947").is_ok());
+    assert!(CzechiaIcoValidator::is_valid("45274649").is_ok());
+    assert!(CzechiaIcoValidator::is_valid("69947830").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        CzechiaIcoValidator::is_valid("25596642"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        CzechiaIcoValidator::is_valid("69947831"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        CzechiaIcoValidator::is_valid("2559664X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        CzechiaIcoValidator::is_valid("255-9664"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(CzechiaIcoValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(CzechiaIcoValidator::is_valid("6947"), Err("Invalid length"));
+    assert_eq!(
+        CzechiaIcoValidator::is_valid("255966410"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ico() {
+    let ico: Ico = "255 96 641".parse().unwrap();
+    assert_eq!(ico.as_str(), "25596641");
+    assert_eq!(ico.check_digit(), 1);
+    assert_eq!(ico.to_string(), "25596641");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ico() {
+    assert!(CzechiaIcoValidator::validator("25596641").is_ok());
+    assert!(CzechiaIcoValidator::validator("25596642").is_err());
+}
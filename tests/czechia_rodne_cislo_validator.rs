@@ -0,0 +1,115 @@
+use docval::czechia::{CzechiaRodneCisloValidator, RodneCislo};
+use docval::Sex;
+
+#[test]
+fn test_valid_rodne_cislo() {
+    assert!(CzechiaRodneCisloValidator::is_valid("7851011234").is_ok());
+    assert!(CzechiaRodneCisloValidator::is_valid("785101/1234").is_ok());
+    assert!(CzechiaRodneCisloValidator::is_valid("736028/0004").is_ok());
+    assert!(CzechiaRodneCisloValidator::is_valid("000229/0013").is_ok());
+    assert!(CzechiaRodneCisloValidator::is_valid("0456010016").is_ok());
+}
+
+#[test]
+fn test_valid_rodne_cislo_extended_month() {
+    assert!(CzechiaRodneCisloValidator::is_valid("042101/0018").is_ok());
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("992101/0011"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_valid_rodne_cislo_remainder_ten() {
+    assert!(CzechiaRodneCisloValidator::is_valid("710319/2460").is_ok());
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("710319/2461"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_valid_short_rodne_cislo() {
+    assert!(CzechiaRodneCisloValidator::is_valid("530101/123").is_ok());
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("540101/123"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("785101/1235"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("0456010017"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("855230/0010"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("781301/1234"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("78510A/1234"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("785101/123X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("785101/12"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        CzechiaRodneCisloValidator::is_valid("785101/12340"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rodne_cislo() {
+    let rodne_cislo: RodneCislo = "785101/1234".parse().unwrap();
+    assert_eq!(rodne_cislo.as_str(), "7851011234");
+    assert_eq!(rodne_cislo.birth_year(), 1978);
+    assert_eq!(rodne_cislo.birth_month(), 1);
+    assert_eq!(rodne_cislo.birth_day(), 1);
+    assert_eq!(rodne_cislo.sex(), Sex::Female);
+    assert_eq!(rodne_cislo.check_digit(), Some(4));
+    assert_eq!(rodne_cislo.to_string(), "785101/1234");
+
+    let rodne_cislo: RodneCislo = "530101123".parse().unwrap();
+    assert_eq!(rodne_cislo.birth_year(), 1953);
+    assert_eq!(rodne_cislo.sex(), Sex::Male);
+    assert_eq!(rodne_cislo.check_digit(), None);
+    assert_eq!(rodne_cislo.to_string(), "530101/123");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rodne_cislo() {
+    assert!(CzechiaRodneCisloValidator::validator("785101/1234").is_ok());
+    assert!(CzechiaRodneCisloValidator::validator("785101/1235").is_err());
+}
@@ -0,0 +1,76 @@
+use docval::denmark::{Cpr, CprMode, DenmarkCprValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_cpr() {
+    assert!(DenmarkCprValidator::is_valid("070761-4285").is_ok());
+    assert!(DenmarkCprValidator::is_valid("0102031237").is_ok());
+    assert!(DenmarkCprValidator::is_valid("290200-4001").is_ok());
+    assert!(DenmarkCprValidator::is_valid("010170-9000").is_ok());
+    assert!(DenmarkCprValidator::is_valid("311299-3990").is_ok());
+}
+
+#[test]
+fn test_valid_cpr_without_checksum() {
+    assert!(DenmarkCprValidator::is_valid("070761-4286").is_ok());
+    assert!(DenmarkCprValidator::is_valid_with("070761-4286", CprMode::Lenient).is_ok());
+}
+
+#[test]
+fn test_valid_cpr_strict() {
+    assert!(DenmarkCprValidator::is_valid_with("070761-4285", CprMode::Strict).is_ok());
+    assert!(DenmarkCprValidator::is_valid_with("150585-5120", CprMode::Strict).is_ok());
+    assert_eq!(
+        DenmarkCprValidator::is_valid_with("070761-4286", CprMode::Strict),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_cpr() {
+    assert_eq!(DenmarkCprValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        DenmarkCprValidator::is_valid("070761-428"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        DenmarkCprValidator::is_valid("070761-428A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        DenmarkCprValidator::is_valid("320761-4285"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        DenmarkCprValidator::is_valid("290200-3000"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_parse_cpr() {
+    let cpr: Cpr = "070761-4285".parse().unwrap();
+    assert_eq!(cpr.as_str(), "0707614285");
+    assert_eq!(cpr.birth_year(), 1961);
+    assert_eq!(cpr.birth_month(), 7);
+    assert_eq!(cpr.birth_day(), 7);
+    assert_eq!(cpr.serial(), 4285);
+    assert_eq!(cpr.sex(), Sex::Male);
+    assert!(cpr.has_valid_checksum());
+    assert_eq!(cpr.to_string(), "070761-4285");
+
+    let cpr = Cpr::parse("010160-5000").unwrap();
+    assert_eq!(cpr.birth_year(), 1860);
+    assert_eq!(cpr.sex(), Sex::Female);
+
+    let cpr = Cpr::parse("070761-4286").unwrap();
+    assert!(!cpr.has_valid_checksum());
+    assert!(Cpr::parse_with("070761-4286", CprMode::Strict).is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cpr() {
+    assert!(DenmarkCprValidator::validator("070761-4285").is_ok());
+    assert!(DenmarkCprValidator::validator("320761-4285").is_err());
+}
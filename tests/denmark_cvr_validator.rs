@@ -0,0 +1,55 @@
+use docval::denmark::{Cvr, DenmarkCvrValidator};
+
+#[test]
+fn test_valid_cvr() {
+    assert!(DenmarkCvrValidator::is_valid("13585628").is_ok());
+    assert!(DenmarkCvrValidator::is_valid("24256790").is_ok());
+    assert!(DenmarkCvrValidator::is_valid("10150817").is_ok());
+    assert!(DenmarkCvrValidator::is_valid("61 12 62 28").is_ok());
+}
+
+#[test]
+fn test_valid_cvr_with_prefix() {
+    assert!(DenmarkCvrValidator::is_valid("DK13585628").is_ok());
+    assert!(DenmarkCvrValidator::is_valid("dk 25313763").is_ok());
+}
+
+#[test]
+fn test_invalid_cvr() {
+    assert_eq!(DenmarkCvrValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        DenmarkCvrValidator::is_valid("1358562"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        DenmarkCvrValidator::is_valid("SE13585628"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        DenmarkCvrValidator::is_valid("1358562A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        DenmarkCvrValidator::is_valid("04585623"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        DenmarkCvrValidator::is_valid("13585629"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_cvr() {
+    let cvr: Cvr = "DK 24 25 67 90".parse().unwrap();
+    assert_eq!(cvr.as_str(), "24256790");
+    assert_eq!(cvr.vat_number(), "DK24256790");
+    assert_eq!(cvr.to_string(), "24256790");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cvr() {
+    assert!(DenmarkCvrValidator::validator("13585628").is_ok());
+    assert!(DenmarkCvrValidator::validator("13585629").is_err());
+}
@@ -0,0 +1,52 @@
+use docval::dominican_republic::{Cedula, DominicanRepublicCedulaValidator};
+
+#[test]
+fn test_valid_cedula() {
+    assert!(DominicanRepublicCedulaValidator::is_valid("001-0001072-7").is_ok());
+    assert!(DominicanRepublicCedulaValidator::is_valid("224-1234567-4").is_ok());
+    assert!(DominicanRepublicCedulaValidator::is_valid("40200000004").is_ok());
+    assert!(DominicanRepublicCedulaValidator::is_valid("031 0000000 3").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        DominicanRepublicCedulaValidator::is_valid("001-0001072-8"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        DominicanRepublicCedulaValidator::is_valid("224-1234567-0"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        DominicanRepublicCedulaValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        DominicanRepublicCedulaValidator::is_valid("001-0001072"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        DominicanRepublicCedulaValidator::is_valid("001-00010720-7"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cedula() {
+    let cedula: Cedula = "00100010727".parse().unwrap();
+    assert_eq!(cedula.as_str(), "00100010727");
+    assert_eq!(cedula.municipality(), "001");
+    assert_eq!(cedula.to_string(), "001-0001072-7");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cedula() {
+    assert!(DominicanRepublicCedulaValidator::validator("001-0001072-7").is_ok());
+    assert!(DominicanRepublicCedulaValidator::validator("001-0001072-0").is_err());
+}
@@ -0,0 +1,62 @@
+use docval::dominican_republic::{DominicanRepublicRncValidator, Rnc, RncKind};
+
+#[test]
+fn test_valid_company_rnc() {
+    assert!(DominicanRepublicRncValidator::is_valid("1-01-85004-3").is_ok());
+    assert!(DominicanRepublicRncValidator::is_valid("131098193").is_ok());
+    assert!(DominicanRepublicRncValidator::is_valid("4-01-00000-8").is_ok());
+    assert!(DominicanRepublicRncValidator::is_valid("123456786").is_ok());
+}
+
+#[test]
+fn test_valid_person_rnc() {
+    assert!(DominicanRepublicRncValidator::is_valid("001-0001072-7").is_ok());
+    assert!(DominicanRepublicRncValidator::is_valid("22412345674").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        DominicanRepublicRncValidator::is_valid("1-01-85004-4"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        DominicanRepublicRncValidator::is_valid("001-0001072-1"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        DominicanRepublicRncValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        DominicanRepublicRncValidator::is_valid("1-01-8500-3"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        DominicanRepublicRncValidator::is_valid("1010185004"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rnc() {
+    let rnc: Rnc = "101850043".parse().unwrap();
+    assert_eq!(rnc.as_str(), "101850043");
+    assert_eq!(rnc.kind(), RncKind::Company);
+    assert_eq!(rnc.to_string(), "1-01-85004-3");
+
+    let rnc = Rnc::parse("00100010727").unwrap();
+    assert_eq!(rnc.kind(), RncKind::Person);
+    assert_eq!(rnc.to_string(), "001-0001072-7");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rnc() {
+    assert!(DominicanRepublicRncValidator::validator("1-01-85004-3").is_ok());
+    assert!(DominicanRepublicRncValidator::validator("1-01-85004-0").is_err());
+}
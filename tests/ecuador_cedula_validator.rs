@@ -0,0 +1,70 @@
+use docval::ecuador::{Cedula, EcuadorCedulaValidator};
+
+#[test]
+fn test_valid_cedula() {
+    assert!(EcuadorCedulaValidator::is_valid("1710034065").is_ok());
+    assert!(EcuadorCedulaValidator::is_valid("0920048774").is_ok());
+    assert!(EcuadorCedulaValidator::is_valid("010101010-6").is_ok());
+    assert!(EcuadorCedulaValidator::is_valid("2400000002").is_ok());
+    assert!(EcuadorCedulaValidator::is_valid("3023456787").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("1710034066"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("0920048770"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_province() {
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("0010034065"),
+        Err("Invalid province")
+    );
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("2510034065"),
+        Err("Invalid province")
+    );
+}
+
+#[test]
+fn test_invalid_third_digit() {
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("1760034065"),
+        Err("Invalid third digit")
+    );
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("1790034065"),
+        Err("Invalid third digit")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(EcuadorCedulaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        EcuadorCedulaValidator::is_valid("171003406"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cedula() {
+    let cedula: Cedula = "171003406-5".parse().unwrap();
+    assert_eq!(cedula.as_str(), "1710034065");
+    assert_eq!(cedula.province(), 17);
+    assert_eq!(cedula.to_string(), "1710034065");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cedula() {
+    assert!(EcuadorCedulaValidator::validator("1710034065").is_ok());
+    assert!(EcuadorCedulaValidator::validator("1710034060").is_err());
+}
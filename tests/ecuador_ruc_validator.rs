@@ -0,0 +1,89 @@
+use docval::ecuador::{EcuadorRucValidator, Ruc, RucKind};
+
+#[test]
+fn test_valid_natural_person_ruc() {
+    assert!(EcuadorRucValidator::is_valid("1710034065001").is_ok());
+    assert!(EcuadorRucValidator::is_valid("0920048774002").is_ok());
+}
+
+#[test]
+fn test_valid_public_entity_ruc() {
+    assert!(EcuadorRucValidator::is_valid("1760001390001").is_ok());
+    assert!(EcuadorRucValidator::is_valid("1760001040001").is_ok());
+}
+
+#[test]
+fn test_valid_private_company_ruc() {
+    assert!(EcuadorRucValidator::is_valid("1792060346001").is_ok());
+    assert!(EcuadorRucValidator::is_valid("0990042632001").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1710034066001"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1760001310001"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1792060345001"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_structure() {
+    assert_eq!(
+        EcuadorRucValidator::is_valid("2692060346001"),
+        Err("Invalid province")
+    );
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1772060346001"),
+        Err("Invalid third digit")
+    );
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1792060346000"),
+        Err("Invalid establishment")
+    );
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1760001390000"),
+        Err("Invalid establishment")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(EcuadorRucValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        EcuadorRucValidator::is_valid("1710034065"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ruc() {
+    let ruc: Ruc = "1710034065001".parse().unwrap();
+    assert_eq!(ruc.province(), 17);
+    assert_eq!(ruc.kind(), RucKind::NaturalPerson);
+    assert_eq!(ruc.cedula(), Some("1710034065"));
+    assert_eq!(ruc.establishment(), "001");
+
+    let ruc = Ruc::parse("1760001390001").unwrap();
+    assert_eq!(ruc.kind(), RucKind::PublicEntity);
+    assert_eq!(ruc.cedula(), None);
+    assert_eq!(ruc.establishment(), "0001");
+
+    let ruc = Ruc::parse("1792060346001").unwrap();
+    assert_eq!(ruc.kind(), RucKind::PrivateCompany);
+    assert_eq!(ruc.to_string(), "1792060346001");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ruc() {
+    assert!(EcuadorRucValidator::validator("1792060346001").is_ok());
+    assert!(EcuadorRucValidator::validator("1792060347001").is_err());
+}
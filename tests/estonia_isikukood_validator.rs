@@ -0,0 +1,70 @@
+use docval::estonia::{EstoniaIsikukoodValidator, Isikukood};
+use docval::Sex;
+
+#[test]
+fn test_valid_isikukood() {
+    assert!(EstoniaIsikukoodValidator::is_valid("37605030299").is_ok());
+    assert!(EstoniaIsikukoodValidator::is_valid("50002291239").is_ok());
+    assert!(EstoniaIsikukoodValidator::is_valid("18501010000").is_ok());
+    assert!(EstoniaIsikukoodValidator::is_valid("29912319996").is_ok());
+    assert!(EstoniaIsikukoodValidator::is_valid("376 050 302 99").is_ok());
+}
+
+#[test]
+fn test_valid_isikukood_second_stage() {
+    assert!(EstoniaIsikukoodValidator::is_valid("38001011044").is_ok());
+    assert!(EstoniaIsikukoodValidator::is_valid("60002291230").is_ok());
+    assert!(EstoniaIsikukoodValidator::is_valid("38001010250").is_ok());
+}
+
+#[test]
+fn test_invalid_isikukood() {
+    assert_eq!(
+        EstoniaIsikukoodValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        EstoniaIsikukoodValidator::is_valid("3760503029"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        EstoniaIsikukoodValidator::is_valid("3760503029A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        EstoniaIsikukoodValidator::is_valid("97605030299"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        EstoniaIsikukoodValidator::is_valid("49002291234"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        EstoniaIsikukoodValidator::is_valid("37605030298"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_isikukood() {
+    let isikukood: Isikukood = "37605030299".parse().unwrap();
+    assert_eq!(isikukood.as_str(), "37605030299");
+    assert_eq!(isikukood.birth_year(), 1976);
+    assert_eq!(isikukood.birth_month(), 5);
+    assert_eq!(isikukood.birth_day(), 3);
+    assert_eq!(isikukood.sex(), Sex::Male);
+    assert_eq!(isikukood.serial(), 29);
+    assert_eq!(isikukood.check_digit(), 9);
+    assert_eq!(isikukood.to_string(), "37605030299");
+
+    let isikukood = Isikukood::parse("29912319996").unwrap();
+    assert_eq!(isikukood.birth_year(), 1899);
+    assert_eq!(isikukood.sex(), Sex::Female);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_isikukood() {
+    assert!(EstoniaIsikukoodValidator::validator("37605030299").is_ok());
+    assert!(EstoniaIsikukoodValidator::validator("37605030298").is_err());
+}
@@ -0,0 +1,166 @@
+use docval::eu::{EuVatValidator, MemberState, VatNumber};
+
+const VALID_NUMBERS: &[&str] = &[
+    "ATU13585627",
+    "BE0403019261",
+    "BG175074752",
+    "BG7523169263",
+    "CY10259033P",
+    "CZ25123891",
+    "CZ640903926",
+    "CZ7103192745",
+    "DE136695976",
+    "DK13585628",
+    "EE100931558",
+    "EL094259216",
+    "ESA13585625",
+    "ESX2482300W",
+    "ES54362315K",
+    "FI20774740",
+    "FR40303265045",
+    "FRK7399859412",
+    "FR4Z123456782",
+    "HR33392005961",
+    "HU12892312",
+    "IE6433435F",
+    "IE6433435OA",
+    "IE8D79739I",
+    "IT00743110157",
+    "LT119511515",
+    "LT100001919017",
+    "LU15027442",
+    "LV40003521600",
+    "LV16117519997",
+    "MT11679112",
+    "NL004495445B01",
+    "PL8567346215",
+    "PT501964843",
+    "RO18547290",
+    "SE123456789701",
+    "SI50223054",
+    "SK2022749619",
+    "XI980780684",
+    "XIGD001",
+    "XIHA500",
+];
+
+#[test]
+fn test_valid_vat_numbers() {
+    for number in VALID_NUMBERS {
+        assert!(EuVatValidator::is_valid(number).is_ok(), "{}", number);
+    }
+}
+
+#[test]
+fn test_valid_formatted_vat_numbers() {
+    assert!(EuVatValidator::is_valid("DE 136 695 976").is_ok());
+    assert!(EuVatValidator::is_valid("be 0403.019.261").is_ok());
+    assert!(EuVatValidator::is_valid("GR094259216").is_ok());
+    assert!(EuVatValidator::is_valid("BE403019261").is_ok());
+    assert!(EuVatValidator::is_valid_for("136695976", MemberState::Germany).is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    for number in [
+        "ATU13585628",
+        "BE0403019262",
+        "CY10259033A",
+        "DE136695977",
+        "DK13585627",
+        "ESA13585626",
+        "FR41303265045",
+        "IE6433435G",
+        "NL004495446B01",
+        "PL8567346216",
+        "RO18547291",
+        "SE123456789801",
+        "XI241727626",
+    ] {
+        assert_eq!(
+            EuVatValidator::is_valid(number),
+            Err("Invalid checksum"),
+            "{}",
+            number
+        );
+    }
+}
+
+#[test]
+fn test_invalid_country_code() {
+    assert_eq!(
+        EuVatValidator::is_valid("GB980780684"),
+        Err("Invalid country code")
+    );
+    assert_eq!(
+        EuVatValidator::is_valid("US123456789"),
+        Err("Invalid country code")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        EuVatValidator::is_valid("AT13585627X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        EuVatValidator::is_valid("DE036695976"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        EuVatValidator::is_valid("NL004495445B00"),
+        Err("Invalid format")
+    );
+    assert_eq!(EuVatValidator::is_valid("XIGD500"), Err("Invalid format"));
+    assert_eq!(
+        EuVatValidator::is_valid("BE2123456791"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        EuVatValidator::is_valid("XIGB980780684"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        EuVatValidator::is_valid("DE13669597É"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_category() {
+    assert_eq!(
+        EuVatValidator::is_valid("PT400000008"),
+        Err("Invalid category")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(EuVatValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(EuVatValidator::is_valid("D"), Err("Invalid length"));
+    assert_eq!(
+        EuVatValidator::is_valid("DE1366959761"),
+        Err("Invalid length")
+    );
+    assert_eq!(EuVatValidator::is_valid("FI2077474"), Err("Invalid length"));
+}
+
+#[test]
+fn test_parse_vat_number() {
+    let vat: VatNumber = "el 094 259 216".parse().unwrap();
+    assert_eq!(vat.member_state(), MemberState::Greece);
+    assert_eq!(vat.number(), "094259216");
+    assert_eq!(vat.to_string(), "EL094259216");
+
+    let vat = VatNumber::parse("XI 980 7806 84").unwrap();
+    assert_eq!(vat.member_state(), MemberState::NorthernIreland);
+    assert_eq!(vat.to_string(), "XI980780684");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_vat_number() {
+    assert!(EuVatValidator::validator("DE136695976").is_ok());
+    assert!(EuVatValidator::validator("DE136695977").is_err());
+}
@@ -0,0 +1,78 @@
+use docval::finland::{FinlandHetuValidator, Hetu};
+use docval::Sex;
+
+#[test]
+fn test_valid_hetu() {
+    assert!(FinlandHetuValidator::is_valid("131052-308T").is_ok());
+    assert!(FinlandHetuValidator::is_valid("150585+456B").is_ok());
+    assert!(FinlandHetuValidator::is_valid("010100A002H").is_ok());
+    assert!(FinlandHetuValidator::is_valid("290200a1239").is_ok());
+    assert!(FinlandHetuValidator::is_valid("311299-999E").is_ok());
+}
+
+#[test]
+fn test_valid_hetu_new_century_signs() {
+    assert!(FinlandHetuValidator::is_valid("131052Y308T").is_ok());
+    assert!(FinlandHetuValidator::is_valid("131052U308T").is_ok());
+    assert!(FinlandHetuValidator::is_valid("131052B308T").is_ok());
+    assert!(FinlandHetuValidator::is_valid("131052F308T").is_ok());
+}
+
+#[test]
+fn test_invalid_hetu() {
+    assert_eq!(FinlandHetuValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        FinlandHetuValidator::is_valid("131052-308"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        FinlandHetuValidator::is_valid("131052-3O8T"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        FinlandHetuValidator::is_valid("13105é308T"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        FinlandHetuValidator::is_valid("131052G308T"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        FinlandHetuValidator::is_valid("131052-001T"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        FinlandHetuValidator::is_valid("290200-1239"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        FinlandHetuValidator::is_valid("131052-308U"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_hetu() {
+    let hetu: Hetu = "131052-308t".parse().unwrap();
+    assert_eq!(hetu.as_str(), "131052-308T");
+    assert_eq!(hetu.birth_year(), 1952);
+    assert_eq!(hetu.birth_month(), 10);
+    assert_eq!(hetu.birth_day(), 13);
+    assert_eq!(hetu.century_sign(), '-');
+    assert_eq!(hetu.individual_number(), 308);
+    assert_eq!(hetu.sex(), Sex::Female);
+    assert!(!hetu.is_temporary());
+    assert_eq!(hetu.control_character(), 'T');
+    assert_eq!(hetu.to_string(), "131052-308T");
+
+    assert_eq!(Hetu::parse("150585+456B").unwrap().birth_year(), 1885);
+    assert_eq!(Hetu::parse("131052D308T").unwrap().birth_year(), 2052);
+    assert!(Hetu::parse("311299-999E").unwrap().is_temporary());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_hetu() {
+    assert!(FinlandHetuValidator::validator("131052-308T").is_ok());
+    assert!(FinlandHetuValidator::validator("131052-308U").is_err());
+}
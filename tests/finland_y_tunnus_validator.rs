@@ -0,0 +1,57 @@
+use docval::finland::{FinlandYTunnusValidator, YTunnus};
+
+#[test]
+fn test_valid_y_tunnus() {
+    assert!(FinlandYTunnusValidator::is_valid("0112038-9").is_ok());
+    assert!(FinlandYTunnusValidator::is_valid("0737546-2").is_ok());
+    assert!(FinlandYTunnusValidator::is_valid("2336509-6").is_ok());
+    assert!(FinlandYTunnusValidator::is_valid("1572860-0").is_ok());
+    assert!(FinlandYTunnusValidator::is_valid("01012636").is_ok());
+}
+
+#[test]
+fn test_valid_y_tunnus_six_digits() {
+    assert!(FinlandYTunnusValidator::is_valid("112038-9").is_ok());
+    assert!(FinlandYTunnusValidator::is_valid("737546-2").is_ok());
+}
+
+#[test]
+fn test_invalid_y_tunnus() {
+    assert_eq!(FinlandYTunnusValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        FinlandYTunnusValidator::is_valid("12038-9"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        FinlandYTunnusValidator::is_valid("10112038-9"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        FinlandYTunnusValidator::is_valid("011203A-9"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        FinlandYTunnusValidator::is_valid("0112038-8"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        FinlandYTunnusValidator::is_valid("1234568-0"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_y_tunnus() {
+    let y_tunnus: YTunnus = "0737546-2".parse().unwrap();
+    assert_eq!(y_tunnus.as_str(), "07375462");
+    assert_eq!(y_tunnus.vat_number(), "FI07375462");
+    assert_eq!(y_tunnus.check_digit(), 2);
+    assert_eq!(y_tunnus.to_string(), "0737546-2");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_y_tunnus() {
+    assert!(FinlandYTunnusValidator::validator("0112038-9").is_ok());
+    assert!(FinlandYTunnusValidator::validator("0112038-8").is_err());
+}
@@ -0,0 +1,110 @@
+use docval::france::{FranceNirValidator, Nir};
+use docval::Sex;
+
+#[test]
+fn test_valid_nir() {
+    assert!(FranceNirValidator::is_valid("295109912611193").is_ok());
+    assert!(FranceNirValidator::is_valid("2 95 10 99 126 111 93").is_ok());
+    assert!(FranceNirValidator::is_valid("2.69.02.974.123.45-45").is_ok());
+    assert!(FranceNirValidator::is_valid("785057512345664").is_ok());
+    assert!(FranceNirValidator::is_valid("185427512345683").is_ok());
+}
+
+#[test]
+fn test_valid_corsican_nir() {
+    assert!(FranceNirValidator::is_valid("1 85 07 2A 004 123 88").is_ok());
+    assert!(FranceNirValidator::is_valid("2 82 02 2b 123 456 12").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        FranceNirValidator::is_valid("295109912611194"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        FranceNirValidator::is_valid("185072B00412388"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_fields() {
+    assert_eq!(
+        FranceNirValidator::is_valid("395109912611193"),
+        Err("Invalid sex")
+    );
+    assert_eq!(
+        FranceNirValidator::is_valid("295139912611193"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        FranceNirValidator::is_valid("295100012611193"),
+        Err("Invalid department")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        FranceNirValidator::is_valid("185072C00412388"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        FranceNirValidator::is_valid("1850702A0412388"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(FranceNirValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        FranceNirValidator::is_valid("2951099126111"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        FranceNirValidator::is_valid("2951099126111930"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nir() {
+    let nir: Nir = "2 95 10 99 126 111 93".parse().unwrap();
+    assert_eq!(nir.as_str(), "295109912611193");
+    assert_eq!(nir.sex(), Sex::Female);
+    assert!(!nir.is_temporary());
+    assert_eq!(nir.birth_year(), 95);
+    assert_eq!(nir.birth_month(), Some(10));
+    assert_eq!(nir.department(), "99");
+    assert_eq!(nir.commune(), "126");
+    assert!(nir.is_born_abroad());
+    assert_eq!(nir.order(), 111);
+    assert_eq!(nir.key(), 93);
+    assert_eq!(nir.to_string(), "2 95 10 99 126 111 93");
+}
+
+#[test]
+fn test_parse_nir_departments() {
+    let nir = Nir::parse("185072a00412388").unwrap();
+    assert_eq!(nir.department(), "2A");
+    assert_eq!(nir.commune(), "004");
+
+    let nir = Nir::parse("269029741234545").unwrap();
+    assert_eq!(nir.department(), "974");
+    assert_eq!(nir.commune(), "12");
+    assert!(!nir.is_born_abroad());
+
+    let nir = Nir::parse("785057512345664").unwrap();
+    assert_eq!(nir.sex(), Sex::Male);
+    assert!(nir.is_temporary());
+    assert_eq!(Nir::parse("185427512345683").unwrap().birth_month(), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nir() {
+    assert!(FranceNirValidator::validator("295109912611193").is_ok());
+    assert!(FranceNirValidator::validator("295109912611194").is_err());
+}
@@ -0,0 +1,82 @@
+use docval::france::{FranceSirenValidator, FranceSiretValidator, Siren, Siret};
+
+#[test]
+fn test_valid_siren() {
+    assert!(FranceSirenValidator::is_valid("732829320").is_ok());
+    assert!(FranceSirenValidator::is_valid("732 829 320").is_ok());
+    assert!(FranceSirenValidator::is_valid("552100554").is_ok());
+    assert!(FranceSirenValidator::is_valid("356000000").is_ok());
+}
+
+#[test]
+fn test_valid_siret() {
+    assert!(FranceSiretValidator::is_valid("73282932000074").is_ok());
+    assert!(FranceSiretValidator::is_valid("552 100 554 00013").is_ok());
+    assert!(FranceSiretValidator::is_valid("404 833 048 00022").is_ok());
+    assert!(FranceSiretValidator::is_valid("44306184100047").is_ok());
+}
+
+#[test]
+fn test_valid_la_poste_siret() {
+    assert!(FranceSiretValidator::is_valid("35600000049837").is_ok());
+    assert!(FranceSiretValidator::is_valid("356 000 000 00006").is_ok());
+    assert_eq!(
+        FranceSiretValidator::is_valid("35600000000048"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        FranceSirenValidator::is_valid("732829321"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        FranceSiretValidator::is_valid("73282932000075"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        FranceSiretValidator::is_valid("73282932100073"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(FranceSirenValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        FranceSirenValidator::is_valid("73282932"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        FranceSirenValidator::is_valid("73282932000074"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        FranceSiretValidator::is_valid("732829320"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_siren_and_siret() {
+    let siren: Siren = "732 829 320".parse().unwrap();
+    assert_eq!(siren.as_str(), "732829320");
+    assert_eq!(siren.to_string(), "732 829 320");
+
+    let siret: Siret = "73282932000074".parse().unwrap();
+    assert_eq!(siret.as_str(), "73282932000074");
+    assert_eq!(siret.siren(), siren);
+    assert_eq!(siret.nic(), "00074");
+    assert_eq!(siret.to_string(), "732 829 320 00074");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_siren_and_siret() {
+    assert!(FranceSirenValidator::validator("732829320").is_ok());
+    assert!(FranceSirenValidator::validator("732829321").is_err());
+    assert!(FranceSiretValidator::validator("73282932000074").is_ok());
+    assert!(FranceSiretValidator::validator("73282932000075").is_err());
+}
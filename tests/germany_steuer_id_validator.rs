@@ -0,0 +1,75 @@
+use docval::germany::{GermanySteuerIdValidator, SteuerId};
+
+#[test]
+fn test_valid_steuer_id() {
+    assert!(GermanySteuerIdValidator::is_valid("36574261809").is_ok());
+    assert!(GermanySteuerIdValidator::is_valid("36 574 261 809").is_ok());
+    assert!(GermanySteuerIdValidator::is_valid("86095742719").is_ok());
+    assert!(GermanySteuerIdValidator::is_valid("47036892816").is_ok());
+    assert!(GermanySteuerIdValidator::is_valid("65929970489").is_ok());
+}
+
+#[test]
+fn test_valid_steuer_id_with_tripled_digit() {
+    assert!(GermanySteuerIdValidator::is_valid("12131456787").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("36574261890"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("86095742718"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("01234567896"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("12345678903"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("11223456785"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("11123456786"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(GermanySteuerIdValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("3657426180"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        GermanySteuerIdValidator::is_valid("365742618090"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_steuer_id() {
+    let steuer_id: SteuerId = "86 095 742 719".parse().unwrap();
+    assert_eq!(steuer_id.as_str(), "86095742719");
+    assert_eq!(steuer_id.check_digit(), 9);
+    assert_eq!(steuer_id.to_string(), "86 095 742 719");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_steuer_id() {
+    assert!(GermanySteuerIdValidator::validator("36574261809").is_ok());
+    assert!(GermanySteuerIdValidator::validator("36574261890").is_err());
+}
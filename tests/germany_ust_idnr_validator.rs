@@ -0,0 +1,70 @@
+use docval::germany::{GermanyUstIdNrValidator, UstIdNr};
+
+#[test]
+fn test_valid_ust_idnr() {
+    assert!(GermanyUstIdNrValidator::is_valid("DE136695976").is_ok());
+    assert!(GermanyUstIdNrValidator::is_valid("DE 136 695 976").is_ok());
+    assert!(GermanyUstIdNrValidator::is_valid("de811829215").is_ok());
+    assert!(GermanyUstIdNrValidator::is_valid("123456788").is_ok());
+    assert!(GermanyUstIdNrValidator::is_valid("999999995").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("DE136695977"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("123456789"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("DE036695976"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("AT1366959"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("DE13669597X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(GermanyUstIdNrValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("DE"),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("DE13669597"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        GermanyUstIdNrValidator::is_valid("DE1366959761"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ust_idnr() {
+    let ust_idnr: UstIdNr = "DE 136 695 976".parse().unwrap();
+    assert_eq!(ust_idnr.as_str(), "136695976");
+    assert_eq!(ust_idnr.check_digit(), 6);
+    assert_eq!(ust_idnr.to_string(), "DE136695976");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ust_idnr() {
+    assert!(GermanyUstIdNrValidator::validator("DE136695976").is_ok());
+    assert!(GermanyUstIdNrValidator::validator("DE136695977").is_err());
+}
@@ -0,0 +1,77 @@
+use docval::greece::{Afm, GreeceAfmValidator};
+
+#[test]
+fn test_valid_afm() {
+    assert!(GreeceAfmValidator::is_valid("094259216").is_ok());
+    assert!(GreeceAfmValidator::is_valid("123456783").is_ok());
+    assert!(GreeceAfmValidator::is_valid("997645360").is_ok());
+    assert!(GreeceAfmValidator::is_valid("094 259 216").is_ok());
+}
+
+#[test]
+fn test_valid_afm_with_prefix() {
+    assert!(GreeceAfmValidator::is_valid("EL094259216").is_ok());
+    assert!(GreeceAfmValidator::is_valid("gr 077111064").is_ok());
+}
+
+#[test]
+fn test_valid_afm_remainder_ten() {
+    assert!(GreeceAfmValidator::is_valid("123456090").is_ok());
+    assert_eq!(
+        GreeceAfmValidator::is_valid("123456091"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        GreeceAfmValidator::is_valid("094259217"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        GreeceAfmValidator::is_valid("EL123456789"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        GreeceAfmValidator::is_valid("09425921X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GreeceAfmValidator::is_valid("CY0942592"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(GreeceAfmValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(GreeceAfmValidator::is_valid("EL"), Err("Invalid input"));
+    assert_eq!(
+        GreeceAfmValidator::is_valid("94259216"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        GreeceAfmValidator::is_valid("0942592160"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_afm() {
+    let afm: Afm = "EL 094 259 216".parse().unwrap();
+    assert_eq!(afm.as_str(), "094259216");
+    assert_eq!(afm.check_digit(), 6);
+    assert_eq!(afm.to_string(), "094259216");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_afm() {
+    assert!(GreeceAfmValidator::validator("094259216").is_ok());
+    assert!(GreeceAfmValidator::validator("094259217").is_err());
+}
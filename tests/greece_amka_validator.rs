@@ -0,0 +1,83 @@
+use docval::greece::{Amka, GreeceAmkaValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_amka() {
+    assert!(GreeceAmkaValidator::is_valid("01018001238").is_ok());
+    assert!(GreeceAmkaValidator::is_valid("010180 01238").is_ok());
+    assert!(GreeceAmkaValidator::is_valid("15038512347").is_ok());
+    assert!(GreeceAmkaValidator::is_valid("31129910027").is_ok());
+    assert!(GreeceAmkaValidator::is_valid("29020004569").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("01018001239"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("15038512348"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("29020104567"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("31028512346"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("00018001238"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("0101800123X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("01-01-80123"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(GreeceAmkaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("0101800123"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        GreeceAmkaValidator::is_valid("010180012380"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_amka() {
+    let amka: Amka = "01018001238".parse().unwrap();
+    assert_eq!(amka.as_str(), "01018001238");
+    assert_eq!(amka.birth_day(), 1);
+    assert_eq!(amka.birth_month(), 1);
+    assert_eq!(amka.birth_year_digits(), 80);
+    assert_eq!(amka.sex(), Sex::Male);
+    assert_eq!(amka.check_digit(), 8);
+    assert_eq!(amka.to_string(), "01018001238");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_amka() {
+    assert!(GreeceAmkaValidator::validator("01018001238").is_ok());
+    assert!(GreeceAmkaValidator::validator("01018001239").is_err());
+}
@@ -0,0 +1,72 @@
+use docval::guatemala::{GuatemalaNitValidator, Nit};
+
+#[test]
+fn test_valid_nit() {
+    assert!(GuatemalaNitValidator::is_valid("7108-0").is_ok());
+    assert!(GuatemalaNitValidator::is_valid("3952550-3").is_ok());
+    assert!(GuatemalaNitValidator::is_valid("89771125").is_ok());
+    assert!(GuatemalaNitValidator::is_valid("1234567 9").is_ok());
+    assert!(GuatemalaNitValidator::is_valid("12345678-9").is_ok());
+}
+
+#[test]
+fn test_valid_nit_with_k() {
+    assert!(GuatemalaNitValidator::is_valid("576937-K").is_ok());
+    assert!(GuatemalaNitValidator::is_valid("576937k").is_ok());
+    assert!(GuatemalaNitValidator::is_valid("00576937-K").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        GuatemalaNitValidator::is_valid("576937-0"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        GuatemalaNitValidator::is_valid("7108-K"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        GuatemalaNitValidator::is_valid("57A937-K"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GuatemalaNitValidator::is_valid("576937/K"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        GuatemalaNitValidator::is_valid("576937-É"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(GuatemalaNitValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(GuatemalaNitValidator::is_valid("000"), Err("Invalid input"));
+    assert_eq!(GuatemalaNitValidator::is_valid("7"), Err("Invalid length"));
+    assert_eq!(
+        GuatemalaNitValidator::is_valid("1234567890123"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nit() {
+    let nit: Nit = "0576937k".parse().unwrap();
+    assert_eq!(nit.as_str(), "576937K");
+    assert_eq!(nit.number(), "576937");
+    assert_eq!(nit.check_character(), 'K');
+    assert_eq!(nit.to_string(), "576937-K");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nit() {
+    assert!(GuatemalaNitValidator::validator("576937-K").is_ok());
+    assert!(GuatemalaNitValidator::validator("576937-1").is_err());
+}
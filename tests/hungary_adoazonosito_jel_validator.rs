@@ -0,0 +1,78 @@
+use docval::hungary::{AdoazonositoJel, HungaryAdoazonositoJelValidator};
+
+#[test]
+fn test_valid_adoazonosito_jel() {
+    assert!(HungaryAdoazonositoJelValidator::is_valid("8412721233").is_ok());
+    assert!(HungaryAdoazonositoJelValidator::is_valid("84127 21233").is_ok());
+    assert!(HungaryAdoazonositoJelValidator::is_valid("8396110018").is_ok());
+    assert!(HungaryAdoazonositoJelValidator::is_valid("8486360420").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid("8412721234"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid("8396110017"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_check_digit_ten() {
+    for check_digit in 0..=9 {
+        assert_eq!(
+            HungaryAdoazonositoJelValidator::is_valid(&format!("841272108{}", check_digit)),
+            Err("Invalid checksum")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid("7412721233"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid("841272123X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid("841272123"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        HungaryAdoazonositoJelValidator::is_valid("84127212330"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_adoazonosito_jel() {
+    let adoazonosito_jel: AdoazonositoJel = "8486360420".parse().unwrap();
+    assert_eq!(adoazonosito_jel.as_str(), "8486360420");
+    assert_eq!(adoazonosito_jel.birth_year(), 2000);
+    assert_eq!(adoazonosito_jel.birth_month(), 2);
+    assert_eq!(adoazonosito_jel.birth_day(), 29);
+    assert_eq!(adoazonosito_jel.serial(), 42);
+    assert_eq!(adoazonosito_jel.check_digit(), 0);
+    assert_eq!(adoazonosito_jel.to_string(), "8486360420");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_adoazonosito_jel() {
+    assert!(HungaryAdoazonositoJelValidator::validator("8412721233").is_ok());
+    assert!(HungaryAdoazonositoJelValidator::validator("8412721234").is_err());
+}
@@ -0,0 +1,104 @@
+use docval::hungary::{Adoszam, HungaryAdoszamValidator, VatStatus};
+
+#[test]
+fn test_valid_adoszam() {
+    assert!(HungaryAdoszamValidator::is_valid("10892639-2-42").is_ok());
+    assert!(HungaryAdoszamValidator::is_valid("10892639242").is_ok());
+    assert!(HungaryAdoszamValidator::is_valid("12345676-1-13").is_ok());
+    assert!(HungaryAdoszamValidator::is_valid("21323375-3-02").is_ok());
+}
+
+#[test]
+fn test_valid_eu_vat_number() {
+    assert!(HungaryAdoszamValidator::is_valid("HU10892639").is_ok());
+    assert!(HungaryAdoszamValidator::is_valid("hu 12892226").is_ok());
+    assert!(HungaryAdoszamValidator::is_valid("10892639").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892638-2-42"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("HU12345677"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_vat_and_county_codes() {
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-6-42"),
+        Err("Invalid VAT code")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-0-42"),
+        Err("Invalid VAT code")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-2-01"),
+        Err("Invalid county code")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-2-21"),
+        Err("Invalid county code")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-2-4X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("1089.639"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(HungaryAdoszamValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("HU"),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("1089263"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-2"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        HungaryAdoszamValidator::is_valid("10892639-2-420"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_adoszam() {
+    let adoszam: Adoszam = "10892639-2-42".parse().unwrap();
+    assert_eq!(adoszam.as_str(), "10892639242");
+    assert_eq!(adoszam.base(), "10892639");
+    assert_eq!(adoszam.vat_status(), Some(VatStatus::General));
+    assert_eq!(adoszam.county_code(), Some(42));
+    assert_eq!(adoszam.to_string(), "10892639-2-42");
+
+    let adoszam: Adoszam = "hu10892639".parse().unwrap();
+    assert_eq!(adoszam.as_str(), "10892639");
+    assert_eq!(adoszam.vat_status(), None);
+    assert_eq!(adoszam.county_code(), None);
+    assert_eq!(adoszam.to_string(), "HU10892639");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_adoszam() {
+    assert!(HungaryAdoszamValidator::validator("10892639-2-42").is_ok());
+    assert!(HungaryAdoszamValidator::validator("10892638-2-42").is_err());
+}
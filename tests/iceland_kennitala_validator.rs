@@ -0,0 +1,71 @@
+use docval::iceland::{IcelandKennitalaValidator, Kennitala, KennitalaKind};
+
+#[test]
+fn test_valid_kennitala() {
+    assert!(IcelandKennitalaValidator::is_valid("120174-3399").is_ok());
+    assert!(IcelandKennitalaValidator::is_valid("2902002100").is_ok());
+    assert!(IcelandKennitalaValidator::is_valid("010185-2118").is_ok());
+    assert!(IcelandKennitalaValidator::is_valid("311299-1239").is_ok());
+}
+
+#[test]
+fn test_valid_kennitala_legal_entity() {
+    assert!(IcelandKennitalaValidator::is_valid("550500-3530").is_ok());
+    assert!(IcelandKennitalaValidator::is_valid("410573-1219").is_ok());
+}
+
+#[test]
+fn test_invalid_kennitala() {
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid("120174-339"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid("120174-339A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid("120174-3391"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid("290200-2109"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid("720174-3399"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        IcelandKennitalaValidator::is_valid("120174-3389"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_kennitala() {
+    let kennitala: Kennitala = "120174-3399".parse().unwrap();
+    assert_eq!(kennitala.as_str(), "1201743399");
+    assert_eq!(kennitala.kind(), KennitalaKind::Individual);
+    assert_eq!(kennitala.birth_year(), 1974);
+    assert_eq!(kennitala.birth_month(), 1);
+    assert_eq!(kennitala.birth_day(), 12);
+    assert_eq!(kennitala.check_digit(), 9);
+    assert_eq!(kennitala.to_string(), "120174-3399");
+
+    let kennitala = Kennitala::parse("410573-1219").unwrap();
+    assert_eq!(kennitala.kind(), KennitalaKind::LegalEntity);
+    assert_eq!(kennitala.birth_year(), 1973);
+    assert_eq!(kennitala.birth_day(), 1);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_kennitala() {
+    assert!(IcelandKennitalaValidator::validator("120174-3399").is_ok());
+    assert!(IcelandKennitalaValidator::validator("120174-3389").is_err());
+}
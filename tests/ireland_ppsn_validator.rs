@@ -0,0 +1,88 @@
+use docval::ireland::{IrelandPpsnValidator, Ppsn};
+
+#[test]
+fn test_valid_ppsn() {
+    assert!(IrelandPpsnValidator::is_valid("1234567T").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("6433435F").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("7654321G").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("1234567 t").is_ok());
+}
+
+#[test]
+fn test_valid_ppsn_with_second_letter() {
+    assert!(IrelandPpsnValidator::is_valid("1234567FA").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("1234567OB").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("6433435OA").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("1234567TW").is_ok());
+    assert!(IrelandPpsnValidator::is_valid("1234567TX").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("1234567A"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("1234567TA"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("6433435FB"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("123456TT"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("12345678"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("1234567TC"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("0113Z8€"),
+        Err("Invalid format")
+    );
+    assert_eq!(Ppsn::parse("abc123É4"), Err("Invalid format"));
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(IrelandPpsnValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("123456T"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        IrelandPpsnValidator::is_valid("1234567TWA"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ppsn() {
+    let ppsn: Ppsn = "1234567fa".parse().unwrap();
+    assert_eq!(ppsn.as_str(), "1234567FA");
+    assert_eq!(ppsn.number(), "1234567");
+    assert_eq!(ppsn.check_character(), 'F');
+    assert_eq!(ppsn.second_letter(), Some('A'));
+    assert_eq!(ppsn.to_string(), "1234567FA");
+
+    let ppsn: Ppsn = "1234567T".parse().unwrap();
+    assert_eq!(ppsn.second_letter(), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ppsn() {
+    assert!(IrelandPpsnValidator::validator("1234567T").is_ok());
+    assert!(IrelandPpsnValidator::validator("1234567A").is_err());
+}
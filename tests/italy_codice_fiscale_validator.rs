@@ -0,0 +1,108 @@
+use docval::italy::{CodiceFiscale, ItalyCodiceFiscaleValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_codice_fiscale() {
+    assert!(ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A562S").is_ok());
+    assert!(ItalyCodiceFiscaleValidator::is_valid("MRTMTT25D09F205Z").is_ok());
+    assert!(ItalyCodiceFiscaleValidator::is_valid("mllsnt82p65z404u").is_ok());
+    assert!(ItalyCodiceFiscaleValidator::is_valid("BNC LRA 00A41 H501 L").is_ok());
+}
+
+#[test]
+fn test_valid_omocode_codice_fiscale() {
+    assert!(ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A56NH").is_ok());
+    assert!(ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A5SNT").is_ok());
+    assert!(ItalyCodiceFiscaleValidator::is_valid("RSSMRAURTMLARSNL").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A562T"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A56NS"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85F10A562S"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T35A562S"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85B30A562S"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMR185T10A562S"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA8AT10A562S"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A5620"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A562"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        ItalyCodiceFiscaleValidator::is_valid("RSSMRA85T10A562SS"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_codice_fiscale() {
+    let cf: CodiceFiscale = "rssmra85t10a562s".parse().unwrap();
+    assert_eq!(cf.as_str(), "RSSMRA85T10A562S");
+    assert_eq!(cf.birth_year(), 85);
+    assert_eq!(cf.birth_month(), 12);
+    assert_eq!(cf.birth_day(), 10);
+    assert_eq!(cf.sex(), Sex::Male);
+    assert_eq!(cf.birthplace_code(), "A562");
+    assert!(!cf.is_born_abroad());
+    assert!(!cf.is_omocode());
+    assert_eq!(cf.control_character(), 'S');
+}
+
+#[test]
+fn test_parse_omocode_codice_fiscale() {
+    let cf = CodiceFiscale::parse("RSSMRAURTMLARSNL").unwrap();
+    assert!(cf.is_omocode());
+    assert_eq!(cf.birth_year(), 85);
+    assert_eq!(cf.birth_day(), 10);
+    assert_eq!(cf.birthplace_code(), "A562");
+    assert_eq!(cf.to_string(), "RSSMRAURTMLARSNL");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_codice_fiscale() {
+    assert!(ItalyCodiceFiscaleValidator::validator("RSSMRA85T10A562S").is_ok());
+    assert!(ItalyCodiceFiscaleValidator::validator("RSSMRA85T10A562T").is_err());
+}
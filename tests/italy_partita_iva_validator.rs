@@ -0,0 +1,78 @@
+use docval::italy::{ItalyPartitaIvaValidator, PartitaIva};
+
+#[test]
+fn test_valid_partita_iva() {
+    assert!(ItalyPartitaIvaValidator::is_valid("00743110157").is_ok());
+    assert!(ItalyPartitaIvaValidator::is_valid("01234560124").is_ok());
+    assert!(ItalyPartitaIvaValidator::is_valid("IT00743110157").is_ok());
+    assert!(ItalyPartitaIvaValidator::is_valid("007 4311 015 7").is_ok());
+}
+
+#[test]
+fn test_valid_special_office_codes() {
+    assert!(ItalyPartitaIvaValidator::is_valid("00000011205").is_ok());
+    assert!(ItalyPartitaIvaValidator::is_valid("00000018887").is_ok());
+    assert!(ItalyPartitaIvaValidator::is_valid("12345679992").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("00743110158"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("01234560120"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_office() {
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("12345671015"),
+        Err("Invalid office")
+    );
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("12345672005"),
+        Err("Invalid office")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("00000000158"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(ItalyPartitaIvaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("0074311015"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        ItalyPartitaIvaValidator::is_valid("007431101570"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_partita_iva() {
+    let partita_iva: PartitaIva = "IT 00743110157".parse().unwrap();
+    assert_eq!(partita_iva.as_str(), "00743110157");
+    assert_eq!(partita_iva.number(), "0074311");
+    assert_eq!(partita_iva.office_code(), 15);
+    assert_eq!(partita_iva.check_digit(), 7);
+    assert_eq!(partita_iva.to_string(), "00743110157");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_partita_iva() {
+    assert!(ItalyPartitaIvaValidator::validator("00743110157").is_ok());
+    assert!(ItalyPartitaIvaValidator::validator("00743110158").is_err());
+}
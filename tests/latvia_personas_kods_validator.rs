@@ -0,0 +1,70 @@
+use docval::latvia::{LatviaPersonasKodsValidator, PersonasKods};
+
+#[test]
+fn test_valid_personas_kods() {
+    assert!(LatviaPersonasKodsValidator::is_valid("161175-19997").is_ok());
+    assert!(LatviaPersonasKodsValidator::is_valid("010190-12349").is_ok());
+    assert!(LatviaPersonasKodsValidator::is_valid("29020021239").is_ok());
+    assert!(LatviaPersonasKodsValidator::is_valid("010185-01239").is_ok());
+}
+
+#[test]
+fn test_valid_personas_kods_anonymous() {
+    assert!(LatviaPersonasKodsValidator::is_valid("321234-56789").is_ok());
+    assert!(LatviaPersonasKodsValidator::is_valid("32000000000").is_ok());
+}
+
+#[test]
+fn test_invalid_personas_kods() {
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid("161175-1999"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid("161175-1999A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid("161175-39997"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid("290200-11233"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid("161175-19998"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        LatviaPersonasKodsValidator::is_valid("010190-10010"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_personas_kods() {
+    let personas_kods: PersonasKods = "161175-19997".parse().unwrap();
+    assert_eq!(personas_kods.as_str(), "16117519997");
+    assert!(!personas_kods.is_anonymous());
+    assert_eq!(personas_kods.birth_year(), Some(1975));
+    assert_eq!(personas_kods.birth_month(), Some(11));
+    assert_eq!(personas_kods.birth_day(), Some(16));
+    assert_eq!(personas_kods.to_string(), "161175-19997");
+
+    let personas_kods = PersonasKods::parse("32123456789").unwrap();
+    assert!(personas_kods.is_anonymous());
+    assert_eq!(personas_kods.birth_year(), None);
+    assert_eq!(personas_kods.to_string(), "321234-56789");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_personas_kods() {
+    assert!(LatviaPersonasKodsValidator::validator("161175-19997").is_ok());
+    assert!(LatviaPersonasKodsValidator::validator("161175-19998").is_err());
+}
@@ -0,0 +1,72 @@
+use docval::lithuania::{AsmensKodas, LithuaniaAsmensKodasValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_asmens_kodas() {
+    assert!(LithuaniaAsmensKodasValidator::is_valid("33309240064").is_ok());
+    assert!(LithuaniaAsmensKodasValidator::is_valid("49001011238").is_ok());
+    assert!(LithuaniaAsmensKodasValidator::is_valid("51012310013").is_ok());
+    assert!(LithuaniaAsmensKodasValidator::is_valid("39912312342").is_ok());
+    assert!(LithuaniaAsmensKodasValidator::is_valid("185 010 112 37").is_ok());
+}
+
+#[test]
+fn test_valid_asmens_kodas_exceptional() {
+    assert!(LithuaniaAsmensKodasValidator::is_valid("90000000009").is_ok());
+}
+
+#[test]
+fn test_invalid_asmens_kodas() {
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid("3330924006"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid("3330924006A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid("73309240064"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid("61002291231"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid("33309240065"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        LithuaniaAsmensKodasValidator::is_valid("90000000008"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_asmens_kodas() {
+    let asmens_kodas: AsmensKodas = "33309240064".parse().unwrap();
+    assert_eq!(asmens_kodas.as_str(), "33309240064");
+    assert_eq!(asmens_kodas.birth_year(), Some(1933));
+    assert_eq!(asmens_kodas.birth_month(), Some(9));
+    assert_eq!(asmens_kodas.birth_day(), Some(24));
+    assert_eq!(asmens_kodas.sex(), Some(Sex::Male));
+    assert_eq!(asmens_kodas.serial(), 6);
+    assert_eq!(asmens_kodas.check_digit(), 4);
+    assert_eq!(asmens_kodas.to_string(), "33309240064");
+
+    let asmens_kodas = AsmensKodas::parse("90000000009").unwrap();
+    assert_eq!(asmens_kodas.birth_year(), None);
+    assert_eq!(asmens_kodas.sex(), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_asmens_kodas() {
+    assert!(LithuaniaAsmensKodasValidator::validator("33309240064").is_ok());
+    assert!(LithuaniaAsmensKodasValidator::validator("33309240065").is_err());
+}
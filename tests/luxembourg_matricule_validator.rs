@@ -0,0 +1,85 @@
+use docval::luxembourg::{LuxembourgMatriculeValidator, Matricule};
+
+#[test]
+fn test_valid_matricule() {
+    assert!(LuxembourgMatriculeValidator::is_valid("1983120105747").is_ok());
+    assert!(LuxembourgMatriculeValidator::is_valid("1983 1201 057 47").is_ok());
+    assert!(LuxembourgMatriculeValidator::is_valid("1994020312315").is_ok());
+    assert!(LuxembourgMatriculeValidator::is_valid("2000022900197").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("1983120105748"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("1983120105737"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("1983120105847"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("1983130105732"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("1900022900129"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("198312010574X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("1983-12-01057"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("198312010574"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        LuxembourgMatriculeValidator::is_valid("19831201057470"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_matricule() {
+    let matricule: Matricule = "1983 1201 057 47".parse().unwrap();
+    assert_eq!(matricule.as_str(), "1983120105747");
+    assert_eq!(matricule.birth_year(), 1983);
+    assert_eq!(matricule.birth_month(), 12);
+    assert_eq!(matricule.birth_day(), 1);
+    assert_eq!(matricule.serial(), 57);
+    assert_eq!(matricule.luhn_check_digit(), 4);
+    assert_eq!(matricule.verhoeff_check_digit(), 7);
+    assert_eq!(matricule.to_string(), "1983120105747");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_matricule() {
+    assert!(LuxembourgMatriculeValidator::validator("1983120105747").is_ok());
+    assert!(LuxembourgMatriculeValidator::validator("1983120105748").is_err());
+}
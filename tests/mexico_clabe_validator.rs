@@ -0,0 +1,45 @@
+use docval::mexico::{Clabe, MexicoClabeValidator};
+
+#[test]
+fn test_valid_clabe() {
+    assert!(MexicoClabeValidator::is_valid("032180000118359719").is_ok());
+    assert!(MexicoClabeValidator::is_valid("002180012345678906").is_ok());
+    assert!(MexicoClabeValidator::is_valid("012 180 01234567890 9").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        MexicoClabeValidator::is_valid("032180000118359718"),
+        Err("Invalid checksum")
+    );
+    assert!(MexicoClabeValidator::is_valid("072180001234567890").is_err());
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(MexicoClabeValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        MexicoClabeValidator::is_valid("03218000011835971"),
+        Err("Invalid length")
+    );
+    assert!(MexicoClabeValidator::is_valid("0321800001183597190").is_err());
+}
+
+#[test]
+fn test_parse_clabe() {
+    let clabe: Clabe = "072180001234567897".parse().unwrap();
+    assert_eq!(clabe.as_str(), "072180001234567897");
+    assert_eq!(clabe.bank_code(), "072");
+    assert_eq!(clabe.plaza_code(), "180");
+    assert_eq!(clabe.account_number(), "00123456789");
+    assert_eq!(clabe.check_digit(), 7);
+    assert_eq!(clabe.to_string(), "072180001234567897");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_clabe() {
+    assert!(MexicoClabeValidator::validator("032180000118359719").is_ok());
+    assert!(MexicoClabeValidator::validator("032180000118359710").is_err());
+}
@@ -0,0 +1,95 @@
+use docval::mexico::{Curp, MexicoCurpValidator, State};
+use docval::Sex;
+
+#[test]
+fn test_valid_curp() {
+    assert!(MexicoCurpValidator::is_valid("BOXW310820HNERXN09").is_ok());
+    assert!(MexicoCurpValidator::is_valid("SAHM800101HDFNRR06").is_ok());
+    assert!(MexicoCurpValidator::is_valid("goma050315mjcnrra0").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW310820HNERXN08"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW310230HNERXN09"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_sex() {
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW310820XNERXN09"),
+        Err("Invalid sex")
+    );
+}
+
+#[test]
+fn test_invalid_state() {
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW310820HXXRXN09"),
+        Err("Invalid state")
+    );
+}
+
+#[test]
+fn test_inconvenient_word() {
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BACA310820HNERXN09"),
+        Err("Invalid name")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(MexicoCurpValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW310820HNERXN0"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        MexicoCurpValidator::is_valid("B0XW310820HNERXN09"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW310820HNERXN0A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        MexicoCurpValidator::is_valid("BOXW31082ÑHNERXN09"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_parse_curp() {
+    let curp: Curp = "goma050315mjcnrra0".parse().unwrap();
+    assert_eq!(curp.as_str(), "GOMA050315MJCNRRA0");
+    assert_eq!(curp.birth_year(), 2005);
+    assert_eq!(curp.birth_month(), 3);
+    assert_eq!(curp.birth_day(), 15);
+    assert_eq!(curp.sex(), Sex::Female);
+    assert_eq!(curp.state(), Some(State::Jalisco));
+    assert!(!curp.is_born_abroad());
+
+    let curp = Curp::parse("BOXW310820HNERXN09").unwrap();
+    assert_eq!(curp.birth_year(), 1931);
+    assert_eq!(curp.sex(), Sex::Male);
+    assert_eq!(curp.state(), None);
+    assert!(curp.is_born_abroad());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_curp() {
+    assert!(MexicoCurpValidator::validator("BOXW310820HNERXN09").is_ok());
+    assert!(MexicoCurpValidator::validator("BOXW310820HNERXN00").is_err());
+}
@@ -0,0 +1,96 @@
+use docval::mexico::{MexicoRfcValidator, Rfc, RfcKind};
+
+#[test]
+fn test_valid_individual_rfc() {
+    assert!(MexicoRfcValidator::is_valid("GODE561231GR8").is_ok());
+    assert!(MexicoRfcValidator::is_valid("gode 561231 gr8").is_ok());
+    assert!(MexicoRfcValidator::is_valid("ñoñe800101abA").is_ok());
+    assert!(MexicoRfcValidator::is_valid("LOPJ840229A10").is_ok());
+}
+
+#[test]
+fn test_valid_company_rfc() {
+    assert!(MexicoRfcValidator::is_valid("MAB9307148T4").is_ok());
+    assert!(MexicoRfcValidator::is_valid("MAB-930714-8T4").is_ok());
+    assert!(MexicoRfcValidator::is_valid("ñañ850101ab5").is_ok());
+    assert!(MexicoRfcValidator::is_valid("AAA010101AA1").is_ok());
+}
+
+#[test]
+fn test_generic_rfc() {
+    assert!(MexicoRfcValidator::is_valid("XAXX010101000").is_ok());
+    assert!(MexicoRfcValidator::is_valid("XEXX010101000").is_ok());
+    assert!(Rfc::parse("XAXX010101000").unwrap().is_generic());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        MexicoRfcValidator::is_valid("GODE561231GR9"),
+        Err("Invalid checksum")
+    );
+    assert!(MexicoRfcValidator::is_valid("MAB9307148T5").is_err());
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        MexicoRfcValidator::is_valid("LOPJ850229A13"),
+        Err("Invalid date")
+    );
+    assert!(MexicoRfcValidator::is_valid("GODE561331GR8").is_err());
+}
+
+#[test]
+fn test_inconvenient_word() {
+    assert_eq!(
+        MexicoRfcValidator::is_valid("CACA850101AB2"),
+        Err("Invalid name")
+    );
+    assert!(MexicoRfcValidator::is_valid("CACX850101AB2").is_ok());
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(MexicoRfcValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        MexicoRfcValidator::is_valid("GODE56123GR8"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        MexicoRfcValidator::is_valid("GOD1561231GR8"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        MexicoRfcValidator::is_valid("GODE561231G8"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        MexicoRfcValidator::is_valid("GODE561231G"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rfc() {
+    let rfc: Rfc = "GODE 561231 GR8".parse().unwrap();
+    assert_eq!(rfc.as_str(), "GODE561231GR8");
+    assert_eq!(rfc.kind(), RfcKind::Individual);
+    assert_eq!(rfc.name_code(), "GODE");
+    assert_eq!(rfc.date(), "561231");
+    assert_eq!(rfc.homoclave(), "GR8");
+    assert!(!rfc.is_generic());
+
+    let rfc = Rfc::parse("ÑAÑ850101AB5").unwrap();
+    assert_eq!(rfc.kind(), RfcKind::Company);
+    assert_eq!(rfc.name_code(), "ÑAÑ");
+    assert_eq!(rfc.date(), "850101");
+    assert_eq!(rfc.to_string(), "ÑAÑ850101AB5");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rfc() {
+    assert!(MexicoRfcValidator::validator("GODE561231GR8").is_ok());
+    assert!(MexicoRfcValidator::validator("GODE561231GR0").is_err());
+}
@@ -0,0 +1,73 @@
+use docval::netherlands::{Bsn, NetherlandsBsnValidator};
+
+#[test]
+fn test_valid_bsn() {
+    assert!(NetherlandsBsnValidator::is_valid("123456782").is_ok());
+    assert!(NetherlandsBsnValidator::is_valid("1234.56.782").is_ok());
+    assert!(NetherlandsBsnValidator::is_valid("111222333").is_ok());
+    assert!(NetherlandsBsnValidator::is_valid("111 222 333").is_ok());
+}
+
+#[test]
+fn test_valid_legacy_bsn() {
+    assert!(NetherlandsBsnValidator::is_valid("12345672").is_ok());
+    assert!(NetherlandsBsnValidator::is_valid("012345672").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("123456789"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("111222334"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("12345678"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("000000000"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("12345678X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(NetherlandsBsnValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("1234567"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        NetherlandsBsnValidator::is_valid("1234567820"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_bsn() {
+    let bsn: Bsn = "1234.56.782".parse().unwrap();
+    assert_eq!(bsn.as_str(), "123456782");
+    assert_eq!(bsn.to_string(), "1234.56.782");
+
+    let bsn: Bsn = "12345672".parse().unwrap();
+    assert_eq!(bsn.as_str(), "012345672");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_bsn() {
+    assert!(NetherlandsBsnValidator::validator("123456782").is_ok());
+    assert!(NetherlandsBsnValidator::validator("123456789").is_err());
+}
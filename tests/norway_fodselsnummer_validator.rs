@@ -0,0 +1,91 @@
+use docval::norway::{Fodselsnummer, FodselsnummerKind, NorwayFodselsnummerValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_fodselsnummer() {
+    assert!(NorwayFodselsnummerValidator::is_valid("15078545620").is_ok());
+    assert!(NorwayFodselsnummerValidator::is_valid("150785 45701").is_ok());
+    assert!(NorwayFodselsnummerValidator::is_valid("29020050169").is_ok());
+    assert!(NorwayFodselsnummerValidator::is_valid("01017095079").is_ok());
+    assert!(NorwayFodselsnummerValidator::is_valid("01018560026").is_ok());
+    assert!(NorwayFodselsnummerValidator::is_valid("31129949980").is_ok());
+}
+
+#[test]
+fn test_valid_d_number() {
+    assert!(NorwayFodselsnummerValidator::is_valid("41019012393").is_ok());
+}
+
+#[test]
+fn test_invalid_fodselsnummer() {
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("1507854562"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("1507854562A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("30020050108"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("15078545621"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("15078545630"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_fodselsnummer_century() {
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("01014560013"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        NorwayFodselsnummerValidator::is_valid("01014580049"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_parse_fodselsnummer() {
+    let fodselsnummer: Fodselsnummer = "15078545620".parse().unwrap();
+    assert_eq!(fodselsnummer.as_str(), "15078545620");
+    assert_eq!(fodselsnummer.kind(), FodselsnummerKind::BirthNumber);
+    assert_eq!(fodselsnummer.birth_year(), 1985);
+    assert_eq!(fodselsnummer.birth_month(), 7);
+    assert_eq!(fodselsnummer.birth_day(), 15);
+    assert_eq!(fodselsnummer.individual_number(), 456);
+    assert_eq!(fodselsnummer.sex(), Sex::Female);
+    assert_eq!(fodselsnummer.check_digits(), 20);
+    assert_eq!(fodselsnummer.to_string(), "15078545620");
+
+    assert_eq!(
+        Fodselsnummer::parse("29020050169").unwrap().birth_year(),
+        2000
+    );
+    assert_eq!(
+        Fodselsnummer::parse("01018560026").unwrap().birth_year(),
+        1885
+    );
+    assert_eq!(
+        Fodselsnummer::parse("15078545701").unwrap().sex(),
+        Sex::Male
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_fodselsnummer() {
+    assert!(NorwayFodselsnummerValidator::validator("15078545620").is_ok());
+    assert!(NorwayFodselsnummerValidator::validator("15078545621").is_err());
+}
@@ -0,0 +1,64 @@
+use docval::norway::{NorwayOrganisasjonsnummerValidator, Organisasjonsnummer};
+
+#[test]
+fn test_valid_organisasjonsnummer() {
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("923609016").is_ok());
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("974 760 673").is_ok());
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("983363628").is_ok());
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("812345672").is_ok());
+}
+
+#[test]
+fn test_valid_organisasjonsnummer_vat_form() {
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("NO923609016MVA").is_ok());
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("NO 974 760 673 MVA").is_ok());
+    assert!(NorwayOrganisasjonsnummerValidator::is_valid("no 974760673 mva").is_ok());
+}
+
+#[test]
+fn test_invalid_organisasjonsnummer() {
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid("92360901"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid("SE923609016MVA"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid("92360901A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid("123456785"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid("923609017"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        NorwayOrganisasjonsnummerValidator::is_valid("912345670"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_organisasjonsnummer() {
+    let organisasjonsnummer: Organisasjonsnummer = "NO 974 760 673 MVA".parse().unwrap();
+    assert_eq!(organisasjonsnummer.as_str(), "974760673");
+    assert_eq!(organisasjonsnummer.vat_number(), "NO974760673MVA");
+    assert_eq!(organisasjonsnummer.check_digit(), 3);
+    assert_eq!(organisasjonsnummer.to_string(), "974 760 673");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_organisasjonsnummer() {
+    assert!(NorwayOrganisasjonsnummerValidator::validator("923609016").is_ok());
+    assert!(NorwayOrganisasjonsnummerValidator::validator("923609017").is_err());
+}
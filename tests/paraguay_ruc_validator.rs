@@ -0,0 +1,56 @@
+use docval::paraguay::{ParaguayRucValidator, Ruc};
+
+#[test]
+fn test_valid_ruc() {
+    assert!(ParaguayRucValidator::is_valid("80028061-0").is_ok());
+    assert!(ParaguayRucValidator::is_valid("80016096-7").is_ok());
+    assert!(ParaguayRucValidator::is_valid("1234567-9").is_ok());
+    assert!(ParaguayRucValidator::is_valid("34567895").is_ok());
+}
+
+#[test]
+fn test_valid_short_ruc() {
+    assert!(ParaguayRucValidator::is_valid("4401-6").is_ok());
+    assert!(ParaguayRucValidator::is_valid("800000-0").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        ParaguayRucValidator::is_valid("80028061-1"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        ParaguayRucValidator::is_valid("1234567-8"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(ParaguayRucValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        ParaguayRucValidator::is_valid("440-1"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        ParaguayRucValidator::is_valid("800280610-0"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ruc() {
+    let ruc: Ruc = "80028061-0".parse().unwrap();
+    assert_eq!(ruc.as_str(), "800280610");
+    assert_eq!(ruc.number(), "80028061");
+    assert_eq!(ruc.check_digit(), 0);
+    assert_eq!(ruc.to_string(), "80028061-0");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ruc() {
+    assert!(ParaguayRucValidator::validator("80028061-0").is_ok());
+    assert!(ParaguayRucValidator::validator("80028061-2").is_err());
+}
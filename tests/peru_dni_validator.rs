@@ -0,0 +1,75 @@
+use docval::peru::{Dni, PeruDniValidator};
+
+#[test]
+fn test_valid_dni() {
+    assert!(PeruDniValidator::is_valid("12345678").is_ok());
+    assert!(PeruDniValidator::is_valid("07654321").is_ok());
+    assert!(PeruDniValidator::is_valid("44444444").is_ok());
+}
+
+#[test]
+fn test_valid_check_character() {
+    assert!(PeruDniValidator::is_valid("12345678-1").is_ok());
+    assert!(PeruDniValidator::is_valid("12345678-E").is_ok());
+    assert!(PeruDniValidator::is_valid("12345678e").is_ok());
+    assert!(PeruDniValidator::is_valid("10000000 3").is_ok());
+    assert!(PeruDniValidator::is_valid("44444444-D").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PeruDniValidator::is_valid("12345678-2"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PeruDniValidator::is_valid("12345678-K"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        PeruDniValidator::is_valid("1234567A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PeruDniValidator::is_valid("1234567É"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PeruDniValidator::is_valid("12.345.678"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PeruDniValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(PeruDniValidator::is_valid("1234567"), Err("Invalid length"));
+    assert_eq!(
+        PeruDniValidator::is_valid("12345678-11"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_dni() {
+    let dni: Dni = "12345678-E".parse().unwrap();
+    assert_eq!(dni.number(), "12345678");
+    assert_eq!(dni.check_digit(), '1');
+    assert_eq!(dni.check_letter(), 'E');
+    assert_eq!(dni.to_string(), "12345678");
+
+    let dni = Dni::parse("07654321").unwrap();
+    assert_eq!(dni.check_digit(), '1');
+    assert_eq!(dni.check_letter(), 'F');
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_dni() {
+    assert!(PeruDniValidator::validator("12345678-1").is_ok());
+    assert!(PeruDniValidator::validator("12345678-0").is_err());
+}
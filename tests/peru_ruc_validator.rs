@@ -0,0 +1,71 @@
+use docval::peru::{PeruRucValidator, Ruc, RucKind};
+
+#[test]
+fn test_valid_ruc() {
+    assert!(PeruRucValidator::is_valid("20131280069").is_ok());
+    assert!(PeruRucValidator::is_valid("10123456781").is_ok());
+    assert!(PeruRucValidator::is_valid("15460278977").is_ok());
+    assert!(PeruRucValidator::is_valid("17123456785").is_ok());
+    assert!(PeruRucValidator::is_valid("20-50000000-8").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PeruRucValidator::is_valid("20131280060"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PeruRucValidator::is_valid("10123456782"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        PeruRucValidator::is_valid("21000662382"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        PeruRucValidator::is_valid("30131280069"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PeruRucValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        PeruRucValidator::is_valid("2013128006"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_person() {
+    let ruc: Ruc = "10123456781".parse().unwrap();
+    assert_eq!(ruc.as_str(), "10123456781");
+    assert_eq!(ruc.prefix(), 10);
+    assert_eq!(ruc.kind(), RucKind::Person);
+    assert_eq!(ruc.dni(), Some("12345678"));
+
+    let ruc = Ruc::parse("15460278977").unwrap();
+    assert_eq!(ruc.kind(), RucKind::Person);
+    assert_eq!(ruc.dni(), None);
+}
+
+#[test]
+fn test_parse_company() {
+    let ruc = Ruc::parse("20131280069").unwrap();
+    assert_eq!(ruc.kind(), RucKind::Company);
+    assert_eq!(ruc.dni(), None);
+    assert_eq!(ruc.to_string(), "20131280069");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ruc() {
+    assert!(PeruRucValidator::validator("20131280069").is_ok());
+    assert!(PeruRucValidator::validator("20131280068").is_err());
+}
@@ -0,0 +1,82 @@
+use docval::poland::{Nip, PolandNipValidator};
+
+#[test]
+fn test_valid_nip() {
+    assert!(PolandNipValidator::is_valid("1234563218").is_ok());
+    assert!(PolandNipValidator::is_valid("123-456-32-18").is_ok());
+    assert!(PolandNipValidator::is_valid("123-45-63-218").is_ok());
+    assert!(PolandNipValidator::is_valid("PL5260250274").is_ok());
+    assert!(PolandNipValidator::is_valid("pl 777 000 29 03").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PolandNipValidator::is_valid("1234563219"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PolandNipValidator::is_valid("525-000-12-84"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_check_digit_ten() {
+    for check_digit in 0..=9 {
+        assert_eq!(
+            PolandNipValidator::is_valid(&format!("123456789{}", check_digit)),
+            Err("Invalid checksum")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        PolandNipValidator::is_valid("12.3456321"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PolandNipValidator::is_valid("123/456/32"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PolandNipValidator::is_valid("DE12345632"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PolandNipValidator::is_valid("123456321X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PolandNipValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(PolandNipValidator::is_valid("PL"), Err("Invalid input"));
+    assert_eq!(
+        PolandNipValidator::is_valid("123456321"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PolandNipValidator::is_valid("12345632180"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nip() {
+    let nip: Nip = "PL 123-45-63-218".parse().unwrap();
+    assert_eq!(nip.as_str(), "1234563218");
+    assert_eq!(nip.tax_office(), "123");
+    assert_eq!(nip.check_digit(), 8);
+    assert_eq!(nip.to_string(), "123-456-32-18");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nip() {
+    assert!(PolandNipValidator::validator("123-456-32-18").is_ok());
+    assert!(PolandNipValidator::validator("1234563219").is_err());
+}
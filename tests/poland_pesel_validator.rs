@@ -0,0 +1,98 @@
+use docval::poland::{Pesel, PolandPeselValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_pesel() {
+    assert!(PolandPeselValidator::is_valid("44051401359").is_ok());
+    assert!(PolandPeselValidator::is_valid("440514 01359").is_ok());
+    assert!(PolandPeselValidator::is_valid("02270803624").is_ok());
+    assert!(PolandPeselValidator::is_valid("80123100124").is_ok());
+    assert!(PolandPeselValidator::is_valid("04022900018").is_ok());
+}
+
+#[test]
+fn test_valid_pesel_other_centuries() {
+    assert!(PolandPeselValidator::is_valid("90921100006").is_ok());
+    assert!(PolandPeselValidator::is_valid("24510100016").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PolandPeselValidator::is_valid("44051401358"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PolandPeselValidator::is_valid("02270803625"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        PolandPeselValidator::is_valid("00022900010"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        PolandPeselValidator::is_valid("87022900011"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        PolandPeselValidator::is_valid("44131401359"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        PolandPeselValidator::is_valid("44000101359"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        PolandPeselValidator::is_valid("4405140135X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PolandPeselValidator::is_valid("44-05-14013"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PolandPeselValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        PolandPeselValidator::is_valid("4405140135"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PolandPeselValidator::is_valid("440514013590"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_pesel() {
+    let pesel: Pesel = "44051401359".parse().unwrap();
+    assert_eq!(pesel.as_str(), "44051401359");
+    assert_eq!(pesel.birth_year(), 1944);
+    assert_eq!(pesel.birth_month(), 5);
+    assert_eq!(pesel.birth_day(), 14);
+    assert_eq!(pesel.sex(), Sex::Male);
+    assert_eq!(pesel.check_digit(), 9);
+    assert_eq!(pesel.to_string(), "44051401359");
+
+    let pesel: Pesel = "02270803624".parse().unwrap();
+    assert_eq!(pesel.birth_year(), 2002);
+    assert_eq!(pesel.birth_month(), 7);
+    assert_eq!(pesel.sex(), Sex::Female);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_pesel() {
+    assert!(PolandPeselValidator::validator("44051401359").is_ok());
+    assert!(PolandPeselValidator::validator("44051401358").is_err());
+}
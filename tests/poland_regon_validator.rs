@@ -0,0 +1,92 @@
+use docval::poland::{PolandRegonValidator, Regon, RegonKind};
+
+#[test]
+fn test_valid_regon() {
+    assert!(PolandRegonValidator::is_valid("123456785").is_ok());
+    assert!(PolandRegonValidator::is_valid("192531257").is_ok());
+    assert!(PolandRegonValidator::is_valid("732-601-448").is_ok());
+    assert!(PolandRegonValidator::is_valid("100000050").is_ok());
+}
+
+#[test]
+fn test_valid_local_unit_regon() {
+    assert!(PolandRegonValidator::is_valid("12345678512347").is_ok());
+    assert!(PolandRegonValidator::is_valid("19253125700005").is_ok());
+    assert!(PolandRegonValidator::is_valid("73260144800011").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PolandRegonValidator::is_valid("123456786"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PolandRegonValidator::is_valid("12345678512348"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_local_unit_root() {
+    // The local unit check digit is correct, but the embedded REGON is not.
+    assert_eq!(
+        PolandRegonValidator::is_valid("12345678612342"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        PolandRegonValidator::is_valid("12345678X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PolandRegonValidator::is_valid("123.456.785"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PolandRegonValidator::is_valid("1234.5678"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PolandRegonValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        PolandRegonValidator::is_valid("12345678"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PolandRegonValidator::is_valid("1234567851234"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PolandRegonValidator::is_valid("123456785123470"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_regon() {
+    let regon: Regon = "123-456-785".parse().unwrap();
+    assert_eq!(regon.as_str(), "123456785");
+    assert_eq!(regon.kind(), RegonKind::Business);
+    assert_eq!(regon.root(), "123456785");
+    assert_eq!(regon.local_unit(), None);
+
+    let regon: Regon = "12345678512347".parse().unwrap();
+    assert_eq!(regon.kind(), RegonKind::LocalUnit);
+    assert_eq!(regon.root(), "123456785");
+    assert_eq!(regon.local_unit(), Some("1234"));
+    assert_eq!(regon.to_string(), "12345678512347");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_regon() {
+    assert!(PolandRegonValidator::validator("123456785").is_ok());
+    assert!(PolandRegonValidator::validator("123456786").is_err());
+}
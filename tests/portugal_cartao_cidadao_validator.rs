@@ -0,0 +1,82 @@
+use docval::portugal::{CartaoCidadao, PortugalCartaoCidadaoValidator};
+
+#[test]
+fn test_valid_cartao_cidadao() {
+    assert!(PortugalCartaoCidadaoValidator::is_valid("12345678 9 ZZ1").is_ok());
+    assert!(PortugalCartaoCidadaoValidator::is_valid("123456789ZX5").is_ok());
+    assert!(PortugalCartaoCidadaoValidator::is_valid("00000000 0 ZZ4").is_ok());
+    assert!(PortugalCartaoCidadaoValidator::is_valid("14897475-9-zz4").is_ok());
+}
+
+#[test]
+fn test_valid_cartao_cidadao_other_versions() {
+    assert!(PortugalCartaoCidadaoValidator::is_valid("00000000 0 AB7").is_ok());
+    assert!(PortugalCartaoCidadaoValidator::is_valid("14897475 9 AB7").is_ok());
+    assert!(PortugalCartaoCidadaoValidator::is_valid("12345678 9 015").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 9 ZZ2"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 9 ZY1"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 8 ZZ1"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("1234567A 9 ZZ1"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 9 ZZA"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 9 Z.1"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 9"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PortugalCartaoCidadaoValidator::is_valid("12345678 9 ZZ10"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cartao_cidadao() {
+    let card: CartaoCidadao = "12345678-9-zz1".parse().unwrap();
+    assert_eq!(card.as_str(), "123456789ZZ1");
+    assert_eq!(card.civil_number(), "12345678");
+    assert_eq!(card.civil_check_digit(), 9);
+    assert_eq!(card.version(), "ZZ");
+    assert_eq!(card.check_digit(), 1);
+    assert_eq!(card.to_string(), "12345678 9 ZZ1");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cartao_cidadao() {
+    assert!(PortugalCartaoCidadaoValidator::validator("12345678 9 ZZ1").is_ok());
+    assert!(PortugalCartaoCidadaoValidator::validator("12345678 9 ZZ2").is_err());
+}
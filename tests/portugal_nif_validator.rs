@@ -0,0 +1,90 @@
+use docval::portugal::{Nif, NifCategory, PortugalNifValidator};
+
+#[test]
+fn test_valid_nif() {
+    assert!(PortugalNifValidator::is_valid("501964843").is_ok());
+    assert!(PortugalNifValidator::is_valid("501 964 843").is_ok());
+    assert!(PortugalNifValidator::is_valid("PT501964843").is_ok());
+    assert!(PortugalNifValidator::is_valid("123456789").is_ok());
+    assert!(PortugalNifValidator::is_valid("500000000").is_ok());
+}
+
+#[test]
+fn test_valid_nif_categories() {
+    assert!(PortugalNifValidator::is_valid("280000006").is_ok());
+    assert!(PortugalNifValidator::is_valid("450000001").is_ok());
+    assert!(PortugalNifValidator::is_valid("600023605").is_ok());
+    assert!(PortugalNifValidator::is_valid("700000003").is_ok());
+    assert!(PortugalNifValidator::is_valid("900000007").is_ok());
+    assert!(PortugalNifValidator::is_valid("980000009").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PortugalNifValidator::is_valid("501964844"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PortugalNifValidator::is_valid("123456780"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_category() {
+    assert_eq!(
+        PortugalNifValidator::is_valid("012345678"),
+        Err("Invalid category")
+    );
+    assert_eq!(
+        PortugalNifValidator::is_valid("400000000"),
+        Err("Invalid category")
+    );
+    assert_eq!(
+        PortugalNifValidator::is_valid("730000000"),
+        Err("Invalid category")
+    );
+    assert_eq!(
+        PortugalNifValidator::is_valid("920000000"),
+        Err("Invalid category")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PortugalNifValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        PortugalNifValidator::is_valid("50196484"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PortugalNifValidator::is_valid("5019648430"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nif() {
+    let nif: Nif = "PT 501 964 843".parse().unwrap();
+    assert_eq!(nif.as_str(), "501964843");
+    assert_eq!(nif.category(), NifCategory::Company);
+    assert!(!nif.category().is_individual());
+    assert_eq!(nif.check_digit(), 3);
+    assert_eq!(nif.to_string(), "501 964 843");
+
+    let nif = Nif::parse("123456789").unwrap();
+    assert_eq!(nif.category(), NifCategory::Individual);
+    assert!(nif.category().is_individual());
+    assert_eq!(
+        Nif::parse("600023605").unwrap().category(),
+        NifCategory::PublicEntity
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nif() {
+    assert!(PortugalNifValidator::validator("501964843").is_ok());
+    assert!(PortugalNifValidator::validator("501964844").is_err());
+}
@@ -0,0 +1,72 @@
+use docval::portugal::{Niss, NissKind, PortugalNissValidator};
+
+#[test]
+fn test_valid_niss() {
+    assert!(PortugalNissValidator::is_valid("11084129855").is_ok());
+    assert!(PortugalNissValidator::is_valid("12345678902").is_ok());
+    assert!(PortugalNissValidator::is_valid("1 2000 0000 04").is_ok());
+    assert!(PortugalNissValidator::is_valid("17000000009").is_ok());
+}
+
+#[test]
+fn test_valid_entity_niss() {
+    assert!(PortugalNissValidator::is_valid("20000000001").is_ok());
+    assert!(PortugalNissValidator::is_valid("25012345675").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        PortugalNissValidator::is_valid("11084129856"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        PortugalNissValidator::is_valid("20000000000"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        PortugalNissValidator::is_valid("31084129855"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        PortugalNissValidator::is_valid("01084129855"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(PortugalNissValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        PortugalNissValidator::is_valid("1108412985"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        PortugalNissValidator::is_valid("110841298550"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_niss() {
+    let niss: Niss = "2501 2345 675".parse().unwrap();
+    assert_eq!(niss.as_str(), "25012345675");
+    assert_eq!(niss.kind(), NissKind::Entity);
+    assert_eq!(niss.check_digit(), 5);
+    assert_eq!(niss.to_string(), "25012345675");
+    assert_eq!(
+        Niss::parse("11084129855").unwrap().kind(),
+        NissKind::Individual
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_niss() {
+    assert!(PortugalNissValidator::validator("11084129855").is_ok());
+    assert!(PortugalNissValidator::validator("11084129856").is_err());
+}
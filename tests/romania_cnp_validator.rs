@@ -0,0 +1,106 @@
+use docval::romania::{Cnp, RomaniaCnpValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_cnp() {
+    assert!(RomaniaCnpValidator::is_valid("1800101221144").is_ok());
+    assert!(RomaniaCnpValidator::is_valid("1 800101 22 114 4").is_ok());
+    assert!(RomaniaCnpValidator::is_valid("6091231012345").is_ok());
+    assert!(RomaniaCnpValidator::is_valid("3711205700125").is_ok());
+    assert!(RomaniaCnpValidator::is_valid("5040229123431").is_ok());
+}
+
+#[test]
+fn test_valid_foreigner_cnp() {
+    assert!(RomaniaCnpValidator::is_valid("7000229134560").is_ok());
+    assert!(RomaniaCnpValidator::is_valid("9000101700013").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("1800101221145"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("5040229123430"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("2900229401231"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("1801301221144"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_county_code() {
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("1800101490011"),
+        Err("Invalid county code")
+    );
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("1800101001144"),
+        Err("Invalid county code")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("0800101221144"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("180010122114X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(RomaniaCnpValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("180010122114"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RomaniaCnpValidator::is_valid("18001012211440"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cnp() {
+    let cnp: Cnp = "1800101221144".parse().unwrap();
+    assert_eq!(cnp.as_str(), "1800101221144");
+    assert_eq!(cnp.birth_year(), Some(1980));
+    assert_eq!(cnp.birth_month(), 1);
+    assert_eq!(cnp.birth_day(), 1);
+    assert_eq!(cnp.sex(), Some(Sex::Male));
+    assert_eq!(cnp.county_code(), 22);
+    assert_eq!(cnp.serial(), 114);
+    assert_eq!(cnp.check_digit(), 4);
+
+    let cnp: Cnp = "3711205700125".parse().unwrap();
+    assert_eq!(cnp.birth_year(), Some(1871));
+
+    let cnp: Cnp = "9000101700013".parse().unwrap();
+    assert_eq!(cnp.birth_year(), None);
+    assert_eq!(cnp.sex(), None);
+    assert_eq!(cnp.county_code(), 70);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cnp() {
+    assert!(RomaniaCnpValidator::validator("1800101221144").is_ok());
+    assert!(RomaniaCnpValidator::validator("1800101221145").is_err());
+}
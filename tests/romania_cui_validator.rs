@@ -0,0 +1,70 @@
+use docval::romania::{Cui, RomaniaCuiValidator};
+
+#[test]
+fn test_valid_cui() {
+    assert!(RomaniaCuiValidator::is_valid("18547290").is_ok());
+    assert!(RomaniaCuiValidator::is_valid("13548146").is_ok());
+    assert!(RomaniaCuiValidator::is_valid("1234567897").is_ok());
+    assert!(RomaniaCuiValidator::is_valid("19").is_ok());
+    assert!(RomaniaCuiValidator::is_valid("1596").is_ok());
+}
+
+#[test]
+fn test_valid_cui_with_prefix() {
+    assert!(RomaniaCuiValidator::is_valid("RO18547290").is_ok());
+    assert!(RomaniaCuiValidator::is_valid("ro 13548146").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        RomaniaCuiValidator::is_valid("18547291"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        RomaniaCuiValidator::is_valid("RO1234567890"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        RomaniaCuiValidator::is_valid("018547290"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        RomaniaCuiValidator::is_valid("1854-7290"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        RomaniaCuiValidator::is_valid("BG18547290"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(RomaniaCuiValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(RomaniaCuiValidator::is_valid("RO"), Err("Invalid input"));
+    assert_eq!(RomaniaCuiValidator::is_valid("1"), Err("Invalid length"));
+    assert_eq!(
+        RomaniaCuiValidator::is_valid("12345678970"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cui() {
+    let cui: Cui = "RO 18547290".parse().unwrap();
+    assert_eq!(cui.as_str(), "18547290");
+    assert_eq!(cui.check_digit(), 0);
+    assert_eq!(cui.to_string(), "18547290");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cui() {
+    assert!(RomaniaCuiValidator::validator("RO18547290").is_ok());
+    assert!(RomaniaCuiValidator::validator("18547291").is_err());
+}
@@ -0,0 +1,68 @@
+use docval::russia::{Inn, InnKind, RussiaInnValidator};
+
+#[test]
+fn test_valid_inn_legal_entity() {
+    assert!(RussiaInnValidator::is_valid("7707083893").is_ok());
+    assert!(RussiaInnValidator::is_valid("7728014890").is_ok());
+    assert!(RussiaInnValidator::is_valid("7811 000 861").is_ok());
+}
+
+#[test]
+fn test_valid_inn_individual() {
+    assert!(RussiaInnValidator::is_valid("500100732259").is_ok());
+    assert!(RussiaInnValidator::is_valid("770708389405").is_ok());
+    assert!(RussiaInnValidator::is_valid("783000229357").is_ok());
+}
+
+#[test]
+fn test_invalid_inn() {
+    assert_eq!(RussiaInnValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        RussiaInnValidator::is_valid("770708389"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RussiaInnValidator::is_valid("77070838931"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RussiaInnValidator::is_valid("770708389A"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_inn_checksum() {
+    assert_eq!(
+        RussiaInnValidator::is_valid("7707083894"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        RussiaInnValidator::is_valid("500100732258"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        RussiaInnValidator::is_valid("500100732159"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_inn() {
+    let inn: Inn = "7707083893".parse().unwrap();
+    assert_eq!(inn.as_str(), "7707083893");
+    assert_eq!(inn.kind(), InnKind::LegalEntity);
+    assert_eq!(inn.tax_office(), "7707");
+    assert_eq!(inn.to_string(), "7707083893");
+
+    let inn = Inn::parse("783000229357").unwrap();
+    assert_eq!(inn.kind(), InnKind::Individual);
+    assert_eq!(inn.tax_office(), "7830");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_inn() {
+    assert!(RussiaInnValidator::validator("7707083893").is_ok());
+    assert!(RussiaInnValidator::validator("7707083894").is_err());
+}
@@ -0,0 +1,78 @@
+use docval::russia::{Ogrn, OgrnKind, RussiaOgrnValidator};
+
+#[test]
+fn test_valid_ogrn() {
+    assert!(RussiaOgrnValidator::is_valid("1027700132195").is_ok());
+    assert!(RussiaOgrnValidator::is_valid("1027739000079").is_ok());
+    assert!(RussiaOgrnValidator::is_valid("5067745248962").is_ok());
+    assert!(RussiaOgrnValidator::is_valid("1157746145006").is_ok());
+}
+
+#[test]
+fn test_valid_ogrnip() {
+    assert!(RussiaOgrnValidator::is_valid("304500116000157").is_ok());
+    assert!(RussiaOgrnValidator::is_valid("312774600360203").is_ok());
+    assert!(RussiaOgrnValidator::is_valid("325 500 000 123 450").is_ok());
+}
+
+#[test]
+fn test_invalid_ogrn() {
+    assert_eq!(RussiaOgrnValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("102770013219"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("30450011600015"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("102770013219A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("3027700132195"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("104500116000157"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_ogrn_checksum() {
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("1027700132196"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        RussiaOgrnValidator::is_valid("304500116000158"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_ogrn() {
+    let ogrn: Ogrn = "1027700132195".parse().unwrap();
+    assert_eq!(ogrn.as_str(), "1027700132195");
+    assert_eq!(ogrn.kind(), OgrnKind::LegalEntity);
+    assert_eq!(ogrn.registration_year(), 2002);
+    assert_eq!(ogrn.region_code(), 77);
+    assert_eq!(ogrn.tax_office(), "7700");
+    assert_eq!(ogrn.check_digit(), 5);
+    assert_eq!(ogrn.to_string(), "1027700132195");
+
+    let ogrn = Ogrn::parse("325500000123450").unwrap();
+    assert_eq!(ogrn.kind(), OgrnKind::IndividualEntrepreneur);
+    assert_eq!(ogrn.registration_year(), 2025);
+    assert_eq!(ogrn.region_code(), 50);
+    assert_eq!(ogrn.check_digit(), 0);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ogrn() {
+    assert!(RussiaOgrnValidator::validator("1027700132195").is_ok());
+    assert!(RussiaOgrnValidator::validator("1027700132196").is_err());
+}
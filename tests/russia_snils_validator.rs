@@ -0,0 +1,57 @@
+use docval::russia::{RussiaSnilsValidator, Snils};
+
+#[test]
+fn test_valid_snils() {
+    assert!(RussiaSnilsValidator::is_valid("112-233-445 95").is_ok());
+    assert!(RussiaSnilsValidator::is_valid("11223344595").is_ok());
+    assert!(RussiaSnilsValidator::is_valid("123-456-789 64").is_ok());
+    assert!(RussiaSnilsValidator::is_valid("087-654-303 00").is_ok());
+    assert!(RussiaSnilsValidator::is_valid("999-999-999 01").is_ok());
+    assert!(RussiaSnilsValidator::is_valid("001-001-999 65").is_ok());
+}
+
+#[test]
+fn test_valid_snils_unchecked() {
+    assert!(RussiaSnilsValidator::is_valid("001-001-998 00").is_ok());
+    assert!(RussiaSnilsValidator::is_valid("000-000-100 42").is_ok());
+}
+
+#[test]
+fn test_invalid_snils() {
+    assert_eq!(RussiaSnilsValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        RussiaSnilsValidator::is_valid("112-233-445 9"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RussiaSnilsValidator::is_valid("112-233-445 9A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        RussiaSnilsValidator::is_valid("112-233-445 96"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        RussiaSnilsValidator::is_valid("087-654-303 100"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        RussiaSnilsValidator::is_valid("001-001-999 00"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_snils() {
+    let snils: Snils = "123 456 789 64".parse().unwrap();
+    assert_eq!(snils.as_str(), "12345678964");
+    assert_eq!(snils.check_digits(), 64);
+    assert_eq!(snils.to_string(), "123-456-789 64");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_snils() {
+    assert!(RussiaSnilsValidator::validator("112-233-445 95").is_ok());
+    assert!(RussiaSnilsValidator::validator("112-233-445 96").is_err());
+}
@@ -0,0 +1,49 @@
+use docval::slovakia::{Ico, SlovakiaIcoValidator};
+
+#[test]
+fn test_valid_ico() {
+    assert!(SlovakiaIcoValidator::is_valid("35757442").is_ok());
+    assert!(SlovakiaIcoValidator::is_valid("35 757 442").is_ok());
+    assert!(SlovakiaIcoValidator::is_valid("31764126").is_ok());
+    assert!(SlovakiaIcoValidator::is_valid("00151599").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SlovakiaIcoValidator::is_valid("35757443"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        SlovakiaIcoValidator::is_valid("3575744X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(SlovakiaIcoValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        SlovakiaIcoValidator::is_valid("3575744"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ico() {
+    let ico: Ico = "35 757 442".parse().unwrap();
+    assert_eq!(ico.as_str(), "35757442");
+    assert_eq!(ico.check_digit(), 2);
+    assert_eq!(ico.to_string(), "35757442");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ico() {
+    assert!(SlovakiaIcoValidator::validator("35757442").is_ok());
+    assert!(SlovakiaIcoValidator::validator("35757443").is_err());
+}
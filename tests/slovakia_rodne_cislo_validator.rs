@@ -0,0 +1,61 @@
+use docval::slovakia::{RodneCislo, SlovakiaRodneCisloValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_rodne_cislo() {
+    assert!(SlovakiaRodneCisloValidator::is_valid("7360280004").is_ok());
+    assert!(SlovakiaRodneCisloValidator::is_valid("736028/0004").is_ok());
+    assert!(SlovakiaRodneCisloValidator::is_valid("710319/2460").is_ok());
+    assert!(SlovakiaRodneCisloValidator::is_valid("530101/123").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SlovakiaRodneCisloValidator::is_valid("736028/0005"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        SlovakiaRodneCisloValidator::is_valid("855230/0010"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        SlovakiaRodneCisloValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        SlovakiaRodneCisloValidator::is_valid("540101/123"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SlovakiaRodneCisloValidator::is_valid("736028/00040"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rodne_cislo() {
+    let rodne_cislo: RodneCislo = "736028/0004".parse().unwrap();
+    assert_eq!(rodne_cislo.as_str(), "7360280004");
+    assert_eq!(rodne_cislo.birth_year(), 1973);
+    assert_eq!(rodne_cislo.birth_month(), 10);
+    assert_eq!(rodne_cislo.birth_day(), 28);
+    assert_eq!(rodne_cislo.sex(), Sex::Female);
+    assert_eq!(rodne_cislo.check_digit(), Some(4));
+    assert_eq!(rodne_cislo.to_string(), "736028/0004");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rodne_cislo() {
+    assert!(SlovakiaRodneCisloValidator::validator("736028/0004").is_ok());
+    assert!(SlovakiaRodneCisloValidator::validator("736028/0005").is_err());
+}
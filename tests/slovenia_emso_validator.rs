@@ -0,0 +1,92 @@
+use docval::slovenia::{Emso, SloveniaEmsoValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_emso() {
+    assert!(SloveniaEmsoValidator::is_valid("0101980500129").is_ok());
+    assert!(SloveniaEmsoValidator::is_valid("0101980 50 012 9").is_ok());
+    assert!(SloveniaEmsoValidator::is_valid("0101980505007").is_ok());
+    assert!(SloveniaEmsoValidator::is_valid("1506971500012").is_ok());
+    assert!(SloveniaEmsoValidator::is_valid("0101005500010").is_ok());
+    assert!(SloveniaEmsoValidator::is_valid("2902000501248").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("0101980500128"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("1506971500013"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_check_digit_ten() {
+    for check_digit in 0..=9 {
+        assert_eq!(
+            SloveniaEmsoValidator::is_valid(&format!("290200050123{}", check_digit)),
+            Err("Invalid checksum")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_date() {
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("2902900501235"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("3102980500010"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("010198050012X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("01.01.1980500"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(SloveniaEmsoValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("010198050012"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SloveniaEmsoValidator::is_valid("01019805001290"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_emso() {
+    let emso: Emso = "0101980500129".parse().unwrap();
+    assert_eq!(emso.as_str(), "0101980500129");
+    assert_eq!(emso.birth_year(), 1980);
+    assert_eq!(emso.birth_month(), 1);
+    assert_eq!(emso.birth_day(), 1);
+    assert_eq!(emso.region_code(), 50);
+    assert_eq!(emso.serial(), 12);
+    assert_eq!(emso.sex(), Sex::Male);
+    assert_eq!(emso.check_digit(), 9);
+    assert_eq!(emso.to_string(), "0101980500129");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_emso() {
+    assert!(SloveniaEmsoValidator::validator("0101980500129").is_ok());
+    assert!(SloveniaEmsoValidator::validator("0101980500128").is_err());
+}
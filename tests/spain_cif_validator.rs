@@ -0,0 +1,85 @@
+use docval::spain::{Cif, OrganizationType, SpainCifValidator};
+
+#[test]
+fn test_valid_cif_with_digit() {
+    assert!(SpainCifValidator::is_valid("A13585625").is_ok());
+    assert!(SpainCifValidator::is_valid("B-12345674").is_ok());
+    assert!(SpainCifValidator::is_valid("a58042094").is_ok());
+    assert!(SpainCifValidator::is_valid("H 4600000 6").is_ok());
+}
+
+#[test]
+fn test_valid_cif_with_letter() {
+    assert!(SpainCifValidator::is_valid("Q2826000H").is_ok());
+    assert!(SpainCifValidator::is_valid("P0800000B").is_ok());
+    assert!(SpainCifValidator::is_valid("S0000000J").is_ok());
+}
+
+#[test]
+fn test_valid_cif_with_either_form() {
+    assert!(SpainCifValidator::is_valid("G12345674").is_ok());
+    assert!(SpainCifValidator::is_valid("G1234567D").is_ok());
+    assert!(SpainCifValidator::is_valid("F4600000F").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SpainCifValidator::is_valid("A13585626"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SpainCifValidator::is_valid("B1234567D"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SpainCifValidator::is_valid("Q28260008"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_type_and_format() {
+    assert_eq!(
+        SpainCifValidator::is_valid("I12345674"),
+        Err("Invalid type")
+    );
+    assert_eq!(
+        SpainCifValidator::is_valid("12345678Z"),
+        Err("Invalid type")
+    );
+    assert_eq!(
+        SpainCifValidator::is_valid("B1234A674"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(SpainCifValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        SpainCifValidator::is_valid("B1234567"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SpainCifValidator::is_valid("B123456745"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cif() {
+    let cif: Cif = "q-2826000-h".parse().unwrap();
+    assert_eq!(cif.as_str(), "Q2826000H");
+    assert_eq!(cif.organization_type(), OrganizationType::PublicBody);
+    assert_eq!(cif.province_code(), "28");
+    assert_eq!(cif.control_character(), 'H');
+    assert_eq!(cif.to_string(), "Q2826000H");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cif() {
+    assert!(SpainCifValidator::validator("A13585625").is_ok());
+    assert!(SpainCifValidator::validator("A13585626").is_err());
+}
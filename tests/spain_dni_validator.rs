@@ -0,0 +1,95 @@
+use docval::spain::{IdentityNumber, IdentityNumberKind, SpainDniValidator, SpainNieValidator};
+
+#[test]
+fn test_valid_dni() {
+    assert!(SpainDniValidator::is_valid("12345678Z").is_ok());
+    assert!(SpainDniValidator::is_valid("12.345.678-Z").is_ok());
+    assert!(SpainDniValidator::is_valid("54362315k").is_ok());
+    assert!(SpainDniValidator::is_valid("00000000T").is_ok());
+    assert!(SpainDniValidator::is_valid("99999999R").is_ok());
+}
+
+#[test]
+fn test_valid_nie() {
+    assert!(SpainNieValidator::is_valid("X1234567L").is_ok());
+    assert!(SpainNieValidator::is_valid("Y-1234567-X").is_ok());
+    assert!(SpainNieValidator::is_valid("z1234567r").is_ok());
+    assert!(SpainNieValidator::is_valid("X2482300W").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SpainDniValidator::is_valid("12345678A"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SpainNieValidator::is_valid("X1234567Z"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SpainNieValidator::is_valid("Y1234567L"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        SpainDniValidator::is_valid("X1234567L"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SpainNieValidator::is_valid("12345678Z"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SpainDniValidator::is_valid("A2345678Z"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SpainDniValidator::is_valid("1234567ÑZ"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SpainDniValidator::is_valid("123456ÑZ"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(SpainDniValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        SpainDniValidator::is_valid("1234567L"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SpainNieValidator::is_valid("X12345678Z"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_identity_number() {
+    let dni: IdentityNumber = "12.345.678-z".parse().unwrap();
+    assert_eq!(dni.as_str(), "12345678Z");
+    assert_eq!(dni.kind(), IdentityNumberKind::Dni);
+    assert_eq!(dni.number(), 12345678);
+    assert_eq!(dni.control_letter(), 'Z');
+    assert_eq!(dni.to_string(), "12345678Z");
+
+    let nie = IdentityNumber::parse("Y 1234567 X").unwrap();
+    assert_eq!(nie.kind(), IdentityNumberKind::Nie);
+    assert_eq!(nie.number(), 11234567);
+    assert_eq!(nie.control_letter(), 'X');
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_identity_numbers() {
+    assert!(SpainDniValidator::validator("12345678Z").is_ok());
+    assert!(SpainDniValidator::validator("12345678A").is_err());
+    assert!(SpainNieValidator::validator("X1234567L").is_ok());
+    assert!(SpainNieValidator::validator("X1234567Z").is_err());
+}
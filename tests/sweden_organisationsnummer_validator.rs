@@ -0,0 +1,63 @@
+use docval::sweden::{Organisationsnummer, SwedenOrganisationsnummerValidator};
+
+#[test]
+fn test_valid_organisationsnummer() {
+    assert!(SwedenOrganisationsnummerValidator::is_valid("556012-5790").is_ok());
+    assert!(SwedenOrganisationsnummerValidator::is_valid("5560747569").is_ok());
+    assert!(SwedenOrganisationsnummerValidator::is_valid("212000-0142").is_ok());
+    assert!(SwedenOrganisationsnummerValidator::is_valid("802002-4355").is_ok());
+    assert!(SwedenOrganisationsnummerValidator::is_valid("969663-6548").is_ok());
+}
+
+#[test]
+fn test_valid_organisationsnummer_registry_form() {
+    assert!(SwedenOrganisationsnummerValidator::is_valid("16556012-5790").is_ok());
+    assert!(SwedenOrganisationsnummerValidator::is_valid("165560747569").is_ok());
+}
+
+#[test]
+fn test_invalid_organisationsnummer() {
+    assert_eq!(
+        SwedenOrganisationsnummerValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        SwedenOrganisationsnummerValidator::is_valid("556012-579"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SwedenOrganisationsnummerValidator::is_valid("195560125790"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SwedenOrganisationsnummerValidator::is_valid("556012-579A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwedenOrganisationsnummerValidator::is_valid("551234-5678"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwedenOrganisationsnummerValidator::is_valid("556012-5791"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_organisationsnummer() {
+    let organisationsnummer: Organisationsnummer = "16556074-7569".parse().unwrap();
+    assert_eq!(organisationsnummer.as_str(), "5560747569");
+    assert_eq!(organisationsnummer.group(), 5);
+    assert_eq!(organisationsnummer.check_digit(), 9);
+    assert_eq!(organisationsnummer.to_string(), "556074-7569");
+
+    let organisationsnummer = Organisationsnummer::parse("802002-4355").unwrap();
+    assert_eq!(organisationsnummer.group(), 8);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_organisationsnummer() {
+    assert!(SwedenOrganisationsnummerValidator::validator("556012-5790").is_ok());
+    assert!(SwedenOrganisationsnummerValidator::validator("556012-5791").is_err());
+}
@@ -0,0 +1,96 @@
+use docval::sweden::{Personnummer, PersonnummerKind, SwedenPersonnummerValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_personnummer() {
+    assert!(SwedenPersonnummerValidator::is_valid("811218-9876").is_ok());
+    assert!(SwedenPersonnummerValidator::is_valid("8112189876").is_ok());
+    assert!(SwedenPersonnummerValidator::is_valid("19811218-9876").is_ok());
+    assert!(SwedenPersonnummerValidator::is_valid("198112189876").is_ok());
+    assert!(SwedenPersonnummerValidator::is_valid("811218+9876").is_ok());
+    assert!(SwedenPersonnummerValidator::is_valid("850709-9805").is_ok());
+}
+
+#[test]
+fn test_valid_samordningsnummer() {
+    assert!(SwedenPersonnummerValidator::is_valid("701063-2391").is_ok());
+    assert!(SwedenPersonnummerValidator::is_valid("19701063-2391").is_ok());
+}
+
+#[test]
+fn test_invalid_personnummer() {
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("811218-987"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("81121-89876"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("19811218+9876"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("811218-987A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("811232-9876"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("811218-9875"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_personnummer_century() {
+    assert!(SwedenPersonnummerValidator::is_valid_at("000229-1235", 2026).is_ok());
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid_at("000229+1235", 2026),
+        Err("Invalid date")
+    );
+    assert!(SwedenPersonnummerValidator::is_valid("20000229-1235").is_ok());
+    assert_eq!(
+        SwedenPersonnummerValidator::is_valid("19000229-1235"),
+        Err("Invalid date")
+    );
+
+    let personnummer = Personnummer::parse_at("811218+9876", 2026).unwrap();
+    assert_eq!(personnummer.birth_year(), 1881);
+    let personnummer = Personnummer::parse_at("000229-1235", 2026).unwrap();
+    assert_eq!(personnummer.birth_year(), 2000);
+}
+
+#[test]
+fn test_parse_personnummer() {
+    let personnummer: Personnummer = "811218-9876".parse().unwrap();
+    assert_eq!(personnummer.as_str(), "198112189876");
+    assert_eq!(personnummer.kind(), PersonnummerKind::Personal);
+    assert_eq!(personnummer.birth_year(), 1981);
+    assert_eq!(personnummer.birth_month(), 12);
+    assert_eq!(personnummer.birth_day(), 18);
+    assert_eq!(personnummer.sex(), Sex::Male);
+    assert_eq!(personnummer.check_digit(), 6);
+    assert_eq!(personnummer.to_string(), "19811218-9876");
+
+    let personnummer = Personnummer::parse("850709-9805").unwrap();
+    assert_eq!(personnummer.sex(), Sex::Female);
+
+    let personnummer = Personnummer::parse("701063-2391").unwrap();
+    assert_eq!(personnummer.kind(), PersonnummerKind::Coordination);
+    assert_eq!(personnummer.birth_day(), 3);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_personnummer() {
+    assert!(SwedenPersonnummerValidator::validator("811218-9876").is_ok());
+    assert!(SwedenPersonnummerValidator::validator("811218-9875").is_err());
+}
@@ -0,0 +1,73 @@
+use docval::switzerland::{Ahv, SwitzerlandAhvValidator};
+
+#[test]
+fn test_valid_ahv() {
+    assert!(SwitzerlandAhvValidator::is_valid("7569217076985").is_ok());
+    assert!(SwitzerlandAhvValidator::is_valid("756.9217.0769.85").is_ok());
+    assert!(SwitzerlandAhvValidator::is_valid("756 1234 5678 97").is_ok());
+    assert!(SwitzerlandAhvValidator::is_valid("756.3542.6890.18").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("7569217076986"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("756.1234.5678.90"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("7579217076985"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("1234567890128"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("756-9217-0769"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("75692170769X5"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(SwitzerlandAhvValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("756.9217.0769.8"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SwitzerlandAhvValidator::is_valid("75692170769850"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_ahv() {
+    let ahv: Ahv = "756 9217 0769 85".parse().unwrap();
+    assert_eq!(ahv.as_str(), "7569217076985");
+    assert_eq!(ahv.check_digit(), 5);
+    assert_eq!(ahv.to_string(), "756.9217.0769.85");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ahv() {
+    assert!(SwitzerlandAhvValidator::validator("756.9217.0769.85").is_ok());
+    assert!(SwitzerlandAhvValidator::validator("7569217076986").is_err());
+}
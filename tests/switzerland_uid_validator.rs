@@ -0,0 +1,101 @@
+use docval::switzerland::{SwitzerlandUidValidator, Uid, VatSuffix};
+
+#[test]
+fn test_valid_uid() {
+    assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.710").is_ok());
+    assert!(SwitzerlandUidValidator::is_valid("CHE116281710").is_ok());
+    assert!(SwitzerlandUidValidator::is_valid("che-109.456.513").is_ok());
+    assert!(SwitzerlandUidValidator::is_valid("CHE 123 456 788").is_ok());
+}
+
+#[test]
+fn test_valid_vat_number() {
+    assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.710 MWST").is_ok());
+    assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.710 TVA").is_ok());
+    assert!(SwitzerlandUidValidator::is_valid("CHE-116.281.710 IVA").is_ok());
+    assert!(SwitzerlandUidValidator::is_valid("CHE109456513mwst").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-116.281.711"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-123.456.789 MWST"),
+        Err("Invalid checksum")
+    );
+    for check_digit in 0..=9 {
+        assert_eq!(
+            SwitzerlandUidValidator::is_valid(&format!("CHE10000016{}", check_digit)),
+            Err("Invalid checksum")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("116.281.710"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CH-116.281.710"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-116.281.71X"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-116/281/7"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-116.281 VAT"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(SwitzerlandUidValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-116.281.71"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE-116.281.7100"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        SwitzerlandUidValidator::is_valid("CHE MWST"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_uid() {
+    let uid: Uid = "CHE-116.281.710".parse().unwrap();
+    assert_eq!(uid.as_str(), "CHE116281710");
+    assert_eq!(uid.number(), "116281710");
+    assert_eq!(uid.vat_suffix(), None);
+    assert_eq!(uid.to_string(), "CHE-116.281.710");
+
+    let uid: Uid = "che 116 281 710 iva".parse().unwrap();
+    assert_eq!(uid.as_str(), "CHE116281710IVA");
+    assert_eq!(uid.vat_suffix(), Some(VatSuffix::Iva));
+    assert_eq!(uid.to_string(), "CHE-116.281.710 IVA");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_uid() {
+    assert!(SwitzerlandUidValidator::validator("CHE-116.281.710 MWST").is_ok());
+    assert!(SwitzerlandUidValidator::validator("CHE-116.281.711").is_err());
+}
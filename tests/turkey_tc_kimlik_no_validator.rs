@@ -0,0 +1,61 @@
+use docval::turkey::{TcKimlikNo, TurkeyTcKimlikNoValidator};
+
+#[test]
+fn test_valid_tc_kimlik_no() {
+    assert!(TurkeyTcKimlikNoValidator::is_valid("10000000146").is_ok());
+    assert!(TurkeyTcKimlikNoValidator::is_valid("12345678950").is_ok());
+    assert!(TurkeyTcKimlikNoValidator::is_valid("98765432150").is_ok());
+    assert!(TurkeyTcKimlikNoValidator::is_valid("11111111110").is_ok());
+    assert!(TurkeyTcKimlikNoValidator::is_valid("100 000 001 46").is_ok());
+}
+
+#[test]
+fn test_invalid_tc_kimlik_no() {
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid("1000000014"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid("1000000014A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid("01234567840"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_tc_kimlik_no_checksum() {
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid("10000000147"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid("10000000156"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        TurkeyTcKimlikNoValidator::is_valid("12345678901"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_tc_kimlik_no() {
+    let tc_kimlik_no: TcKimlikNo = "100 000 001 46".parse().unwrap();
+    assert_eq!(tc_kimlik_no.as_str(), "10000000146");
+    assert_eq!(tc_kimlik_no.check_digits(), 46);
+    assert_eq!(tc_kimlik_no.to_string(), "10000000146");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_tc_kimlik_no() {
+    assert!(TurkeyTcKimlikNoValidator::validator("10000000146").is_ok());
+    assert!(TurkeyTcKimlikNoValidator::validator("10000000147").is_err());
+}
@@ -0,0 +1,50 @@
+use docval::turkey::{TurkeyVknValidator, Vkn};
+
+#[test]
+fn test_valid_vkn() {
+    assert!(TurkeyVknValidator::is_valid("4540536920").is_ok());
+    assert!(TurkeyVknValidator::is_valid("1234567890").is_ok());
+    assert!(TurkeyVknValidator::is_valid("9876543217").is_ok());
+    assert!(TurkeyVknValidator::is_valid("0000000019").is_ok());
+    assert!(TurkeyVknValidator::is_valid("111 111 111 4").is_ok());
+}
+
+#[test]
+fn test_invalid_vkn() {
+    assert_eq!(TurkeyVknValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        TurkeyVknValidator::is_valid("454053692"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        TurkeyVknValidator::is_valid("45405369201"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        TurkeyVknValidator::is_valid("454053692A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        TurkeyVknValidator::is_valid("4540536921"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        TurkeyVknValidator::is_valid("9999999990"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_vkn() {
+    let vkn: Vkn = "987 654 321 7".parse().unwrap();
+    assert_eq!(vkn.as_str(), "9876543217");
+    assert_eq!(vkn.check_digit(), 7);
+    assert_eq!(vkn.to_string(), "9876543217");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_vkn() {
+    assert!(TurkeyVknValidator::validator("4540536920").is_ok());
+    assert!(TurkeyVknValidator::validator("4540536921").is_err());
+}
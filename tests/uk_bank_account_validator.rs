@@ -0,0 +1,172 @@
+use docval::uk::{ModulusTable, UkBankAccountValidator};
+
+const EXCEPTIONS_WEIGHTS: &str = "\
+074456 074456 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1   12
+074456 074456 MOD10    0    0    0    0    0    0    7    1    3    7    1    3    7    1   13
+086090 086090 MOD11    0    0    6    5    4    3    2    7    6    5    4    3    2    1    8
+180002 180002 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1   14
+200915 200915 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1    6
+200915 200915 DBLAL    2    1    2    1    2    1    2    1    2    1    2    1    2    1    6
+309070 309070 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1    2
+309070 309070 MOD11    0    0    6    5    4    3    2    7    6    5    4    3    2    1    9
+772798 772798 MOD11    0    0    6    5    4    3    2    7    6    5    4    3    2    1    7
+820000 820000 MOD10    0    0    0    0    0    0    7    1    3    7    1    3    7    1
+820000 820000 DBLAL    2    1    2    1    2    1    2    1    2    1    2    1    2    1    3
+871427 871427 MOD11    0    0    0    0    0    0    8    7    6    5    4    3    2    1   10
+871427 871427 MOD11    0    0    6    5    4    3    2    7    6    5    4    3    2    1   11
+";
+
+fn exceptions_table() -> ModulusTable {
+    ModulusTable::parse(EXCEPTIONS_WEIGHTS, "").unwrap()
+}
+
+#[test]
+fn test_valid_account() {
+    assert!(UkBankAccountValidator::is_valid("089999", "66374958").is_ok());
+    assert!(UkBankAccountValidator::is_valid("10-79-99", "88837491").is_ok());
+    assert!(UkBankAccountValidator::is_valid("118765", "64371389").is_ok());
+    assert!(UkBankAccountValidator::is_valid("134020", "63849203").is_ok());
+}
+
+#[test]
+fn test_valid_account_with_exception_5() {
+    assert!(UkBankAccountValidator::is_valid("938611", "07806039").is_ok());
+    assert!(UkBankAccountValidator::is_valid("938600", "42368003").is_ok());
+    assert!(UkBankAccountValidator::is_valid("938063", "55065200").is_ok());
+    for account_number in ["15764273", "15764264", "15763217"] {
+        assert_eq!(
+            UkBankAccountValidator::is_valid("938063", account_number),
+            Err("Invalid checksum")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UkBankAccountValidator::is_valid("089999", "66374959"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("107999", "88837493"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("118765", "64371388"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_unknown_sort_code() {
+    assert_eq!(
+        UkBankAccountValidator::is_valid("400000", "12345678"),
+        Err("Unknown sort code")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("20-00-00", "55779911"),
+        Err("Unknown sort code")
+    );
+}
+
+#[test]
+fn test_short_account_number() {
+    let table = ModulusTable::parse(
+        "089999 089999 MOD10    0    0    0    0    0    0    7    1    3    7    1    3    7    1",
+        "",
+    )
+    .unwrap();
+    assert!(UkBankAccountValidator::is_valid_with(&table, "089999", "0066374958").is_err());
+    assert!(UkBankAccountValidator::is_valid_with(&table, "089999", "066374958").is_err());
+    assert!(UkBankAccountValidator::is_valid_with(&table, "089999", "6374950").is_ok());
+}
+
+#[test]
+fn test_exceptions() {
+    let table = exceptions_table();
+    let is_valid = |sort_code, account_number| {
+        UkBankAccountValidator::is_valid_with(&table, sort_code, account_number).is_ok()
+    };
+    // Exception 2 and 9: alternative weights, then the 309634 sort code.
+    assert!(is_valid("309070", "12345677"));
+    assert!(is_valid("309070", "99345694"));
+    assert!(is_valid("309070", "75216612"));
+    assert!(!is_valid("309070", "12345678"));
+    // Exception 3: the second check is skipped when c is 6 or 9.
+    assert!(is_valid("820000", "73688637"));
+    assert!(is_valid("820000", "09456105"));
+    assert!(!is_valid("820000", "63383683"));
+    // Exception 6: foreign currency accounts are not checked.
+    assert!(is_valid("200915", "41011166"));
+    assert!(!is_valid("200915", "41011167"));
+    // Exception 7: the sort code weights are ignored when g is 9.
+    assert!(is_valid("772798", "78400092"));
+    // Exception 8: the 090126 sort code is used.
+    assert!(is_valid("086090", "51193972"));
+    // Exceptions 10 and 11, 12 and 13: either check may pass.
+    assert!(is_valid("871427", "09508799"));
+    assert!(is_valid("871427", "60994072"));
+    assert!(is_valid("074456", "82767015"));
+    assert!(!is_valid("871427", "86286829"));
+    assert!(!is_valid("074456", "86286829"));
+    // Exception 14: a trailing 0, 1 or 9 is dropped.
+    assert!(is_valid("180002", "00000190"));
+    assert!(!is_valid("180002", "00000192"));
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        UkBankAccountValidator::is_valid("08999X", "66374958"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("089999", "6637495X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(
+        UkBankAccountValidator::is_valid("", "66374958"),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("089999", ""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("08999", "66374958"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("089999", "663749"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("089999", "66374"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkBankAccountValidator::is_valid("089999", "663749580"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_table() {
+    assert!(ModulusTable::parse(EXCEPTIONS_WEIGHTS, "938600 938611\n").is_ok());
+    assert_eq!(
+        ModulusTable::parse("089999 089999 MOD12 0 0 0 0 0 0 7 1 3 7 1 3 7 1", ""),
+        Err("Invalid table")
+    );
+    assert_eq!(
+        ModulusTable::parse(EXCEPTIONS_WEIGHTS, "938600"),
+        Err("Invalid table")
+    );
+    assert_eq!(
+        ModulusTable::parse("089999 089999 MOD10 0 0 0 0 0 0 7 1 3 7 1 3 7 1 15", ""),
+        Err("Invalid table")
+    );
+}
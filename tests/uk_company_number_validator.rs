@@ -0,0 +1,100 @@
+use docval::uk::{CompanyNumber, EntityType, Jurisdiction, UkCompanyNumberValidator};
+
+#[test]
+fn test_valid_company_number() {
+    assert!(UkCompanyNumberValidator::is_valid("00445790").is_ok());
+    assert!(UkCompanyNumberValidator::is_valid("445790").is_ok());
+    assert!(UkCompanyNumberValidator::is_valid("SC005336").is_ok());
+    assert!(UkCompanyNumberValidator::is_valid("ni 015264").is_ok());
+    assert!(UkCompanyNumberValidator::is_valid("OC123").is_ok());
+    assert!(UkCompanyNumberValidator::is_valid("FC031457").is_ok());
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("XX123456"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("NZ123456"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("S1234567"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("SC"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("1234-567"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("SC000000"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UkCompanyNumberValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("123456789"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkCompanyNumberValidator::is_valid("SC1234567"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_company_number() {
+    let company_number: CompanyNumber = "445790".parse().unwrap();
+    assert_eq!(company_number.as_str(), "00445790");
+    assert_eq!(company_number.prefix(), None);
+    assert_eq!(company_number.number(), 445790);
+    assert_eq!(company_number.jurisdiction(), Jurisdiction::EnglandAndWales);
+    assert_eq!(company_number.entity_type(), EntityType::Company);
+    assert_eq!(company_number.to_string(), "00445790");
+}
+
+#[test]
+fn test_parse_prefixed_company_number() {
+    let company_number: CompanyNumber = "so 301234".parse().unwrap();
+    assert_eq!(company_number.as_str(), "SO301234");
+    assert_eq!(company_number.prefix(), Some("SO"));
+    assert_eq!(company_number.number(), 301234);
+    assert_eq!(company_number.jurisdiction(), Jurisdiction::Scotland);
+    assert_eq!(
+        company_number.entity_type(),
+        EntityType::LimitedLiabilityPartnership
+    );
+}
+
+#[test]
+fn test_prefix_metadata() {
+    assert_eq!(
+        Jurisdiction::from_prefix("NL"),
+        Some(Jurisdiction::NorthernIreland)
+    );
+    assert_eq!(
+        EntityType::from_prefix("NL"),
+        Some(EntityType::LimitedPartnership)
+    );
+    assert_eq!(Jurisdiction::from_prefix("XX"), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_company_number() {
+    assert!(UkCompanyNumberValidator::validator("SC005336").is_ok());
+    assert!(UkCompanyNumberValidator::validator("XX123456").is_err());
+}
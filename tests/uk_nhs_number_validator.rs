@@ -0,0 +1,72 @@
+use docval::uk::{NhsNumber, UkNhsNumberValidator};
+
+#[test]
+fn test_valid_nhs_number() {
+    assert!(UkNhsNumberValidator::is_valid("9434765919").is_ok());
+    assert!(UkNhsNumberValidator::is_valid("943 476 5919").is_ok());
+    assert!(UkNhsNumberValidator::is_valid("401-023-2137").is_ok());
+    assert!(UkNhsNumberValidator::is_valid("4505577104").is_ok());
+    assert!(UkNhsNumberValidator::is_valid("9876543210").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UkNhsNumberValidator::is_valid("9434765918"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkNhsNumberValidator::is_valid("4010232138"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_check_digit_ten() {
+    for check_digit in 0..=9 {
+        assert_eq!(
+            UkNhsNumberValidator::is_valid(&format!("123456713{}", check_digit)),
+            Err("Invalid checksum")
+        );
+    }
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        UkNhsNumberValidator::is_valid("943.765919"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkNhsNumberValidator::is_valid("943476591X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UkNhsNumberValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UkNhsNumberValidator::is_valid("943476591"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkNhsNumberValidator::is_valid("94347659190"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nhs_number() {
+    let nhs_number: NhsNumber = "943-476-5919".parse().unwrap();
+    assert_eq!(nhs_number.as_str(), "9434765919");
+    assert_eq!(nhs_number.check_digit(), 9);
+    assert_eq!(nhs_number.to_string(), "943 476 5919");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nhs_number() {
+    assert!(UkNhsNumberValidator::validator("9434765919").is_ok());
+    assert!(UkNhsNumberValidator::validator("9434765918").is_err());
+}
@@ -0,0 +1,89 @@
+use docval::uk::{Nino, UkNinoValidator};
+
+#[test]
+fn test_valid_nino() {
+    assert!(UkNinoValidator::is_valid("AB123456C").is_ok());
+    assert!(UkNinoValidator::is_valid("AB 12 34 56 C").is_ok());
+    assert!(UkNinoValidator::is_valid("jg103759a").is_ok());
+    assert!(UkNinoValidator::is_valid("OA000000D").is_ok());
+    assert!(UkNinoValidator::is_valid("ZA999999B").is_ok());
+}
+
+#[test]
+fn test_invalid_prefix() {
+    for prefix in ["DA", "FA", "IA", "QA", "UA", "VA", "AD", "AO", "AQ"] {
+        assert_eq!(
+            UkNinoValidator::is_valid(&format!("{}123456C", prefix)),
+            Err("Invalid prefix"),
+            "{}",
+            prefix
+        );
+    }
+}
+
+#[test]
+fn test_invalid_administrative_prefix() {
+    for prefix in ["BG", "GB", "KN", "NK", "NT", "TN", "ZZ"] {
+        assert_eq!(
+            UkNinoValidator::is_valid(&format!("{}123456A", prefix)),
+            Err("Invalid prefix"),
+            "{}",
+            prefix
+        );
+    }
+}
+
+#[test]
+fn test_invalid_suffix() {
+    assert_eq!(
+        UkNinoValidator::is_valid("AB123456E"),
+        Err("Invalid suffix")
+    );
+    assert_eq!(
+        UkNinoValidator::is_valid("AB123456Z"),
+        Err("Invalid suffix")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        UkNinoValidator::is_valid("A1123456C"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkNinoValidator::is_valid("AB12345CC"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkNinoValidator::is_valid("AB12-456C"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UkNinoValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UkNinoValidator::is_valid("AB123456"), Err("Invalid length"));
+    assert_eq!(
+        UkNinoValidator::is_valid("AB1234567C"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_nino() {
+    let nino: Nino = "ab 12 34 56 c".parse().unwrap();
+    assert_eq!(nino.as_str(), "AB123456C");
+    assert_eq!(nino.prefix(), "AB");
+    assert_eq!(nino.number(), "123456");
+    assert_eq!(nino.suffix(), 'C');
+    assert_eq!(nino.to_string(), "AB 12 34 56 C");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_nino() {
+    assert!(UkNinoValidator::validator("AB123456C").is_ok());
+    assert!(UkNinoValidator::validator("GB123456C").is_err());
+}
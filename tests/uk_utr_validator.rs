@@ -0,0 +1,66 @@
+use docval::uk::{UkUtrValidator, Utr};
+
+#[test]
+fn test_valid_utr() {
+    assert!(UkUtrValidator::is_valid("1955839661").is_ok());
+    assert!(UkUtrValidator::is_valid("19558 39661").is_ok());
+    assert!(UkUtrValidator::is_valid("2234567890").is_ok());
+    assert!(UkUtrValidator::is_valid("9999999999").is_ok());
+    assert!(UkUtrValidator::is_valid("1123456789").is_ok());
+}
+
+#[test]
+fn test_valid_utr_with_k_suffix() {
+    assert!(UkUtrValidator::is_valid("1955839661K").is_ok());
+    assert!(UkUtrValidator::is_valid("19558 39661 k").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UkUtrValidator::is_valid("1234567890"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkUtrValidator::is_valid("2955839661"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        UkUtrValidator::is_valid("19558-9661"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkUtrValidator::is_valid("K955839661"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UkUtrValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UkUtrValidator::is_valid("K"), Err("Invalid input"));
+    assert_eq!(UkUtrValidator::is_valid("195583966"), Err("Invalid length"));
+    assert_eq!(
+        UkUtrValidator::is_valid("19558396610"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_utr() {
+    let utr: Utr = "19558 39661K".parse().unwrap();
+    assert_eq!(utr.as_str(), "1955839661");
+    assert_eq!(utr.check_digit(), 1);
+    assert_eq!(utr.to_string(), "19558 39661");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_utr() {
+    assert!(UkUtrValidator::validator("1955839661").is_ok());
+    assert!(UkUtrValidator::validator("1234567890").is_err());
+}
@@ -0,0 +1,93 @@
+use docval::uk::{UkVatValidator, VatNumberKind, VatRegistrationNumber};
+
+#[test]
+fn test_valid_vat_number() {
+    assert!(UkVatValidator::is_valid("GB980780684").is_ok());
+    assert!(UkVatValidator::is_valid("GB 980 7806 84").is_ok());
+    assert!(UkVatValidator::is_valid("434031494").is_ok());
+    assert!(UkVatValidator::is_valid("gb562235945").is_ok());
+    assert!(UkVatValidator::is_valid("012345620").is_ok());
+}
+
+#[test]
+fn test_valid_vat_number_9755() {
+    assert!(UkVatValidator::is_valid("GB241727613").is_ok());
+    assert!(UkVatValidator::is_valid("GB100000132").is_ok());
+    assert_eq!(
+        UkVatValidator::is_valid("GB012345662"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkVatValidator::is_valid("GB241727626"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_valid_branch_and_special_formats() {
+    assert!(UkVatValidator::is_valid("GB 980 7806 84 001").is_ok());
+    assert!(UkVatValidator::is_valid("GBGD001").is_ok());
+    assert!(UkVatValidator::is_valid("GBGD499").is_ok());
+    assert!(UkVatValidator::is_valid("HA500").is_ok());
+    assert!(UkVatValidator::is_valid("GBHA999").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UkVatValidator::is_valid("GB980780685"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkVatValidator::is_valid("GB123456789001"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(UkVatValidator::is_valid("GBGD500"), Err("Invalid format"));
+    assert_eq!(UkVatValidator::is_valid("GBHA499"), Err("Invalid format"));
+    assert_eq!(UkVatValidator::is_valid("GBXX123"), Err("Invalid format"));
+    assert_eq!(
+        UkVatValidator::is_valid("GB98078068X"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UkVatValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UkVatValidator::is_valid("GB"), Err("Invalid input"));
+    assert_eq!(
+        UkVatValidator::is_valid("GB98078068"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkVatValidator::is_valid("GB9807806840"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_vat_number() {
+    let vat: VatRegistrationNumber = "GB 980 7806 84".parse().unwrap();
+    assert_eq!(vat.as_str(), "980780684");
+    assert_eq!(vat.kind(), VatNumberKind::Standard);
+    assert_eq!(vat.branch(), None);
+    assert_eq!(vat.to_string(), "GB 980 7806 84");
+
+    let vat: VatRegistrationNumber = "gbgd001".parse().unwrap();
+    assert_eq!(vat.kind(), VatNumberKind::GovernmentDepartment);
+    assert_eq!(vat.to_string(), "GBGD001");
+
+    let vat: VatRegistrationNumber = "HA500".parse().unwrap();
+    assert_eq!(vat.kind(), VatNumberKind::HealthAuthority);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_vat_number() {
+    assert!(UkVatValidator::validator("GB980780684").is_ok());
+    assert!(UkVatValidator::validator("GB980780685").is_err());
+}
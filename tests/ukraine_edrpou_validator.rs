@@ -0,0 +1,53 @@
+use docval::ukraine::{Edrpou, UkraineEdrpouValidator};
+
+#[test]
+fn test_valid_edrpou() {
+    assert!(UkraineEdrpouValidator::is_valid("32855961").is_ok());
+    assert!(UkraineEdrpouValidator::is_valid("00032129").is_ok());
+    assert!(UkraineEdrpouValidator::is_valid("12345678").is_ok());
+    assert!(UkraineEdrpouValidator::is_valid("60000006").is_ok());
+    assert!(UkraineEdrpouValidator::is_valid("3285 5961").is_ok());
+}
+
+#[test]
+fn test_valid_edrpou_second_stage() {
+    assert!(UkraineEdrpouValidator::is_valid("10000062").is_ok());
+    assert!(UkraineEdrpouValidator::is_valid("10000291").is_ok());
+    assert!(UkraineEdrpouValidator::is_valid("30000005").is_ok());
+}
+
+#[test]
+fn test_invalid_edrpou() {
+    assert_eq!(UkraineEdrpouValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UkraineEdrpouValidator::is_valid("3285596"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkraineEdrpouValidator::is_valid("3285596A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkraineEdrpouValidator::is_valid("32855962"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkraineEdrpouValidator::is_valid("10000061"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_edrpou() {
+    let edrpou: Edrpou = "3285 5961".parse().unwrap();
+    assert_eq!(edrpou.as_str(), "32855961");
+    assert_eq!(edrpou.check_digit(), 1);
+    assert_eq!(edrpou.to_string(), "32855961");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_edrpou() {
+    assert!(UkraineEdrpouValidator::validator("32855961").is_ok());
+    assert!(UkraineEdrpouValidator::validator("32855962").is_err());
+}
@@ -0,0 +1,65 @@
+use docval::ukraine::{Rntrc, UkraineRntrcValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_rntrc() {
+    assert!(UkraineRntrcValidator::is_valid("1759013776").is_ok());
+    assert!(UkraineRntrcValidator::is_valid("3000000014").is_ok());
+    assert!(UkraineRntrcValidator::is_valid("1234567899").is_ok());
+    assert!(UkraineRntrcValidator::is_valid("0000100004").is_ok());
+    assert!(UkraineRntrcValidator::is_valid("17590 13776").is_ok());
+}
+
+#[test]
+fn test_invalid_rntrc() {
+    assert_eq!(UkraineRntrcValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UkraineRntrcValidator::is_valid("175901377"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UkraineRntrcValidator::is_valid("175901377A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UkraineRntrcValidator::is_valid("0000012340"),
+        Err("Invalid date")
+    );
+}
+
+#[test]
+fn test_invalid_rntrc_checksum() {
+    assert_eq!(
+        UkraineRntrcValidator::is_valid("1759013777"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UkraineRntrcValidator::is_valid("3000000015"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_rntrc() {
+    let rntrc: Rntrc = "17590 13776".parse().unwrap();
+    assert_eq!(rntrc.as_str(), "1759013776");
+    assert_eq!(rntrc.birth_year(), 1948);
+    assert_eq!(rntrc.birth_month(), 2);
+    assert_eq!(rntrc.birth_day(), 28);
+    assert_eq!(rntrc.sex(), Sex::Male);
+    assert_eq!(rntrc.check_digit(), 6);
+    assert_eq!(rntrc.to_string(), "1759013776");
+
+    let rntrc = Rntrc::parse("3000000020").unwrap();
+    assert_eq!(rntrc.birth_year(), 1982);
+    assert_eq!(rntrc.birth_month(), 2);
+    assert_eq!(rntrc.birth_day(), 19);
+    assert_eq!(rntrc.sex(), Sex::Female);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rntrc() {
+    assert!(UkraineRntrcValidator::validator("1759013776").is_ok());
+    assert!(UkraineRntrcValidator::validator("1759013777").is_err());
+}
@@ -0,0 +1,60 @@
+use docval::uruguay::{Cedula, UruguayCedulaValidator};
+
+#[test]
+fn test_valid_cedula() {
+    assert!(UruguayCedulaValidator::is_valid("1.234.567-2").is_ok());
+    assert!(UruguayCedulaValidator::is_valid("4.567.890-5").is_ok());
+    assert!(UruguayCedulaValidator::is_valid("34567894").is_ok());
+}
+
+#[test]
+fn test_valid_short_cedula() {
+    assert!(UruguayCedulaValidator::is_valid("123.456-1").is_ok());
+    assert!(UruguayCedulaValidator::is_valid("987.654-9").is_ok());
+    assert!(UruguayCedulaValidator::is_valid("9999997").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UruguayCedulaValidator::is_valid("1.234.567-3"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UruguayCedulaValidator::is_valid("123.456-2"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UruguayCedulaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UruguayCedulaValidator::is_valid("12.345"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        UruguayCedulaValidator::is_valid("12.345.678-9"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_cedula() {
+    let cedula: Cedula = "1.234.567-2".parse().unwrap();
+    assert_eq!(cedula.as_str(), "12345672");
+    assert_eq!(cedula.number(), 1234567);
+    assert_eq!(cedula.check_digit(), 2);
+    assert_eq!(cedula.to_string(), "1.234.567-2");
+
+    let cedula = Cedula::parse("1234561").unwrap();
+    assert_eq!(cedula.number(), 123456);
+    assert_eq!(cedula.to_string(), "123.456-1");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_cedula() {
+    assert!(UruguayCedulaValidator::validator("1.234.567-2").is_ok());
+    assert!(UruguayCedulaValidator::validator("1.234.567-0").is_err());
+}
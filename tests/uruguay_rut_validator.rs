@@ -0,0 +1,66 @@
+use docval::uruguay::{Rut, UruguayRutValidator};
+
+#[test]
+fn test_valid_rut() {
+    assert!(UruguayRutValidator::is_valid("21-100342-001-7").is_ok());
+    assert!(UruguayRutValidator::is_valid("211234560019").is_ok());
+    assert!(UruguayRutValidator::is_valid("01 234567 001 2").is_ok());
+    assert!(UruguayRutValidator::is_valid("112345000013").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UruguayRutValidator::is_valid("21-100342-001-8"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        UruguayRutValidator::is_valid("21-513890-001-0"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_structure() {
+    assert_eq!(
+        UruguayRutValidator::is_valid("22-100342-001-7"),
+        Err("Invalid office")
+    );
+    assert_eq!(
+        UruguayRutValidator::is_valid("00-100342-001-7"),
+        Err("Invalid office")
+    );
+    assert_eq!(
+        UruguayRutValidator::is_valid("21-000000-001-7"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UruguayRutValidator::is_valid("21-100342-002-7"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UruguayRutValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UruguayRutValidator::is_valid("21-100342-001"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rut() {
+    let rut: Rut = "211003420017".parse().unwrap();
+    assert_eq!(rut.as_str(), "211003420017");
+    assert_eq!(rut.office(), 21);
+    assert_eq!(rut.number(), "100342");
+    assert_eq!(rut.to_string(), "21-100342-001-7");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rut() {
+    assert!(UruguayRutValidator::validator("21-100342-001-7").is_ok());
+    assert!(UruguayRutValidator::validator("21-100342-001-6").is_err());
+}
@@ -0,0 +1,54 @@
+use docval::us::{DeaNumber, UsDeaValidator};
+
+#[test]
+fn test_valid_dea() {
+    assert!(UsDeaValidator::is_valid("AB1234563").is_ok());
+    assert!(UsDeaValidator::is_valid("fj4326511").is_ok());
+    assert!(UsDeaValidator::is_valid("M91234563").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UsDeaValidator::is_valid("AB1234564"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_registrant_type() {
+    assert_eq!(
+        UsDeaValidator::is_valid("IB1234563"),
+        Err("Invalid registrant type")
+    );
+    assert!(UsDeaValidator::is_valid("ZB1234563").is_err());
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(UsDeaValidator::is_valid("A11234563"), Err("Invalid format"));
+    assert_eq!(UsDeaValidator::is_valid("AB12345C3"), Err("Invalid format"));
+    assert_eq!(UsDeaValidator::is_valid("ABÇ234563"), Err("Invalid format"));
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UsDeaValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UsDeaValidator::is_valid("AB123456"), Err("Invalid length"));
+}
+
+#[test]
+fn test_parse_dea() {
+    let dea: DeaNumber = "m91234563".parse().unwrap();
+    assert_eq!(dea.as_str(), "M91234563");
+    assert_eq!(dea.registrant_type(), 'M');
+    assert_eq!(dea.initial(), '9');
+    assert_eq!(dea.to_string(), "M91234563");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_dea() {
+    assert!(UsDeaValidator::validator("AB1234563").is_ok());
+    assert!(UsDeaValidator::validator("AB1234560").is_err());
+}
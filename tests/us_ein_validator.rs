@@ -0,0 +1,69 @@
+use docval::us::{Ein, EinCampus, UsEinValidator};
+
+#[test]
+fn test_valid_ein() {
+    assert!(UsEinValidator::is_valid("12-3456789").is_ok());
+    assert!(UsEinValidator::is_valid("123456789").is_ok());
+    assert!(UsEinValidator::is_valid("99-0000001").is_ok());
+}
+
+#[test]
+fn test_invalid_prefix() {
+    for prefix in [
+        "00", "07", "08", "09", "17", "18", "19", "28", "29", "49", "69", "70", "78", "79", "89",
+        "96", "97",
+    ] {
+        assert_eq!(
+            UsEinValidator::is_valid(&format!("{}-3456789", prefix)),
+            Err("Invalid prefix"),
+            "{}",
+            prefix
+        );
+    }
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(UsEinValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UsEinValidator::is_valid("12345678"), Err("Invalid length"));
+    assert_eq!(
+        UsEinValidator::is_valid("123-456789"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UsEinValidator::is_valid("12-34567A9"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_parse_ein() {
+    let ein: Ein = "12-3456789".parse().unwrap();
+    assert_eq!(ein.as_str(), "123456789");
+    assert_eq!(ein.prefix(), 12);
+    assert_eq!(ein.campus(), EinCampus::Andover);
+    assert_eq!(ein.to_string(), "12-3456789");
+}
+
+#[test]
+fn test_campuses() {
+    assert_eq!(
+        Ein::parse("01-0000001").unwrap().campus(),
+        EinCampus::Brookhaven
+    );
+    assert_eq!(
+        Ein::parse("46-0000001").unwrap().campus(),
+        EinCampus::Internet
+    );
+    assert_eq!(
+        Ein::parse("94-0000001").unwrap().campus(),
+        EinCampus::Memphis
+    );
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ein() {
+    assert!(UsEinValidator::validator("12-3456789").is_ok());
+    assert!(UsEinValidator::validator("00-3456789").is_err());
+}
@@ -0,0 +1,74 @@
+use docval::us::{Itin, TinKind, UsItinValidator, UsSsnValidator, UsTinValidator};
+
+#[test]
+fn test_valid_itin() {
+    assert!(UsItinValidator::is_valid("912-70-1234").is_ok());
+    assert!(UsItinValidator::is_valid("900501234").is_ok());
+    assert!(UsItinValidator::is_valid("999-99-9999").is_ok());
+}
+
+#[test]
+fn test_invalid_group() {
+    for group in ["00", "49", "66", "69", "89", "93"] {
+        assert_eq!(
+            UsItinValidator::is_valid(&format!("912-{}-1234", group)),
+            Err("Invalid group number"),
+            "{}",
+            group
+        );
+    }
+}
+
+#[test]
+fn test_invalid_area() {
+    assert_eq!(
+        UsItinValidator::is_valid("123-70-1234"),
+        Err("Invalid area number")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(UsItinValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UsItinValidator::is_valid("91270123"), Err("Invalid length"));
+    assert_eq!(
+        UsItinValidator::is_valid("912 70 1234"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_itin_is_not_ssn() {
+    assert!(UsSsnValidator::is_valid("912-70-1234").is_err());
+}
+
+#[test]
+fn test_parse_itin() {
+    let itin: Itin = "912701234".parse().unwrap();
+    assert_eq!(itin.as_str(), "912701234");
+    assert_eq!(itin.masked(), "***-**-1234");
+    assert_eq!(itin.to_string(), "912-70-1234");
+}
+
+#[test]
+fn test_identify() {
+    assert_eq!(UsTinValidator::identify("123-45-6789"), Ok(TinKind::Ssn));
+    assert_eq!(UsTinValidator::identify("912701234"), Ok(TinKind::Itin));
+    assert_eq!(
+        UsTinValidator::identify("912-40-1234"),
+        Err("Invalid group number")
+    );
+    assert_eq!(
+        UsTinValidator::identify("666-45-6789"),
+        Err("Invalid area number")
+    );
+    assert!(UsTinValidator::is_valid("12345").is_err());
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_itin() {
+    assert!(UsItinValidator::validator("912-70-1234").is_ok());
+    assert!(UsItinValidator::validator("912-45-1234").is_err());
+    assert!(UsTinValidator::validator("123-45-6789").is_ok());
+}
@@ -0,0 +1,66 @@
+use docval::us::{Mbi, UsMbiValidator};
+
+#[test]
+fn test_valid_mbi() {
+    assert!(UsMbiValidator::is_valid("1EG4TE5MK73").is_ok());
+    assert!(UsMbiValidator::is_valid("1EG4-TE5-MK73").is_ok());
+    assert!(UsMbiValidator::is_valid("1eg4-te5-mk73").is_ok());
+    assert!(UsMbiValidator::is_valid("9A00A00AA00").is_ok());
+}
+
+#[test]
+fn test_excluded_letters() {
+    for letter in ['S', 'L', 'O', 'I', 'B', 'Z'] {
+        assert_eq!(
+            UsMbiValidator::is_valid(&format!("1{}G4TE5MK73", letter)),
+            Err("Invalid format"),
+            "{}",
+            letter
+        );
+    }
+}
+
+#[test]
+fn test_position_classes() {
+    assert!(UsMbiValidator::is_valid("0EG4TE5MK73").is_err());
+    assert!(UsMbiValidator::is_valid("11G4TE5MK73").is_err());
+    assert!(UsMbiValidator::is_valid("1EGATE5MK73").is_err());
+    assert!(UsMbiValidator::is_valid("1EG4TE5M173").is_err());
+    assert!(UsMbiValidator::is_valid("1EG4TE5MK7A").is_err());
+}
+
+#[test]
+fn test_invalid_dashes() {
+    assert_eq!(
+        UsMbiValidator::is_valid("1EG-4TE5-MK73"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UsMbiValidator::is_valid("1EG4-TE5MK73"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UsMbiValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UsMbiValidator::is_valid("1EG4TE5MK7"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_mbi() {
+    let mbi: Mbi = "1eg4-te5-mk73".parse().unwrap();
+    assert_eq!(mbi.as_str(), "1EG4TE5MK73");
+    assert_eq!(mbi.to_dashed(), "1EG4-TE5-MK73");
+    assert_eq!(mbi.to_string(), "1EG4TE5MK73");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_mbi() {
+    assert!(UsMbiValidator::validator("1EG4-TE5-MK73").is_ok());
+    assert!(UsMbiValidator::validator("1EG4-TE5-MK7O").is_err());
+}
@@ -0,0 +1,42 @@
+use docval::us::{Npi, UsNpiValidator};
+
+#[test]
+fn test_valid_npi() {
+    assert!(UsNpiValidator::is_valid("1234567893").is_ok());
+    assert!(UsNpiValidator::is_valid("1928374655").is_ok());
+    assert!(UsNpiValidator::is_valid("2000000002").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UsNpiValidator::is_valid("1234567890"),
+        Err("Invalid checksum")
+    );
+    assert!(UsNpiValidator::is_valid("1000000000").is_err());
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UsNpiValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UsNpiValidator::is_valid("123456789"), Err("Invalid length"));
+    assert_eq!(
+        UsNpiValidator::is_valid("808401234567893"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_npi() {
+    let npi: Npi = "1234567893".parse().unwrap();
+    assert_eq!(npi.as_str(), "1234567893");
+    assert_eq!(npi.as_card_number(), "808401234567893");
+    assert_eq!(npi.to_string(), "1234567893");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_npi() {
+    assert!(UsNpiValidator::validator("1234567893").is_ok());
+    assert!(UsNpiValidator::validator("1234567894").is_err());
+}
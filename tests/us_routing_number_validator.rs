@@ -0,0 +1,59 @@
+use docval::us::{RoutingNumber, UsRoutingNumberValidator};
+
+#[test]
+fn test_valid_routing_numbers() {
+    assert!(UsRoutingNumberValidator::is_valid("011000015").is_ok());
+    assert!(UsRoutingNumberValidator::is_valid("021000021").is_ok());
+    assert!(UsRoutingNumberValidator::is_valid("111000025").is_ok());
+    assert!(UsRoutingNumberValidator::is_valid("322271627").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        UsRoutingNumberValidator::is_valid("021000022"),
+        Err("Invalid checksum")
+    );
+    assert!(UsRoutingNumberValidator::is_valid("322271628").is_err());
+}
+
+#[test]
+fn test_invalid_prefix() {
+    assert_eq!(
+        UsRoutingNumberValidator::is_valid("131000000"),
+        Err("Invalid prefix")
+    );
+    assert_eq!(
+        UsRoutingNumberValidator::is_valid("500000000"),
+        Err("Invalid prefix")
+    );
+}
+
+#[test]
+fn test_invalid_length() {
+    assert_eq!(UsRoutingNumberValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        UsRoutingNumberValidator::is_valid("02100002"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_routing_number() {
+    let routing_number: RoutingNumber = "021000021".parse().unwrap();
+    assert_eq!(routing_number.as_str(), "021000021");
+    assert_eq!(routing_number.prefix(), 2);
+    assert_eq!(routing_number.federal_reserve_district(), Some(2));
+    assert_eq!(routing_number.institution_id(), "0002");
+    assert_eq!(routing_number.check_digit(), 1);
+
+    let routing_number = RoutingNumber::parse("322271627").unwrap();
+    assert_eq!(routing_number.federal_reserve_district(), Some(12));
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_routing_number() {
+    assert!(UsRoutingNumberValidator::validator("021000021").is_ok());
+    assert!(UsRoutingNumberValidator::validator("021000022").is_err());
+}
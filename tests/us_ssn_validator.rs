@@ -0,0 +1,77 @@
+use docval::us::{Ssn, UsSsnValidator};
+
+#[test]
+fn test_valid_ssn() {
+    assert!(UsSsnValidator::is_valid("123-45-6789").is_ok());
+    assert!(UsSsnValidator::is_valid("123456789").is_ok());
+    assert!(UsSsnValidator::is_valid("899-99-9999").is_ok());
+}
+
+#[test]
+fn test_invalid_area() {
+    assert_eq!(
+        UsSsnValidator::is_valid("000-45-6789"),
+        Err("Invalid area number")
+    );
+    assert_eq!(
+        UsSsnValidator::is_valid("666-45-6789"),
+        Err("Invalid area number")
+    );
+    assert_eq!(
+        UsSsnValidator::is_valid("900-45-6789"),
+        Err("Invalid area number")
+    );
+}
+
+#[test]
+fn test_invalid_group_and_serial() {
+    assert_eq!(
+        UsSsnValidator::is_valid("123-00-6789"),
+        Err("Invalid group number")
+    );
+    assert_eq!(
+        UsSsnValidator::is_valid("123-45-0000"),
+        Err("Invalid serial number")
+    );
+}
+
+#[test]
+fn test_voided_numbers() {
+    assert_eq!(
+        UsSsnValidator::is_valid("078-05-1120"),
+        Err("Voided number")
+    );
+    assert!(UsSsnValidator::is_valid("219099999").is_err());
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(UsSsnValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(UsSsnValidator::is_valid("12345678"), Err("Invalid length"));
+    assert_eq!(
+        UsSsnValidator::is_valid("123 45 6789"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        UsSsnValidator::is_valid("12-345-6789"),
+        Err("Invalid format")
+    );
+}
+
+#[test]
+fn test_parse_ssn() {
+    let ssn: Ssn = "123-45-6789".parse().unwrap();
+    assert_eq!(ssn.as_str(), "123456789");
+    assert_eq!(ssn.area(), "123");
+    assert_eq!(ssn.group(), "45");
+    assert_eq!(ssn.serial(), "6789");
+    assert_eq!(ssn.masked(), "***-**-6789");
+    assert_eq!(ssn.to_string(), "123-45-6789");
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_ssn() {
+    assert!(UsSsnValidator::validator("123-45-6789").is_ok());
+    assert!(UsSsnValidator::validator("123-45-0000").is_err());
+}
@@ -0,0 +1,73 @@
+use docval::venezuela::{Rif, RifCategory, VenezuelaRifValidator};
+
+#[test]
+fn test_valid_rif() {
+    assert!(VenezuelaRifValidator::is_valid("V-12345678-1").is_ok());
+    assert!(VenezuelaRifValidator::is_valid("E-81234567-5").is_ok());
+    assert!(VenezuelaRifValidator::is_valid("J-00012345-4").is_ok());
+    assert!(VenezuelaRifValidator::is_valid("P123456780").is_ok());
+    assert!(VenezuelaRifValidator::is_valid("g-20000150-0").is_ok());
+}
+
+#[test]
+fn test_invalid_checksum() {
+    assert_eq!(
+        VenezuelaRifValidator::is_valid("V-12345678-2"),
+        Err("Invalid checksum")
+    );
+    assert_eq!(
+        VenezuelaRifValidator::is_valid("E-12345678-1"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_invalid_category() {
+    assert_eq!(
+        VenezuelaRifValidator::is_valid("X-12345678-1"),
+        Err("Invalid category")
+    );
+    assert_eq!(
+        VenezuelaRifValidator::is_valid("1123456781"),
+        Err("Invalid category")
+    );
+}
+
+#[test]
+fn test_invalid_format() {
+    assert_eq!(
+        VenezuelaRifValidator::is_valid("V-1234567A-1"),
+        Err("Invalid format")
+    );
+    assert_eq!(VenezuelaRifValidator::is_valid(""), Err("Invalid input"));
+    assert_eq!(
+        VenezuelaRifValidator::is_valid("V-1234567-1"),
+        Err("Invalid length")
+    );
+}
+
+#[test]
+fn test_parse_rif() {
+    let rif: Rif = "j000123454".parse().unwrap();
+    assert_eq!(rif.as_str(), "J000123454");
+    assert_eq!(rif.category(), RifCategory::LegalEntity);
+    assert_eq!(rif.number(), "00012345");
+    assert_eq!(rif.to_string(), "J-00012345-4");
+}
+
+#[test]
+fn test_category_letters() {
+    for letter in ['V', 'E', 'J', 'P', 'G'] {
+        let category = RifCategory::from_letter(letter).unwrap();
+        assert_eq!(category.letter(), letter);
+    }
+    assert_eq!(RifCategory::from_letter('g'), Some(RifCategory::Government));
+    assert_eq!(RifCategory::from_letter('C'), None);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_rif() {
+    assert!(VenezuelaRifValidator::validator("V-12345678-1").is_ok());
+    assert!(VenezuelaRifValidator::validator("V-12345678-0").is_err());
+}
@@ -0,0 +1,116 @@
+use docval::western_balkans::{Jmbg, JmbgCountry, WesternBalkansJmbgValidator};
+use docval::Sex;
+
+#[test]
+fn test_valid_jmbg() {
+    assert!(WesternBalkansJmbgValidator::is_valid("0101990710008").is_ok());
+    assert!(WesternBalkansJmbgValidator::is_valid("1505985175002").is_ok());
+    assert!(WesternBalkansJmbgValidator::is_valid("3103982451234").is_ok());
+    assert!(WesternBalkansJmbgValidator::is_valid("2007857210044").is_ok());
+    assert!(WesternBalkansJmbgValidator::is_valid("0101 990 80 1232").is_ok());
+}
+
+#[test]
+fn test_valid_jmbg_any_region() {
+    assert!(WesternBalkansJmbgValidator::is_valid("0101990500127").is_ok());
+    assert!(WesternBalkansJmbgValidator::is_valid("0101990012343").is_ok());
+}
+
+#[test]
+fn test_invalid_jmbg() {
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid(""),
+        Err("Invalid input")
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid("010199071000"),
+        Err("Invalid length")
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid("010199071000A"),
+        Err("Invalid format")
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid("3002990710008"),
+        Err("Invalid date")
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid("0101990710009"),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_jmbg_for_country() {
+    assert!(
+        WesternBalkansJmbgValidator::is_valid_for("0101990710008", JmbgCountry::Serbia).is_ok()
+    );
+    assert!(
+        WesternBalkansJmbgValidator::is_valid_for("0101990950017", JmbgCountry::Serbia).is_ok()
+    );
+    assert!(WesternBalkansJmbgValidator::is_valid_for(
+        "1505985175002",
+        JmbgCountry::BosniaAndHerzegovina
+    )
+    .is_ok());
+    assert!(WesternBalkansJmbgValidator::is_valid_for(
+        "3103982451234",
+        JmbgCountry::NorthMacedonia
+    )
+    .is_ok());
+    assert!(
+        WesternBalkansJmbgValidator::is_valid_for("2007857210044", JmbgCountry::Montenegro).is_ok()
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid_for("0101990710008", JmbgCountry::Montenegro),
+        Err("Invalid region")
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid_for("0101990500127", JmbgCountry::Serbia),
+        Err("Invalid region")
+    );
+    assert_eq!(
+        WesternBalkansJmbgValidator::is_valid_for("0101990710009", JmbgCountry::Serbia),
+        Err("Invalid checksum")
+    );
+}
+
+#[test]
+fn test_parse_jmbg() {
+    let jmbg: Jmbg = "3103982451234".parse().unwrap();
+    assert_eq!(jmbg.as_str(), "3103982451234");
+    assert_eq!(jmbg.birth_year(), 1982);
+    assert_eq!(jmbg.birth_month(), 3);
+    assert_eq!(jmbg.birth_day(), 31);
+    assert_eq!(jmbg.region_code(), 45);
+    assert_eq!(jmbg.country(), Some(JmbgCountry::NorthMacedonia));
+    assert_eq!(jmbg.serial(), 123);
+    assert_eq!(jmbg.sex(), Sex::Male);
+    assert_eq!(jmbg.check_digit(), 4);
+    assert_eq!(jmbg.to_string(), "3103982451234");
+
+    let jmbg = Jmbg::parse("0101990500127").unwrap();
+    assert_eq!(jmbg.country(), None);
+
+    assert!(Jmbg::parse_for("2007857210044", JmbgCountry::Montenegro).is_ok());
+    assert!(Jmbg::parse_for("2007857210044", JmbgCountry::Serbia).is_err());
+}
+
+#[test]
+fn test_jmbg_country_from_region_code() {
+    assert_eq!(
+        JmbgCountry::from_region_code(17),
+        Some(JmbgCountry::BosniaAndHerzegovina)
+    );
+    assert_eq!(JmbgCountry::from_region_code(80), Some(JmbgCountry::Serbia));
+    assert_eq!(JmbgCountry::from_region_code(35), None);
+    assert_eq!(JmbgCountry::from_region_code(40), None);
+    assert_eq!(JmbgCountry::Serbia.region_codes(), 70..=99);
+}
+
+#[cfg(feature = "validator-integration")]
+#[test]
+fn test_validate_jmbg() {
+    assert!(WesternBalkansJmbgValidator::validator("0101990710008").is_ok());
+    assert!(WesternBalkansJmbgValidator::validator("0101990710009").is_err());
+}